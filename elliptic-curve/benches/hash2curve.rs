@@ -0,0 +1,61 @@
+//! Benchmarks comparing `expand_message_xmd` with a prepared domain
+//! separation tag against the one-shot API.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use elliptic_curve::hash2curve::{ExpandMsg, ExpandMsgPrepared, ExpandMsgXmd, Expander};
+use sha2::Sha256;
+
+type Xmd = ExpandMsgXmd<Sha256>;
+
+/// Message lengths to measure, which are short enough for the DST handling
+/// to be a significant part of the cost.
+const MSG_LENS: [usize; 3] = [0, 16, 64];
+
+/// Number of bytes to expand each message to.
+const LEN_IN_BYTES: usize = 48;
+
+fn bench_dst(c: &mut Criterion, name: &str, dst: &[u8]) {
+    let dsts = [dst];
+    let prepared = Xmd::prepare_dst(&dsts).unwrap();
+    let mut group = c.benchmark_group(name);
+
+    for len in MSG_LENS {
+        let msg = vec![0x5a; len];
+        let mut okm = [0; LEN_IN_BYTES];
+
+        group.bench_function(BenchmarkId::new("one-shot", len), |b| {
+            b.iter(|| {
+                Xmd::expand_message(&[black_box(&msg)], &dsts, LEN_IN_BYTES)
+                    .unwrap()
+                    .fill_bytes(&mut okm)
+            })
+        });
+
+        group.bench_function(BenchmarkId::new("prepared", len), |b| {
+            b.iter(|| {
+                Xmd::expand_message_prepared(&[black_box(&msg)], &prepared, LEN_IN_BYTES)
+                    .unwrap()
+                    .fill_bytes(&mut okm)
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn expand_message_xmd(c: &mut Criterion) {
+    bench_dst(
+        c,
+        "expand_message_xmd",
+        b"QUUX-V01-CS02-with-expander-SHA256-128",
+    );
+}
+
+/// DSTs longer than 255 bytes are hashed first, which the prepared DST only
+/// does once.
+fn expand_message_xmd_oversize_dst(c: &mut Criterion) {
+    bench_dst(c, "expand_message_xmd_oversize_dst", &[0x42; 300]);
+}
+
+criterion_group!(benches, expand_message_xmd, expand_message_xmd_oversize_dst);
+criterion_main!(benches);
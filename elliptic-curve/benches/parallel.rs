@@ -0,0 +1,72 @@
+//! Benchmarks for the parallel batch operations enabled by the `rayon` feature.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use elliptic_curve::{
+    dev::{AffinePoint, MockCurve, ProjectivePoint, Scalar},
+    ff::Field,
+    group::{Curve, Group},
+    ops::{batch_invert_par, MultiScalarMul},
+    point::ToAffineBatch,
+    rayon::ThreadPoolBuilder,
+};
+use rand_core::OsRng;
+use std::vec::Vec;
+
+/// Thread counts to measure, to show how the parallel versions scale.
+const THREADS: [usize; 4] = [1, 2, 4, 8];
+
+/// Minimum number of elements per parallel chunk.
+const THRESHOLD: usize = 64;
+
+fn bench_with_threads(c: &mut Criterion, name: &str, f: impl Fn() + Sync) {
+    let mut group = c.benchmark_group(name);
+
+    for threads in THREADS {
+        let pool = ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .unwrap();
+
+        group.bench_function(BenchmarkId::from_parameter(threads), |b| {
+            b.iter(|| pool.install(&f))
+        });
+    }
+
+    group.finish();
+}
+
+fn to_affine_batch_par(c: &mut Criterion) {
+    let points = (0..4096)
+        .map(|_| ProjectivePoint::random(&mut OsRng))
+        .collect::<Vec<_>>();
+
+    bench_with_threads(c, "to_affine_batch_par", || {
+        MockCurve::to_affine_batch_par::<Vec<_>>(&points, THRESHOLD);
+    });
+}
+
+fn msm_vartime_par(c: &mut Criterion) {
+    let points = (0..1024)
+        .map(|_| ProjectivePoint::random(&mut OsRng).to_affine())
+        .collect::<Vec<AffinePoint>>();
+    let scalars = (0..1024)
+        .map(|_| Scalar::random(&mut OsRng))
+        .collect::<Vec<_>>();
+
+    bench_with_threads(c, "msm_vartime_par", || {
+        MockCurve::msm_vartime_par(&points, &scalars, THRESHOLD);
+    });
+}
+
+fn batch_invert(c: &mut Criterion) {
+    let scalars = (0..4096)
+        .map(|_| Scalar::random(&mut OsRng))
+        .collect::<Vec<_>>();
+
+    bench_with_threads(c, "batch_invert_par", || {
+        batch_invert_par(&scalars, THRESHOLD).unwrap();
+    });
+}
+
+criterion_group!(benches, to_affine_batch_par, msm_vartime_par, batch_invert);
+criterion_main!(benches);
@@ -1,32 +1,126 @@
 //! Elliptic curve arithmetic traits.
 
 use crate::{
-    ops::{Invert, LinearCombination, MulByGenerator, Reduce, ShrAssign},
-    point::AffineCoordinates,
-    scalar::{FromUintUnchecked, IsHigh},
-    Curve, FieldBytes, PrimeCurve, ScalarPrimitive,
+    ops::{sum_tree_mixed, AddMixed, Invert, LinearCombination, MulByGenerator, Reduce, ShrAssign},
+    point::{AffineCoordinates, EdwardsCoordinates},
+    scalar::{CanonicalUint, FromUintUnchecked, IsHigh},
+    Curve, EdwardsCurve, EdwardsPointBytes, FieldBytes, FieldBytesEncoding, FieldBytesSize,
+    PrimeCurve, ScalarPrimitive,
+};
+use core::{
+    fmt::Debug,
+    iter::Sum,
+    ops::{Add, Neg, Sub},
+};
+use crypto_bigint::Limb;
+use ff::Field;
+use generic_array::typenum::Unsigned;
+use group::GroupEncoding;
+use rand_core::CryptoRngCore;
+use subtle::{
+    Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq, ConstantTimeLess,
+    CtOption,
 };
-use core::fmt::Debug;
-use subtle::{ConditionallySelectable, ConstantTimeEq, CtOption};
 use zeroize::DefaultIsZeroes;
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "sec1")]
+use crate::sec1::{CompressedPoint, ModulusSize, ToEncodedPoint};
+
+/// Elliptic curve with an arithmetic implementation of its scalar field.
+///
+/// This is sufficient for code which only operates on scalars, e.g. secret
+/// sharing, without requiring the curve's point types.
+pub trait ScalarArithmetic: Curve {
+    /// Scalar field modulo this curve's order.
+    ///
+    /// Note: the following bounds are provided by [`ff::Field`]:
+    /// - `'static`
+    /// - [`Copy`]
+    /// - [`Clone`]
+    /// - [`ConditionallySelectable`]
+    /// - [`ConstantTimeEq`]
+    /// - [`Debug`]
+    /// - [`Default`]
+    /// - [`Send`]
+    /// - [`Sync`]
+    /// - [`Sum`](core::iter::Sum) and [`Product`](core::iter::Product), both by
+    ///   value and by reference
+    /// - `for<'a> Add<&'a Self>`, `Sub<&'a Self>`, `Mul<&'a Self>` and their
+    ///   `*Assign` forms
+    ///
+    /// Implementations should override [`Field::square`] with a dedicated
+    /// squaring formula, which is faster than multiplying a scalar by itself.
+    ///
+    /// Note: the [`PartialOrd`] bound is not guaranteed to be constant-time.
+    /// Use [`ScalarPrimitive::ct_cmp`] or the [`subtle`] comparison traits
+    /// impl'd on [`ScalarPrimitive`] when comparing secret values.
+    type Scalar: AsRef<Self::Scalar>
+        + DefaultIsZeroes
+        + From<ScalarPrimitive<Self>>
+        + CanonicalUint<Uint = Self::Uint>
+        + FromUintUnchecked<Uint = Self::Uint>
+        + Into<FieldBytes<Self>>
+        + Into<ScalarPrimitive<Self>>
+        + Into<Self::Uint>
+        + Invert<Output = CtOption<Self::Scalar>>
+        + IsHigh
+        + PartialOrd
+        + Reduce<Self::Uint, Bytes = FieldBytes<Self>>
+        + ShrAssign<usize>
+        + Field
+        + ff::PrimeField<Repr = FieldBytes<Self>>;
+
+    /// Parse a scalar from its big endian encoding, either rejecting
+    /// (`STRICT = true`) or reducing (`STRICT = false`) values which are not
+    /// less than the order.
+    ///
+    /// With `STRICT = false` the result is always `Some`.
+    fn scalar_from_bytes<const STRICT: bool>(bytes: &FieldBytes<Self>) -> CtOption<Self::Scalar> {
+        if STRICT {
+            ff::PrimeField::from_repr(bytes.clone())
+        } else {
+            CtOption::new(Self::Scalar::reduce_bytes(bytes), Choice::from(1))
+        }
+    }
+}
+
 /// Elliptic curve with an arithmetic implementation.
-pub trait CurveArithmetic: Curve {
+///
+/// The scalar field is provided by [`ScalarArithmetic`].
+pub trait CurveArithmetic: ScalarArithmetic {
     /// Elliptic curve point in affine coordinates.
+    ///
+    /// Adding or subtracting two affine points yields a projective point,
+    /// which implementations should compute with mixed addition. Mixed
+    /// arithmetic with a projective point on the left is provided by
+    /// [`group::Curve`], see [`CurveArithmetic::ProjectivePoint`].
     type AffinePoint: 'static
         + AffineCoordinates<FieldRepr = FieldBytes<Self>>
         + Copy
+        + ConditionallyNegatable
         + ConditionallySelectable
         + ConstantTimeEq
         + Debug
         + Default
         + DefaultIsZeroes
         + Eq
+        + GroupEncoding
+        + Neg<Output = Self::AffinePoint>
+        + Add<Output = Self::ProjectivePoint>
+        + for<'a> Add<&'a Self::AffinePoint, Output = Self::ProjectivePoint>
+        + Sub<Output = Self::ProjectivePoint>
+        + for<'a> Sub<&'a Self::AffinePoint, Output = Self::ProjectivePoint>
         + PartialEq
         + Sized
         + Send
         + Sync;
 
+    /// Base field element, i.e. the field the point coordinates belong to.
+    type FieldElement: ff::PrimeField<Repr = FieldBytes<Self>>;
+
     /// Elliptic curve point in projective coordinates.
     ///
     /// Note: the following bounds are provided by [`group::Group`]:
@@ -38,7 +132,24 @@ pub trait CurveArithmetic: Curve {
     /// - [`Sized`]
     /// - [`Send`]
     /// - [`Sync`]
-    type ProjectivePoint: ConditionallySelectable
+    ///
+    /// The following reference-taking operations are provided by
+    /// [`group::GroupOpsOwned`] and [`group::ScalarMulOwned`]:
+    /// - `for<'a> Add<&'a Self>` / `AddAssign<&'a Self>`
+    /// - `for<'a> Sub<&'a Self>` / `SubAssign<&'a Self>`
+    /// - `Add<Self::AffinePoint>` / `AddAssign<Self::AffinePoint>`, by value
+    ///   and by reference
+    /// - `Sub<Self::AffinePoint>` / `SubAssign<Self::AffinePoint>`, by value
+    ///   and by reference
+    /// - `for<'a> Mul<&'a Self::Scalar>` / `MulAssign<&'a Self::Scalar>`
+    /// - [`Sum`] and `for<'a> Sum<&'a Self>`
+    ///
+    /// Summation of affine points via [`Sum<Self::AffinePoint>`] should use
+    /// mixed addition when the implementation provides it. Summing an empty
+    /// iterator yields the identity.
+    type ProjectivePoint: AddMixed<Self::AffinePoint>
+        + ConditionallyNegatable
+        + ConditionallySelectable
         + ConstantTimeEq
         + Default
         + DefaultIsZeroes
@@ -46,35 +157,287 @@ pub trait CurveArithmetic: Curve {
         + Into<Self::AffinePoint>
         + LinearCombination
         + MulByGenerator
+        + Sum<Self::AffinePoint>
+        + for<'a> Sum<&'a Self::AffinePoint>
+        + GroupEncoding<Repr = <Self::AffinePoint as GroupEncoding>::Repr>
         + group::Curve<AffineRepr = Self::AffinePoint>
         + group::Group<Scalar = Self::Scalar>;
 
-    /// Scalar field modulo this curve's order.
+    /// Fixed-length compressed encoding of a point, e.g. a SEC1
+    /// [`CompressedPoint`](crate::sec1::CompressedPoint) of
+    /// `1 + FieldBytes` bytes for short Weierstrass curves.
+    type CompressedPoint: AsRef<[u8]> + AsMut<[u8]> + Copy + Debug + Default + Eq;
+
+    /// Fixed-length uncompressed encoding of a point, e.g. a SEC1
+    /// [`UncompressedPoint`](crate::sec1::UncompressedPoint) of
+    /// `1 + 2 * FieldBytes` bytes for short Weierstrass curves.
+    type UncompressedPoint: AsRef<[u8]> + AsMut<[u8]> + Copy + Debug + Default + Eq;
+
+    /// Coefficient `a` in the curve equation `y² = x³ + ax + b`.
+    const EQUATION_A: Self::FieldElement;
+
+    /// Coefficient `b` in the curve equation `y² = x³ + ax + b`.
+    const EQUATION_B: Self::FieldElement;
+
+    /// Modulus `p` of the base field, i.e. [`ff::PrimeField::MODULUS`] for
+    /// [`CurveArithmetic::FieldElement`] as an integer.
     ///
-    /// Note: the following bounds are provided by [`ff::Field`]:
-    /// - `'static`
-    /// - [`Copy`]
-    /// - [`Clone`]
-    /// - [`ConditionallySelectable`]
-    /// - [`ConstantTimeEq`]
-    /// - [`Debug`]
-    /// - [`Default`]
-    /// - [`Send`]
-    /// - [`Sync`]
-    type Scalar: AsRef<Self::Scalar>
-        + DefaultIsZeroes
-        + From<ScalarPrimitive<Self>>
-        + FromUintUnchecked<Uint = Self::Uint>
-        + Into<FieldBytes<Self>>
-        + Into<ScalarPrimitive<Self>>
-        + Into<Self::Uint>
-        + Invert<Output = CtOption<Self::Scalar>>
-        + IsHigh
-        + PartialOrd
-        + Reduce<Self::Uint, Bytes = FieldBytes<Self>>
-        + ShrAssign<usize>
-        + ff::Field
-        + ff::PrimeField<Repr = FieldBytes<Self>>;
+    /// The order of the scalar field is [`Curve::ORDER`].
+    const FIELD_MODULUS: Self::Uint;
+
+    /// Bit length of the curve's order, i.e. [`ff::PrimeField::NUM_BITS`] for
+    /// [`ScalarArithmetic::Scalar`].
+    const ORDER_BITS: u32 = <Self::Scalar as ff::PrimeField>::NUM_BITS;
+
+    /// Size of a serialized field element in bytes, i.e. the length of
+    /// [`FieldBytes`].
+    const FIELD_BYTES_SIZE: usize = <FieldBytesSize<Self> as Unsigned>::USIZE;
+
+    /// Cofactor of the curve, i.e. the order of the curve group divided by
+    /// [`Curve::ORDER`].
+    ///
+    /// This is `1` for prime order curves, and must not be zero. For curves
+    /// with a cofactor it drives [`CofactorArithmetic::clear_cofactor`].
+    const COFACTOR: u64 = 1;
+
+    /// Get the affine x-coordinate of the given point as a base field element.
+    ///
+    /// Returns `None` if the serialized coordinate is not canonical.
+    fn affine_x(point: &Self::AffinePoint) -> CtOption<Self::FieldElement> {
+        ff::PrimeField::from_repr(point.x())
+    }
+
+    /// Get the affine y-coordinate of the given point as a base field element.
+    ///
+    /// Returns `None` if the serialized coordinate is not canonical.
+    fn affine_y(point: &Self::AffinePoint) -> CtOption<Self::FieldElement> {
+        ff::PrimeField::from_repr(point.y())
+    }
+
+    /// Reduce the affine x-coordinate of the given point modulo the curve's
+    /// order, e.g. to compute an ECDSA `r` value.
+    ///
+    /// The identity has no x-coordinate: this returns the reduction of
+    /// whatever [`AffineCoordinates::x`] yields for it, which is zero for
+    /// curves serializing it as all-zeroes. Callers should reject it first.
+    fn x_reduced_to_scalar(point: &Self::AffinePoint) -> Self::Scalar {
+        Self::Scalar::reduce_bytes(&point.x())
+    }
+
+    /// Is the affine x-coordinate of the given point, as an integer, greater
+    /// than or equal to the curve's order?
+    ///
+    /// This is the overflow bit of an ECDSA recovery ID, i.e. whether
+    /// [`CurveArithmetic::x_reduced_to_scalar`] lost information.
+    fn x_overflows_order(point: &Self::AffinePoint) -> Choice {
+        !Self::Uint::decode_field_bytes(&point.x()).ct_lt(&Self::ORDER)
+    }
+
+    /// Does the given affine point satisfy the curve equation
+    /// `y² = x³ + ax + b`?
+    ///
+    /// The identity is considered to be on the curve, while points whose
+    /// coordinates aren't canonical field elements are not.
+    fn is_on_curve(point: &Self::AffinePoint) -> Choice {
+        let residual = curve_equation_residual::<Self>(point);
+        let is_zero = residual.map(|r| r.is_zero()).unwrap_or(Choice::from(0));
+        Self::is_identity(&(*point).into()) | is_zero
+    }
+
+    /// Check whether all of the given affine points are on the curve, using
+    /// [`CurveArithmetic::is_on_curve`] for each of them.
+    fn are_on_curve_strict(points: &[Self::AffinePoint]) -> Choice {
+        points
+            .iter()
+            .fold(Choice::from(1), |acc, point| acc & Self::is_on_curve(point))
+    }
+
+    /// Probabilistically check whether all of the given affine points are on
+    /// the curve, by checking that a random linear combination of their
+    /// curve equation residuals `y² - x³ - ax - b` is zero.
+    ///
+    /// This only needs a single comparison for the whole batch. A batch
+    /// containing an off-curve point passes with probability at most `1/p`
+    /// over the choice of weights from `rng`, so the weights must be
+    /// unpredictable to whoever chose the points. Use
+    /// [`CurveArithmetic::are_on_curve_strict`] when that can't be ensured.
+    fn are_on_curve_batch(points: &[Self::AffinePoint], rng: &mut impl CryptoRngCore) -> Choice {
+        let mut acc = Self::FieldElement::ZERO;
+        let mut is_canonical = Choice::from(1);
+
+        for point in points {
+            let residual = curve_equation_residual::<Self>(point);
+            let is_identity = Self::is_identity(&(*point).into());
+            is_canonical &= residual.is_some() | is_identity;
+
+            let residual = Self::FieldElement::conditional_select(
+                &residual.unwrap_or(Self::FieldElement::ZERO),
+                &Self::FieldElement::ZERO,
+                is_identity,
+            );
+            acc += residual * Self::FieldElement::random(&mut *rng);
+        }
+
+        is_canonical & acc.is_zero()
+    }
+
+    /// Add two affine points, returning the sum in projective coordinates.
+    ///
+    /// The provided impl lifts only `lhs` and uses mixed addition, which is
+    /// cheaper than lifting both points. Either point may be the identity.
+    fn add_affine(lhs: &Self::AffinePoint, rhs: &Self::AffinePoint) -> Self::ProjectivePoint {
+        Self::ProjectivePoint::from(*lhs).add_mixed(rhs)
+    }
+
+    /// Tweak a point additively, i.e. compute `point + G * tweak` where `G`
+    /// is the generator, matching [`AddTweak::add_tweak`] on the secret scalar.
+    ///
+    /// [`AddTweak::add_tweak`]: crate::scalar::AddTweak::add_tweak
+    ///
+    /// Returns `None` if `tweak` is zero or the result is the identity.
+    fn add_tweak_point(
+        point: &Self::AffinePoint,
+        tweak: &Self::Scalar,
+    ) -> CtOption<Self::ProjectivePoint> {
+        let tweaked = Self::ProjectivePoint::mul_by_generator(tweak).add_mixed(point);
+        CtOption::new(
+            tweaked,
+            !Field::is_zero(tweak) & !Self::is_identity(&tweaked),
+        )
+    }
+
+    /// Sum a slice of affine points, returning the result in projective
+    /// coordinates.
+    ///
+    /// The provided impl is [`sum_tree_mixed`], which adds pairs of affine
+    /// points with [`AddMixed::add_mixed`] and reduces the partial sums as a
+    /// balanced tree. An empty slice yields the identity.
+    fn sum_affine(points: &[Self::AffinePoint]) -> Self::ProjectivePoint {
+        sum_tree_mixed(points.iter().copied())
+    }
+
+    /// Double each point in a fixed-size array.
+    ///
+    /// Curves can override this to share field work across the batch.
+    fn double_batch<const N: usize>(
+        points: &[Self::ProjectivePoint; N],
+    ) -> [Self::ProjectivePoint; N] {
+        points.map(|point| group::Group::double(&point))
+    }
+
+    /// Double each point in a slice.
+    ///
+    /// Curves can override this to share field work across the batch.
+    #[cfg(feature = "alloc")]
+    fn double_batch_slice(points: &[Self::ProjectivePoint]) -> Vec<Self::ProjectivePoint> {
+        points.iter().map(group::Group::double).collect()
+    }
+
+    /// Is the given scalar a quadratic residue modulo the curve's order?
+    ///
+    /// Uses Euler's criterion, i.e. checks `scalar^((n - 1) / 2) == 1`, which
+    /// is cheaper than [`Field::sqrt`] when the root itself isn't needed.
+    /// Zero is considered a residue, consistent with `sqrt`.
+    ///
+    /// This runs in constant time with respect to `scalar`.
+    fn is_quadratic_residue(scalar: &Self::Scalar) -> Choice {
+        let one = <Self::Scalar as Field>::ONE;
+        let mut acc = one;
+
+        // `ORDER` is odd, so `(n - 1) / 2` is `n >> 1`
+        for limb in (Self::ORDER >> 1).as_ref().iter().rev() {
+            for i in (0..Limb::BITS).rev() {
+                acc = Field::square(&acc);
+
+                if (limb.0 >> i) & 1 == 1 {
+                    acc *= scalar;
+                }
+            }
+        }
+
+        acc.ct_eq(&one) | Field::is_zero(scalar)
+    }
+
+    /// Is the given point the identity, i.e. the point at infinity?
+    ///
+    /// Equivalent to [`group::Group::is_identity`], which the provided impl
+    /// calls, so generic code doesn't need to import `group`.
+    fn is_identity(point: &Self::ProjectivePoint) -> Choice {
+        group::Group::is_identity(point)
+    }
+
+    /// Is the given point in the prime order subgroup, i.e. free of any
+    /// small-order torsion component?
+    ///
+    /// The provided impl always returns true, which is correct for prime order
+    /// curves. Curves with a cofactor must override it (see
+    /// [`CofactorArithmetic`]) so that e.g. [`PublicKey`](crate::PublicKey)
+    /// rejects small-subgroup points.
+    #[allow(unused_variables)]
+    fn is_torsion_free(point: &Self::ProjectivePoint) -> Choice {
+        Choice::from(1)
+    }
+
+    /// Multiply a point by a scalar and serialize the product as a SEC1
+    /// compressed point.
+    ///
+    /// The identity has no compressed encoding and is serialized as all-zeroes,
+    /// matching [`ToCompressedPoint`](crate::sec1::ToCompressedPoint).
+    #[cfg(feature = "sec1")]
+    fn mul_compressed(point: &Self::AffinePoint, scalar: &Self::Scalar) -> Self::CompressedPoint
+    where
+        Self::AffinePoint: ToEncodedPoint<Self>,
+        Self::CompressedPoint: From<CompressedPoint<Self>>,
+        FieldBytesSize<Self>: ModulusSize,
+    {
+        let product = Self::ProjectivePoint::from(*point) * scalar;
+        crate::sec1::compress(&group::Curve::to_affine(&product)).into()
+    }
+}
+
+/// Elliptic curve whose group order is a cofactor multiple of [`Curve::ORDER`].
+///
+/// Implementations must also override [`CurveArithmetic::is_torsion_free`],
+/// e.g. by checking [`CofactorArithmetic::mul_by_order`] yields the identity.
+pub trait CofactorArithmetic: CurveArithmetic {
+    /// Map a point into the prime order subgroup by multiplying it by
+    /// [`CurveArithmetic::COFACTOR`].
+    ///
+    /// The provided impl uses double-and-add over the bits of the (public)
+    /// cofactor rather than a full scalar multiplication.
+    fn clear_cofactor(point: &Self::ProjectivePoint) -> Self::ProjectivePoint {
+        let mut acc = <Self::ProjectivePoint as group::Group>::identity();
+
+        for i in (0..u64::BITS - Self::COFACTOR.leading_zeros()).rev() {
+            acc = group::Group::double(&acc);
+
+            if (Self::COFACTOR >> i) & 1 == 1 {
+                acc += point;
+            }
+        }
+
+        acc
+    }
+
+    /// Multiply a point by [`Curve::ORDER`].
+    ///
+    /// The result is the identity if and only if the point is torsion free.
+    /// This runs in time which depends only on the (public) curve order.
+    fn mul_by_order(point: &Self::ProjectivePoint) -> Self::ProjectivePoint {
+        let mut acc = <Self::ProjectivePoint as group::Group>::identity();
+
+        for limb in Self::ORDER.as_ref().iter().rev() {
+            for i in (0..Limb::BITS).rev() {
+                acc = group::Group::double(&acc);
+
+                if (limb.0 >> i) & 1 == 1 {
+                    acc += point;
+                }
+            }
+        }
+
+        acc
+    }
 }
 
 /// Prime order elliptic curve with projective arithmetic implementation.
@@ -84,3 +447,162 @@ pub trait PrimeCurveArithmetic:
     /// Prime order elliptic curve group.
     type CurveGroup: group::prime::PrimeCurve<Affine = <Self as CurveArithmetic>::AffinePoint>;
 }
+
+/// Twisted Edwards curve with an arithmetic implementation.
+///
+/// Points are serialized via [`group::GroupEncoding`] using the compressed
+/// encoding, and the cofactor is handled via [`CofactorArithmetic`].
+///
+/// [`CurveArithmetic::EQUATION_A`] and [`CurveArithmetic::EQUATION_B`] are the
+/// coefficients of a birationally equivalent short Weierstrass model.
+pub trait EdwardsCurveArithmetic:
+    EdwardsCurve
+    + CofactorArithmetic
+    + CurveArithmetic<AffinePoint = Self::EdwardsAffinePoint, ProjectivePoint = Self::EdwardsPoint>
+{
+    /// Elliptic curve point in affine coordinates.
+    type EdwardsAffinePoint: EdwardsCoordinates<FieldRepr = FieldBytes<Self>>
+        + GroupEncoding<Repr = EdwardsPointBytes<Self>>;
+
+    /// Elliptic curve point in projective coordinates.
+    type EdwardsPoint: group::Curve<AffineRepr = Self::EdwardsAffinePoint>
+        + group::Group<Scalar = Self::Scalar>
+        + GroupEncoding<Repr = EdwardsPointBytes<Self>>;
+
+    /// Coefficient `a` in the curve equation `ax² + y² = 1 + dx²y²`.
+    const EDWARDS_A: Self::FieldElement;
+
+    /// Coefficient `d` in the curve equation `ax² + y² = 1 + dx²y²`.
+    const EDWARDS_D: Self::FieldElement;
+}
+
+/// X-only scalar multiplication on a Montgomery curve, as used by X25519 and
+/// X448 Diffie-Hellman ([RFC 7748]).
+///
+/// Scalars and u-coordinates are serialized as [`FieldBytes`] using the
+/// curve's own encoding conventions, i.e. little endian for RFC 7748 curves.
+///
+/// [RFC 7748]: https://www.rfc-editor.org/rfc/rfc7748
+pub trait MontgomeryXOnly: Curve {
+    /// Get the u-coordinate of the base point.
+    fn generator_u() -> FieldBytes<Self>;
+
+    /// Multiply the point with the given u-coordinate by `scalar` using the
+    /// Montgomery ladder, returning the u-coordinate of the result.
+    ///
+    /// Following RFC 7748 § 5:
+    /// - the scalar is clamped before use, so every input is accepted
+    /// - unused high bits of `u` are masked, and non-canonical u-coordinates
+    ///   (i.e. those greater than or equal to the field modulus) are accepted
+    ///   and reduced
+    ///
+    /// Returns `None` if the result is the all-zero value, which happens if
+    /// and only if `u` is a small-order point (see RFC 7748 § 6.1).
+    fn x_only_mul(scalar: &FieldBytes<Self>, u: &FieldBytes<Self>) -> CtOption<FieldBytes<Self>>;
+}
+
+/// Compute the curve equation residual `y² - x³ - ax - b` of an affine point.
+///
+/// Returns `None` if its coordinates aren't canonical field elements.
+fn curve_equation_residual<C: CurveArithmetic>(
+    point: &C::AffinePoint,
+) -> CtOption<C::FieldElement> {
+    C::affine_x(point).and_then(|x| {
+        C::affine_y(point).map(|y| y.square() - ((x.square() + C::EQUATION_A) * x + C::EQUATION_B))
+    })
+}
+
+/// Elliptic curve whose scalars provide a packed bit representation.
+///
+/// This allows generic windowed scalar multiplication algorithms (e.g. wNAF,
+/// Straus) to access limb-aligned scalar bits without a round trip through
+/// [`FieldBytes`].
+#[cfg(feature = "bits")]
+pub trait CurveArithmeticBits: CurveArithmetic<Scalar = Self::ScalarBits> {
+    /// Scalar field element with a packed little endian bit representation.
+    ///
+    /// This is the same type as [`ScalarArithmetic::Scalar`].
+    type ScalarBits: ff::PrimeFieldBits<Repr = FieldBytes<Self>>;
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::{CurveArithmetic, ScalarArithmetic};
+    use crate::{
+        bigint::{ArrayEncoding, U256},
+        dev::{FieldBytes, MockCurve, ProjectivePoint, Scalar},
+        scalar::AddTweak,
+    };
+    use ff::Field;
+    use group::{Curve, Group};
+    use rand_core::OsRng;
+
+    /// Uses only reference-taking operations on points and scalars.
+    fn by_ref<C: CurveArithmetic>(
+        points: &[C::ProjectivePoint],
+        scalars: &[C::Scalar],
+    ) -> C::ProjectivePoint {
+        let mut acc = C::ProjectivePoint::identity();
+        let mut weight = C::Scalar::from(1u64);
+
+        for (point, scalar) in points.iter().zip(scalars) {
+            acc += *point * scalar;
+            acc -= point;
+            weight *= scalar;
+            weight = weight + scalar - scalar;
+        }
+
+        acc *= &weight;
+        acc
+    }
+
+    #[test]
+    fn reference_ops() {
+        let g = <MockCurve as CurveArithmetic>::ProjectivePoint::generator();
+        let s = <MockCurve as ScalarArithmetic>::Scalar::from(3u64);
+        assert_eq!(by_ref::<MockCurve>(&[g], &[s]), (g * s - g) * s);
+    }
+
+    #[test]
+    fn add_tweak_point() {
+        let parent = Scalar::random(&mut OsRng);
+        let tweak = Scalar::random(&mut OsRng);
+        let point = (ProjectivePoint::generator() * parent).to_affine();
+
+        let child = parent.add_tweak(&tweak).unwrap();
+        assert_eq!(
+            MockCurve::add_tweak_point(&point, &tweak).unwrap(),
+            ProjectivePoint::generator() * child
+        );
+
+        assert!(bool::from(
+            MockCurve::add_tweak_point(&point, &Scalar::ZERO).is_none()
+        ));
+        assert!(bool::from(
+            MockCurve::add_tweak_point(&point, &-parent).is_none()
+        ));
+    }
+
+    #[test]
+    fn scalar_from_bytes_at_order() {
+        let bytes = |uint: U256| FieldBytes::from(uint.to_be_byte_array());
+        let order = <MockCurve as crate::Curve>::ORDER;
+        let max = bytes(order.wrapping_sub(&U256::ONE));
+
+        let strict = MockCurve::scalar_from_bytes::<true>;
+        assert_eq!(strict(&max).unwrap(), -Scalar::ONE);
+        assert!(bool::from(strict(&bytes(order)).is_none()));
+        assert!(bool::from(
+            strict(&bytes(order.wrapping_add(&U256::ONE))).is_none()
+        ));
+        assert!(bool::from(strict(&bytes(U256::MAX)).is_none()));
+
+        let reduced = MockCurve::scalar_from_bytes::<false>;
+        assert_eq!(reduced(&max).unwrap(), -Scalar::ONE);
+        assert_eq!(reduced(&bytes(order)).unwrap(), Scalar::ZERO);
+        assert_eq!(
+            reduced(&bytes(order.wrapping_add(&U256::ONE))).unwrap(),
+            Scalar::ONE
+        );
+    }
+}
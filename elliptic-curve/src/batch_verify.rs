@@ -0,0 +1,178 @@
+//! Batch verification of Schnorr-style equations `s * G == R + e * P`, e.g.
+//! for signatures, using a random linear combination.
+
+use crate::{
+    ops::MsmAccumulator, scalar::reduce_u128, AffinePoint, CurveArithmetic, Error, ErrorKind,
+    ProjectivePoint, Result, Scalar,
+};
+use alloc::vec::Vec;
+use ff::Field;
+use group::{Curve, Group};
+use rand_core::CryptoRngCore;
+
+/// Accumulator for verifying many equations `s_i * G == R_i + e_i * P_i` at
+/// once, e.g. Schnorr signatures `(R_i, s_i)` with challenges `e_i` on public
+/// keys `P_i`.
+///
+/// [`BatchVerifier::verify`] samples random 128-bit weights `z_i` and checks
+///
+/// ```text
+/// Σ z_i * (s_i * G - e_i * P_i - R_i) == 0
+/// ```
+///
+/// with a single multi-scalar multiplication.
+///
+/// # Soundness
+///
+/// The check is probabilistic: if any of the equations does not hold, the
+/// batch is accepted with probability at most `2^-128` over the choice of
+/// the weights, provided `rng` is not known to whoever chose the inputs. On
+/// curves with a cofactor, the points must be checked to be in the prime
+/// order subgroup beforehand.
+///
+/// This is **NOT** constant-time, and must only be used with public inputs.
+#[derive(Clone, Debug)]
+pub struct BatchVerifier<C: CurveArithmetic> {
+    /// Pending `(R_i, s_i, e_i, P_i)` tuples.
+    items: Vec<Equation<C>>,
+}
+
+/// Equation `s * G == r + e * p` in a [`BatchVerifier`].
+#[derive(Clone, Debug)]
+struct Equation<C: CurveArithmetic> {
+    r: AffinePoint<C>,
+    s: Scalar<C>,
+    e: Scalar<C>,
+    p: AffinePoint<C>,
+}
+
+impl<C: CurveArithmetic> BatchVerifier<C> {
+    /// Create a new, empty batch.
+    pub fn new() -> Self {
+        Self { items: Vec::new() }
+    }
+
+    /// Create a new, empty batch with room for `capacity` equations.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            items: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Add the equation `s * G == r + e * p` to the batch.
+    pub fn push(&mut self, r: &AffinePoint<C>, s: &Scalar<C>, e: &Scalar<C>, p: &AffinePoint<C>) {
+        self.items.push(Equation {
+            r: *r,
+            s: *s,
+            e: *e,
+            p: *p,
+        });
+    }
+
+    /// Number of equations in the batch.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Is the batch empty?
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Verify all equations in the batch at once, see the
+    /// [soundness](BatchVerifier#soundness) notes.
+    ///
+    /// An empty batch is valid.
+    ///
+    /// # Errors
+    /// If the random linear combination of the equations does not hold, i.e.
+    /// (with overwhelming probability) if any of them does not hold.
+    pub fn verify(self, rng: &mut impl CryptoRngCore) -> Result<()> {
+        let mut msm = MsmAccumulator::<C>::new();
+        let mut s_sum = Scalar::<C>::ZERO;
+
+        for Equation { r, s, e, p } in &self.items {
+            let mut z = [0u8; 16];
+            rng.fill_bytes(&mut z);
+            let z = reduce_u128::<C>(u128::from_le_bytes(z));
+
+            s_sum += z * s;
+            msm.add(p, &-(z * e));
+            msm.add(r, &-z);
+        }
+
+        msm.add(&ProjectivePoint::<C>::generator().to_affine(), &s_sum);
+
+        if msm.finalize().is_identity().into() {
+            Ok(())
+        } else {
+            Err(Error::new(ErrorKind::Other))
+        }
+    }
+}
+
+impl<C: CurveArithmetic> Default for BatchVerifier<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::BatchVerifier;
+    use crate::dev::{AffinePoint, MockCurve, ProjectivePoint, Scalar};
+    use alloc::vec::Vec;
+    use ff::Field;
+    use group::{Curve, Group};
+    use rand_core::OsRng;
+
+    type Signature = (AffinePoint, Scalar, Scalar, AffinePoint);
+
+    /// Signatures `(R, s)` with challenges `e` on public keys `P` satisfying
+    /// `s * G == R + e * P`.
+    fn signatures(n: usize) -> impl Iterator<Item = Signature> {
+        (0..n).map(|_| {
+            let x = Scalar::random(&mut OsRng);
+            let k = Scalar::random(&mut OsRng);
+            let e = Scalar::random(&mut OsRng);
+            let g = ProjectivePoint::generator();
+            ((g * k).to_affine(), k + e * x, e, (g * x).to_affine())
+        })
+    }
+
+    fn batch(items: &[Signature]) -> BatchVerifier<MockCurve> {
+        let mut batch = BatchVerifier::with_capacity(items.len());
+        for (r, s, e, p) in items {
+            batch.push(r, s, e, p);
+        }
+        batch
+    }
+
+    #[test]
+    fn valid_batch() {
+        assert!(BatchVerifier::<MockCurve>::new().verify(&mut OsRng).is_ok());
+
+        let items: Vec<_> = signatures(16).collect();
+        let batch = batch(&items);
+        assert_eq!(batch.len(), 16);
+        assert!(batch.verify(&mut OsRng).is_ok());
+    }
+
+    #[test]
+    fn invalid_signature_rejected() {
+        let items: Vec<_> = signatures(16).collect();
+
+        for i in [0, 7, 15] {
+            let mut invalid = items.clone();
+            invalid[i].1 += Scalar::ONE;
+
+            for _ in 0..8 {
+                assert!(batch(&invalid).verify(&mut OsRng).is_err());
+            }
+
+            let mut invalid = items.clone();
+            invalid[i].2 = invalid[(i + 1) % 16].2;
+            assert!(batch(&invalid).verify(&mut OsRng).is_err());
+        }
+    }
+}
@@ -0,0 +1,158 @@
+//! Minimal fixed-size binary encoding.
+//!
+//! An alternative to `serde` for embedded and custom protocols which only
+//! need a fixed wire size and a pair of encode/decode functions.
+
+use crate::{Curve, Result, ScalarPrimitive};
+use generic_array::typenum::Unsigned;
+
+#[cfg(feature = "arithmetic")]
+use crate::{CurveArithmetic, Error, ErrorKind, FieldBytes, NonZeroScalar};
+
+#[cfg(all(feature = "arithmetic", feature = "sec1"))]
+use crate::{
+    sec1::{CompressedPointSize, FromEncodedPoint, ModulusSize, ToEncodedPoint},
+    AffinePoint, FieldBytesSize, PublicKey,
+};
+
+/// Fixed-size binary encoding.
+pub trait Codec: Sized {
+    /// Size of the encoding in bytes.
+    const ENCODED_LEN: usize;
+
+    /// Encode `self` into the first [`Codec::ENCODED_LEN`] bytes of `out`,
+    /// returning the number of bytes written.
+    ///
+    /// # Panics
+    ///
+    /// If `out` is shorter than [`Codec::ENCODED_LEN`].
+    fn encode(&self, out: &mut [u8]) -> usize;
+
+    /// Decode a value from exactly [`Codec::ENCODED_LEN`] bytes.
+    ///
+    /// # Errors
+    /// If `bytes` has the wrong length or is not a valid encoding.
+    fn decode(bytes: &[u8]) -> Result<Self>;
+}
+
+/// Scalars are encoded as big endian [`FieldBytes`].
+impl<C> Codec for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    const ENCODED_LEN: usize = C::FieldBytesSize::USIZE;
+
+    fn encode(&self, out: &mut [u8]) -> usize {
+        out[..Self::ENCODED_LEN].copy_from_slice(&self.to_bytes());
+        Self::ENCODED_LEN
+    }
+
+    fn decode(bytes: &[u8]) -> Result<Self> {
+        Self::from_slice(bytes)
+    }
+}
+
+/// Scalars are encoded as their [`FieldBytes`] representation.
+#[cfg(feature = "arithmetic")]
+impl<C> Codec for NonZeroScalar<C>
+where
+    C: CurveArithmetic,
+{
+    const ENCODED_LEN: usize = C::FieldBytesSize::USIZE;
+
+    fn encode(&self, out: &mut [u8]) -> usize {
+        out[..Self::ENCODED_LEN].copy_from_slice(&FieldBytes::<C>::from(self));
+        Self::ENCODED_LEN
+    }
+
+    fn decode(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() != Self::ENCODED_LEN {
+            return Err(Error::invalid_length(Self::ENCODED_LEN, bytes.len()));
+        }
+
+        Option::from(Self::from_repr(FieldBytes::<C>::clone_from_slice(bytes)))
+            .ok_or(ErrorKind::ScalarOutOfRange.into())
+    }
+}
+
+/// Public keys are encoded as SEC1 compressed points.
+#[cfg(all(feature = "arithmetic", feature = "sec1"))]
+impl<C> Codec for PublicKey<C>
+where
+    C: CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    const ENCODED_LEN: usize = CompressedPointSize::<C>::USIZE;
+
+    fn encode(&self, out: &mut [u8]) -> usize {
+        out[..Self::ENCODED_LEN].copy_from_slice(self.to_encoded_point(true).as_bytes());
+        Self::ENCODED_LEN
+    }
+
+    fn decode(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() != Self::ENCODED_LEN {
+            return Err(Error::invalid_length(Self::ENCODED_LEN, bytes.len()));
+        }
+
+        Self::from_sec1_bytes(bytes)
+    }
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::Codec;
+    use crate::{
+        dev::{NonZeroScalar, PublicKey, ScalarPrimitive},
+        sec1::ToEncodedPoint,
+    };
+    use rand_core::OsRng;
+
+    fn round_trip<T: Codec>(value: &T) -> T {
+        let mut buf = [0xffu8; 80];
+        assert_eq!(value.encode(&mut buf), T::ENCODED_LEN);
+        assert!(buf[T::ENCODED_LEN..].iter().all(|&b| b == 0xff));
+
+        for len in 0..T::ENCODED_LEN {
+            assert!(T::decode(&buf[..len]).is_err());
+        }
+        assert!(T::decode(&buf[..T::ENCODED_LEN + 1]).is_err());
+
+        T::decode(&buf[..T::ENCODED_LEN]).unwrap()
+    }
+
+    #[test]
+    fn scalar_primitive_round_trip() {
+        assert_eq!(ScalarPrimitive::ENCODED_LEN, 32);
+        let scalar = ScalarPrimitive::random(&mut OsRng);
+        assert_eq!(round_trip(&scalar), scalar);
+        assert!(ScalarPrimitive::decode(&[0xff; 32]).is_err());
+    }
+
+    #[test]
+    fn nonzero_scalar_round_trip() {
+        assert_eq!(NonZeroScalar::ENCODED_LEN, 32);
+        let scalar = NonZeroScalar::random(&mut OsRng);
+        assert_eq!(*round_trip(&scalar), *scalar);
+        assert!(NonZeroScalar::decode(&[0; 32]).is_err());
+    }
+
+    #[test]
+    fn public_key_round_trip() {
+        assert_eq!(PublicKey::ENCODED_LEN, 33);
+        let public_key = PublicKey::from_secret_scalar(&NonZeroScalar::random(&mut OsRng));
+        assert_eq!(round_trip(&public_key), public_key);
+
+        // Uncompressed points are rejected even if their prefix is valid
+        let uncompressed = public_key.to_encoded_point(false);
+        assert!(PublicKey::decode(uncompressed.as_bytes()).is_err());
+        assert!(PublicKey::decode(&uncompressed.as_bytes()[..33]).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn encode_panics_on_short_buffer() {
+        let mut buf = [0u8; 31];
+        ScalarPrimitive::random(&mut OsRng).encode(&mut buf);
+    }
+}
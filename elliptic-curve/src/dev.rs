@@ -4,36 +4,48 @@
 //! the traits in this crate.
 
 use crate::{
-    bigint::{Limb, U256},
-    error::{Error, Result},
+    bigint::{
+        modular::constant_mod::{Residue, ResidueParams},
+        ArrayEncoding, Integer, NonZero, U256,
+    },
+    error::{Error, ErrorKind, Result},
     generic_array::typenum::U32,
-    ops::{Invert, LinearCombination, MulByGenerator, Reduce, ShrAssign},
+    ops::{
+        sum_tree, sum_tree_mixed, AddMixed, Endomorphism, Invert, LinearCombination, MulBatch,
+        MulByGenerator, MultiScalarMul, Reduce, ReduceNonZero, ShrAssign,
+    },
     pkcs8,
-    point::AffineCoordinates,
+    point::{AffineCoordinates, DecompressPoint, GeneratorCoordinates, ProjectiveCoordinates},
     rand_core::RngCore,
-    scalar::{FromUintUnchecked, IsHigh},
-    sec1::{CompressedPoint, FromEncodedPoint, ToEncodedPoint},
+    scalar::{CanonicalUint, FromUintUnchecked, IsHigh},
+    sec1::{CompressedPoint, Coordinates, FromEncodedPoint, ToEncodedPoint},
     subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption},
+    weierstrass,
     zeroize::DefaultIsZeroes,
-    Curve, CurveArithmetic, FieldBytesEncoding, PrimeCurve,
+    Curve, CurveArithmetic, FieldBytesEncoding, PrimeCurve, ScalarArithmetic,
 };
 use core::{
+    hash::{Hash, Hasher},
     iter::{Product, Sum},
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 use ff::{Field, PrimeField};
-use hex_literal::hex;
 use pkcs8::AssociatedOid;
 
 #[cfg(feature = "bits")]
-use ff::PrimeFieldBits;
+use {crate::CurveArithmeticBits, ff::PrimeFieldBits};
 
 #[cfg(feature = "jwk")]
 use crate::JwkParameters;
 
-/// Pseudo-coordinate for fixed-based scalar mult output
-pub const PSEUDO_COORDINATE_FIXED_BASE_MUL: [u8; 32] =
-    hex!("deadbeef00000000000000000000000000000000000000000000000000000001");
+pub mod cofactor;
+pub mod conformance;
+pub mod edwards;
+pub mod vectors;
+pub mod wide;
+
+#[cfg(feature = "hash2curve")]
+mod hash2curve;
 
 /// SEC1 encoded point.
 pub type EncodedPoint = crate::sec1::EncodedPoint<MockCurve>;
@@ -58,11 +70,34 @@ pub type ScalarPrimitive = crate::ScalarPrimitive<MockCurve>;
 #[cfg(feature = "bits")]
 pub type ScalarBits = crate::scalar::ScalarBits<MockCurve>;
 
+/// Moduli used by the mock curve's Montgomery-form residues.
+mod moduli {
+    use crate::bigint::{impl_modulus, U256};
+
+    impl_modulus!(
+        FieldModulus,
+        U256,
+        "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff"
+    );
+
+    impl_modulus!(
+        ScalarModulus,
+        U256,
+        "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551"
+    );
+}
+
+/// Base field residue.
+type FieldResidue = Residue<moduli::FieldModulus, { U256::LIMBS }>;
+
+/// Scalar field residue.
+type ScalarResidue = Residue<moduli::ScalarModulus, { U256::LIMBS }>;
+
 /// Mock elliptic curve type useful for writing tests which require a concrete
 /// curve type.
 ///
-/// Note: this type is roughly modeled off of NIST P-256, but does not provide
-/// an actual cure arithmetic implementation.
+/// Note: this type is modeled off of NIST P-256 and provides a simple,
+/// unoptimized arithmetic implementation which is only suitable for tests.
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
 pub struct MockCurve;
 
@@ -76,10 +111,27 @@ impl Curve for MockCurve {
 
 impl PrimeCurve for MockCurve {}
 
+impl ScalarArithmetic for MockCurve {
+    type Scalar = Scalar;
+}
+
 impl CurveArithmetic for MockCurve {
     type AffinePoint = AffinePoint;
+    type FieldElement = FieldElement;
     type ProjectivePoint = ProjectivePoint;
-    type Scalar = Scalar;
+    type CompressedPoint = CompressedPoint<Self>;
+    type UncompressedPoint = crate::sec1::UncompressedPoint<Self>;
+
+    const EQUATION_A: FieldElement =
+        FieldElement::from_hex("ffffffff00000001000000000000000000000000fffffffffffffffffffffffc");
+    const EQUATION_B: FieldElement =
+        FieldElement::from_hex("5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b");
+    const FIELD_MODULUS: U256 = moduli::FieldModulus::MODULUS;
+}
+
+#[cfg(feature = "bits")]
+impl CurveArithmeticBits for MockCurve {
+    type ScalarBits = Scalar;
 }
 
 impl AssociatedOid for MockCurve {
@@ -87,6 +139,9 @@ impl AssociatedOid for MockCurve {
     const OID: pkcs8::ObjectIdentifier = pkcs8::ObjectIdentifier::new_unwrap("1.2.840.10045.3.1.7");
 }
 
+#[cfg(feature = "ecdh")]
+impl crate::ecdh::ValidatePublicKey for MockCurve {}
+
 #[cfg(feature = "jwk")]
 impl JwkParameters for MockCurve {
     const CRV: &'static str = "P-256";
@@ -96,6 +151,18 @@ impl JwkParameters for MockCurve {
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
 pub struct Scalar(ScalarPrimitive);
 
+impl Scalar {
+    /// Convert to a Montgomery-form residue.
+    fn to_residue(self) -> ScalarResidue {
+        ScalarResidue::new(self.0.as_uint())
+    }
+
+    /// Convert from a Montgomery-form residue.
+    fn from_residue(residue: ScalarResidue) -> Self {
+        Self(ScalarPrimitive::from_uint_unchecked(residue.retrieve()))
+    }
+}
+
 impl Field for Scalar {
     const ZERO: Self = Self(ScalarPrimitive::ZERO);
     const ONE: Self = Self(ScalarPrimitive::ONE);
@@ -115,18 +182,17 @@ impl Field for Scalar {
         self.0.is_zero()
     }
 
-    #[must_use]
     fn square(&self) -> Self {
-        unimplemented!();
+        Self::from_residue(self.to_residue().square())
     }
 
-    #[must_use]
     fn double(&self) -> Self {
         self.add(self)
     }
 
     fn invert(&self) -> CtOption<Self> {
-        unimplemented!();
+        let (inverse, is_some) = self.to_residue().invert();
+        CtOption::new(Self::from_residue(inverse), is_some.into())
     }
 
     fn sqrt(&self) -> CtOption<Self> {
@@ -142,7 +208,7 @@ impl PrimeField for Scalar {
     type Repr = FieldBytes;
 
     const MODULUS: &'static str =
-        "0xffffffff00000001000000000000000000000000ffffffffffffffffffffffff";
+        "0xffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551";
     const NUM_BITS: u32 = 256;
     const CAPACITY: u32 = 255;
     const TWO_INV: Self = Self::ZERO; // BOGUS!
@@ -261,28 +327,28 @@ impl SubAssign<&Scalar> for Scalar {
 impl Mul<Scalar> for Scalar {
     type Output = Scalar;
 
-    fn mul(self, _other: Scalar) -> Scalar {
-        unimplemented!();
+    fn mul(self, other: Scalar) -> Scalar {
+        self.mul(&other)
     }
 }
 
 impl Mul<&Scalar> for Scalar {
     type Output = Scalar;
 
-    fn mul(self, _other: &Scalar) -> Scalar {
-        unimplemented!();
+    fn mul(self, other: &Scalar) -> Scalar {
+        Self::from_residue(self.to_residue() * other.to_residue())
     }
 }
 
 impl MulAssign<Scalar> for Scalar {
-    fn mul_assign(&mut self, _rhs: Scalar) {
-        unimplemented!();
+    fn mul_assign(&mut self, rhs: Scalar) {
+        *self = *self * rhs;
     }
 }
 
 impl MulAssign<&Scalar> for Scalar {
-    fn mul_assign(&mut self, _rhs: &Scalar) {
-        unimplemented!();
+    fn mul_assign(&mut self, rhs: &Scalar) {
+        *self = *self * rhs;
     }
 }
 
@@ -301,26 +367,26 @@ impl ShrAssign<usize> for Scalar {
 }
 
 impl Sum for Scalar {
-    fn sum<I: Iterator<Item = Self>>(_iter: I) -> Self {
-        unimplemented!();
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self(iter.map(|scalar| scalar.0).sum())
     }
 }
 
 impl<'a> Sum<&'a Scalar> for Scalar {
-    fn sum<I: Iterator<Item = &'a Scalar>>(_iter: I) -> Self {
-        unimplemented!();
+    fn sum<I: Iterator<Item = &'a Scalar>>(iter: I) -> Self {
+        iter.copied().sum()
     }
 }
 
 impl Product for Scalar {
-    fn product<I: Iterator<Item = Self>>(_iter: I) -> Self {
-        unimplemented!();
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, Mul::mul)
     }
 }
 
 impl<'a> Product<&'a Scalar> for Scalar {
-    fn product<I: Iterator<Item = &'a Scalar>>(_iter: I) -> Self {
-        unimplemented!();
+    fn product<I: Iterator<Item = &'a Scalar>>(iter: I) -> Self {
+        iter.copied().product()
     }
 }
 
@@ -328,23 +394,38 @@ impl Invert for Scalar {
     type Output = CtOption<Scalar>;
 
     fn invert(&self) -> CtOption<Scalar> {
-        unimplemented!();
+        Field::invert(self)
+    }
+
+    fn invert_vartime(&self) -> CtOption<Scalar> {
+        crate::scalar::invert_vartime::<MockCurve>(self)
     }
 }
 
 impl Reduce<U256> for Scalar {
     type Bytes = FieldBytes;
 
-    #[allow(clippy::integer_arithmetic)]
     fn reduce(w: U256) -> Self {
-        let (r, underflow) = w.sbb(&MockCurve::ORDER, Limb::ZERO);
-        let underflow = Choice::from((underflow.0 >> (Limb::BITS - 1)) as u8);
-        let reduced = U256::conditional_select(&w, &r, !underflow);
-        Self(ScalarPrimitive::new(reduced).unwrap())
+        Self(ScalarPrimitive::from_uint_unchecked(
+            MockCurve::reduce_uint(&w),
+        ))
+    }
+
+    fn reduce_bytes(bytes: &FieldBytes) -> Self {
+        Self::reduce(U256::from_be_byte_array(*bytes))
+    }
+}
+
+impl ReduceNonZero<U256> for Scalar {
+    fn reduce_nonzero(w: U256) -> Self {
+        let order_minus_one = NonZero::new(MockCurve::ORDER.wrapping_sub(&U256::ONE)).unwrap();
+        Self(ScalarPrimitive::from_uint_unchecked(
+            (w % order_minus_one).wrapping_add(&U256::ONE),
+        ))
     }
 
-    fn reduce_bytes(_: &FieldBytes) -> Self {
-        todo!()
+    fn reduce_nonzero_bytes(bytes: &FieldBytes) -> Self {
+        Self::reduce_nonzero(U256::from_be_byte_array(*bytes))
     }
 }
 
@@ -378,7 +459,21 @@ impl TryFrom<U256> for Scalar {
     type Error = Error;
 
     fn try_from(w: U256) -> Result<Self> {
-        Option::from(ScalarPrimitive::new(w)).map(Self).ok_or(Error)
+        Option::from(ScalarPrimitive::new(w))
+            .map(Self)
+            .ok_or(Error::new(ErrorKind::Other))
+    }
+}
+
+impl CanonicalUint for Scalar {
+    type Uint = U256;
+
+    fn to_canonical_uint(&self) -> U256 {
+        self.0.to_canonical_uint()
+    }
+
+    fn from_canonical_uint(uint: U256) -> CtOption<Self> {
+        ScalarPrimitive::new(uint).map(Self)
     }
 }
 
@@ -408,383 +503,882 @@ impl IsHigh for Scalar {
     }
 }
 
-/// Example affine point type
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-pub enum AffinePoint {
-    /// Result of fixed-based scalar multiplication.
-    FixedBaseOutput(Scalar),
-
-    /// Identity.
-    Identity,
-
-    /// Base point.
-    Generator,
-
-    /// Point corresponding to a given [`EncodedPoint`].
-    Other(EncodedPoint),
-}
-
-impl AffineCoordinates for AffinePoint {
-    type FieldRepr = FieldBytes;
+/// Example base field element type.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct FieldElement(FieldResidue);
 
-    fn x(&self) -> FieldBytes {
-        unimplemented!();
+impl FieldElement {
+    /// Parse a field element from a big endian hex string.
+    ///
+    /// Does *not* perform a check that the value is in range.
+    const fn from_hex(hex: &str) -> Self {
+        Self(FieldResidue::new(&U256::from_be_hex(hex)))
     }
 
-    fn y_is_odd(&self) -> Choice {
-        unimplemented!();
+    /// Compute `x³ + ax + b`, i.e. the right hand side of the curve equation.
+    fn curve_equation_rhs(x: &Self) -> Self {
+        (x.square() + MockCurve::EQUATION_A) * x + MockCurve::EQUATION_B
     }
 }
 
-impl ConstantTimeEq for AffinePoint {
-    fn ct_eq(&self, other: &Self) -> Choice {
-        match (self, other) {
-            (Self::FixedBaseOutput(scalar), Self::FixedBaseOutput(other_scalar)) => {
-                scalar.ct_eq(other_scalar)
+impl Field for FieldElement {
+    const ZERO: Self = Self(FieldResidue::ZERO);
+    const ONE: Self = Self(FieldResidue::ONE);
+
+    fn random(mut rng: impl RngCore) -> Self {
+        let mut bytes = FieldBytes::default();
+
+        loop {
+            rng.fill_bytes(&mut bytes);
+            if let Some(fe) = Self::from_repr(bytes).into() {
+                return fe;
             }
-            (Self::Identity, Self::Identity) | (Self::Generator, Self::Generator) => 1.into(),
-            (Self::Other(point), Self::Other(other_point)) => u8::from(point == other_point).into(),
-            _ => 0.into(),
         }
     }
-}
 
-impl ConditionallySelectable for AffinePoint {
-    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
-        // Not really constant time, but this is dev code
-        if choice.into() {
-            *b
-        } else {
-            *a
-        }
+    fn is_zero(&self) -> Choice {
+        self.0.ct_eq(&FieldResidue::ZERO)
     }
-}
 
-impl Default for AffinePoint {
-    fn default() -> Self {
-        Self::Identity
+    fn square(&self) -> Self {
+        Self(self.0.square())
     }
-}
-
-impl DefaultIsZeroes for AffinePoint {}
-
-impl FromEncodedPoint<MockCurve> for AffinePoint {
-    fn from_encoded_point(encoded_point: &EncodedPoint) -> CtOption<Self> {
-        let point = if encoded_point.is_identity() {
-            Self::Identity
-        } else {
-            Self::Other(*encoded_point)
-        };
 
-        CtOption::new(point, Choice::from(1))
+    fn double(&self) -> Self {
+        Self(self.0 + self.0)
     }
-}
 
-impl ToEncodedPoint<MockCurve> for AffinePoint {
-    fn to_encoded_point(&self, compress: bool) -> EncodedPoint {
-        match self {
-            Self::FixedBaseOutput(scalar) => EncodedPoint::from_affine_coordinates(
-                &scalar.to_repr(),
-                &PSEUDO_COORDINATE_FIXED_BASE_MUL.into(),
-                false,
-            ),
-            Self::Other(point) => {
-                if compress == point.is_compressed() {
-                    *point
-                } else {
-                    unimplemented!();
-                }
-            }
-            _ => unimplemented!(),
-        }
+    fn invert(&self) -> CtOption<Self> {
+        let (inverse, is_some) = self.0.invert();
+        CtOption::new(Self(inverse), is_some.into())
     }
-}
 
-impl Mul<NonZeroScalar> for AffinePoint {
-    type Output = AffinePoint;
+    fn sqrt(&self) -> CtOption<Self> {
+        // p = 3 mod 4, so the square root is `self^((p + 1) / 4)`
+        let sqrt = self.0.pow(&U256::from_be_hex(
+            "3fffffffc0000000400000000000000000000000400000000000000000000000",
+        ));
+        CtOption::new(Self(sqrt), sqrt.square().ct_eq(&self.0))
+    }
 
-    fn mul(self, _scalar: NonZeroScalar) -> Self {
-        unimplemented!();
+    fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+        ff::helpers::sqrt_ratio_generic(num, div)
     }
 }
 
-/// Example projective point type
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-pub enum ProjectivePoint {
-    /// Result of fixed-based scalar multiplication
-    FixedBaseOutput(Scalar),
+impl PrimeField for FieldElement {
+    type Repr = FieldBytes;
 
-    /// Is this point the identity point?
-    Identity,
+    const MODULUS: &'static str =
+        "0xffffffff00000001000000000000000000000000ffffffffffffffffffffffff";
+    const NUM_BITS: u32 = 256;
+    const CAPACITY: u32 = 255;
+    const TWO_INV: Self =
+        Self::from_hex("7fffffff80000000800000000000000000000000800000000000000000000000");
+    const MULTIPLICATIVE_GENERATOR: Self =
+        Self::from_hex("0000000000000000000000000000000000000000000000000000000000000006");
+    const S: u32 = 1;
+    const ROOT_OF_UNITY: Self =
+        Self::from_hex("ffffffff00000001000000000000000000000000fffffffffffffffffffffffe");
+    const ROOT_OF_UNITY_INV: Self = Self::ROOT_OF_UNITY;
+    const DELTA: Self =
+        Self::from_hex("0000000000000000000000000000000000000000000000000000000000000024");
+
+    fn from_repr(bytes: FieldBytes) -> CtOption<Self> {
+        let modulus = NonZero::from_uint(moduli::FieldModulus::MODULUS);
+        <U256 as FieldBytesEncoding<MockCurve>>::decode_field_bytes_checked(&bytes, &modulus)
+            .map(|uint| Self(FieldResidue::new(&uint)))
+    }
 
-    /// Is this point the generator point?
-    Generator,
+    fn to_repr(&self) -> FieldBytes {
+        self.0.retrieve().to_be_byte_array()
+    }
 
-    /// Is this point a different point corresponding to a given [`AffinePoint`]
-    Other(AffinePoint),
+    fn is_odd(&self) -> Choice {
+        self.0.retrieve().is_odd()
+    }
 }
 
-impl ConstantTimeEq for ProjectivePoint {
-    fn ct_eq(&self, other: &Self) -> Choice {
-        match (self, other) {
-            (Self::FixedBaseOutput(scalar), Self::FixedBaseOutput(other_scalar)) => {
-                scalar.ct_eq(other_scalar)
-            }
-            (Self::Identity, Self::Identity) | (Self::Generator, Self::Generator) => 1.into(),
-            (Self::Other(point), Self::Other(other_point)) => point.ct_eq(other_point),
-            _ => 0.into(),
-        }
+impl From<u64> for FieldElement {
+    fn from(n: u64) -> FieldElement {
+        Self(FieldResidue::new(&U256::from_u64(n)))
     }
 }
 
-impl ConditionallySelectable for ProjectivePoint {
+impl ConditionallySelectable for FieldElement {
     fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
-        if choice.into() {
-            *b
-        } else {
-            *a
-        }
+        Self(FieldResidue::conditional_select(&a.0, &b.0, choice))
     }
 }
 
-impl Default for ProjectivePoint {
-    fn default() -> Self {
-        Self::Identity
+impl ConstantTimeEq for FieldElement {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
     }
 }
 
-impl DefaultIsZeroes for ProjectivePoint {}
+impl Add<FieldElement> for FieldElement {
+    type Output = FieldElement;
 
-impl From<AffinePoint> for ProjectivePoint {
-    fn from(point: AffinePoint) -> ProjectivePoint {
-        match point {
-            AffinePoint::FixedBaseOutput(scalar) => ProjectivePoint::FixedBaseOutput(scalar),
-            AffinePoint::Identity => ProjectivePoint::Identity,
-            AffinePoint::Generator => ProjectivePoint::Generator,
-            other => ProjectivePoint::Other(other),
-        }
+    fn add(self, other: FieldElement) -> FieldElement {
+        self.add(&other)
     }
 }
 
-impl From<ProjectivePoint> for AffinePoint {
-    fn from(point: ProjectivePoint) -> AffinePoint {
-        group::Curve::to_affine(&point)
+impl Add<&FieldElement> for FieldElement {
+    type Output = FieldElement;
+
+    fn add(self, other: &FieldElement) -> FieldElement {
+        Self(self.0 + other.0)
     }
 }
 
-impl FromEncodedPoint<MockCurve> for ProjectivePoint {
-    fn from_encoded_point(_point: &EncodedPoint) -> CtOption<Self> {
-        unimplemented!();
+impl AddAssign<FieldElement> for FieldElement {
+    fn add_assign(&mut self, other: FieldElement) {
+        *self = *self + other;
     }
 }
 
-impl ToEncodedPoint<MockCurve> for ProjectivePoint {
-    fn to_encoded_point(&self, _compress: bool) -> EncodedPoint {
-        unimplemented!();
+impl AddAssign<&FieldElement> for FieldElement {
+    fn add_assign(&mut self, other: &FieldElement) {
+        *self = *self + other;
     }
 }
 
-impl group::Group for ProjectivePoint {
-    type Scalar = Scalar;
+impl Sub<FieldElement> for FieldElement {
+    type Output = FieldElement;
 
-    fn random(_rng: impl RngCore) -> Self {
-        unimplemented!();
+    fn sub(self, other: FieldElement) -> FieldElement {
+        self.sub(&other)
     }
+}
 
-    fn identity() -> Self {
-        Self::Identity
+impl Sub<&FieldElement> for FieldElement {
+    type Output = FieldElement;
+
+    fn sub(self, other: &FieldElement) -> FieldElement {
+        Self(self.0 - other.0)
     }
+}
 
-    fn generator() -> Self {
-        Self::Generator
+impl SubAssign<FieldElement> for FieldElement {
+    fn sub_assign(&mut self, other: FieldElement) {
+        *self = *self - other;
     }
+}
 
-    fn is_identity(&self) -> Choice {
-        Choice::from(u8::from(self == &Self::Identity))
+impl SubAssign<&FieldElement> for FieldElement {
+    fn sub_assign(&mut self, other: &FieldElement) {
+        *self = *self - other;
     }
+}
 
-    #[must_use]
-    fn double(&self) -> Self {
-        unimplemented!();
+impl Mul<FieldElement> for FieldElement {
+    type Output = FieldElement;
+
+    fn mul(self, other: FieldElement) -> FieldElement {
+        self.mul(&other)
     }
 }
 
-impl group::GroupEncoding for AffinePoint {
-    type Repr = CompressedPoint<MockCurve>;
+impl Mul<&FieldElement> for FieldElement {
+    type Output = FieldElement;
 
-    fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
-        EncodedPoint::from_bytes(bytes)
-            .map(|point| CtOption::new(point, Choice::from(1)))
-            .unwrap_or_else(|_| {
-                let is_identity = bytes.ct_eq(&Self::Repr::default());
-                CtOption::new(EncodedPoint::identity(), is_identity)
-            })
-            .and_then(|point| Self::from_encoded_point(&point))
+    fn mul(self, other: &FieldElement) -> FieldElement {
+        Self(self.0 * other.0)
     }
+}
 
-    fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
-        Self::from_bytes(bytes)
+impl MulAssign<FieldElement> for FieldElement {
+    fn mul_assign(&mut self, other: FieldElement) {
+        *self = *self * other;
     }
+}
 
-    fn to_bytes(&self) -> Self::Repr {
-        let encoded = self.to_encoded_point(true);
-        let mut result = CompressedPoint::<MockCurve>::default();
-        result[..encoded.len()].copy_from_slice(encoded.as_bytes());
-        result
+impl MulAssign<&FieldElement> for FieldElement {
+    fn mul_assign(&mut self, other: &FieldElement) {
+        *self = *self * other;
     }
 }
 
-impl group::GroupEncoding for ProjectivePoint {
-    type Repr = CompressedPoint<MockCurve>;
+impl Neg for FieldElement {
+    type Output = FieldElement;
 
-    fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
-        <AffinePoint as group::GroupEncoding>::from_bytes(bytes).map(Into::into)
+    fn neg(self) -> FieldElement {
+        Self(-self.0)
     }
+}
 
-    fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
-        Self::from_bytes(bytes)
+impl Sum for FieldElement {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
     }
+}
 
-    fn to_bytes(&self) -> Self::Repr {
-        group::Curve::to_affine(self).to_bytes()
+impl<'a> Sum<&'a FieldElement> for FieldElement {
+    fn sum<I: Iterator<Item = &'a FieldElement>>(iter: I) -> Self {
+        iter.copied().sum()
     }
 }
 
-impl group::Curve for ProjectivePoint {
-    type AffineRepr = AffinePoint;
-
-    fn to_affine(&self) -> AffinePoint {
-        match self {
-            Self::FixedBaseOutput(scalar) => AffinePoint::FixedBaseOutput(*scalar),
-            Self::Other(affine) => *affine,
-            _ => unimplemented!(),
-        }
+impl Product for FieldElement {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, Mul::mul)
     }
 }
 
-impl LinearCombination for ProjectivePoint {}
-
-impl Add<ProjectivePoint> for ProjectivePoint {
-    type Output = ProjectivePoint;
-
-    fn add(self, _other: ProjectivePoint) -> ProjectivePoint {
-        unimplemented!();
+impl<'a> Product<&'a FieldElement> for FieldElement {
+    fn product<I: Iterator<Item = &'a FieldElement>>(iter: I) -> Self {
+        iter.copied().product()
     }
 }
 
-impl Add<&ProjectivePoint> for ProjectivePoint {
-    type Output = ProjectivePoint;
+/// Example affine point type
+#[derive(Clone, Copy, Debug)]
+pub struct AffinePoint {
+    /// x-coordinate
+    x: FieldElement,
+
+    /// y-coordinate
+    y: FieldElement,
+
+    /// Is this point the point at infinity? 0 = no, 1 = yes
+    infinity: u8,
+}
+
+impl AffinePoint {
+    /// Additive identity of the group a.k.a. the point at infinity.
+    pub const IDENTITY: Self = Self {
+        x: FieldElement::ZERO,
+        y: FieldElement::ZERO,
+        infinity: 1,
+    };
+
+    /// Base point of the curve.
+    pub const GENERATOR: Self = Self {
+        x: FieldElement::from_hex(
+            "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+        ),
+        y: FieldElement::from_hex(
+            "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
+        ),
+        infinity: 0,
+    };
 
-    fn add(self, _other: &ProjectivePoint) -> ProjectivePoint {
-        unimplemented!();
+    /// Is this point the identity point?
+    pub fn is_identity(&self) -> Choice {
+        Choice::from(self.infinity)
     }
 }
 
-impl AddAssign<ProjectivePoint> for ProjectivePoint {
-    fn add_assign(&mut self, _rhs: ProjectivePoint) {
-        unimplemented!();
-    }
-}
+impl AffineCoordinates for AffinePoint {
+    type FieldRepr = FieldBytes;
 
-impl AddAssign<&ProjectivePoint> for ProjectivePoint {
-    fn add_assign(&mut self, _rhs: &ProjectivePoint) {
-        unimplemented!();
+    const IDENTITY: Self = AffinePoint::IDENTITY;
+
+    fn x(&self) -> FieldBytes {
+        self.x.to_repr()
     }
-}
 
-impl Sub<ProjectivePoint> for ProjectivePoint {
-    type Output = ProjectivePoint;
+    fn y(&self) -> FieldBytes {
+        self.y.to_repr()
+    }
 
-    fn sub(self, _other: ProjectivePoint) -> ProjectivePoint {
-        unimplemented!();
+    fn y_is_odd(&self) -> Choice {
+        self.y.is_odd()
     }
 }
 
-impl Sub<&ProjectivePoint> for ProjectivePoint {
-    type Output = ProjectivePoint;
+impl GeneratorCoordinates<MockCurve> for AffinePoint {
+    const GENERATOR: Self = AffinePoint::GENERATOR;
+}
 
-    fn sub(self, _other: &ProjectivePoint) -> ProjectivePoint {
-        unimplemented!();
+impl ConstantTimeEq for AffinePoint {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.x.ct_eq(&other.x) & self.y.ct_eq(&other.y) & self.infinity.ct_eq(&other.infinity)
     }
 }
 
-impl SubAssign<ProjectivePoint> for ProjectivePoint {
-    fn sub_assign(&mut self, _rhs: ProjectivePoint) {
-        unimplemented!();
+impl ConditionallySelectable for AffinePoint {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self {
+            x: FieldElement::conditional_select(&a.x, &b.x, choice),
+            y: FieldElement::conditional_select(&a.y, &b.y, choice),
+            infinity: u8::conditional_select(&a.infinity, &b.infinity, choice),
+        }
     }
 }
 
-impl SubAssign<&ProjectivePoint> for ProjectivePoint {
-    fn sub_assign(&mut self, _rhs: &ProjectivePoint) {
-        unimplemented!();
+impl Default for AffinePoint {
+    fn default() -> Self {
+        Self::IDENTITY
     }
 }
 
-impl Add<AffinePoint> for ProjectivePoint {
-    type Output = ProjectivePoint;
+impl DefaultIsZeroes for AffinePoint {}
 
-    fn add(self, _other: AffinePoint) -> ProjectivePoint {
-        unimplemented!();
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for AffinePoint {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::point::arbitrary_affine_point::<MockCurve>(u)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <crate::ScalarPrimitive<MockCurve> as arbitrary::Arbitrary>::size_hint(depth)
     }
 }
 
-impl Add<&AffinePoint> for ProjectivePoint {
-    type Output = ProjectivePoint;
+impl Eq for AffinePoint {}
 
-    fn add(self, _other: &AffinePoint) -> ProjectivePoint {
-        unimplemented!();
+impl PartialEq for AffinePoint {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
     }
 }
 
-impl AddAssign<AffinePoint> for ProjectivePoint {
-    fn add_assign(&mut self, _rhs: AffinePoint) {
-        unimplemented!();
+impl Neg for AffinePoint {
+    type Output = AffinePoint;
+
+    fn neg(self) -> AffinePoint {
+        -&self
     }
 }
 
-impl AddAssign<&AffinePoint> for ProjectivePoint {
-    fn add_assign(&mut self, _rhs: &AffinePoint) {
-        unimplemented!();
+impl Neg for &AffinePoint {
+    type Output = AffinePoint;
+
+    fn neg(self) -> AffinePoint {
+        AffinePoint {
+            x: self.x,
+            y: -self.y,
+            infinity: self.infinity,
+        }
     }
 }
 
-impl Sum for ProjectivePoint {
-    fn sum<I: Iterator<Item = Self>>(_iter: I) -> Self {
-        unimplemented!();
+impl Hash for AffinePoint {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        group::GroupEncoding::to_bytes(self).hash(state);
     }
 }
 
-impl<'a> Sum<&'a ProjectivePoint> for ProjectivePoint {
-    fn sum<I: Iterator<Item = &'a ProjectivePoint>>(_iter: I) -> Self {
-        unimplemented!();
+impl DecompressPoint<MockCurve> for AffinePoint {
+    fn decompress(x_bytes: &FieldBytes, y_is_odd: Choice) -> CtOption<Self> {
+        FieldElement::from_repr(*x_bytes).and_then(|x| {
+            FieldElement::curve_equation_rhs(&x).sqrt().map(|y| {
+                let y = FieldElement::conditional_select(&-y, &y, y.is_odd().ct_eq(&y_is_odd));
+                Self { x, y, infinity: 0 }
+            })
+        })
+    }
+}
+
+impl FromEncodedPoint<MockCurve> for AffinePoint {
+    fn from_encoded_point(encoded_point: &EncodedPoint) -> CtOption<Self> {
+        match encoded_point.coordinates() {
+            Coordinates::Identity => CtOption::new(Self::IDENTITY, Choice::from(1)),
+            Coordinates::Compact { .. } => CtOption::new(Self::IDENTITY, Choice::from(0)),
+            Coordinates::Compressed { x, y_is_odd } => {
+                Self::decompress(x, Choice::from(u8::from(y_is_odd)))
+            }
+            Coordinates::Uncompressed { x, y } => FieldElement::from_repr(*x).and_then(|x| {
+                FieldElement::from_repr(*y).and_then(|y| {
+                    let on_curve = y.square().ct_eq(&FieldElement::curve_equation_rhs(&x));
+                    CtOption::new(Self { x, y, infinity: 0 }, on_curve)
+                })
+            }),
+        }
+    }
+}
+
+impl ToEncodedPoint<MockCurve> for AffinePoint {
+    fn to_encoded_point(&self, compress: bool) -> EncodedPoint {
+        if self.is_identity().into() {
+            EncodedPoint::identity()
+        } else {
+            EncodedPoint::from_affine_coordinates(&self.x.to_repr(), &self.y.to_repr(), compress)
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for AffinePoint {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        Self::from_sec1_bytes(bytes)
+    }
+}
+
+impl Mul<NonZeroScalar> for AffinePoint {
+    type Output = AffinePoint;
+
+    fn mul(self, scalar: NonZeroScalar) -> Self {
+        (ProjectivePoint::from(self) * scalar.as_ref()).into()
+    }
+}
+
+/// Example projective point type
+#[derive(Clone, Copy, Debug)]
+pub struct ProjectivePoint {
+    /// X-coordinate
+    x: FieldElement,
+
+    /// Y-coordinate
+    y: FieldElement,
+
+    /// Z-coordinate
+    z: FieldElement,
+}
+
+impl ProjectivePoint {
+    /// Additive identity of the group a.k.a. the point at infinity.
+    pub const IDENTITY: Self = Self {
+        x: FieldElement::ZERO,
+        y: FieldElement::ONE,
+        z: FieldElement::ZERO,
+    };
+
+    /// Base point of the curve.
+    pub const GENERATOR: Self = Self {
+        x: AffinePoint::GENERATOR.x,
+        y: AffinePoint::GENERATOR.y,
+        z: FieldElement::ONE,
+    };
+}
+
+impl ProjectiveCoordinates for ProjectivePoint {
+    type FieldElement = FieldElement;
+
+    fn x(&self) -> FieldElement {
+        self.x
+    }
+
+    fn y(&self) -> FieldElement {
+        self.y
+    }
+
+    fn z(&self) -> FieldElement {
+        self.z
+    }
+}
+
+impl ConstantTimeEq for ProjectivePoint {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        // Compare X1*Z2 == X2*Z1 and Y1*Z2 == Y2*Z1
+        (self.x * other.z).ct_eq(&(other.x * self.z))
+            & (self.y * other.z).ct_eq(&(other.y * self.z))
+    }
+}
+
+impl ConditionallySelectable for ProjectivePoint {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self {
+            x: FieldElement::conditional_select(&a.x, &b.x, choice),
+            y: FieldElement::conditional_select(&a.y, &b.y, choice),
+            z: FieldElement::conditional_select(&a.z, &b.z, choice),
+        }
+    }
+}
+
+impl Default for ProjectivePoint {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl DefaultIsZeroes for ProjectivePoint {}
+
+impl Eq for ProjectivePoint {}
+
+impl PartialEq for ProjectivePoint {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Hash for ProjectivePoint {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        group::Curve::to_affine(self).hash(state);
+    }
+}
+
+impl From<AffinePoint> for ProjectivePoint {
+    fn from(point: AffinePoint) -> ProjectivePoint {
+        let projective = ProjectivePoint {
+            x: point.x,
+            y: point.y,
+            z: FieldElement::ONE,
+        };
+
+        Self::conditional_select(&projective, &Self::IDENTITY, point.is_identity())
+    }
+}
+
+impl From<ProjectivePoint> for AffinePoint {
+    fn from(point: ProjectivePoint) -> AffinePoint {
+        group::Curve::to_affine(&point)
+    }
+}
+
+impl FromEncodedPoint<MockCurve> for ProjectivePoint {
+    fn from_encoded_point(point: &EncodedPoint) -> CtOption<Self> {
+        AffinePoint::from_encoded_point(point).map(Into::into)
+    }
+}
+
+impl ToEncodedPoint<MockCurve> for ProjectivePoint {
+    fn to_encoded_point(&self, compress: bool) -> EncodedPoint {
+        group::Curve::to_affine(self).to_encoded_point(compress)
+    }
+}
+
+impl group::Group for ProjectivePoint {
+    type Scalar = Scalar;
+
+    fn random(rng: impl RngCore) -> Self {
+        Self::GENERATOR * Scalar::random(rng)
+    }
+
+    fn identity() -> Self {
+        Self::IDENTITY
+    }
+
+    fn generator() -> Self {
+        Self::GENERATOR
+    }
+
+    fn is_identity(&self) -> Choice {
+        self.z.is_zero()
+    }
+
+    fn double(&self) -> Self {
+        let (x, y, z) = weierstrass::double((self.x, self.y, self.z), MockCurve::EQUATION_B);
+        Self { x, y, z }
+    }
+}
+
+impl group::GroupEncoding for AffinePoint {
+    type Repr = CompressedPoint<MockCurve>;
+
+    fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
+        EncodedPoint::from_bytes(bytes)
+            .map(|point| CtOption::new(point, Choice::from(1)))
+            .unwrap_or_else(|_| {
+                let is_identity = bytes.ct_eq(&Self::Repr::default());
+                CtOption::new(EncodedPoint::identity(), is_identity)
+            })
+            .and_then(|point| Self::from_encoded_point(&point))
+    }
+
+    fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
+        Self::from_bytes(bytes)
+    }
+
+    fn to_bytes(&self) -> Self::Repr {
+        let encoded = self.to_encoded_point(true);
+        let mut result = CompressedPoint::<MockCurve>::default();
+        result[..encoded.len()].copy_from_slice(encoded.as_bytes());
+        result
+    }
+}
+
+impl group::GroupEncoding for ProjectivePoint {
+    type Repr = CompressedPoint<MockCurve>;
+
+    fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
+        <AffinePoint as group::GroupEncoding>::from_bytes(bytes).map(Into::into)
+    }
+
+    fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
+        Self::from_bytes(bytes)
+    }
+
+    fn to_bytes(&self) -> Self::Repr {
+        group::Curve::to_affine(self).to_bytes()
+    }
+}
+
+impl group::Curve for ProjectivePoint {
+    type AffineRepr = AffinePoint;
+
+    fn to_affine(&self) -> AffinePoint {
+        self.z
+            .invert()
+            .map(|z_inv| AffinePoint {
+                x: self.x * z_inv,
+                y: self.y * z_inv,
+                infinity: 0,
+            })
+            .unwrap_or(AffinePoint::IDENTITY)
+    }
+}
+
+impl group::prime::PrimeGroup for ProjectivePoint {}
+
+impl group::cofactor::CofactorGroup for ProjectivePoint {
+    type Subgroup = ProjectivePoint;
+
+    fn clear_cofactor(&self) -> Self::Subgroup {
+        *self
+    }
+
+    fn into_subgroup(self) -> CtOption<Self::Subgroup> {
+        CtOption::new(self, Choice::from(1))
+    }
+
+    fn is_torsion_free(&self) -> Choice {
+        Choice::from(1)
+    }
+}
+
+impl AddMixed<AffinePoint> for ProjectivePoint {
+    fn add_mixed(&self, affine: &AffinePoint) -> ProjectivePoint {
+        *self + affine
+    }
+}
+
+impl LinearCombination for ProjectivePoint {}
+
+impl MulBatch for MockCurve {}
+
+impl MultiScalarMul for MockCurve {}
+
+impl crate::precompute::BasepointTable for MockCurve {
+    type Table = [ProjectivePoint; 16];
+    const TABLE_LEN: usize = 16;
+}
+
+/// GLV endomorphism with `λ` a cube root of unity modulo the curve order.
+///
+/// P-256 has no efficiently computable endomorphism, so `φ(P)` is computed
+/// with a full scalar multiplication. This exists to test code generic over
+/// [`Endomorphism`] with a secp256k1-like scalar decomposition.
+impl Endomorphism for MockCurve {
+    const DECOMPOSED_BITS: usize = 129;
+
+    fn endomorphism(point: &AffinePoint) -> AffinePoint {
+        (ProjectivePoint::from(*point) * Scalar::from_uint_unchecked(GLV_LAMBDA)).into()
+    }
+
+    fn decompose(scalar: &Scalar) -> [(Scalar, Choice); 2] {
+        let k = U256::from(*scalar);
+        let c1 = Scalar::from_uint_unchecked(glv_round(&k, &GLV_G1));
+        let c2 = Scalar::from_uint_unchecked(glv_round(&k, &GLV_G2));
+
+        // The basis vectors are `(a1, b1)` and `(a2, b2)` with `a2 = -b1`
+        let minus_b1 = Scalar::from_uint_unchecked(GLV_MINUS_B1);
+        let k1 = *scalar - c1 * Scalar::from_uint_unchecked(GLV_A1) - c2 * minus_b1;
+        let k2 = c1 * minus_b1 - c2 * Scalar::from_uint_unchecked(GLV_B2);
+
+        [k1, k2].map(|k| {
+            let is_neg = k.is_high();
+            (Scalar::conditional_select(&k, &-k, is_neg), is_neg)
+        })
+    }
+}
+
+/// Cube root of unity modulo the [`MockCurve`] order.
+const GLV_LAMBDA: U256 =
+    U256::from_be_hex("52891d43d946a0354e786d0777fd6aef9405335ce9c83e1d7cbf87ff12884e21");
+
+/// Short basis of the lattice `{(x, y) : x + y * λ = 0 (mod n)}`.
+const GLV_A1: U256 =
+    U256::from_be_hex("00000000000000000000000000000000b1f5b33127b21c7048f35b0f4bd7a081");
+const GLV_MINUS_B1: U256 =
+    U256::from_be_hex("00000000000000000000000000000000736e4b2daed5f0b07591353b8d035c2f");
+const GLV_B2: U256 =
+    U256::from_be_hex("000000000000000000000000000000012563fe5ed6880d20be84904ad8dafcb0");
+
+/// `round(2^382 * b2 / n)` and `round(2^382 * -b1 / n)`.
+const GLV_G1: U256 =
+    U256::from_be_hex("4958ff97fefb02dfe543275a9c7ee3a6ca7532684082ea21c5e60574ef8ce2df");
+const GLV_G2: U256 =
+    U256::from_be_hex("1cdb92cb88910ef78919c97ae3c9918f2240118a6b4d785d2cc3a71a4345ba27");
+
+/// Compute `round(k * g / 2^382)`.
+fn glv_round(k: &U256, g: &U256) -> U256 {
+    let (_, hi) = k.mul_wide(g);
+    hi.shr_vartime(126)
+        .wrapping_add(&U256::from_u64(hi.bit_vartime(125).into()))
+}
+
+impl Add<ProjectivePoint> for ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    fn add(self, other: ProjectivePoint) -> ProjectivePoint {
+        self.add(&other)
+    }
+}
+
+impl Add<&ProjectivePoint> for ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    fn add(self, other: &ProjectivePoint) -> ProjectivePoint {
+        let (x, y, z) = weierstrass::add(
+            (self.x, self.y, self.z),
+            (other.x, other.y, other.z),
+            MockCurve::EQUATION_B,
+        );
+        Self { x, y, z }
+    }
+}
+
+impl AddAssign<ProjectivePoint> for ProjectivePoint {
+    fn add_assign(&mut self, rhs: ProjectivePoint) {
+        *self = *self + rhs;
+    }
+}
+
+impl AddAssign<&ProjectivePoint> for ProjectivePoint {
+    fn add_assign(&mut self, rhs: &ProjectivePoint) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<ProjectivePoint> for ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    fn sub(self, other: ProjectivePoint) -> ProjectivePoint {
+        self.sub(&other)
+    }
+}
+
+impl Sub<&ProjectivePoint> for ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    fn sub(self, other: &ProjectivePoint) -> ProjectivePoint {
+        self.add(&other.neg())
+    }
+}
+
+impl SubAssign<ProjectivePoint> for ProjectivePoint {
+    fn sub_assign(&mut self, rhs: ProjectivePoint) {
+        *self = *self - rhs;
+    }
+}
+
+impl SubAssign<&ProjectivePoint> for ProjectivePoint {
+    fn sub_assign(&mut self, rhs: &ProjectivePoint) {
+        *self = *self - rhs;
+    }
+}
+
+impl Add<AffinePoint> for AffinePoint {
+    type Output = ProjectivePoint;
+
+    fn add(self, other: AffinePoint) -> ProjectivePoint {
+        ProjectivePoint::from(self) + other
+    }
+}
+
+impl Add<&AffinePoint> for AffinePoint {
+    type Output = ProjectivePoint;
+
+    fn add(self, other: &AffinePoint) -> ProjectivePoint {
+        ProjectivePoint::from(self) + other
+    }
+}
+
+impl Sub<AffinePoint> for AffinePoint {
+    type Output = ProjectivePoint;
+
+    fn sub(self, other: AffinePoint) -> ProjectivePoint {
+        ProjectivePoint::from(self) - other
+    }
+}
+
+impl Sub<&AffinePoint> for AffinePoint {
+    type Output = ProjectivePoint;
+
+    fn sub(self, other: &AffinePoint) -> ProjectivePoint {
+        ProjectivePoint::from(self) - other
+    }
+}
+
+impl Add<AffinePoint> for ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    fn add(self, other: AffinePoint) -> ProjectivePoint {
+        self.add(&other)
+    }
+}
+
+impl Add<&AffinePoint> for ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    fn add(self, other: &AffinePoint) -> ProjectivePoint {
+        let (x, y, z) = weierstrass::add_mixed(
+            (self.x, self.y, self.z),
+            (other.x, other.y),
+            MockCurve::EQUATION_B,
+        );
+
+        // The mixed addition formula is not complete for an affine identity
+        Self::conditional_select(&Self { x, y, z }, &self, other.is_identity())
+    }
+}
+
+impl AddAssign<AffinePoint> for ProjectivePoint {
+    fn add_assign(&mut self, rhs: AffinePoint) {
+        *self = *self + rhs;
+    }
+}
+
+impl AddAssign<&AffinePoint> for ProjectivePoint {
+    fn add_assign(&mut self, rhs: &AffinePoint) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sum for ProjectivePoint {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        sum_tree(iter)
+    }
+}
+
+impl<'a> Sum<&'a ProjectivePoint> for ProjectivePoint {
+    fn sum<I: Iterator<Item = &'a ProjectivePoint>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl Sum<AffinePoint> for ProjectivePoint {
+    fn sum<I: Iterator<Item = AffinePoint>>(iter: I) -> Self {
+        sum_tree_mixed(iter)
+    }
+}
+
+impl<'a> Sum<&'a AffinePoint> for ProjectivePoint {
+    fn sum<I: Iterator<Item = &'a AffinePoint>>(iter: I) -> Self {
+        iter.copied().sum()
     }
 }
 
 impl Sub<AffinePoint> for ProjectivePoint {
     type Output = ProjectivePoint;
 
-    fn sub(self, _other: AffinePoint) -> ProjectivePoint {
-        unimplemented!();
+    fn sub(self, other: AffinePoint) -> ProjectivePoint {
+        self.sub(&other)
     }
 }
 
 impl Sub<&AffinePoint> for ProjectivePoint {
     type Output = ProjectivePoint;
 
-    fn sub(self, _other: &AffinePoint) -> ProjectivePoint {
-        unimplemented!();
+    fn sub(self, other: &AffinePoint) -> ProjectivePoint {
+        self - ProjectivePoint::from(*other)
     }
 }
 
 impl SubAssign<AffinePoint> for ProjectivePoint {
-    fn sub_assign(&mut self, _rhs: AffinePoint) {
-        unimplemented!();
+    fn sub_assign(&mut self, rhs: AffinePoint) {
+        *self = *self - rhs;
     }
 }
 
 impl SubAssign<&AffinePoint> for ProjectivePoint {
-    fn sub_assign(&mut self, _rhs: &AffinePoint) {
-        unimplemented!();
+    fn sub_assign(&mut self, rhs: &AffinePoint) {
+        *self = *self - rhs;
     }
 }
 
@@ -792,48 +1386,91 @@ impl Mul<Scalar> for ProjectivePoint {
     type Output = ProjectivePoint;
 
     fn mul(self, scalar: Scalar) -> ProjectivePoint {
-        match self {
-            Self::Generator => Self::FixedBaseOutput(scalar),
-            _ => unimplemented!(),
-        }
+        self * &scalar
     }
 }
 
 impl Mul<&Scalar> for ProjectivePoint {
     type Output = ProjectivePoint;
 
+    /// Constant-time double-and-add (from the most significant bit).
+    #[allow(clippy::integer_arithmetic)]
     fn mul(self, scalar: &Scalar) -> ProjectivePoint {
-        self * *scalar
+        let mut acc = Self::IDENTITY;
+
+        for byte in scalar.to_repr() {
+            for i in (0..8).rev() {
+                acc = group::Group::double(&acc);
+                acc.conditional_assign(&(acc + self), Choice::from((byte >> i) & 1));
+            }
+        }
+
+        acc
     }
 }
 
 impl MulAssign<Scalar> for ProjectivePoint {
-    fn mul_assign(&mut self, _rhs: Scalar) {
-        unimplemented!();
+    fn mul_assign(&mut self, rhs: Scalar) {
+        *self = *self * rhs;
     }
 }
 
 impl MulAssign<&Scalar> for ProjectivePoint {
-    fn mul_assign(&mut self, _rhs: &Scalar) {
-        unimplemented!();
+    fn mul_assign(&mut self, rhs: &Scalar) {
+        *self = *self * rhs;
     }
 }
 
 impl MulByGenerator for ProjectivePoint {}
 
+#[cfg(feature = "critical-section")]
+impl crate::precompute::SharedGeneratorTable for MockCurve {
+    fn generator_table() -> &'static crate::precompute::GeneratorTable<Self> {
+        static TABLE: crate::precompute::LazyGeneratorTable<MockCurve> =
+            crate::precompute::LazyGeneratorTable::new();
+        TABLE.get()
+    }
+}
+
 impl Neg for ProjectivePoint {
     type Output = ProjectivePoint;
 
     fn neg(self) -> ProjectivePoint {
-        unimplemented!();
+        -&self
+    }
+}
+
+impl Neg for &ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    fn neg(self) -> ProjectivePoint {
+        ProjectivePoint {
+            x: self.x,
+            y: -self.y,
+            z: self.z,
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Scalar;
-    use ff::PrimeField;
+    use super::{AffinePoint, FieldBytes, FieldElement, MockCurve, ProjectivePoint, Scalar};
+    use crate::{
+        bigint::U256,
+        ops::{AddMixed, Reduce},
+        point::{
+            AffineCoordinates, DecompressPoint, GeneratorCoordinates, ProjectiveCoordinates,
+            ToAffineBatch,
+        },
+        sec1::{CompressInto, CompressedPoint, ToCompressedPoint, ToEncodedPoint},
+        subtle::{Choice, ConditionallyNegatable},
+        Curve as _, CurveArithmetic,
+    };
+    use alloc::vec::Vec;
+    use ff::{Field, PrimeField};
+    use group::{Curve, Group};
     use hex_literal::hex;
+    use rand_core::OsRng;
 
     #[test]
     fn round_trip() {
@@ -841,4 +1478,947 @@ mod tests {
         let scalar = Scalar::from_repr(bytes.into()).unwrap();
         assert_eq!(&bytes, scalar.to_repr().as_slice());
     }
+
+    #[test]
+    fn generator_satisfies_curve_equation() {
+        let AffinePoint { x, y, .. } = ProjectivePoint::generator().to_affine();
+        let rhs = x.square() * x + MockCurve::EQUATION_A * x + MockCurve::EQUATION_B;
+        assert_eq!(y.square(), rhs);
+        assert_ne!(FieldElement::ZERO, rhs);
+    }
+
+    #[test]
+    fn to_encoded_point_compressed_round_trip() {
+        let point = ProjectivePoint::generator() * Scalar::from(42u64);
+        let bytes = point.to_encoded_point_compressed().unwrap();
+        let x = FieldBytes::clone_from_slice(&bytes[1..]);
+        let decompressed = AffinePoint::decompress(&x, Choice::from(bytes[0] & 1)).unwrap();
+        assert_eq!(decompressed, point.to_affine());
+        assert!(bool::from(
+            ProjectivePoint::identity()
+                .to_encoded_point_compressed()
+                .is_none()
+        ));
+    }
+
+    #[test]
+    fn compress_into() {
+        let points = [
+            ProjectivePoint::generator(),
+            ProjectivePoint::identity(),
+            ProjectivePoint::generator() * Scalar::from(42u64),
+            -ProjectivePoint::generator(),
+        ];
+        let compressed: Vec<_> = MockCurve::to_compressed_batch(&points);
+
+        for (point, expected) in points.iter().zip(&compressed) {
+            let affine = point.to_affine();
+            let mut out = CompressedPoint::<MockCurve>::default();
+            out.fill(0xff);
+            affine.compress_into(&mut out);
+            assert_eq!(&out, expected);
+
+            let encoded = affine.to_encoded_point(true);
+            assert_eq!(&out[..encoded.len()], encoded.as_bytes());
+        }
+    }
+
+    #[test]
+    fn cmp_canonical() {
+        let g = ProjectivePoint::generator();
+        let mut points: Vec<AffinePoint> = (0..16u64)
+            .map(|i| (g * Scalar::from(i % 12)).to_affine())
+            .collect();
+
+        let mut reversed = points.clone();
+        reversed.reverse();
+
+        points.sort_by(CompressInto::cmp_canonical);
+        reversed.sort_by(CompressInto::cmp_canonical);
+        assert_eq!(points, reversed);
+        assert_eq!(points[0], AffinePoint::IDENTITY);
+
+        let encodings: Vec<CompressedPoint<MockCurve>> = points
+            .iter()
+            .map(|point| {
+                let mut bytes = CompressedPoint::<MockCurve>::default();
+                point.compress_into(&mut bytes);
+                bytes
+            })
+            .collect();
+        assert!(encodings.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+
+    #[test]
+    fn to_compressed_batch() {
+        let points = [
+            ProjectivePoint::generator(),
+            ProjectivePoint::identity(),
+            ProjectivePoint::generator() * Scalar::from(42u64),
+        ];
+        let compressed: Vec<_> = MockCurve::to_compressed_batch(&points);
+        assert_eq!(compressed.len(), points.len());
+        assert_eq!(
+            compressed[0],
+            points[0].to_encoded_point_compressed().unwrap()
+        );
+        assert_eq!(compressed[1], Default::default());
+        assert_eq!(
+            compressed[2],
+            points[2].to_encoded_point_compressed().unwrap()
+        );
+    }
+
+    #[test]
+    fn mul_compressed() {
+        let point = ProjectivePoint::random(&mut OsRng).to_affine();
+
+        for scalar in [Scalar::random(&mut OsRng), Scalar::ONE, -Scalar::ONE] {
+            let product = ProjectivePoint::from(point) * scalar;
+            assert_eq!(
+                MockCurve::mul_compressed(&point, &scalar),
+                product.to_encoded_point_compressed().unwrap()
+            );
+        }
+
+        assert_eq!(
+            MockCurve::mul_compressed(&point, &Scalar::ZERO),
+            Default::default()
+        );
+    }
+
+    #[test]
+    fn batch_sec1_round_trip() {
+        use crate::point::{batch_from_sec1, batch_to_sec1_compressed};
+
+        let step = ProjectivePoint::random(&mut OsRng);
+        let mut points = Vec::with_capacity(2048);
+        let mut acc = ProjectivePoint::random(&mut OsRng);
+        for _ in 0..2048 {
+            points.push(acc);
+            acc += step;
+        }
+        points[7] = ProjectivePoint::identity();
+
+        let mut bytes = b"prefix".to_vec();
+        batch_to_sec1_compressed::<MockCurve>(&points, &mut bytes);
+        assert_eq!(&bytes[..6], b"prefix");
+        let bytes = &bytes[6..];
+        assert_eq!(bytes.len(), points.len() * 33);
+
+        for (chunk, point) in bytes.chunks_exact(33).zip(&points).skip(8).take(64) {
+            assert_eq!(chunk, point.to_encoded_point(true).as_bytes());
+        }
+        assert_eq!(&bytes[7 * 33..8 * 33], &[0; 33]);
+
+        assert_eq!(batch_from_sec1::<MockCurve>(bytes).unwrap(), points);
+        assert!(batch_from_sec1::<MockCurve>(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn batch_from_sec1_errors() {
+        use crate::{point::batch_from_sec1, Error};
+
+        let points = [ProjectivePoint::generator(); 4];
+        let mut bytes = Vec::new();
+        crate::point::batch_to_sec1_compressed::<MockCurve>(&points, &mut bytes);
+
+        assert_eq!(
+            batch_from_sec1::<MockCurve>(&bytes[..100]),
+            Err(Error::invalid_length(132, 100))
+        );
+
+        let mut invalid = bytes.clone();
+        invalid[2 * 33] = 0x04;
+        assert_eq!(
+            batch_from_sec1::<MockCurve>(&invalid),
+            Err(Error::invalid_batch_element(2))
+        );
+
+        // Not a valid x-coordinate
+        let mut invalid = bytes;
+        invalid[33 + 1..2 * 33].fill(0xff);
+        invalid[3 * 33] = 0x05;
+        assert_eq!(
+            batch_from_sec1::<MockCurve>(&invalid),
+            Err(Error::invalid_batch_element(1))
+        );
+    }
+
+    #[cfg(feature = "bits")]
+    #[test]
+    fn scalar_bits_match_field_bytes() {
+        use crate::CurveArithmeticBits;
+        use ff::PrimeFieldBits;
+
+        fn le_bits<C: CurveArithmeticBits>(scalar: &C::Scalar) -> crate::scalar::ScalarBits<C> {
+            scalar.to_le_bits()
+        }
+
+        let scalar = Scalar::from_repr(
+            hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721").into(),
+        )
+        .unwrap();
+        let bytes = scalar.to_repr();
+        let bits = le_bits::<MockCurve>(&scalar);
+        assert_eq!(bits.len(), 256);
+
+        for (i, bit) in bits.iter().enumerate() {
+            let byte = bytes[bytes.len() - 1 - i / 8];
+            assert_eq!(*bit, (byte >> (i % 8)) & 1 == 1);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hash_equal_points() {
+        use std::collections::HashSet;
+
+        let a = ProjectivePoint::generator() * Scalar::from(6u64);
+        let b = ProjectivePoint::generator().double() * Scalar::from(3u64);
+        let c = ProjectivePoint::generator();
+        assert_ne!((a.x, a.y, a.z), (b.x, b.y, b.z));
+
+        let set: HashSet<_> = [a.to_affine(), b.to_affine(), c.to_affine()].into();
+        assert_eq!(set.len(), 2);
+
+        let set: HashSet<_> = [a, b, c].into();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&(c + c + c + c + c + c)));
+    }
+
+    #[test]
+    fn sum_points() {
+        let empty: [AffinePoint; 0] = [];
+        assert_eq!(
+            empty.iter().sum::<ProjectivePoint>(),
+            ProjectivePoint::IDENTITY
+        );
+        assert_eq!(
+            core::iter::empty::<ProjectivePoint>().sum::<ProjectivePoint>(),
+            ProjectivePoint::IDENTITY
+        );
+
+        let g = ProjectivePoint::generator();
+        assert_eq!([g.to_affine()].into_iter().sum::<ProjectivePoint>(), g);
+        assert_eq!([g].iter().sum::<ProjectivePoint>(), g);
+
+        let points: [ProjectivePoint; 100] =
+            core::array::from_fn(|i| g * Scalar::from(i as u64 * 7 + 1));
+        let affine = MockCurve::to_affine_batch_array(&points);
+        let expected = points
+            .iter()
+            .fold(ProjectivePoint::IDENTITY, |acc, p| acc + p);
+        assert_eq!(points.iter().sum::<ProjectivePoint>(), expected);
+        assert_eq!(affine.iter().sum::<ProjectivePoint>(), expected);
+        assert_eq!(
+            points[..50].iter().sum::<ProjectivePoint>()
+                + affine[50..].iter().sum::<ProjectivePoint>(),
+            expected
+        );
+    }
+
+    #[test]
+    fn conditional_negate() {
+        let p = ProjectivePoint::generator() * Scalar::from(42u64);
+        let mut q = p;
+        q.conditional_negate(Choice::from(0));
+        assert_eq!((q.x, q.y, q.z), (p.x, p.y, p.z));
+        q.conditional_negate(Choice::from(1));
+        assert_eq!(q, -p);
+        assert!(bool::from((q + p).is_identity()));
+
+        let a = p.to_affine();
+        let mut b = a;
+        b.conditional_negate(Choice::from(0));
+        assert_eq!((b.x, b.y, b.infinity), (a.x, a.y, a.infinity));
+        b.conditional_negate(Choice::from(1));
+        assert_eq!(b, -a);
+        assert_eq!(b, (-p).to_affine());
+    }
+
+    #[test]
+    fn reduce_checked() {
+        let (scalar, is_canonical) = Scalar::reduce_checked(U256::from_u64(42));
+        assert_eq!(scalar, Scalar::from(42u64));
+        assert!(bool::from(is_canonical));
+
+        let (scalar, is_canonical) =
+            Scalar::reduce_checked(MockCurve::ORDER.wrapping_add(&U256::ONE));
+        assert_eq!(scalar, Scalar::ONE);
+        assert!(!bool::from(is_canonical));
+    }
+
+    #[test]
+    fn generator_coordinates() {
+        let generator = ProjectivePoint::generator().to_affine();
+        assert_eq!(
+            generator,
+            <AffinePoint as GeneratorCoordinates<MockCurve>>::GENERATOR
+        );
+        assert_eq!(
+            AffinePoint::generator_x().as_slice(),
+            hex!("6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296")
+        );
+        assert_eq!(
+            AffinePoint::generator_y().as_slice(),
+            hex!("4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5")
+        );
+        assert_eq!(AffinePoint::generator_y(), generator.y());
+    }
+
+    #[test]
+    fn challenge_bytes_canonical() {
+        let point = ProjectivePoint::generator() * Scalar::from(42u64);
+        let lambda = FieldElement::from(7u64);
+        let rescaled = ProjectivePoint {
+            x: point.x * lambda,
+            y: point.y * lambda,
+            z: point.z * lambda,
+        };
+        assert_ne!(point.z, rescaled.z);
+        assert_eq!(point.challenge_bytes(), rescaled.challenge_bytes());
+        assert_eq!(
+            point.challenge_bytes(),
+            point.to_encoded_point_compressed().unwrap()
+        );
+
+        let identity = ProjectivePoint::identity();
+        let rescaled_identity = ProjectivePoint {
+            x: identity.x * lambda,
+            y: identity.y * lambda,
+            z: identity.z * lambda,
+        };
+        assert_eq!(
+            identity.challenge_bytes(),
+            rescaled_identity.challenge_bytes()
+        );
+        assert!(identity.challenge_bytes().iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn size_consts() {
+        let mut buf = [0u8; MockCurve::FIELD_BYTES_SIZE];
+        buf.copy_from_slice(&Scalar::ONE.to_repr());
+        assert_eq!(buf.len(), FieldBytes::default().len());
+        assert_eq!(buf.len(), FieldElement::ONE.to_repr().len());
+        assert_eq!(MockCurve::ORDER_BITS, Scalar::NUM_BITS);
+        assert_eq!(MockCurve::ORDER_BITS as usize, MockCurve::ORDER.bits());
+    }
+
+    #[test]
+    fn batch_normalize_into() {
+        let g = ProjectivePoint::generator();
+        let points: [ProjectivePoint; 8] = core::array::from_fn(|i| g * Scalar::from(i as u64));
+
+        let mut out = [AffinePoint::default(); 8];
+        MockCurve::batch_normalize_into(&points, &mut out);
+        assert_eq!(out, MockCurve::to_affine_batch_array(&points));
+        assert_eq!(
+            out.to_vec(),
+            MockCurve::to_affine_batch_slice::<Vec<_>>(&points)
+        );
+    }
+
+    #[test]
+    fn to_affine_batch_iter() {
+        let g = ProjectivePoint::generator();
+        let scalars: Vec<Scalar> = (0..8).map(Scalar::from).collect();
+        let points: Vec<ProjectivePoint> = scalars.iter().map(|k| g * k).collect();
+        let expected = MockCurve::to_affine_batch_slice::<Vec<_>>(&points);
+
+        let mapped = MockCurve::to_affine_batch_iter::<_, Vec<_>>(scalars.iter().map(|k| g * k));
+        assert_eq!(mapped.unwrap(), expected);
+        assert_eq!(
+            MockCurve::to_affine_batch_iter::<_, Vec<_>>(points).unwrap(),
+            expected
+        );
+
+        let empty = MockCurve::to_affine_batch_iter::<_, Vec<_>>(core::iter::empty());
+        assert!(empty.unwrap().is_empty());
+    }
+
+    #[test]
+    fn to_affine_batch_iter_length_mismatch() {
+        /// Iterator reporting one more item than it yields.
+        struct Short(core::iter::Once<ProjectivePoint>);
+
+        impl Iterator for Short {
+            type Item = ProjectivePoint;
+
+            fn next(&mut self) -> Option<ProjectivePoint> {
+                self.0.next()
+            }
+        }
+
+        impl ExactSizeIterator for Short {
+            fn len(&self) -> usize {
+                2
+            }
+        }
+
+        let points = Short(core::iter::once(ProjectivePoint::generator()));
+        assert_eq!(
+            MockCurve::to_affine_batch_iter::<_, Vec<_>>(points),
+            Err(crate::Error::invalid_length(2, 1))
+        );
+    }
+
+    #[test]
+    fn to_affine_batch_matches_batch_normalize() {
+        use crate::dev::cofactor::{self, MockCofactorCurve};
+
+        let points: Vec<ProjectivePoint> = (0..5)
+            .map(|_| ProjectivePoint::random(&mut OsRng))
+            .chain([ProjectivePoint::IDENTITY])
+            .collect();
+        let mut expected = vec![AffinePoint::default(); points.len()];
+        ProjectivePoint::batch_normalize(&points, &mut expected);
+        assert_eq!(
+            MockCurve::to_affine_batch_slice::<Vec<_>>(&points),
+            expected
+        );
+
+        // Curves only implementing `group::Curve` also get `ToAffineBatch`
+        let points = [
+            cofactor::ProjectivePoint::GENERATOR,
+            cofactor::ProjectivePoint::IDENTITY,
+        ];
+        let mut expected = [cofactor::AffinePoint::default(); 2];
+        cofactor::ProjectivePoint::batch_normalize(&points, &mut expected);
+        assert_eq!(MockCofactorCurve::to_affine_batch_array(&points), expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn batch_normalize_into_length_mismatch() {
+        let points = [ProjectivePoint::generator(); 2];
+        let mut out = [AffinePoint::default(); 3];
+        MockCurve::batch_normalize_into(&points, &mut out);
+    }
+
+    #[test]
+    fn mul_to_affine_batch() {
+        let points: Vec<AffinePoint> = (0..8)
+            .map(|_| ProjectivePoint::random(&mut OsRng).to_affine())
+            .collect();
+        let scalars: Vec<Scalar> = (0..8).map(|_| Scalar::random(&mut OsRng)).collect();
+
+        let expected: Vec<AffinePoint> = points
+            .iter()
+            .zip(&scalars)
+            .map(|(p, k)| (ProjectivePoint::from(*p) * k).to_affine())
+            .collect();
+        assert_eq!(
+            MockCurve::mul_to_affine_batch::<Vec<_>>(&points, &scalars),
+            expected
+        );
+    }
+
+    #[test]
+    fn to_affine_batch_with_zinv() {
+        let mut points: [ProjectivePoint; 8] =
+            core::array::from_fn(|_| ProjectivePoint::random(&mut OsRng).double());
+        let (affine, inverses) = MockCurve::to_affine_batch_with_zinv(&points).unwrap();
+
+        for ((point, affine), z_inv) in points.iter().zip(&affine).zip(&inverses) {
+            assert_eq!(*affine, point.to_affine());
+            assert_eq!(ProjectiveCoordinates::x(point) * z_inv, affine.x);
+            assert_eq!(ProjectiveCoordinates::y(point) * z_inv, affine.y);
+        }
+
+        points[5] = ProjectivePoint::IDENTITY;
+        assert!(bool::from(
+            MockCurve::to_affine_batch_with_zinv(&points).is_none()
+        ));
+        assert!(bool::from(
+            MockCurve::to_affine_batch_with_zinv(&[]).is_some()
+        ));
+    }
+
+    #[test]
+    fn to_affine_batch_masked() {
+        let mut points: [ProjectivePoint; 8] =
+            core::array::from_fn(|_| ProjectivePoint::random(&mut OsRng));
+        points[0] = ProjectivePoint::IDENTITY;
+        points[3] = ProjectivePoint::IDENTITY;
+        points[7] = ProjectivePoint::IDENTITY;
+
+        let (affine, mask) = MockCurve::to_affine_batch_masked(&points);
+        for ((point, affine), is_valid) in points.iter().zip(&affine).zip(&mask) {
+            assert_eq!(bool::from(*is_valid), !bool::from(point.is_identity()));
+            assert_eq!(*affine, point.to_affine());
+        }
+        assert_eq!(affine[3], AffinePoint::IDENTITY);
+
+        let (_, mask) = MockCurve::to_affine_batch_masked(&[ProjectivePoint::generator(); 4]);
+        assert!(mask.iter().all(|is_valid| bool::from(*is_valid)));
+    }
+
+    #[test]
+    fn to_affine_batch_partition() {
+        let mut points: [ProjectivePoint; 8] =
+            core::array::from_fn(|_| ProjectivePoint::random(&mut OsRng));
+        for i in [0, 2, 3, 6] {
+            points[i] = ProjectivePoint::IDENTITY;
+        }
+
+        let (affine, valid) = MockCurve::to_affine_batch_partition(&points);
+        assert_eq!(valid, 4);
+        for (affine, i) in affine.iter().zip([1, 4, 5, 7]) {
+            assert_eq!(*affine, points[i].to_affine());
+        }
+        assert!(affine[valid..]
+            .iter()
+            .all(|affine| *affine == AffinePoint::IDENTITY));
+
+        let points = [ProjectivePoint::generator(), ProjectivePoint::IDENTITY];
+        assert_eq!(
+            MockCurve::to_affine_batch_partition(&points),
+            ([AffinePoint::GENERATOR, AffinePoint::IDENTITY], 1)
+        );
+        assert_eq!(
+            MockCurve::to_affine_batch_partition(&[ProjectivePoint::IDENTITY; 3]),
+            ([AffinePoint::IDENTITY; 3], 0)
+        );
+        assert_eq!(MockCurve::to_affine_batch_partition(&[]), ([], 0));
+
+        // Every pattern of identities
+        let points: [ProjectivePoint; 7] =
+            core::array::from_fn(|_| ProjectivePoint::random(&mut OsRng));
+        for pattern in 0..1 << points.len() {
+            let mut masked = points;
+            for (i, point) in masked.iter_mut().enumerate() {
+                if pattern & (1 << i) != 0 {
+                    *point = ProjectivePoint::IDENTITY;
+                }
+            }
+
+            let expected: Vec<AffinePoint> = masked
+                .iter()
+                .filter(|point| !bool::from(point.is_identity()))
+                .map(|point| point.to_affine())
+                .collect();
+            let (affine, valid) = MockCurve::to_affine_batch_partition(&masked);
+            assert_eq!(&affine[..valid], expected.as_slice());
+            assert!(affine[valid..]
+                .iter()
+                .all(|affine| *affine == AffinePoint::IDENTITY));
+        }
+    }
+
+    #[test]
+    fn batch_z_inverses() {
+        let points: Vec<ProjectivePoint> = (1..8)
+            .map(|_| ProjectivePoint::random(&mut OsRng).double())
+            .collect();
+        let inverses = MockCurve::batch_z_inverses(&points).unwrap();
+
+        for (point, z_inv) in points.iter().zip(&inverses) {
+            let affine = point.to_affine();
+            assert_eq!(ProjectiveCoordinates::x(point) * z_inv, affine.x);
+            assert_eq!(ProjectiveCoordinates::y(point) * z_inv, affine.y);
+        }
+
+        let mut with_identity = points;
+        with_identity.push(ProjectivePoint::IDENTITY);
+        assert!(bool::from(
+            MockCurve::batch_z_inverses(&with_identity).is_none()
+        ));
+        assert!(bool::from(MockCurve::batch_z_inverses(&[]).is_some()));
+    }
+
+    #[test]
+    fn mul_by_generator_to_affine_batch() {
+        let mut scalars: Vec<Scalar> = (0..8).map(|_| Scalar::random(&mut OsRng)).collect();
+        scalars.push(Scalar::ZERO);
+
+        let expected: Vec<AffinePoint> = scalars
+            .iter()
+            .map(|k| (ProjectivePoint::generator() * k).to_affine())
+            .collect();
+        assert_eq!(
+            MockCurve::mul_by_generator_to_affine_batch::<Vec<_>>(&scalars),
+            expected
+        );
+        assert!(MockCurve::mul_by_generator_to_affine_batch::<Vec<_>>(&[]).is_empty());
+    }
+
+    #[test]
+    fn is_quadratic_residue() {
+        let s = Scalar::random(&mut OsRng);
+        assert!(bool::from(MockCurve::is_quadratic_residue(&s.square())));
+        assert!(bool::from(MockCurve::is_quadratic_residue(&Scalar::ZERO)));
+        assert!(bool::from(MockCurve::is_quadratic_residue(&Scalar::ONE)));
+        assert!(bool::from(MockCurve::is_quadratic_residue(&Scalar::from(
+            2u64
+        ))));
+
+        // 7 generates the multiplicative group, so it and any odd power of it
+        // are non-residues
+        let g = Scalar::from(7u64);
+        assert!(!bool::from(MockCurve::is_quadratic_residue(&g)));
+        assert!(!bool::from(MockCurve::is_quadratic_residue(
+            &(g * s.square())
+        )));
+    }
+
+    #[test]
+    fn x_reduced_to_scalar() {
+        // RFC 6979 A.2.5: P-256 with SHA-256, message "sample"
+        let k = Scalar::from_repr(
+            hex!("A6E3C57DD01ABE90086538398355DD4C3B17AA873382B0F24D6129493D8AAD60").into(),
+        )
+        .unwrap();
+        let r = hex!("EFD48B2AACB6A8FD1140DD9CD45E81D69D2C877B56AAF991C34D0EA84EAF3716");
+        let point = (ProjectivePoint::generator() * k).to_affine();
+        assert_eq!(MockCurve::x_reduced_to_scalar(&point).to_repr(), r.into());
+
+        // x = n + 3 overflows the order
+        let x = hex!("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632554");
+        let point = AffinePoint::decompress(&x.into(), Choice::from(0)).unwrap();
+        assert_eq!(MockCurve::x_reduced_to_scalar(&point), Scalar::from(3u64));
+
+        assert_eq!(
+            MockCurve::x_reduced_to_scalar(&AffinePoint::IDENTITY),
+            Scalar::ZERO
+        );
+    }
+
+    #[test]
+    fn x_overflows_order() {
+        let point = ProjectivePoint::generator().to_affine();
+        assert!(!bool::from(MockCurve::x_overflows_order(&point)));
+
+        // x = n + 3 overflows the order
+        let x = hex!("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632554");
+        let point = AffinePoint::decompress(&x.into(), Choice::from(0)).unwrap();
+        assert!(bool::from(MockCurve::x_overflows_order(&point)));
+
+        for _ in 0..8 {
+            let point = ProjectivePoint::random(&mut OsRng).to_affine();
+            let x = U256::from_be_slice(&point.x());
+            assert_eq!(
+                bool::from(MockCurve::x_overflows_order(&point)),
+                x >= MockCurve::ORDER
+            );
+        }
+    }
+
+    #[test]
+    fn generator_on_curve() {
+        use crate::dev::{cofactor, edwards, wide};
+
+        assert!(bool::from(MockCurve::is_on_curve(&AffinePoint::GENERATOR)));
+        assert!(bool::from(cofactor::MockCofactorCurve::is_on_curve(
+            &cofactor::AffinePoint::GENERATOR
+        )));
+        assert!(bool::from(edwards::MockEdwardsCurve::is_on_curve(
+            &edwards::AffinePoint::GENERATOR
+        )));
+        assert!(bool::from(wide::MockWideCurve::is_on_curve(
+            &wide::AffinePoint::GENERATOR
+        )));
+    }
+
+    #[test]
+    fn are_on_curve() {
+        let mut points: Vec<AffinePoint> = (0..8)
+            .map(|_| ProjectivePoint::random(&mut OsRng).to_affine())
+            .collect();
+        points.push(AffinePoint::IDENTITY);
+        assert!(bool::from(MockCurve::are_on_curve_strict(&points)));
+        assert!(bool::from(MockCurve::are_on_curve_batch(
+            &points, &mut OsRng
+        )));
+        assert!(bool::from(MockCurve::are_on_curve_batch(&[], &mut OsRng)));
+
+        let off_curve = AffinePoint {
+            y: points[3].y + FieldElement::ONE,
+            ..points[3]
+        };
+        assert!(!bool::from(MockCurve::is_on_curve(&off_curve)));
+
+        points[3] = off_curve;
+        assert!(!bool::from(MockCurve::are_on_curve_strict(&points)));
+        assert!(!bool::from(MockCurve::are_on_curve_batch(
+            &points, &mut OsRng
+        )));
+    }
+
+    #[test]
+    fn add_affine() {
+        let p = (ProjectivePoint::GENERATOR * Scalar::from(3u64)).to_affine();
+        let q = (ProjectivePoint::GENERATOR * Scalar::from(5u64)).to_affine();
+        let identity = AffinePoint::IDENTITY;
+
+        for (a, b) in [
+            (p, q),
+            (p, p),
+            (p, -p),
+            (p, identity),
+            (identity, q),
+            (identity, identity),
+        ] {
+            assert_eq!(
+                MockCurve::add_affine(&a, &b),
+                ProjectivePoint::from(a) + ProjectivePoint::from(b)
+            );
+        }
+    }
+
+    #[test]
+    fn double_batch() {
+        let points: [ProjectivePoint; 4] = [
+            ProjectivePoint::random(&mut OsRng),
+            ProjectivePoint::random(&mut OsRng),
+            ProjectivePoint::IDENTITY,
+            ProjectivePoint::GENERATOR,
+        ];
+        let expected = points.map(|p| p.double());
+
+        assert_eq!(MockCurve::double_batch(&points), expected);
+        assert_eq!(MockCurve::double_batch_slice(&points), expected.to_vec());
+        assert!(MockCurve::double_batch_slice(&[]).is_empty());
+    }
+
+    #[test]
+    fn add_mixed() {
+        let p = ProjectivePoint::GENERATOR * Scalar::from(3u64);
+        let q = (ProjectivePoint::GENERATOR * Scalar::from(5u64)).to_affine();
+        let identity = ProjectivePoint::IDENTITY;
+
+        for (a, b) in [
+            (p, q),
+            (p, p.to_affine()),
+            (p, -q),
+            (-ProjectivePoint::from(q), q),
+            (p, AffinePoint::IDENTITY),
+            (identity, q),
+            (identity, AffinePoint::IDENTITY),
+        ] {
+            assert_eq!(a.add_mixed(&b), a + ProjectivePoint::from(b));
+        }
+    }
+
+    #[test]
+    fn sum_affine() {
+        assert_eq!(MockCurve::sum_affine(&[]), ProjectivePoint::identity());
+
+        let g = ProjectivePoint::generator();
+        let points: Vec<AffinePoint> = (0..9u64)
+            .map(|i| match i % 3 {
+                0 => AffinePoint::IDENTITY,
+                _ => (g * Scalar::from(i + 1)).to_affine(),
+            })
+            .collect();
+
+        for len in 0..=points.len() {
+            let naive = points[..len]
+                .iter()
+                .fold(ProjectivePoint::identity(), |acc, point| {
+                    acc + ProjectivePoint::from(*point)
+                });
+            assert_eq!(MockCurve::sum_affine(&points[..len]), naive);
+        }
+    }
+
+    #[test]
+    fn is_identity() {
+        assert!(bool::from(MockCurve::is_identity(
+            &ProjectivePoint::identity()
+        )));
+        assert!(!bool::from(MockCurve::is_identity(
+            &ProjectivePoint::generator()
+        )));
+    }
+
+    #[test]
+    fn field_arithmetic() {
+        let two = FieldElement::from(2u64);
+        let three = FieldElement::from(3u64);
+        assert_eq!(two + three, FieldElement::from(5u64));
+        assert_eq!(two - three, -FieldElement::ONE);
+        assert_eq!(two * three, FieldElement::from(6u64));
+        assert_eq!(two * two.invert().unwrap(), FieldElement::ONE);
+        assert_eq!(two * FieldElement::TWO_INV, FieldElement::ONE);
+        assert_eq!(three.square().sqrt().unwrap().square(), three.square());
+    }
+
+    #[test]
+    fn coordinate_round_trip() {
+        let point = ProjectivePoint::generator().to_affine();
+        let x = MockCurve::affine_x(&point).unwrap();
+        let y = MockCurve::affine_y(&point).unwrap();
+        assert_eq!(x, point.x);
+        assert_eq!(y, point.y);
+        assert_eq!(x.to_repr(), point.x());
+        assert_eq!(y.to_repr(), point.y());
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary_points_are_subgroup_members() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let inputs: [&[u8]; 3] = [&[0; 32], &[0xff; 32], &[0x5a; 32]];
+        for input in inputs {
+            let point = AffinePoint::arbitrary(&mut Unstructured::new(input)).unwrap();
+            if !bool::from(point.is_identity()) {
+                assert_eq!(point.y.square(), FieldElement::curve_equation_rhs(&point.x));
+            }
+        }
+    }
+
+    /// Tests which are generic over the curve, instantiated for each mock
+    /// curve so that they cover more than one [`FieldBytesSize`] width.
+    ///
+    /// [`FieldBytesSize`]: crate::FieldBytesSize
+    macro_rules! generic_curve_tests {
+        ($($name:ident => $curve:ty),+ $(,)?) => {
+            $(
+                mod $name {
+                    use crate::{
+                        point::{AffineCoordinates, ToAffineBatch},
+                        sec1::{CompressedPoint, EncodedPoint, ToEncodedPoint, UncompressedPoint},
+                        AffinePoint, CurveArithmetic, FieldBytes, FieldBytesSize, NonZeroScalar,
+                        PublicKey, Scalar, ScalarPrimitive,
+                    };
+                    use ff::{Field, PrimeField};
+                    use generic_array::typenum::Unsigned;
+                    use group::{Curve, Group};
+                    use rand_core::OsRng;
+
+                    type C = $curve;
+
+                    #[test]
+                    fn scalar_round_trip() {
+                        let scalar = Scalar::<C>::random(&mut OsRng);
+                        let bytes = scalar.to_repr();
+                        assert_eq!(bytes.len(), FieldBytesSize::<C>::USIZE);
+                        assert_eq!(Scalar::<C>::from_repr(bytes).unwrap(), scalar);
+
+                        let primitive = ScalarPrimitive::<C>::from_slice(&bytes).unwrap();
+                        assert_eq!(primitive.to_bytes(), bytes);
+                        assert_eq!(Scalar::<C>::from(primitive), scalar);
+
+                        let nonzero = NonZeroScalar::<C>::from_repr(bytes).unwrap();
+                        assert_eq!(FieldBytes::<C>::from(nonzero), bytes);
+
+                        let mut too_long = bytes.to_vec();
+                        too_long.push(0);
+                        assert!(ScalarPrimitive::<C>::from_slice(&too_long).is_err());
+                        assert!(ScalarPrimitive::<C>::from_slice(&bytes[1..]).is_err());
+
+                        let mut max = FieldBytes::<C>::default();
+                        max.fill(0xff);
+                        assert!(bool::from(Scalar::<C>::from_repr(max).is_none()));
+                    }
+
+                    #[test]
+                    fn affine_identity() {
+                        let identity = <AffinePoint<C> as AffineCoordinates>::identity();
+                        assert_eq!(identity, AffinePoint::<C>::default());
+                        assert_eq!(identity, <AffinePoint<C> as AffineCoordinates>::IDENTITY);
+                        assert_eq!(
+                            <C as CurveArithmetic>::ProjectivePoint::from(identity),
+                            <C as CurveArithmetic>::ProjectivePoint::identity()
+                        );
+                    }
+
+                    #[test]
+                    fn encoded_point_sizes() {
+                        let field_size = FieldBytesSize::<C>::USIZE;
+                        assert_eq!(CompressedPoint::<C>::default().len(), 1 + field_size);
+                        assert_eq!(UncompressedPoint::<C>::default().len(), 1 + 2 * field_size);
+                        assert_eq!(
+                            AsRef::<[u8]>::as_ref(&<C as CurveArithmetic>::CompressedPoint::default()).len(),
+                            1 + field_size
+                        );
+                        assert_eq!(
+                            AsRef::<[u8]>::as_ref(&<C as CurveArithmetic>::UncompressedPoint::default()).len(),
+                            1 + 2 * field_size
+                        );
+
+                        let point = (<C as CurveArithmetic>::ProjectivePoint::generator()
+                            * Scalar::<C>::from(42u64))
+                        .to_affine();
+
+                        for (compress, len) in [(true, 1 + field_size), (false, 1 + 2 * field_size)] {
+                            let encoded: EncodedPoint<C> = point.to_encoded_point(compress);
+                            assert_eq!(encoded.len(), len);
+                            assert_eq!(encoded.is_compressed(), compress);
+
+                            let public_key = PublicKey::<C>::from_sec1_bytes(encoded.as_bytes()).unwrap();
+                            assert_eq!(public_key.as_affine(), &point);
+                            assert!(PublicKey::<C>::from_sec1_bytes(&encoded.as_bytes()[..len - 1]).is_err());
+                        }
+
+                        assert_eq!(EncodedPoint::<C>::identity().len(), 1);
+                    }
+
+                    #[test]
+                    fn moduli() {
+                        use crate::{
+                            bigint::{ArrayEncoding, ByteArray},
+                            Curve,
+                        };
+
+                        fn parse(hex: &str) -> <C as Curve>::Uint {
+                            let mut bytes = ByteArray::<<C as Curve>::Uint>::default();
+                            base16ct::mixed::decode(hex.trim_start_matches("0x"), &mut bytes).unwrap();
+                            <C as Curve>::Uint::from_be_byte_array(bytes)
+                        }
+
+                        assert_eq!(C::ORDER, parse(Scalar::<C>::MODULUS));
+                        assert_eq!(
+                            C::FIELD_MODULUS,
+                            parse(<C as CurveArithmetic>::FieldElement::MODULUS)
+                        );
+                    }
+
+                    #[test]
+                    fn to_affine_batch() {
+                        let g = <C as CurveArithmetic>::ProjectivePoint::generator();
+                        let points: [_; 6] = core::array::from_fn(|i| g * Scalar::<C>::from(i as u64));
+                        let affine = C::to_affine_batch_array(&points);
+
+                        for (point, affine) in points.iter().zip(&affine) {
+                            assert_eq!(point.to_affine(), *affine);
+                        }
+
+                        assert_eq!(
+                            C::to_affine_batch_slice::<alloc::vec::Vec<_>>(&points),
+                            affine.to_vec()
+                        );
+                    }
+
+                    #[cfg(feature = "hash2curve")]
+                    #[test]
+                    fn hash_to_field_lengths() {
+                        use crate::hash2curve::{hash_to_field, ExpandMsgXmd, FromOkm};
+                        use sha2::Sha256;
+
+                        // `L = ceil((ceil(log2(n)) + k) / 8)` where the security level
+                        // `k` is half the order size
+                        let order_bits = <C as CurveArithmetic>::ORDER_BITS as usize;
+                        assert_eq!(
+                            <Scalar<C> as FromOkm>::Length::USIZE,
+                            (order_bits + order_bits / 2 + 7) / 8
+                        );
+
+                        let mut out = [Scalar::<C>::ZERO; 2];
+                        hash_to_field::<ExpandMsgXmd<Sha256>, _>(&[b"msg"], &[b"DST"], &mut out).unwrap();
+                        assert_ne!(out[0], out[1]);
+                        assert!(!bool::from(out[0].is_zero()));
+                    }
+                }
+            )+
+        };
+    }
+
+    generic_curve_tests! {
+        mock_curve => crate::dev::MockCurve,
+        mock_wide_curve => crate::dev::wide::MockWideCurve,
+    }
 }
@@ -0,0 +1,1346 @@
+//! Mock curve with a cofactor.
+//!
+//! This curve is modeled off of Wei25519, i.e. Curve25519 in short Weierstrass
+//! form, whose group order is `8 * ℓ`. It exists to exercise the small-subgroup
+//! handling paths of this crate, e.g. [`CofactorArithmetic`].
+
+use crate::{
+    bigint::{
+        modular::constant_mod::{Residue, ResidueParams},
+        ArrayEncoding, Integer, U256,
+    },
+    error::{Error, ErrorKind, Result},
+    generic_array::typenum::U32,
+    ops::{
+        sum_tree, sum_tree_mixed, AddMixed, Invert, LinearCombination, MulByGenerator,
+        MultiScalarMul, Reduce, ShrAssign,
+    },
+    point::AffineCoordinates,
+    rand_core::RngCore,
+    scalar::{CanonicalUint, FromUintUnchecked, IsHigh},
+    sec1::{CompressedPoint, Coordinates, FromEncodedPoint, ToEncodedPoint},
+    subtle::{Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeLess, CtOption},
+    zeroize::DefaultIsZeroes,
+    CofactorArithmetic, Curve, CurveArithmetic, FieldBytesEncoding, MontgomeryXOnly,
+    ScalarArithmetic,
+};
+use core::{
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+use ff::{Field, PrimeField};
+
+/// SEC1 encoded point.
+pub type EncodedPoint = crate::sec1::EncodedPoint<MockCofactorCurve>;
+
+/// Field element bytes.
+pub type FieldBytes = crate::FieldBytes<MockCofactorCurve>;
+
+/// Non-zero scalar value.
+pub type NonZeroScalar = crate::NonZeroScalar<MockCofactorCurve>;
+
+/// Public key.
+pub type PublicKey = crate::PublicKey<MockCofactorCurve>;
+
+/// Scalar primitive type.
+pub type ScalarPrimitive = crate::ScalarPrimitive<MockCofactorCurve>;
+
+/// Moduli used by the mock curve's Montgomery-form residues.
+mod moduli {
+    use crate::bigint::{impl_modulus, U256};
+
+    impl_modulus!(
+        FieldModulus,
+        U256,
+        "7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed"
+    );
+
+    impl_modulus!(
+        ScalarModulus,
+        U256,
+        "1000000000000000000000000000000014def9dea2f79cd65812631a5cf5d3ed"
+    );
+}
+
+/// Base field residue.
+type FieldResidue = Residue<moduli::FieldModulus, { U256::LIMBS }>;
+
+/// Scalar field residue.
+type ScalarResidue = Residue<moduli::ScalarModulus, { U256::LIMBS }>;
+
+/// Implement the by-value and `*Assign` forms of an arithmetic operator in
+/// terms of its by-reference form.
+macro_rules! impl_op_variants {
+    ($ty:ty, $op:ident, $op_fn:ident, $assign:ident, $assign_fn:ident) => {
+        impl $op<$ty> for $ty {
+            type Output = $ty;
+
+            fn $op_fn(self, other: $ty) -> $ty {
+                self.$op_fn(&other)
+            }
+        }
+
+        impl $assign<$ty> for $ty {
+            fn $assign_fn(&mut self, other: $ty) {
+                *self = (*self).$op_fn(&other);
+            }
+        }
+
+        impl $assign<&$ty> for $ty {
+            fn $assign_fn(&mut self, other: &$ty) {
+                *self = (*self).$op_fn(other);
+            }
+        }
+    };
+}
+
+pub(super) use impl_op_variants;
+
+/// Mock elliptic curve type with cofactor 8.
+///
+/// Note: this type provides a simple, unoptimized arithmetic implementation
+/// in affine coordinates which is only suitable for tests.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+pub struct MockCofactorCurve;
+
+impl Curve for MockCofactorCurve {
+    type FieldBytesSize = U32;
+    type Uint = U256;
+
+    const ORDER: U256 =
+        U256::from_be_hex("1000000000000000000000000000000014def9dea2f79cd65812631a5cf5d3ed");
+}
+
+impl ScalarArithmetic for MockCofactorCurve {
+    type Scalar = Scalar;
+}
+
+impl CurveArithmetic for MockCofactorCurve {
+    type AffinePoint = AffinePoint;
+    type FieldElement = FieldElement;
+    type ProjectivePoint = ProjectivePoint;
+    type CompressedPoint = CompressedPoint<Self>;
+    type UncompressedPoint = crate::sec1::UncompressedPoint<Self>;
+
+    const EQUATION_A: FieldElement =
+        FieldElement::from_hex("2aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa984914a144");
+    const EQUATION_B: FieldElement =
+        FieldElement::from_hex("7b425ed097b425ed097b425ed097b425ed097b425ed097b4260b5e9c7710c864");
+    const FIELD_MODULUS: U256 = moduli::FieldModulus::MODULUS;
+    const COFACTOR: u64 = 8;
+
+    fn is_torsion_free(point: &ProjectivePoint) -> Choice {
+        group::Group::is_identity(&Self::mul_by_order(point))
+    }
+}
+
+impl CofactorArithmetic for MockCofactorCurve {}
+
+#[cfg(feature = "ecdh")]
+impl crate::ecdh::ValidatePublicKey for MockCofactorCurve {}
+
+/// X25519, i.e. x-only arithmetic on the Montgomery model of this curve.
+impl MontgomeryXOnly for MockCofactorCurve {
+    fn generator_u() -> FieldBytes {
+        let mut u = FieldBytes::default();
+        u[0] = 9;
+        u
+    }
+
+    #[allow(clippy::integer_arithmetic)]
+    fn x_only_mul(scalar: &FieldBytes, u: &FieldBytes) -> CtOption<FieldBytes> {
+        let mut k = *scalar;
+        k[0] &= 248;
+        k[31] &= 127;
+        k[31] |= 64;
+
+        let mut u = *u;
+        u[31] &= 127;
+        let x1 = FieldElement(FieldResidue::new(&U256::from_le_byte_array(u)));
+        let a24 = FieldElement::from(121665);
+
+        let (mut x2, mut z2) = (FieldElement::ONE, FieldElement::ZERO);
+        let (mut x3, mut z3) = (x1, FieldElement::ONE);
+        let mut swap = Choice::from(0);
+
+        for t in (0..255).rev() {
+            let k_t = Choice::from((k[t / 8] >> (t % 8)) & 1);
+            swap ^= k_t;
+            FieldElement::conditional_swap(&mut x2, &mut x3, swap);
+            FieldElement::conditional_swap(&mut z2, &mut z3, swap);
+            swap = k_t;
+
+            let a = x2 + z2;
+            let aa = a.square();
+            let b = x2 - z2;
+            let bb = b.square();
+            let e = aa - bb;
+            let da = (x3 - z3) * a;
+            let cb = (x3 + z3) * b;
+            x3 = (da + cb).square();
+            z3 = x1 * (da - cb).square();
+            x2 = aa * bb;
+            z2 = e * (aa + a24 * e);
+        }
+
+        FieldElement::conditional_swap(&mut x2, &mut x3, swap);
+        FieldElement::conditional_swap(&mut z2, &mut z3, swap);
+
+        let result = x2 * z2.invert().unwrap_or(FieldElement::ZERO);
+        CtOption::new(result.0.retrieve().to_le_byte_array(), !result.is_zero())
+    }
+}
+
+impl FieldBytesEncoding<MockCofactorCurve> for U256 {}
+
+/// Example scalar type
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+pub struct Scalar(ScalarPrimitive);
+
+impl Scalar {
+    /// Parse a scalar from a big endian hex string.
+    ///
+    /// Does *not* perform a check that the value is in range.
+    const fn from_hex(hex: &str) -> Self {
+        Self(ScalarPrimitive::from_uint_unchecked(U256::from_be_hex(hex)))
+    }
+
+    /// Convert to a Montgomery-form residue.
+    fn to_residue(self) -> ScalarResidue {
+        ScalarResidue::new(self.0.as_uint())
+    }
+
+    /// Convert from a Montgomery-form residue.
+    fn from_residue(residue: ScalarResidue) -> Self {
+        Self(ScalarPrimitive::from_uint_unchecked(residue.retrieve()))
+    }
+}
+
+impl Field for Scalar {
+    const ZERO: Self = Self(ScalarPrimitive::ZERO);
+    const ONE: Self = Self(ScalarPrimitive::ONE);
+
+    fn random(mut rng: impl RngCore) -> Self {
+        let mut bytes = FieldBytes::default();
+
+        loop {
+            rng.fill_bytes(&mut bytes);
+            if let Some(scalar) = Self::from_repr(bytes).into() {
+                return scalar;
+            }
+        }
+    }
+
+    fn is_zero(&self) -> Choice {
+        self.0.is_zero()
+    }
+
+    fn square(&self) -> Self {
+        Self::from_residue(self.to_residue().square())
+    }
+
+    fn double(&self) -> Self {
+        self.add(self)
+    }
+
+    fn invert(&self) -> CtOption<Self> {
+        let (inverse, is_some) = self.to_residue().invert();
+        CtOption::new(Self::from_residue(inverse), is_some.into())
+    }
+
+    fn sqrt(&self) -> CtOption<Self> {
+        // ℓ = 5 mod 8, so the square root is either `self^((ℓ + 3) / 8)` or
+        // that value multiplied by `sqrt(-1)`
+        let sqrt = Self::from_residue(self.to_residue().pow(&U256::from_be_hex(
+            "02000000000000000000000000000000029bdf3bd45ef39acb024c634b9eba7e",
+        )));
+        let sqrt = Self::conditional_select(
+            &(sqrt * Self::ROOT_OF_UNITY),
+            &sqrt,
+            sqrt.square().ct_eq(self),
+        );
+        CtOption::new(sqrt, sqrt.square().ct_eq(self))
+    }
+
+    fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+        ff::helpers::sqrt_ratio_generic(num, div)
+    }
+}
+
+impl PrimeField for Scalar {
+    type Repr = FieldBytes;
+
+    const MODULUS: &'static str =
+        "0x1000000000000000000000000000000014def9dea2f79cd65812631a5cf5d3ed";
+    const NUM_BITS: u32 = 253;
+    const CAPACITY: u32 = 252;
+    const TWO_INV: Self =
+        Self::from_hex("080000000000000000000000000000000a6f7cef517bce6b2c09318d2e7ae9f7");
+    const MULTIPLICATIVE_GENERATOR: Self =
+        Self::from_hex("0000000000000000000000000000000000000000000000000000000000000002");
+    const S: u32 = 2;
+    const ROOT_OF_UNITY: Self =
+        Self::from_hex("094a7310e07981e77d3d6d60abc1c27a0ef0565342ce83febe8775dfebbe07d4");
+    const ROOT_OF_UNITY_INV: Self =
+        Self::from_hex("06b58cef1f867e1882c2929f543e3d8605eea38b602918d7998aed3a7137cc19");
+    const DELTA: Self =
+        Self::from_hex("0000000000000000000000000000000000000000000000000000000000000010");
+
+    fn from_repr(bytes: FieldBytes) -> CtOption<Self> {
+        ScalarPrimitive::from_bytes(&bytes).map(Self)
+    }
+
+    fn to_repr(&self) -> FieldBytes {
+        self.0.to_bytes()
+    }
+
+    fn is_odd(&self) -> Choice {
+        self.0.is_odd()
+    }
+}
+
+impl AsRef<Scalar> for Scalar {
+    fn as_ref(&self) -> &Scalar {
+        self
+    }
+}
+
+impl ConditionallySelectable for Scalar {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(ScalarPrimitive::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+impl ConstantTimeEq for Scalar {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl DefaultIsZeroes for Scalar {}
+
+impl Add<&Scalar> for Scalar {
+    type Output = Scalar;
+
+    fn add(self, other: &Scalar) -> Scalar {
+        Self(self.0.add(&other.0))
+    }
+}
+
+impl Sub<&Scalar> for Scalar {
+    type Output = Scalar;
+
+    fn sub(self, other: &Scalar) -> Scalar {
+        Self(self.0.sub(&other.0))
+    }
+}
+
+impl Mul<&Scalar> for Scalar {
+    type Output = Scalar;
+
+    fn mul(self, other: &Scalar) -> Scalar {
+        Self::from_residue(self.to_residue() * other.to_residue())
+    }
+}
+
+impl_op_variants!(Scalar, Add, add, AddAssign, add_assign);
+impl_op_variants!(Scalar, Sub, sub, SubAssign, sub_assign);
+impl_op_variants!(Scalar, Mul, mul, MulAssign, mul_assign);
+
+impl Neg for Scalar {
+    type Output = Scalar;
+
+    fn neg(self) -> Scalar {
+        Self(self.0.neg())
+    }
+}
+
+impl ShrAssign<usize> for Scalar {
+    fn shr_assign(&mut self, rhs: usize) {
+        self.0 >>= rhs;
+    }
+}
+
+impl Sum for Scalar {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self(iter.map(|scalar| scalar.0).sum())
+    }
+}
+
+impl<'a> Sum<&'a Scalar> for Scalar {
+    fn sum<I: Iterator<Item = &'a Scalar>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl Product for Scalar {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, Mul::mul)
+    }
+}
+
+impl<'a> Product<&'a Scalar> for Scalar {
+    fn product<I: Iterator<Item = &'a Scalar>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+impl Invert for Scalar {
+    type Output = CtOption<Scalar>;
+
+    fn invert(&self) -> CtOption<Scalar> {
+        Field::invert(self)
+    }
+}
+
+impl Reduce<U256> for Scalar {
+    type Bytes = FieldBytes;
+
+    fn reduce(w: U256) -> Self {
+        Self(ScalarPrimitive::from_uint_unchecked(
+            MockCofactorCurve::reduce_uint(&w),
+        ))
+    }
+
+    fn reduce_bytes(bytes: &FieldBytes) -> Self {
+        Self::reduce(U256::from_be_byte_array(*bytes))
+    }
+}
+
+impl From<u64> for Scalar {
+    fn from(n: u64) -> Scalar {
+        Self(n.into())
+    }
+}
+
+impl From<ScalarPrimitive> for Scalar {
+    fn from(scalar: ScalarPrimitive) -> Scalar {
+        Self(scalar)
+    }
+}
+
+impl From<Scalar> for ScalarPrimitive {
+    fn from(scalar: Scalar) -> ScalarPrimitive {
+        scalar.0
+    }
+}
+
+impl From<Scalar> for U256 {
+    fn from(scalar: Scalar) -> U256 {
+        scalar.0.to_uint()
+    }
+}
+
+impl TryFrom<U256> for Scalar {
+    type Error = Error;
+
+    fn try_from(w: U256) -> Result<Self> {
+        Option::from(ScalarPrimitive::new(w))
+            .map(Self)
+            .ok_or(Error::new(ErrorKind::Other))
+    }
+}
+
+impl CanonicalUint for Scalar {
+    type Uint = U256;
+
+    fn to_canonical_uint(&self) -> U256 {
+        self.0.to_canonical_uint()
+    }
+
+    fn from_canonical_uint(uint: U256) -> CtOption<Self> {
+        ScalarPrimitive::new(uint).map(Self)
+    }
+}
+
+impl FromUintUnchecked for Scalar {
+    type Uint = U256;
+
+    fn from_uint_unchecked(uint: U256) -> Self {
+        Self(ScalarPrimitive::from_uint_unchecked(uint))
+    }
+}
+
+impl From<Scalar> for FieldBytes {
+    fn from(scalar: Scalar) -> Self {
+        scalar.to_repr()
+    }
+}
+
+impl IsHigh for Scalar {
+    fn is_high(&self) -> Choice {
+        self.0.is_high()
+    }
+}
+
+/// Example base field element type.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct FieldElement(FieldResidue);
+
+impl FieldElement {
+    /// Parse a field element from a big endian hex string.
+    ///
+    /// Does *not* perform a check that the value is in range.
+    pub(super) const fn from_hex(hex: &str) -> Self {
+        Self(FieldResidue::new(&U256::from_be_hex(hex)))
+    }
+
+    /// Compute `x³ + ax + b`, i.e. the right hand side of the curve equation.
+    fn curve_equation_rhs(x: &Self) -> Self {
+        (x.square() + MockCofactorCurve::EQUATION_A) * x + MockCofactorCurve::EQUATION_B
+    }
+}
+
+impl Field for FieldElement {
+    const ZERO: Self = Self(FieldResidue::ZERO);
+    const ONE: Self = Self(FieldResidue::ONE);
+
+    fn random(mut rng: impl RngCore) -> Self {
+        let mut bytes = FieldBytes::default();
+
+        loop {
+            rng.fill_bytes(&mut bytes);
+            if let Some(fe) = Self::from_repr(bytes).into() {
+                return fe;
+            }
+        }
+    }
+
+    fn is_zero(&self) -> Choice {
+        self.0.ct_eq(&FieldResidue::ZERO)
+    }
+
+    fn square(&self) -> Self {
+        Self(self.0.square())
+    }
+
+    fn double(&self) -> Self {
+        Self(self.0 + self.0)
+    }
+
+    fn invert(&self) -> CtOption<Self> {
+        let (inverse, is_some) = self.0.invert();
+        CtOption::new(Self(inverse), is_some.into())
+    }
+
+    fn sqrt(&self) -> CtOption<Self> {
+        // p = 5 mod 8, so the square root is either `self^((p + 3) / 8)` or
+        // that value multiplied by `sqrt(-1)`
+        let sqrt = Self(self.0.pow(&U256::from_be_hex(
+            "0ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe",
+        )));
+        let sqrt = Self::conditional_select(
+            &(sqrt * Self::ROOT_OF_UNITY),
+            &sqrt,
+            sqrt.square().ct_eq(self),
+        );
+        CtOption::new(sqrt, sqrt.square().ct_eq(self))
+    }
+
+    fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+        ff::helpers::sqrt_ratio_generic(num, div)
+    }
+}
+
+impl PrimeField for FieldElement {
+    type Repr = FieldBytes;
+
+    const MODULUS: &'static str =
+        "0x7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed";
+    const NUM_BITS: u32 = 255;
+    const CAPACITY: u32 = 254;
+    const TWO_INV: Self =
+        Self::from_hex("3ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7");
+    const MULTIPLICATIVE_GENERATOR: Self =
+        Self::from_hex("0000000000000000000000000000000000000000000000000000000000000002");
+    const S: u32 = 2;
+    const ROOT_OF_UNITY: Self =
+        Self::from_hex("2b8324804fc1df0b2b4d00993dfbd7a72f431806ad2fe478c4ee1b274a0ea0b0");
+    const ROOT_OF_UNITY_INV: Self =
+        Self::from_hex("547cdb7fb03e20f4d4b2ff66c2042858d0bce7f952d01b873b11e4d8b5f15f3d");
+    const DELTA: Self =
+        Self::from_hex("0000000000000000000000000000000000000000000000000000000000000010");
+
+    fn from_repr(bytes: FieldBytes) -> CtOption<Self> {
+        let uint = U256::from_be_byte_array(bytes);
+        let is_some = uint.ct_lt(&moduli::FieldModulus::MODULUS);
+        CtOption::new(Self(FieldResidue::new(&uint)), is_some)
+    }
+
+    fn to_repr(&self) -> FieldBytes {
+        self.0.retrieve().to_be_byte_array()
+    }
+
+    fn is_odd(&self) -> Choice {
+        self.0.retrieve().is_odd()
+    }
+}
+
+impl From<u64> for FieldElement {
+    fn from(n: u64) -> FieldElement {
+        Self(FieldResidue::new(&U256::from_u64(n)))
+    }
+}
+
+impl ConditionallySelectable for FieldElement {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(FieldResidue::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+impl ConstantTimeEq for FieldElement {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl Add<&FieldElement> for FieldElement {
+    type Output = FieldElement;
+
+    fn add(self, other: &FieldElement) -> FieldElement {
+        Self(self.0 + other.0)
+    }
+}
+
+impl Sub<&FieldElement> for FieldElement {
+    type Output = FieldElement;
+
+    fn sub(self, other: &FieldElement) -> FieldElement {
+        Self(self.0 - other.0)
+    }
+}
+
+impl Mul<&FieldElement> for FieldElement {
+    type Output = FieldElement;
+
+    fn mul(self, other: &FieldElement) -> FieldElement {
+        Self(self.0 * other.0)
+    }
+}
+
+impl_op_variants!(FieldElement, Add, add, AddAssign, add_assign);
+impl_op_variants!(FieldElement, Sub, sub, SubAssign, sub_assign);
+impl_op_variants!(FieldElement, Mul, mul, MulAssign, mul_assign);
+
+impl Neg for FieldElement {
+    type Output = FieldElement;
+
+    fn neg(self) -> FieldElement {
+        Self(-self.0)
+    }
+}
+
+impl Sum for FieldElement {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+impl<'a> Sum<&'a FieldElement> for FieldElement {
+    fn sum<I: Iterator<Item = &'a FieldElement>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl Product for FieldElement {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, Mul::mul)
+    }
+}
+
+impl<'a> Product<&'a FieldElement> for FieldElement {
+    fn product<I: Iterator<Item = &'a FieldElement>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+/// Example affine point type
+#[derive(Clone, Copy, Debug)]
+pub struct AffinePoint {
+    /// x-coordinate
+    x: FieldElement,
+
+    /// y-coordinate
+    y: FieldElement,
+
+    /// Is this point the point at infinity? 0 = no, 1 = yes
+    infinity: u8,
+}
+
+impl AffinePoint {
+    /// Additive identity of the group a.k.a. the point at infinity.
+    pub const IDENTITY: Self = Self {
+        x: FieldElement::ZERO,
+        y: FieldElement::ZERO,
+        infinity: 1,
+    };
+
+    /// Base point of the curve, which generates the prime order subgroup.
+    pub const GENERATOR: Self = Self {
+        x: FieldElement::from_hex(
+            "2aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaad245a",
+        ),
+        y: FieldElement::from_hex(
+            "20ae19a1b8a086b4e01edd2c7748d14c923d4d7e6d7c61b229e9c5a27eced3d9",
+        ),
+        infinity: 0,
+    };
+
+    /// Point of order 8, which generates the small-order torsion subgroup.
+    pub const TORSION: Self = Self {
+        x: FieldElement::from_hex(
+            "01bc4a7b87f8cd833138c7036f06eeaf069a2e47005c7b5bcf36fb4e6742c0c3",
+        ),
+        y: FieldElement::from_hex(
+            "173a6c76c2ba719bce3935ffba04afeadf5bbcb971559722f0efc7bdfb7f9a36",
+        ),
+        infinity: 0,
+    };
+
+    /// Is this point the identity point?
+    pub fn is_identity(&self) -> Choice {
+        Choice::from(self.infinity)
+    }
+
+    /// Add two points using the affine chord-and-tangent formulas.
+    fn add(&self, other: &Self) -> Self {
+        let same_x = self.x.ct_eq(&other.x);
+        let opposite = same_x & (self.y + other.y).is_zero();
+
+        let chord = (other.y - self.y) * (other.x - self.x).invert().unwrap_or(FieldElement::ZERO);
+        let tangent = (self.x.square().double() + self.x.square() + MockCofactorCurve::EQUATION_A)
+            * self.y.double().invert().unwrap_or(FieldElement::ZERO);
+        let lambda = FieldElement::conditional_select(&chord, &tangent, same_x);
+
+        let x = lambda.square() - self.x - other.x;
+        let y = lambda * (self.x - x) - self.y;
+        let sum = Self::conditional_select(&Self { x, y, infinity: 0 }, &Self::IDENTITY, opposite);
+
+        let sum = Self::conditional_select(&sum, other, self.is_identity());
+        Self::conditional_select(&sum, self, other.is_identity())
+    }
+}
+
+impl AffineCoordinates for AffinePoint {
+    type FieldRepr = FieldBytes;
+
+    const IDENTITY: Self = AffinePoint::IDENTITY;
+
+    fn x(&self) -> FieldBytes {
+        self.x.to_repr()
+    }
+
+    fn y(&self) -> FieldBytes {
+        self.y.to_repr()
+    }
+
+    fn y_is_odd(&self) -> Choice {
+        self.y.is_odd()
+    }
+}
+
+impl ConstantTimeEq for AffinePoint {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.x.ct_eq(&other.x) & self.y.ct_eq(&other.y) & self.infinity.ct_eq(&other.infinity)
+    }
+}
+
+impl ConditionallySelectable for AffinePoint {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self {
+            x: FieldElement::conditional_select(&a.x, &b.x, choice),
+            y: FieldElement::conditional_select(&a.y, &b.y, choice),
+            infinity: u8::conditional_select(&a.infinity, &b.infinity, choice),
+        }
+    }
+}
+
+impl Default for AffinePoint {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl DefaultIsZeroes for AffinePoint {}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for AffinePoint {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        crate::point::arbitrary_affine_point::<MockCofactorCurve>(u)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <crate::ScalarPrimitive<MockCofactorCurve> as arbitrary::Arbitrary>::size_hint(depth)
+    }
+}
+
+impl Eq for AffinePoint {}
+
+impl PartialEq for AffinePoint {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Neg for AffinePoint {
+    type Output = AffinePoint;
+
+    fn neg(self) -> AffinePoint {
+        -&self
+    }
+}
+
+impl Neg for &AffinePoint {
+    type Output = AffinePoint;
+
+    fn neg(self) -> AffinePoint {
+        AffinePoint {
+            x: self.x,
+            y: -self.y,
+            infinity: self.infinity,
+        }
+    }
+}
+
+impl FromEncodedPoint<MockCofactorCurve> for AffinePoint {
+    fn from_encoded_point(encoded_point: &EncodedPoint) -> CtOption<Self> {
+        match encoded_point.coordinates() {
+            Coordinates::Identity => CtOption::new(Self::IDENTITY, Choice::from(1)),
+            Coordinates::Compact { .. } => CtOption::new(Self::IDENTITY, Choice::from(0)),
+            Coordinates::Compressed { x, y_is_odd } => FieldElement::from_repr(*x).and_then(|x| {
+                FieldElement::curve_equation_rhs(&x).sqrt().map(|y| {
+                    let y_is_odd = Choice::from(u8::from(y_is_odd));
+                    let y = FieldElement::conditional_select(&-y, &y, y.is_odd().ct_eq(&y_is_odd));
+                    Self { x, y, infinity: 0 }
+                })
+            }),
+            Coordinates::Uncompressed { x, y } => FieldElement::from_repr(*x).and_then(|x| {
+                FieldElement::from_repr(*y).and_then(|y| {
+                    let on_curve = y.square().ct_eq(&FieldElement::curve_equation_rhs(&x));
+                    CtOption::new(Self { x, y, infinity: 0 }, on_curve)
+                })
+            }),
+        }
+    }
+}
+
+impl ToEncodedPoint<MockCofactorCurve> for AffinePoint {
+    fn to_encoded_point(&self, compress: bool) -> EncodedPoint {
+        if self.is_identity().into() {
+            EncodedPoint::identity()
+        } else {
+            EncodedPoint::from_affine_coordinates(&self.x.to_repr(), &self.y.to_repr(), compress)
+        }
+    }
+}
+
+impl group::GroupEncoding for AffinePoint {
+    type Repr = CompressedPoint<MockCofactorCurve>;
+
+    fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
+        EncodedPoint::from_bytes(bytes)
+            .map(|point| CtOption::new(point, Choice::from(1)))
+            .unwrap_or_else(|_| {
+                let is_identity = bytes.ct_eq(&Self::Repr::default());
+                CtOption::new(EncodedPoint::identity(), is_identity)
+            })
+            .and_then(|point| Self::from_encoded_point(&point))
+    }
+
+    fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
+        Self::from_bytes(bytes)
+    }
+
+    fn to_bytes(&self) -> Self::Repr {
+        let encoded = self.to_encoded_point(true);
+        let mut result = CompressedPoint::<MockCofactorCurve>::default();
+        result[..encoded.len()].copy_from_slice(encoded.as_bytes());
+        result
+    }
+}
+
+/// Example projective point type.
+///
+/// Points are kept normalized, i.e. this is a thin wrapper around
+/// [`AffinePoint`] which performs one inversion per group operation.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ProjectivePoint(AffinePoint);
+
+impl ProjectivePoint {
+    /// Additive identity of the group a.k.a. the point at infinity.
+    pub const IDENTITY: Self = Self(AffinePoint::IDENTITY);
+
+    /// Base point of the curve, which generates the prime order subgroup.
+    pub const GENERATOR: Self = Self(AffinePoint::GENERATOR);
+
+    /// Point of order 8, which generates the small-order torsion subgroup.
+    pub const TORSION: Self = Self(AffinePoint::TORSION);
+}
+
+impl ConstantTimeEq for ProjectivePoint {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl ConditionallySelectable for ProjectivePoint {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(AffinePoint::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+impl DefaultIsZeroes for ProjectivePoint {}
+
+impl From<AffinePoint> for ProjectivePoint {
+    fn from(point: AffinePoint) -> ProjectivePoint {
+        Self(point)
+    }
+}
+
+impl From<ProjectivePoint> for AffinePoint {
+    fn from(point: ProjectivePoint) -> AffinePoint {
+        point.0
+    }
+}
+
+impl FromEncodedPoint<MockCofactorCurve> for ProjectivePoint {
+    fn from_encoded_point(point: &EncodedPoint) -> CtOption<Self> {
+        AffinePoint::from_encoded_point(point).map(Self)
+    }
+}
+
+impl ToEncodedPoint<MockCofactorCurve> for ProjectivePoint {
+    fn to_encoded_point(&self, compress: bool) -> EncodedPoint {
+        self.0.to_encoded_point(compress)
+    }
+}
+
+impl group::Group for ProjectivePoint {
+    type Scalar = Scalar;
+
+    fn random(rng: impl RngCore) -> Self {
+        Self::GENERATOR * Scalar::random(rng)
+    }
+
+    fn identity() -> Self {
+        Self::IDENTITY
+    }
+
+    fn generator() -> Self {
+        Self::GENERATOR
+    }
+
+    fn is_identity(&self) -> Choice {
+        self.0.is_identity()
+    }
+
+    fn double(&self) -> Self {
+        Self(AffinePoint::add(&self.0, &self.0))
+    }
+}
+
+impl group::GroupEncoding for ProjectivePoint {
+    type Repr = CompressedPoint<MockCofactorCurve>;
+
+    fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
+        AffinePoint::from_bytes(bytes).map(Self)
+    }
+
+    fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
+        Self::from_bytes(bytes)
+    }
+
+    fn to_bytes(&self) -> Self::Repr {
+        self.0.to_bytes()
+    }
+}
+
+impl group::Curve for ProjectivePoint {
+    type AffineRepr = AffinePoint;
+
+    fn to_affine(&self) -> AffinePoint {
+        self.0
+    }
+}
+
+impl AddMixed<AffinePoint> for ProjectivePoint {}
+
+impl LinearCombination for ProjectivePoint {}
+
+impl MultiScalarMul for MockCofactorCurve {}
+
+impl MulByGenerator for ProjectivePoint {}
+
+impl Add<&ProjectivePoint> for ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    fn add(self, other: &ProjectivePoint) -> ProjectivePoint {
+        Self(AffinePoint::add(&self.0, &other.0))
+    }
+}
+
+impl Sub<&ProjectivePoint> for ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    fn sub(self, other: &ProjectivePoint) -> ProjectivePoint {
+        self.add(&other.neg())
+    }
+}
+
+impl_op_variants!(ProjectivePoint, Add, add, AddAssign, add_assign);
+impl_op_variants!(ProjectivePoint, Sub, sub, SubAssign, sub_assign);
+
+impl Add<AffinePoint> for AffinePoint {
+    type Output = ProjectivePoint;
+
+    fn add(self, other: AffinePoint) -> ProjectivePoint {
+        ProjectivePoint::from(self) + other
+    }
+}
+
+impl Add<&AffinePoint> for AffinePoint {
+    type Output = ProjectivePoint;
+
+    fn add(self, other: &AffinePoint) -> ProjectivePoint {
+        ProjectivePoint::from(self) + other
+    }
+}
+
+impl Sub<AffinePoint> for AffinePoint {
+    type Output = ProjectivePoint;
+
+    fn sub(self, other: AffinePoint) -> ProjectivePoint {
+        ProjectivePoint::from(self) - other
+    }
+}
+
+impl Sub<&AffinePoint> for AffinePoint {
+    type Output = ProjectivePoint;
+
+    fn sub(self, other: &AffinePoint) -> ProjectivePoint {
+        ProjectivePoint::from(self) - other
+    }
+}
+
+impl Add<AffinePoint> for ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    fn add(self, other: AffinePoint) -> ProjectivePoint {
+        self.add(&other)
+    }
+}
+
+impl Add<&AffinePoint> for ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    fn add(self, other: &AffinePoint) -> ProjectivePoint {
+        Self(AffinePoint::add(&self.0, other))
+    }
+}
+
+impl AddAssign<AffinePoint> for ProjectivePoint {
+    fn add_assign(&mut self, rhs: AffinePoint) {
+        *self = *self + rhs;
+    }
+}
+
+impl AddAssign<&AffinePoint> for ProjectivePoint {
+    fn add_assign(&mut self, rhs: &AffinePoint) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<AffinePoint> for ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    fn sub(self, other: AffinePoint) -> ProjectivePoint {
+        self.sub(&other)
+    }
+}
+
+impl Sub<&AffinePoint> for ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    fn sub(self, other: &AffinePoint) -> ProjectivePoint {
+        self.add(&other.neg())
+    }
+}
+
+impl SubAssign<AffinePoint> for ProjectivePoint {
+    fn sub_assign(&mut self, rhs: AffinePoint) {
+        *self = *self - rhs;
+    }
+}
+
+impl SubAssign<&AffinePoint> for ProjectivePoint {
+    fn sub_assign(&mut self, rhs: &AffinePoint) {
+        *self = *self - rhs;
+    }
+}
+
+impl Sum for ProjectivePoint {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        sum_tree(iter)
+    }
+}
+
+impl<'a> Sum<&'a ProjectivePoint> for ProjectivePoint {
+    fn sum<I: Iterator<Item = &'a ProjectivePoint>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl Sum<AffinePoint> for ProjectivePoint {
+    fn sum<I: Iterator<Item = AffinePoint>>(iter: I) -> Self {
+        sum_tree_mixed(iter)
+    }
+}
+
+impl<'a> Sum<&'a AffinePoint> for ProjectivePoint {
+    fn sum<I: Iterator<Item = &'a AffinePoint>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl Mul<Scalar> for ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    fn mul(self, scalar: Scalar) -> ProjectivePoint {
+        self * &scalar
+    }
+}
+
+impl Mul<&Scalar> for ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    /// Constant-time double-and-add (from the most significant bit).
+    #[allow(clippy::integer_arithmetic)]
+    fn mul(self, scalar: &Scalar) -> ProjectivePoint {
+        let mut acc = Self::IDENTITY;
+
+        for byte in scalar.to_repr() {
+            for i in (0..8).rev() {
+                acc = group::Group::double(&acc);
+                acc.conditional_assign(&(acc + self), Choice::from((byte >> i) & 1));
+            }
+        }
+
+        acc
+    }
+}
+
+impl MulAssign<Scalar> for ProjectivePoint {
+    fn mul_assign(&mut self, rhs: Scalar) {
+        *self = *self * rhs;
+    }
+}
+
+impl MulAssign<&Scalar> for ProjectivePoint {
+    fn mul_assign(&mut self, rhs: &Scalar) {
+        *self = *self * rhs;
+    }
+}
+
+impl Neg for ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    fn neg(self) -> ProjectivePoint {
+        -&self
+    }
+}
+
+impl Neg for &ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    fn neg(self) -> ProjectivePoint {
+        ProjectivePoint(-&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AffinePoint, FieldBytes, FieldElement, MockCofactorCurve, ProjectivePoint, Scalar,
+    };
+    use crate::{ops::Reduce, CofactorArithmetic, CurveArithmetic, MontgomeryXOnly};
+    use ff::{Field, PrimeField};
+    use group::{Curve, Group};
+    use hex_literal::hex;
+
+    #[test]
+    fn generator_satisfies_curve_equation() {
+        let g = AffinePoint::GENERATOR;
+        assert_eq!(g.y.square(), FieldElement::curve_equation_rhs(&g.x));
+    }
+
+    #[test]
+    fn torsion_point_has_order_eight() {
+        let t = ProjectivePoint::TORSION;
+        let t = ProjectivePoint::from(t.to_affine());
+        assert_eq!(t.0.y.square(), FieldElement::curve_equation_rhs(&t.0.x));
+        assert!(!bool::from(t.double().double().is_identity()));
+        assert!(bool::from(t.double().double().double().is_identity()));
+    }
+
+    #[test]
+    fn generator_has_prime_order() {
+        let g = ProjectivePoint::GENERATOR;
+        assert!(bool::from(
+            MockCofactorCurve::mul_by_order(&g).is_identity()
+        ));
+        assert_eq!(g * Scalar::from(3u64), g.double() + g);
+        assert_eq!(g * -Scalar::ONE, -g);
+    }
+
+    #[test]
+    fn torsion_checks() {
+        let g = ProjectivePoint::GENERATOR;
+        let t = ProjectivePoint::TORSION;
+        assert!(bool::from(MockCofactorCurve::is_torsion_free(&g)));
+        assert!(!bool::from(MockCofactorCurve::is_torsion_free(&t)));
+        assert!(!bool::from(MockCofactorCurve::is_torsion_free(&(g + t))));
+
+        let cleared = MockCofactorCurve::clear_cofactor(&(g + t));
+        assert!(bool::from(MockCofactorCurve::is_torsion_free(&cleared)));
+        assert_eq!(cleared, g * Scalar::from(8u64));
+        assert!(bool::from(
+            MockCofactorCurve::clear_cofactor(&t).is_identity()
+        ));
+    }
+
+    #[test]
+    fn clear_cofactor() {
+        let g = ProjectivePoint::GENERATOR;
+        let t = ProjectivePoint::TORSION;
+        let cofactor = Scalar::from(MockCofactorCurve::COFACTOR);
+
+        for point in [g, t, g + t, t.double(), ProjectivePoint::IDENTITY] {
+            let product = MockCofactorCurve::clear_cofactor(&point);
+            assert_eq!(product, point * cofactor);
+            assert_eq!(product, point.double().double().double());
+        }
+    }
+
+    #[test]
+    fn x25519_rfc7748_vectors() {
+        let vectors = [
+            (
+                hex!("a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4"),
+                hex!("e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c"),
+                hex!("c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552"),
+            ),
+            (
+                hex!("4b66e9d4d1b4673c5ad22691957d6af5c11b6421e0ea01d42ca4169e7918ba0d"),
+                hex!("e5210f12786811d3f4b7959d0538ae2c31dbe7106fc03c3efc4cd549c715a493"),
+                hex!("95cbde9476e8907d7aade45cb4b873f88b595a68799fa152e6f8f7647aac7957"),
+            ),
+        ];
+
+        for (scalar, u, expected) in vectors {
+            let result = MockCofactorCurve::x_only_mul(&scalar.into(), &u.into()).unwrap();
+            assert_eq!(result, FieldBytes::from(expected));
+        }
+    }
+
+    #[test]
+    fn x25519_diffie_hellman() {
+        let alice = hex!("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a").into();
+        let bob = hex!("5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb").into();
+        let g = MockCofactorCurve::generator_u();
+
+        let alice_public = MockCofactorCurve::x_only_mul(&alice, &g).unwrap();
+        let bob_public = MockCofactorCurve::x_only_mul(&bob, &g).unwrap();
+        assert_eq!(
+            alice_public,
+            FieldBytes::from(hex!(
+                "8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a"
+            ))
+        );
+
+        let shared = MockCofactorCurve::x_only_mul(&alice, &bob_public).unwrap();
+        assert_eq!(
+            shared,
+            MockCofactorCurve::x_only_mul(&bob, &alice_public).unwrap()
+        );
+        assert_eq!(
+            shared,
+            FieldBytes::from(hex!(
+                "4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742"
+            ))
+        );
+
+        // Matches the u-coordinate derived from the short Weierstrass model,
+        // where `u = x - A/3`
+        let mut k = alice;
+        k[0] &= 248;
+        k[31] &= 127;
+        k[31] |= 64;
+        k.reverse();
+        let point = (ProjectivePoint::GENERATOR * Scalar::reduce_bytes(&k)).to_affine();
+        let a_over_3 = FieldElement::from_hex(
+            "2aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaad2451",
+        );
+        let mut u = (point.x - a_over_3).to_repr();
+        u.reverse();
+        assert_eq!(u, alice_public);
+    }
+
+    #[test]
+    fn x25519_input_handling() {
+        let scalar =
+            hex!("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a").into();
+        let public = MockCofactorCurve::x_only_mul(&scalar, &MockCofactorCurve::generator_u());
+
+        // Non-canonical u-coordinates are reduced: `p + 9` is the generator
+        let non_canonical =
+            hex!("f6ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f").into();
+        assert_eq!(
+            MockCofactorCurve::x_only_mul(&scalar, &non_canonical).unwrap(),
+            public.unwrap()
+        );
+
+        // The high bit of u is masked
+        let mut masked = MockCofactorCurve::generator_u();
+        masked[31] |= 0x80;
+        assert_eq!(
+            MockCofactorCurve::x_only_mul(&scalar, &masked).unwrap(),
+            public.unwrap()
+        );
+
+        // Small-order points are rejected
+        let mut one = FieldBytes::default();
+        one[0] = 1;
+        for u in [FieldBytes::default(), one] {
+            assert!(bool::from(
+                MockCofactorCurve::x_only_mul(&scalar, &u).is_none()
+            ));
+        }
+    }
+
+    #[test]
+    fn field_square_roots() {
+        for n in 1..32u64 {
+            let x = FieldElement::from(n);
+            assert_eq!(x.square().sqrt().unwrap().square(), x.square());
+
+            let s = Scalar::from(n);
+            assert_eq!(s.square().sqrt().unwrap().square(), s.square());
+        }
+
+        assert_eq!(FieldElement::ROOT_OF_UNITY.square(), -FieldElement::ONE);
+        assert_eq!(Scalar::ROOT_OF_UNITY.square(), -Scalar::ONE);
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary_points_are_subgroup_members() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let inputs: [&[u8]; 3] = [&[0; 32], &[0xff; 32], &[0x5a; 32]];
+        for input in inputs {
+            let point = AffinePoint::arbitrary(&mut Unstructured::new(input)).unwrap();
+            let point = ProjectivePoint::from(point);
+            assert!(bool::from(MockCofactorCurve::is_torsion_free(&point)));
+        }
+    }
+}
@@ -0,0 +1,492 @@
+//! Conformance tests for [`CurveArithmetic`] implementations.
+//!
+//! Each function is a property test which checks a documented invariant
+//! against inputs from the `proptest` strategies in this module, such as
+//! [`scalar`] and [`projective_point`], panicking with a shrunk counterexample
+//! if it doesn't hold. Curve crates can run the whole battery with
+//! [`impl_arithmetic_conformance_tests!`], and the checks for the batch and
+//! multi-scalar extension traits with [`impl_batch_conformance_tests!`]:
+//!
+//! ```
+//! mod conformance {
+//!     elliptic_curve::impl_arithmetic_conformance_tests!(elliptic_curve::dev::MockCurve);
+//!     elliptic_curve::impl_batch_conformance_tests!(elliptic_curve::dev::MockCurve);
+//! }
+//! ```
+//!
+//! The strategies can also be used directly in a curve crate's own
+//! `proptest!` blocks.
+//!
+//! [`impl_arithmetic_conformance_tests!`]: crate::impl_arithmetic_conformance_tests
+//! [`impl_batch_conformance_tests!`]: crate::impl_batch_conformance_tests
+
+use crate::{
+    ops::{
+        invert_all, Invert, InvertBatch, LinearCombination, MsmAccumulator, MulByGenerator,
+        MultiScalarMul, Reduce,
+    },
+    point::ToAffineBatch,
+    CurveArithmetic, FieldBytes, FieldBytesSize, NonZeroScalar, ScalarPrimitive,
+};
+use alloc::vec::Vec;
+use ff::{Field, PrimeField};
+use generic_array::typenum::Unsigned;
+use group::{Curve, Group};
+use proptest::{
+    collection,
+    prelude::*,
+    test_runner::{Config, TestRunner},
+};
+
+/// Number of cases run by each conformance test, and maximum length of the
+/// batches generated for the batch tests.
+pub const SAMPLES: usize = 8;
+
+/// Instantiate the conformance tests in [`dev::conformance`] as `#[test]`
+/// functions for the given curve.
+///
+/// The tests are defined in the invoking module, so each curve should be
+/// given its own module.
+///
+/// [`dev::conformance`]: crate::dev::conformance
+#[macro_export]
+macro_rules! impl_arithmetic_conformance_tests {
+    ($curve:ty) => {
+        #[test]
+        fn scalar_round_trip() {
+            $crate::dev::conformance::scalar_round_trip::<$curve>();
+        }
+
+        #[test]
+        fn reduce_is_idempotent() {
+            $crate::dev::conformance::reduce_is_idempotent::<$curve>();
+        }
+
+        #[test]
+        fn group_laws() {
+            $crate::dev::conformance::group_laws::<$curve>();
+        }
+
+        #[test]
+        fn mixed_addition() {
+            $crate::dev::conformance::mixed_addition::<$curve>();
+        }
+
+        #[test]
+        fn scalar_mul_distributes() {
+            $crate::dev::conformance::scalar_mul_distributes::<$curve>();
+        }
+
+        #[test]
+        fn lincomb_matches_naive() {
+            $crate::dev::conformance::lincomb_matches_naive::<$curve>();
+        }
+
+        #[test]
+        fn batch_normalize_matches_to_affine() {
+            $crate::dev::conformance::batch_normalize_matches_to_affine::<$curve>();
+        }
+    };
+}
+
+/// Instantiate the conformance tests for [`ToAffineBatch`], [`MultiScalarMul`]
+/// and [`InvertBatch`] in [`dev::conformance`] as `#[test]` functions for the
+/// given curve.
+///
+/// [`dev::conformance`]: crate::dev::conformance
+#[macro_export]
+macro_rules! impl_batch_conformance_tests {
+    ($curve:ty) => {
+        #[test]
+        fn test_to_affine_batch() {
+            $crate::dev::conformance::test_to_affine_batch::<$curve>();
+        }
+
+        #[test]
+        fn test_lincomb() {
+            $crate::dev::conformance::test_lincomb::<$curve>();
+        }
+
+        #[test]
+        fn test_batch_invert() {
+            $crate::dev::conformance::test_batch_invert::<$curve>();
+        }
+    };
+}
+
+/// Deterministic sample of `n` scalars.
+///
+/// Starts with the edge cases `0`, `1` and `-1`, followed by pseudorandom
+/// values covering the full width of the scalar field.
+pub fn sample_scalars<C: CurveArithmetic>(n: usize) -> Vec<C::Scalar> {
+    let seed = C::Scalar::from(0x9e37_79b9_7f4a_7c15);
+    let pseudorandom = core::iter::successors(Some(seed), |k| {
+        Some(k.square() + k + C::Scalar::MULTIPLICATIVE_GENERATOR)
+    });
+
+    [C::Scalar::ZERO, C::Scalar::ONE, -C::Scalar::ONE]
+        .into_iter()
+        .chain(pseudorandom)
+        .take(n)
+        .collect()
+}
+
+/// Deterministic sample of `n` points, computed as multiples of the
+/// generator by [`sample_scalars`].
+///
+/// The first point is therefore the identity.
+pub fn sample_points<C: CurveArithmetic>(n: usize) -> Vec<C::ProjectivePoint> {
+    sample_scalars::<C>(n)
+        .iter()
+        .map(|k| C::ProjectivePoint::generator() * k)
+        .collect()
+}
+
+/// Strategy for arbitrary [`FieldBytes`].
+pub fn field_bytes<C: CurveArithmetic>() -> impl Strategy<Value = FieldBytes<C>> {
+    collection::vec(any::<u8>(), FieldBytesSize::<C>::USIZE)
+        .prop_map(|bytes| FieldBytes::<C>::clone_from_slice(&bytes))
+}
+
+/// Strategy for scalars.
+///
+/// Generates the edge cases `0`, `1` and `-1` as well as [`field_bytes`]
+/// reduced modulo the order.
+pub fn scalar<C: CurveArithmetic>() -> impl Strategy<Value = C::Scalar> {
+    prop_oneof![
+        1 => Just(C::Scalar::ZERO),
+        1 => Just(C::Scalar::ONE),
+        1 => Just(-C::Scalar::ONE),
+        7 => field_bytes::<C>().prop_map(|bytes| C::Scalar::reduce_bytes(&bytes)),
+    ]
+}
+
+/// Strategy for non-zero scalars, i.e. [`scalar`] without zero.
+///
+/// Every value can be wrapped with [`NonZeroScalar::new`]. The values aren't
+/// wrapped already because proptest reports failing inputs with [`Debug`],
+/// which [`NonZeroScalar`] deliberately doesn't impl.
+///
+/// [`Debug`]: core::fmt::Debug
+pub fn nonzero_scalar<C: CurveArithmetic>() -> impl Strategy<Value = C::Scalar> {
+    scalar::<C>().prop_filter("scalar is zero", |k| !bool::from(k.is_zero()))
+}
+
+/// Strategy for projective points, computed as multiples of the generator by
+/// [`scalar`].
+///
+/// This includes the identity.
+pub fn projective_point<C: CurveArithmetic>() -> impl Strategy<Value = C::ProjectivePoint> {
+    scalar::<C>().prop_map(|k| C::ProjectivePoint::generator() * k)
+}
+
+/// Strategy for affine points, computed in the same way as
+/// [`projective_point`].
+pub fn affine_point<C: CurveArithmetic>() -> impl Strategy<Value = C::AffinePoint> {
+    projective_point::<C>().prop_map(|p| p.to_affine())
+}
+
+/// Run `test` against [`SAMPLES`] inputs from `strategy`, panicking with the
+/// shrunk input if it fails.
+#[allow(clippy::cast_possible_truncation, clippy::panic)]
+fn check<S: Strategy>(strategy: S, test: impl Fn(S::Value)) {
+    let config = Config {
+        cases: SAMPLES as u32,
+        failure_persistence: None,
+        ..Config::default()
+    };
+
+    if let Err(err) = TestRunner::new(config).run(&strategy, |value| {
+        test(value);
+        Ok(())
+    }) {
+        panic!("{}", err);
+    }
+}
+
+/// Scalars round-trip through [`PrimeField::to_repr`], [`ScalarPrimitive`]
+/// and (when non-zero) [`NonZeroScalar`].
+pub fn scalar_round_trip<C: CurveArithmetic>() {
+    check(scalar::<C>(), |k| {
+        let repr = k.to_repr();
+        assert_eq!(Option::from(C::Scalar::from_repr(repr.clone())), Some(k));
+
+        let primitive: ScalarPrimitive<C> = k.into();
+        assert_eq!(primitive.to_bytes(), repr);
+        assert_eq!(C::Scalar::from(primitive), k);
+
+        let nonzero = Option::<NonZeroScalar<C>>::from(NonZeroScalar::new(k));
+        assert_eq!(nonzero.is_some(), !bool::from(k.is_zero()));
+    });
+
+    check(nonzero_scalar::<C>(), |k| {
+        let nonzero = NonZeroScalar::<C>::new(k).unwrap();
+        assert_eq!(*NonZeroScalar::<C>::from_repr(k.to_repr()).unwrap(), k);
+        assert_eq!(ScalarPrimitive::from(nonzero), k.into());
+    });
+}
+
+/// Reducing a canonical integer returns the same scalar, and reducing the
+/// order yields zero.
+pub fn reduce_is_idempotent<C: CurveArithmetic>() {
+    check(scalar::<C>(), |k| {
+        assert_eq!(C::Scalar::reduce(k.into()), k);
+        assert_eq!(C::Scalar::reduce_bytes(&k.to_repr()), k);
+    });
+
+    check(field_bytes::<C>(), |bytes| {
+        let k = C::Scalar::reduce_bytes(&bytes);
+        assert_eq!(C::Scalar::reduce_bytes(&k.to_repr()), k);
+    });
+
+    assert_eq!(C::Scalar::reduce(C::ORDER), C::Scalar::ZERO);
+}
+
+/// Point addition is commutative and associative, with the identity as its
+/// neutral element and negation as its inverse.
+pub fn group_laws<C: CurveArithmetic>() {
+    let identity = C::ProjectivePoint::identity();
+
+    check(
+        (
+            projective_point::<C>(),
+            projective_point::<C>(),
+            projective_point::<C>(),
+        ),
+        |(p, q, r)| {
+            let (p, q, r) = (&p, &q, &r);
+            assert_eq!(*p + identity, *p);
+            assert!(bool::from((*p - p).is_identity()));
+            assert!(bool::from((*p + -*p).is_identity()));
+            assert_eq!(p.double(), *p + p);
+            assert_eq!(*p + q, *q + p);
+            assert_eq!((*p + q) + r, *p + (*q + r));
+        },
+    );
+}
+
+/// Mixed addition and subtraction of affine points agree with projective
+/// arithmetic, including when either operand is the identity.
+pub fn mixed_addition<C: CurveArithmetic>() {
+    let identity = C::ProjectivePoint::identity().to_affine();
+
+    check(
+        (projective_point::<C>(), projective_point::<C>()),
+        |(p, q)| {
+            let (p_affine, q_affine) = (&p.to_affine(), &q.to_affine());
+            let sum = p + q;
+            let difference = p - q;
+
+            assert_eq!(*p_affine + *q_affine, sum);
+            assert_eq!(*p_affine + q_affine, sum);
+            assert_eq!(*p_affine - *q_affine, difference);
+            assert_eq!(*p_affine - q_affine, difference);
+            assert_eq!(p + *q_affine, sum);
+            assert_eq!(p + q_affine, sum);
+            assert_eq!(p - *q_affine, difference);
+            assert_eq!(p - q_affine, difference);
+
+            let mut acc = p;
+            acc += *q_affine;
+            assert_eq!(acc, sum);
+            acc -= q_affine;
+            assert_eq!(acc, p);
+
+            assert_eq!(*p_affine + identity, p);
+            assert_eq!(identity + *p_affine, p);
+            assert_eq!(C::ProjectivePoint::identity() + p_affine, p);
+        },
+    );
+}
+
+/// Scalar multiplication distributes over scalar addition and agrees with
+/// [`MulByGenerator`].
+pub fn scalar_mul_distributes<C: CurveArithmetic>() {
+    let g = C::ProjectivePoint::generator();
+
+    check((scalar::<C>(), scalar::<C>()), |(a, b)| {
+        assert_eq!(g * (a + b), g * a + g * b);
+        assert_eq!((g * a) * b, g * (a * b));
+        assert_eq!(C::ProjectivePoint::mul_by_generator(&a), g * a);
+    });
+}
+
+/// [`LinearCombination::lincomb`] equals the sum of the two products.
+pub fn lincomb_matches_naive<C: CurveArithmetic>() {
+    check(
+        (
+            projective_point::<C>(),
+            scalar::<C>(),
+            projective_point::<C>(),
+            scalar::<C>(),
+        ),
+        |(x, k, y, l)| {
+            assert_eq!(C::ProjectivePoint::lincomb(&x, &k, &y, &l), x * k + y * l);
+        },
+    );
+}
+
+/// [`group::Curve::batch_normalize`] agrees with converting each point
+/// individually, including the identity.
+pub fn batch_normalize_matches_to_affine<C: CurveArithmetic>() {
+    check(
+        collection::vec(projective_point::<C>(), 0..=SAMPLES),
+        |points| {
+            let mut affine = vec![C::AffinePoint::default(); points.len()];
+            C::ProjectivePoint::batch_normalize(&points, &mut affine);
+
+            for (point, affine) in points.iter().zip(&affine) {
+                assert_eq!(point.to_affine(), *affine);
+                assert_eq!(C::ProjectivePoint::from(*affine), *point);
+            }
+        },
+    );
+}
+
+/// [`ToAffineBatch`] conversions agree with [`Curve::to_affine`] for empty
+/// batches, single points, and batches containing the identity, and the
+/// array, slice and buffer variants agree with each other.
+pub fn test_to_affine_batch<C: ToAffineBatch>() {
+    let empty: Vec<C::AffinePoint> = C::to_affine_batch_slice(&[]);
+    assert!(empty.is_empty());
+    assert_eq!(C::to_affine_batch_array::<0>(&[]), []);
+
+    let identity = C::ProjectivePoint::identity();
+    assert_eq!(
+        C::to_affine_batch_array(&[identity]),
+        [identity.to_affine()]
+    );
+
+    check(
+        collection::vec(projective_point::<C>(), 0..=SAMPLES),
+        |mut points| {
+            points.push(identity);
+            let expected: Vec<_> = points.iter().map(Curve::to_affine).collect();
+            assert_eq!(C::to_affine_batch_slice::<Vec<_>>(&points), expected);
+
+            let mut out = vec![C::AffinePoint::default(); points.len()];
+            C::batch_normalize_into(&points, &mut out);
+            assert_eq!(out, expected);
+        },
+    );
+
+    check(
+        collection::vec(projective_point::<C>(), SAMPLES),
+        |points| {
+            let array: [C::ProjectivePoint; SAMPLES] = core::array::from_fn(|i| points[i]);
+            let expected: Vec<_> = points.iter().map(Curve::to_affine).collect();
+            assert_eq!(C::to_affine_batch_array(&array).as_slice(), expected);
+        },
+    );
+
+    check(collection::vec(scalar::<C>(), 0..=SAMPLES), |scalars| {
+        let by_generator: Vec<_> = scalars
+            .iter()
+            .map(|k| (C::ProjectivePoint::generator() * k).to_affine())
+            .collect();
+        assert_eq!(
+            C::mul_by_generator_to_affine_batch::<Vec<_>>(&scalars),
+            by_generator
+        );
+    });
+}
+
+/// [`LinearCombination`] and the [`MultiScalarMul`] variants agree with the
+/// naive sum of products, including for empty inputs, single terms, zero
+/// scalars and the identity.
+pub fn test_lincomb<C: MultiScalarMul>() {
+    let identity = C::ProjectivePoint::identity();
+    assert_eq!(C::msm(&[], &[]), identity);
+    assert_eq!(C::msm_vartime(&[], &[]), identity);
+    assert_eq!(MsmAccumulator::<C>::new().finalize(), identity);
+
+    let g = C::ProjectivePoint::generator();
+
+    check((affine_point::<C>(), scalar::<C>()), |(point, k)| {
+        let expected = C::ProjectivePoint::from(point) * k;
+        assert_eq!(C::msm(&[point], &[k]), expected);
+        assert_eq!(C::msm_vartime(&[point], &[k]), expected);
+        assert_eq!(
+            C::ProjectivePoint::lincomb(&point.into(), &k, &g, &C::Scalar::ZERO),
+            expected
+        );
+    });
+
+    check(
+        collection::vec((affine_point::<C>(), scalar::<C>()), 0..=SAMPLES),
+        |terms| {
+            let (points, scalars): (Vec<_>, Vec<_>) = terms.into_iter().unzip();
+            let expected: C::ProjectivePoint = points
+                .iter()
+                .zip(&scalars)
+                .map(|(point, k)| C::ProjectivePoint::from(*point) * k)
+                .sum();
+            assert_eq!(C::msm(&points, &scalars), expected);
+            assert_eq!(C::msm_vartime(&points, &scalars), expected);
+
+            // Accumulating in chunks agrees with a single call
+            let mut acc = MsmAccumulator::<C>::new();
+            for (points, scalars) in points.chunks(3).zip(scalars.chunks(3)) {
+                for (point, k) in points.iter().zip(scalars) {
+                    acc.add(point, k);
+                }
+            }
+            assert_eq!(acc.finalize(), expected);
+
+            let zeros = vec![C::Scalar::ZERO; points.len()];
+            assert_eq!(C::msm(&points, &zeros), identity);
+            assert_eq!(C::msm_vartime(&points, &zeros), identity);
+        },
+    );
+}
+
+/// [`InvertBatch`] agrees with inverting each scalar individually, succeeds
+/// on empty batches, and fails if any element is zero.
+pub fn test_batch_invert<C: CurveArithmetic>() {
+    let empty: Option<Vec<C::Scalar>> = C::Scalar::invert_batch(&[][..]).into();
+    assert_eq!(empty, Some(Vec::new()));
+    let empty: Option<[C::Scalar; 0]> = C::Scalar::invert_batch(&[]).into();
+    assert_eq!(empty, Some([]));
+
+    check(collection::vec(nonzero_scalar::<C>(), SAMPLES), |scalars| {
+        let expected: Option<Vec<_>> = scalars
+            .iter()
+            .map(|k| Option::from(Invert::invert(k)))
+            .collect();
+        assert!(expected.is_some());
+
+        let single: Option<[C::Scalar; 1]> = C::Scalar::invert_batch(&[scalars[0]]).into();
+        assert_eq!(single.map(|s| s[0]), expected.as_ref().map(|e| e[0]));
+
+        let slice: Option<Vec<_>> = C::Scalar::invert_batch(scalars.as_slice()).into();
+        assert_eq!(slice, expected);
+
+        let array: [C::Scalar; SAMPLES] = core::array::from_fn(|i| scalars[i]);
+        let array: Option<[C::Scalar; SAMPLES]> = C::Scalar::invert_batch(&array).into();
+        assert_eq!(array.map(|a| a.to_vec()), expected);
+
+        let all: Option<Vec<_>> = invert_all(scalars.iter().copied()).into();
+        assert_eq!(all, expected);
+
+        let mut with_zero = scalars;
+        with_zero[SAMPLES / 2] = C::Scalar::ZERO;
+        assert!(bool::from(
+            C::Scalar::invert_batch(with_zero.as_slice()).is_none()
+        ));
+        assert!(bool::from(invert_all(with_zero).is_none()));
+    });
+}
+
+// `MockEdwardsCurve` and `MockWideCurve` use affine arithmetic with an
+// inversion per group operation, which makes the scalar multiplications here
+// too slow to run in debug builds
+#[cfg(test)]
+mod tests {
+    crate::impl_arithmetic_conformance_tests!(crate::dev::MockCurve);
+    crate::impl_batch_conformance_tests!(crate::dev::MockCurve);
+
+    mod cofactor {
+        crate::impl_arithmetic_conformance_tests!(crate::dev::cofactor::MockCofactorCurve);
+        crate::impl_batch_conformance_tests!(crate::dev::cofactor::MockCofactorCurve);
+    }
+}
@@ -0,0 +1,692 @@
+//! Mock twisted Edwards curve.
+//!
+//! This curve is modeled off of edwards25519, and shares its base field and
+//! scalar field implementations with [`MockCofactorCurve`], which is the
+//! birationally equivalent short Weierstrass model.
+
+use super::cofactor::{self, impl_op_variants, FieldElement, MockCofactorCurve, Scalar};
+use crate::{
+    bigint::U256,
+    generic_array::{
+        typenum::{U32, U64},
+        GenericArray,
+    },
+    ops::{sum_tree, sum_tree_mixed, AddMixed, LinearCombination, MulByGenerator},
+    point::{AffineCoordinates, EdwardsCoordinates},
+    rand_core::{CryptoRngCore, RngCore},
+    subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption},
+    zeroize::DefaultIsZeroes,
+    CofactorArithmetic, Curve, CurveArithmetic, EdwardsCurve, EdwardsCurveArithmetic,
+    FieldBytesEncoding, ScalarArithmetic,
+};
+use core::{
+    iter::Sum,
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+use ff::{Field, PrimeField};
+
+/// Field element bytes.
+pub type FieldBytes = crate::FieldBytes<MockEdwardsCurve>;
+
+/// Compressed point bytes.
+pub type PointBytes = crate::EdwardsPointBytes<MockEdwardsCurve>;
+
+/// Scalar primitive type.
+pub type ScalarPrimitive = crate::ScalarPrimitive<MockEdwardsCurve>;
+
+/// Mock twisted Edwards curve type with cofactor 8.
+///
+/// Note: this type provides a simple, unoptimized arithmetic implementation
+/// in affine coordinates which is only suitable for tests.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+pub struct MockEdwardsCurve;
+
+impl Curve for MockEdwardsCurve {
+    type FieldBytesSize = U32;
+    type Uint = U256;
+
+    const ORDER: U256 = MockCofactorCurve::ORDER;
+}
+
+impl EdwardsCurve for MockEdwardsCurve {
+    type PointBytesSize = U32;
+}
+
+impl ScalarArithmetic for MockEdwardsCurve {
+    type Scalar = Scalar;
+}
+
+impl CurveArithmetic for MockEdwardsCurve {
+    type AffinePoint = AffinePoint;
+    type FieldElement = FieldElement;
+    type ProjectivePoint = ProjectivePoint;
+    type CompressedPoint = PointBytes;
+    type UncompressedPoint = GenericArray<u8, U64>;
+
+    const EQUATION_A: FieldElement = MockCofactorCurve::EQUATION_A;
+    const EQUATION_B: FieldElement = MockCofactorCurve::EQUATION_B;
+    const FIELD_MODULUS: U256 = MockCofactorCurve::FIELD_MODULUS;
+    const COFACTOR: u64 = 8;
+
+    fn is_torsion_free(point: &ProjectivePoint) -> Choice {
+        group::Group::is_identity(&Self::mul_by_order(point))
+    }
+
+    fn is_on_curve(point: &AffinePoint) -> Choice {
+        edwards_residual(point).is_zero()
+    }
+
+    fn are_on_curve_batch(points: &[AffinePoint], rng: &mut impl CryptoRngCore) -> Choice {
+        points
+            .iter()
+            .fold(FieldElement::ZERO, |acc, point| {
+                acc + edwards_residual(point) * FieldElement::random(&mut *rng)
+            })
+            .is_zero()
+    }
+}
+
+/// Residual `ax² + y² - 1 - dx²y²` of the twisted Edwards curve equation,
+/// which is zero if and only if the point is on the curve.
+fn edwards_residual(point: &AffinePoint) -> FieldElement {
+    let (xx, yy) = (point.x.square(), point.y.square());
+    MockEdwardsCurve::EDWARDS_A * xx + yy
+        - FieldElement::ONE
+        - MockEdwardsCurve::EDWARDS_D * xx * yy
+}
+
+impl CofactorArithmetic for MockEdwardsCurve {
+    fn clear_cofactor(point: &ProjectivePoint) -> ProjectivePoint {
+        use group::Group;
+        point.double().double().double()
+    }
+}
+
+impl EdwardsCurveArithmetic for MockEdwardsCurve {
+    type EdwardsAffinePoint = AffinePoint;
+    type EdwardsPoint = ProjectivePoint;
+
+    const EDWARDS_A: FieldElement =
+        FieldElement::from_hex("7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffec");
+    const EDWARDS_D: FieldElement =
+        FieldElement::from_hex("52036cee2b6ffe738cc740797779e89800700a4d4141d8ab75eb4dca135978a3");
+}
+
+impl FieldBytesEncoding<MockEdwardsCurve> for U256 {}
+
+impl From<ScalarPrimitive> for Scalar {
+    fn from(scalar: ScalarPrimitive) -> Scalar {
+        cofactor::ScalarPrimitive::from_uint_unchecked(scalar.to_uint()).into()
+    }
+}
+
+impl From<Scalar> for ScalarPrimitive {
+    fn from(scalar: Scalar) -> ScalarPrimitive {
+        ScalarPrimitive::from_uint_unchecked(scalar.into())
+    }
+}
+
+/// Example affine point type
+#[derive(Clone, Copy, Debug)]
+pub struct AffinePoint {
+    /// x-coordinate
+    x: FieldElement,
+
+    /// y-coordinate
+    y: FieldElement,
+}
+
+impl AffinePoint {
+    /// Additive identity of the group, i.e. `(0, 1)`.
+    pub const IDENTITY: Self = Self {
+        x: FieldElement::ZERO,
+        y: FieldElement::ONE,
+    };
+
+    /// Base point of the curve, which generates the prime order subgroup.
+    pub const GENERATOR: Self = Self {
+        x: FieldElement::from_hex(
+            "216936d3cd6e53fec0a4e231fdd6dc5c692cc7609525a7b2c9562d608f25d51a",
+        ),
+        y: FieldElement::from_hex(
+            "6666666666666666666666666666666666666666666666666666666666666658",
+        ),
+    };
+
+    /// Point of order 8, which generates the small-order torsion subgroup.
+    pub const TORSION: Self = Self {
+        x: FieldElement::from_hex(
+            "1fd5b9a006394a28e933993238de4abb5c193c7013e5e238dea14646c545d14a",
+        ),
+        y: FieldElement::from_hex(
+            "05fc536d880238b13933c6d305acdfd5f098eff289f4c345b027b2c28f95e826",
+        ),
+    };
+
+    /// Is this point the identity point?
+    pub fn is_identity(&self) -> Choice {
+        self.ct_eq(&Self::IDENTITY)
+    }
+
+    /// Add two points using the complete affine addition formulas.
+    fn add(&self, other: &Self) -> Self {
+        let xx = self.x * other.x;
+        let yy = self.y * other.y;
+        let dxxyy = MockEdwardsCurve::EDWARDS_D * xx * yy;
+
+        // The denominators are never zero since `a` is square and `d` is not
+        let x =
+            (self.x * other.y + self.y * other.x) * (FieldElement::ONE + dxxyy).invert().unwrap();
+        let y =
+            (yy - MockEdwardsCurve::EDWARDS_A * xx) * (FieldElement::ONE - dxxyy).invert().unwrap();
+        Self { x, y }
+    }
+}
+
+impl AffineCoordinates for AffinePoint {
+    type FieldRepr = FieldBytes;
+
+    const IDENTITY: Self = AffinePoint::IDENTITY;
+
+    fn x(&self) -> FieldBytes {
+        self.x.to_repr()
+    }
+
+    fn y(&self) -> FieldBytes {
+        self.y.to_repr()
+    }
+
+    fn y_is_odd(&self) -> Choice {
+        self.y.is_odd()
+    }
+}
+
+impl EdwardsCoordinates for AffinePoint {
+    fn x_is_odd(&self) -> Choice {
+        self.x.is_odd()
+    }
+}
+
+impl ConstantTimeEq for AffinePoint {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.x.ct_eq(&other.x) & self.y.ct_eq(&other.y)
+    }
+}
+
+impl ConditionallySelectable for AffinePoint {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self {
+            x: FieldElement::conditional_select(&a.x, &b.x, choice),
+            y: FieldElement::conditional_select(&a.y, &b.y, choice),
+        }
+    }
+}
+
+impl Default for AffinePoint {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl DefaultIsZeroes for AffinePoint {}
+
+impl Eq for AffinePoint {}
+
+impl PartialEq for AffinePoint {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Neg for AffinePoint {
+    type Output = AffinePoint;
+
+    fn neg(self) -> AffinePoint {
+        -&self
+    }
+}
+
+impl Neg for &AffinePoint {
+    type Output = AffinePoint;
+
+    fn neg(self) -> AffinePoint {
+        AffinePoint {
+            x: -self.x,
+            y: self.y,
+        }
+    }
+}
+
+impl group::GroupEncoding for AffinePoint {
+    type Repr = PointBytes;
+
+    /// Decode a point using the procedure described in RFC 8032 § 5.1.3.
+    fn from_bytes(bytes: &PointBytes) -> CtOption<Self> {
+        let x_is_odd = Choice::from(bytes[31] >> 7);
+        let mut y_bytes = *bytes;
+        y_bytes[31] &= 0x7f;
+        y_bytes.reverse();
+
+        FieldElement::from_repr(y_bytes).and_then(|y| {
+            let yy = y.square();
+            let num = yy - FieldElement::ONE;
+            let div = MockEdwardsCurve::EDWARDS_D * yy - MockEdwardsCurve::EDWARDS_A;
+
+            (num * div.invert().unwrap()).sqrt().and_then(|x| {
+                let x = FieldElement::conditional_select(&x, &-x, x.is_odd() ^ x_is_odd);
+                CtOption::new(Self { x, y }, !(x.is_zero() & x_is_odd))
+            })
+        })
+    }
+
+    fn from_bytes_unchecked(bytes: &PointBytes) -> CtOption<Self> {
+        Self::from_bytes(bytes)
+    }
+
+    fn to_bytes(&self) -> PointBytes {
+        let mut bytes = self.y.to_repr();
+        bytes.reverse();
+        bytes[31] |= self.x.is_odd().unwrap_u8() << 7;
+        bytes
+    }
+}
+
+/// Example projective point type.
+///
+/// Points are kept normalized, i.e. this is a thin wrapper around
+/// [`AffinePoint`] which performs two inversions per group operation.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ProjectivePoint(AffinePoint);
+
+impl ProjectivePoint {
+    /// Additive identity of the group, i.e. `(0, 1)`.
+    pub const IDENTITY: Self = Self(AffinePoint::IDENTITY);
+
+    /// Base point of the curve, which generates the prime order subgroup.
+    pub const GENERATOR: Self = Self(AffinePoint::GENERATOR);
+
+    /// Point of order 8, which generates the small-order torsion subgroup.
+    pub const TORSION: Self = Self(AffinePoint::TORSION);
+}
+
+impl ConstantTimeEq for ProjectivePoint {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl ConditionallySelectable for ProjectivePoint {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(AffinePoint::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+impl DefaultIsZeroes for ProjectivePoint {}
+
+impl From<AffinePoint> for ProjectivePoint {
+    fn from(point: AffinePoint) -> ProjectivePoint {
+        Self(point)
+    }
+}
+
+impl From<ProjectivePoint> for AffinePoint {
+    fn from(point: ProjectivePoint) -> AffinePoint {
+        point.0
+    }
+}
+
+impl group::Group for ProjectivePoint {
+    type Scalar = Scalar;
+
+    fn random(rng: impl RngCore) -> Self {
+        Self::GENERATOR * Scalar::random(rng)
+    }
+
+    fn identity() -> Self {
+        Self::IDENTITY
+    }
+
+    fn generator() -> Self {
+        Self::GENERATOR
+    }
+
+    fn is_identity(&self) -> Choice {
+        self.0.is_identity()
+    }
+
+    fn double(&self) -> Self {
+        Self(AffinePoint::add(&self.0, &self.0))
+    }
+}
+
+impl group::GroupEncoding for ProjectivePoint {
+    type Repr = PointBytes;
+
+    fn from_bytes(bytes: &PointBytes) -> CtOption<Self> {
+        AffinePoint::from_bytes(bytes).map(Self)
+    }
+
+    fn from_bytes_unchecked(bytes: &PointBytes) -> CtOption<Self> {
+        Self::from_bytes(bytes)
+    }
+
+    fn to_bytes(&self) -> PointBytes {
+        self.0.to_bytes()
+    }
+}
+
+impl group::Curve for ProjectivePoint {
+    type AffineRepr = AffinePoint;
+
+    fn to_affine(&self) -> AffinePoint {
+        self.0
+    }
+}
+
+impl AddMixed<AffinePoint> for ProjectivePoint {}
+
+impl LinearCombination for ProjectivePoint {}
+
+impl MulByGenerator for ProjectivePoint {}
+
+impl Add<&ProjectivePoint> for ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    fn add(self, other: &ProjectivePoint) -> ProjectivePoint {
+        Self(AffinePoint::add(&self.0, &other.0))
+    }
+}
+
+impl Sub<&ProjectivePoint> for ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    fn sub(self, other: &ProjectivePoint) -> ProjectivePoint {
+        self.add(&other.neg())
+    }
+}
+
+impl_op_variants!(ProjectivePoint, Add, add, AddAssign, add_assign);
+impl_op_variants!(ProjectivePoint, Sub, sub, SubAssign, sub_assign);
+
+impl Add<AffinePoint> for AffinePoint {
+    type Output = ProjectivePoint;
+
+    fn add(self, other: AffinePoint) -> ProjectivePoint {
+        ProjectivePoint::from(self) + other
+    }
+}
+
+impl Add<&AffinePoint> for AffinePoint {
+    type Output = ProjectivePoint;
+
+    fn add(self, other: &AffinePoint) -> ProjectivePoint {
+        ProjectivePoint::from(self) + other
+    }
+}
+
+impl Sub<AffinePoint> for AffinePoint {
+    type Output = ProjectivePoint;
+
+    fn sub(self, other: AffinePoint) -> ProjectivePoint {
+        ProjectivePoint::from(self) - other
+    }
+}
+
+impl Sub<&AffinePoint> for AffinePoint {
+    type Output = ProjectivePoint;
+
+    fn sub(self, other: &AffinePoint) -> ProjectivePoint {
+        ProjectivePoint::from(self) - other
+    }
+}
+
+impl Add<AffinePoint> for ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    fn add(self, other: AffinePoint) -> ProjectivePoint {
+        self.add(&other)
+    }
+}
+
+impl Add<&AffinePoint> for ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    fn add(self, other: &AffinePoint) -> ProjectivePoint {
+        Self(AffinePoint::add(&self.0, other))
+    }
+}
+
+impl AddAssign<AffinePoint> for ProjectivePoint {
+    fn add_assign(&mut self, rhs: AffinePoint) {
+        *self = *self + rhs;
+    }
+}
+
+impl AddAssign<&AffinePoint> for ProjectivePoint {
+    fn add_assign(&mut self, rhs: &AffinePoint) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<AffinePoint> for ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    fn sub(self, other: AffinePoint) -> ProjectivePoint {
+        self.sub(&other)
+    }
+}
+
+impl Sub<&AffinePoint> for ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    fn sub(self, other: &AffinePoint) -> ProjectivePoint {
+        self.add(&other.neg())
+    }
+}
+
+impl SubAssign<AffinePoint> for ProjectivePoint {
+    fn sub_assign(&mut self, rhs: AffinePoint) {
+        *self = *self - rhs;
+    }
+}
+
+impl SubAssign<&AffinePoint> for ProjectivePoint {
+    fn sub_assign(&mut self, rhs: &AffinePoint) {
+        *self = *self - rhs;
+    }
+}
+
+impl Sum for ProjectivePoint {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        sum_tree(iter)
+    }
+}
+
+impl<'a> Sum<&'a ProjectivePoint> for ProjectivePoint {
+    fn sum<I: Iterator<Item = &'a ProjectivePoint>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl Sum<AffinePoint> for ProjectivePoint {
+    fn sum<I: Iterator<Item = AffinePoint>>(iter: I) -> Self {
+        sum_tree_mixed(iter)
+    }
+}
+
+impl<'a> Sum<&'a AffinePoint> for ProjectivePoint {
+    fn sum<I: Iterator<Item = &'a AffinePoint>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl Mul<Scalar> for ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    fn mul(self, scalar: Scalar) -> ProjectivePoint {
+        self * &scalar
+    }
+}
+
+impl Mul<&Scalar> for ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    /// Constant-time double-and-add (from the most significant bit).
+    #[allow(clippy::integer_arithmetic)]
+    fn mul(self, scalar: &Scalar) -> ProjectivePoint {
+        let mut acc = Self::IDENTITY;
+
+        for byte in scalar.to_repr() {
+            for i in (0..8).rev() {
+                acc = group::Group::double(&acc);
+                acc.conditional_assign(&(acc + self), Choice::from((byte >> i) & 1));
+            }
+        }
+
+        acc
+    }
+}
+
+impl MulAssign<Scalar> for ProjectivePoint {
+    fn mul_assign(&mut self, rhs: Scalar) {
+        *self = *self * rhs;
+    }
+}
+
+impl MulAssign<&Scalar> for ProjectivePoint {
+    fn mul_assign(&mut self, rhs: &Scalar) {
+        *self = *self * rhs;
+    }
+}
+
+impl Neg for ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    fn neg(self) -> ProjectivePoint {
+        -&self
+    }
+}
+
+impl Neg for &ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    fn neg(self) -> ProjectivePoint {
+        ProjectivePoint(-&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AffinePoint, FieldElement, MockEdwardsCurve, PointBytes, ProjectivePoint, Scalar};
+    use crate::{
+        point::{AffineCoordinates, EdwardsCoordinates},
+        CofactorArithmetic, CurveArithmetic, EdwardsCurveArithmetic,
+    };
+    use ff::{Field, PrimeField};
+    use group::{Curve, Group, GroupEncoding};
+    use hex_literal::hex;
+    use rand_core::OsRng;
+
+    /// Generic conformance checks for [`EdwardsCurveArithmetic`] impls.
+    fn check_edwards_curve<C: EdwardsCurveArithmetic>() {
+        let g = C::ProjectivePoint::generator();
+        let affine = g.to_affine();
+
+        // The generator satisfies `ax² + y² = 1 + dx²y²`
+        let x = C::affine_x(&affine).unwrap();
+        let y = C::affine_y(&affine).unwrap();
+        let (xx, yy) = (x.square(), y.square());
+        assert_eq!(
+            C::EDWARDS_A * xx + yy,
+            C::FieldElement::ONE + C::EDWARDS_D * xx * yy
+        );
+        assert_eq!(affine.x_is_odd().unwrap_u8(), x.is_odd().unwrap_u8());
+
+        // Encoding round trips
+        let point = g * C::Scalar::from(42u64);
+        let bytes = point.to_bytes();
+        assert_eq!(C::ProjectivePoint::from_bytes(&bytes).unwrap(), point);
+        assert_eq!(bytes, point.to_affine().to_bytes());
+
+        // Cofactor handling
+        assert!(bool::from(C::is_torsion_free(&point)));
+        assert_eq!(C::clear_cofactor(&point), point * C::Scalar::from(8u64));
+    }
+
+    #[test]
+    fn on_curve() {
+        let mut points = [
+            AffinePoint::GENERATOR,
+            AffinePoint::IDENTITY,
+            AffinePoint::TORSION,
+            (ProjectivePoint::GENERATOR * Scalar::from(42u64)).to_affine(),
+        ];
+        for point in &points {
+            assert!(bool::from(MockEdwardsCurve::is_on_curve(point)));
+        }
+        assert!(bool::from(MockEdwardsCurve::are_on_curve_strict(&points)));
+        assert!(bool::from(MockEdwardsCurve::are_on_curve_batch(
+            &points, &mut OsRng
+        )));
+
+        points[3].y += FieldElement::ONE;
+        assert!(!bool::from(MockEdwardsCurve::is_on_curve(&points[3])));
+        assert!(!bool::from(MockEdwardsCurve::are_on_curve_strict(&points)));
+        assert!(!bool::from(MockEdwardsCurve::are_on_curve_batch(
+            &points, &mut OsRng
+        )));
+    }
+
+    #[test]
+    fn edwards_conformance() {
+        check_edwards_curve::<MockEdwardsCurve>();
+    }
+
+    #[test]
+    fn rfc8032_generator_encoding() {
+        let g = ProjectivePoint::GENERATOR;
+        assert_eq!(
+            g.to_bytes(),
+            PointBytes::from(hex!(
+                "5866666666666666666666666666666666666666666666666666666666666666"
+            ))
+        );
+        assert_eq!(
+            g.double().to_bytes(),
+            PointBytes::from(hex!(
+                "c9a3f86aae465f0e56513864510f3997561fa2c9e85ea21dc2292309f3cd6022"
+            ))
+        );
+    }
+
+    #[test]
+    fn identity_and_negation() {
+        let g = ProjectivePoint::GENERATOR;
+        assert!(bool::from((g - g).is_identity()));
+        assert_eq!(g * -Scalar::ONE, -g);
+        assert_eq!(AffinePoint::IDENTITY.to_bytes()[0], 1);
+        assert_eq!(
+            AffinePoint::from_bytes(&AffinePoint::IDENTITY.to_bytes()).unwrap(),
+            AffinePoint::IDENTITY
+        );
+        assert_eq!(AffinePoint::GENERATOR.y(), (-AffinePoint::GENERATOR).y());
+        assert_ne!(
+            AffinePoint::GENERATOR.to_bytes(),
+            (-AffinePoint::GENERATOR).to_bytes()
+        );
+    }
+
+    #[test]
+    fn torsion_checks() {
+        let g = ProjectivePoint::GENERATOR;
+        let t = ProjectivePoint::TORSION;
+        assert!(!bool::from(t.double().double().is_identity()));
+        assert!(bool::from(t.double().double().double().is_identity()));
+        assert!(!bool::from(MockEdwardsCurve::is_torsion_free(&t)));
+        assert!(!bool::from(MockEdwardsCurve::is_torsion_free(&(g + t))));
+        assert_eq!(
+            MockEdwardsCurve::clear_cofactor(&(g + t)),
+            g * Scalar::from(8u64)
+        );
+    }
+}
@@ -0,0 +1,436 @@
+//! Hash-to-curve support for the mock curve using the `P256_XMD:SHA-256_SSWU`
+//! suites from RFC 9380.
+
+use super::{AffinePoint, FieldElement, MockCurve, ProjectivePoint, Scalar};
+use crate::{
+    bigint::{ArrayEncoding, U256},
+    generic_array::{typenum::U48, GenericArray},
+    hash2curve::{FromOkm, GroupDigest, MapToCurve, OsswuMap, OsswuMapParams, Sgn0},
+    CurveArithmetic,
+};
+use ff::PrimeField;
+use subtle::Choice;
+
+impl GroupDigest for MockCurve {
+    type FieldElement = FieldElement;
+
+    const CURVE_ID: &'static str = "P256";
+}
+
+impl FromOkm for FieldElement {
+    type Length = U48;
+
+    fn from_okm(data: &GenericArray<u8, Self::Length>) -> Self {
+        // 2^256 mod p
+        const F_2_256: FieldElement = FieldElement::from_hex(
+            "00000000fffffffeffffffffffffffffffffffff000000000000000000000001",
+        );
+
+        let mut d0 = GenericArray::default();
+        d0[16..].copy_from_slice(&data[0..16]);
+        let d0 = FieldElement(super::FieldResidue::new(&U256::from_be_byte_array(d0)));
+
+        let d1 = GenericArray::clone_from_slice(&data[16..]);
+        let d1 = FieldElement(super::FieldResidue::new(&U256::from_be_byte_array(d1)));
+
+        d0 * F_2_256 + d1
+    }
+}
+
+impl FromOkm for Scalar {
+    type Length = U48;
+
+    fn from_okm(data: &GenericArray<u8, Self::Length>) -> Self {
+        // 2^256 mod n
+        let f_2_256 = Scalar::from_residue(super::ScalarResidue::new(&U256::from_be_hex(
+            "00000000ffffffff00000000000000004319055258e8617b0c46353d039cdaaf",
+        )));
+
+        let mut d0 = GenericArray::default();
+        d0[16..].copy_from_slice(&data[0..16]);
+        let d0 = Scalar::from_residue(super::ScalarResidue::new(&U256::from_be_byte_array(d0)));
+
+        let d1 = GenericArray::clone_from_slice(&data[16..]);
+        let d1 = Scalar::from_residue(super::ScalarResidue::new(&U256::from_be_byte_array(d1)));
+
+        d0 * f_2_256 + d1
+    }
+}
+
+impl Sgn0 for FieldElement {
+    fn sgn0(&self) -> Choice {
+        self.is_odd()
+    }
+}
+
+impl OsswuMap for FieldElement {
+    const PARAMS: OsswuMapParams<Self> = OsswuMapParams {
+        // (p - 3) / 4
+        c1: &[
+            0xffff_ffff_ffff_ffff,
+            0x0000_0000_3fff_ffff,
+            0x4000_0000_0000_0000,
+            0x3fff_ffff_c000_0000,
+        ],
+        // sqrt(-Z)
+        c2: FieldElement::from_hex(
+            "da538e3be1d89b99c978fc675180aab27b8d1ff84c55d5b62ccd3427e433c47f",
+        ),
+        map_a: MockCurve::EQUATION_A,
+        map_b: MockCurve::EQUATION_B,
+        // -10
+        z: FieldElement::from_hex(
+            "ffffffff00000001000000000000000000000000fffffffffffffffffffffff5",
+        ),
+    };
+}
+
+impl MapToCurve for FieldElement {
+    type IntermediatePoint = ProjectivePoint;
+    type Output = ProjectivePoint;
+
+    const MAP_ID: &'static str = "SSWU";
+
+    fn map_to_curve(&self) -> ProjectivePoint {
+        let (x, y) = self.osswu();
+        AffinePoint { x, y, infinity: 0 }.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        dev::{AffinePoint, FieldElement, MockCurve, ProjectivePoint, Scalar},
+        hash2curve::{
+            hash_to_field, map_to_curve, map_to_curve_sum, Encoding, ExpandMsg, ExpandMsgPrepared,
+            ExpandMsgXmd, ExpandMsgXof, Expander, GroupDigest, MapToCurve,
+        },
+        point::AffineCoordinates,
+        ErrorKind,
+    };
+    use alloc::{string::ToString, vec::Vec};
+    use ff::{Field, PrimeField};
+    use group::Curve;
+    use hex_literal::hex;
+    use sha2::Sha256;
+    use sha3::{Shake128, Shake256};
+
+    const DST_RO: &[u8] = b"QUUX-V01-CS02-with-P256_XMD:SHA-256_SSWU_RO_";
+    const DST_NU: &[u8] = b"QUUX-V01-CS02-with-P256_XMD:SHA-256_SSWU_NU_";
+
+    struct TestVector {
+        msg: &'static [u8],
+        p_x: [u8; 32],
+        p_y: [u8; 32],
+    }
+
+    fn assert_point(point: ProjectivePoint, vector: &TestVector) {
+        let affine = point.to_affine();
+        assert_eq!(affine.x().as_slice(), &vector.p_x);
+        assert_eq!(affine.y().as_slice(), &vector.p_y);
+    }
+
+    /// RFC 9380 Appendix J.1.1: `P256_XMD:SHA-256_SSWU_RO_`
+    #[test]
+    fn hash_from_bytes_vectors() {
+        const VECTORS: &[TestVector] = &[
+            TestVector {
+                msg: b"",
+                p_x: hex!("2c15230b26dbc6fc9a37051158c95b79656e17a1a920b11394ca91c44247d3e4"),
+                p_y: hex!("8a7a74985cc5c776cdfe4b1f19884970453912e9d31528c060be9ab5c43e8415"),
+            },
+            TestVector {
+                msg: b"abc",
+                p_x: hex!("0bb8b87485551aa43ed54f009230450b492fead5f1cc91658775dac4a3388a0f"),
+                p_y: hex!("5c41b3d0731a27a7b14bc0bf0ccded2d8751f83493404c84a88e71ffd424212e"),
+            },
+        ];
+
+        for vector in VECTORS {
+            let point =
+                MockCurve::hash_from_bytes::<ExpandMsgXmd<Sha256>>(&[vector.msg], &[DST_RO])
+                    .unwrap();
+            assert_point(point, vector);
+        }
+    }
+
+    /// RFC 9380 Appendix J.1.2: `P256_XMD:SHA-256_SSWU_NU_`
+    #[test]
+    fn encode_from_bytes_vectors() {
+        const VECTORS: &[TestVector] = &[
+            TestVector {
+                msg: b"",
+                p_x: hex!("f871caad25ea3b59c16cf87c1894902f7e7b2c822c3d3f73596c5ace8ddd14d1"),
+                p_y: hex!("87b9ae23335bee057b99bac1e68588b18b5691af476234b8971bc4f011ddc99b"),
+            },
+            TestVector {
+                msg: b"abc",
+                p_x: hex!("fc3f5d734e8dce41ddac49f47dd2b8a57257522a865c124ed02b92b5237befa4"),
+                p_y: hex!("fe4d197ecf5a62645b9690599e1d80e82c500b22ac705a0b421fac7b47157866"),
+            },
+        ];
+
+        for vector in VECTORS {
+            let point =
+                MockCurve::encode_from_bytes::<ExpandMsgXmd<Sha256>>(&[vector.msg], &[DST_NU])
+                    .unwrap();
+            assert_point(point, vector);
+        }
+    }
+
+    #[test]
+    fn hash_to_field_matches_hash_from_bytes() {
+        let u = MockCurve::hash_to_field::<ExpandMsgXmd<Sha256>, 2>(&[b""], &[DST_RO]).unwrap();
+        assert_eq!(
+            u[0].to_repr().as_slice(),
+            &hex!("ad5342c66a6dd0ff080df1da0ea1c04b96e0330dd89406465eeba11582515009")
+        );
+        assert_eq!(
+            u[1].to_repr().as_slice(),
+            &hex!("8c0f1d43204bd6f6ea70ae8013070a1518b43873bcd850aafa0a9e220e2eea5a")
+        );
+
+        let mut expected = [FieldElement::default(); 2];
+        hash_to_field::<ExpandMsgXmd<Sha256>, _>(&[b""], &[DST_RO], &mut expected).unwrap();
+        assert_eq!(u, expected);
+
+        let point = MockCurve::hash_from_bytes::<ExpandMsgXmd<Sha256>>(&[b""], &[DST_RO]).unwrap();
+        assert_eq!(point, u[0].map_to_curve() + u[1].map_to_curve());
+    }
+
+    /// Mapping with an artificial "isogeny" `P ↦ P + G` on the mock curve,
+    /// whose intermediate points are affine and so cannot be added.
+    ///
+    /// Unlike a real isogeny this isn't a homomorphism, so applying it before
+    /// or after adding the mapped points gives different results.
+    #[derive(Clone, Copy, Default)]
+    struct TranslatingMap(FieldElement);
+
+    impl MapToCurve for TranslatingMap {
+        type IntermediatePoint = AffinePoint;
+        type Output = ProjectivePoint;
+
+        const MAP_ID: &'static str = "SSWU";
+
+        fn map_to_curve(&self) -> AffinePoint {
+            self.0.map_to_curve().to_affine()
+        }
+
+        fn isogeny(point: &AffinePoint) -> ProjectivePoint {
+            ProjectivePoint::from(*point) + ProjectivePoint::GENERATOR
+        }
+    }
+
+    #[test]
+    fn isogeny_applied_before_addition() {
+        let u = MockCurve::hash_to_field::<ExpandMsgXmd<Sha256>, 2>(&[b"abc"], &[DST_RO]).unwrap();
+        let q0 = u[0].map_to_curve();
+        let q1 = u[1].map_to_curve();
+        assert_ne!(q0, q1);
+
+        assert_eq!(map_to_curve(&u[0]), q0);
+        assert_eq!(map_to_curve_sum(&u), q0 + q1);
+        assert_eq!(
+            MockCurve::hash_from_bytes::<ExpandMsgXmd<Sha256>>(&[b"abc"], &[DST_RO]).unwrap(),
+            map_to_curve_sum(&u)
+        );
+
+        let g = ProjectivePoint::GENERATOR;
+        let translated = [TranslatingMap(u[0]), TranslatingMap(u[1])];
+        assert_eq!(map_to_curve(&translated[0]), q0 + g);
+        assert_eq!(map_to_curve_sum(&translated), (q0 + g) + (q1 + g));
+        assert_ne!(map_to_curve_sum(&translated), (q0 + q1) + g);
+    }
+
+    #[test]
+    fn hash_to_scalars() {
+        let scalars =
+            MockCurve::hash_to_scalars::<ExpandMsgXmd<Sha256>, 3>(&[b"abc"], &[DST_RO]).unwrap();
+        let expected = [
+            hex!("e613a83a2719164cee1fcbf4de10aa2feeae7e7980151469d7c9cbd1ad4476a4"),
+            hex!("f14c662a92dce810c1bf0b60587ab769b50342fbfd5b5e66e3d75c51f7f42767"),
+            hex!("91838f234ff2d88c2a6cc50ec77e067ff79cc97d5b6574446927abafb546a76f"),
+        ];
+
+        for (scalar, expected) in scalars.iter().zip(&expected) {
+            assert_eq!(scalar.to_repr().as_slice(), expected);
+        }
+    }
+
+    /// Expander which always outputs zeroes.
+    struct ZeroExpandMsg;
+
+    impl<'a> ExpandMsg<'a> for ZeroExpandMsg {
+        type Expander = Self;
+
+        fn expand_message(_: &[&[u8]], _: &'a [&'a [u8]], _: usize) -> crate::Result<Self> {
+            Ok(Self)
+        }
+    }
+
+    impl Expander for ZeroExpandMsg {
+        fn fill_bytes(&mut self, okm: &mut [u8]) {
+            okm.fill(0);
+        }
+    }
+
+    #[test]
+    fn hash_to_nonzero_scalar() {
+        let scalar =
+            MockCurve::hash_to_nonzero_scalar::<ExpandMsgXmd<Sha256>>(&[b"abc"], &[DST_RO])
+                .unwrap();
+        assert_eq!(
+            *scalar,
+            MockCurve::hash_to_scalar::<ExpandMsgXmd<Sha256>>(&[b"abc"], &[DST_RO]).unwrap()
+        );
+
+        assert_eq!(
+            MockCurve::hash_to_scalar::<ZeroExpandMsg>(&[b"abc"], &[DST_RO]).unwrap(),
+            Scalar::ZERO
+        );
+        assert_eq!(
+            MockCurve::hash_to_nonzero_scalar::<ZeroExpandMsg>(&[b"abc"], &[DST_RO])
+                .err()
+                .map(|err| err.kind()),
+            Some(ErrorKind::ScalarOutOfRange)
+        );
+        assert!(
+            MockCurve::hash_to_nonzero_scalar::<ExpandMsgXmd<Sha256>>(&[b"abc"], &[b""]).is_err()
+        );
+    }
+
+    #[test]
+    fn hash_to_field_length_limits() {
+        assert!(MockCurve::hash_to_field::<ExpandMsgXmd<Sha256>, 0>(&[b""], &[DST_RO]).is_err());
+        assert!(MockCurve::hash_to_field::<ExpandMsgXmd<Sha256>, 170>(&[b""], &[DST_RO]).is_ok());
+        assert!(MockCurve::hash_to_field::<ExpandMsgXmd<Sha256>, 171>(&[b""], &[DST_RO]).is_err());
+        assert!(
+            MockCurve::hash_to_field::<ExpandMsgXof<Shake256>, 1366>(&[b""], &[DST_RO]).is_err()
+        );
+    }
+
+    #[test]
+    fn prepared_dst_matches_one_shot() {
+        const LONG_DST: &[u8] = &[b'Q'; 300];
+
+        for dst in [DST_RO, DST_NU, LONG_DST] {
+            let dsts = [dst];
+            let xmd = ExpandMsgXmd::<Sha256>::prepare_dst(&dsts).unwrap();
+            let xof = ExpandMsgXof::<Shake256>::prepare_dst(&dsts).unwrap();
+
+            for msg in [&b""[..], b"abc", &[0xAA; 200]] {
+                assert_eq!(
+                    MockCurve::hash_from_bytes_prepared::<ExpandMsgXmd<Sha256>>(&[msg], &xmd),
+                    MockCurve::hash_from_bytes::<ExpandMsgXmd<Sha256>>(&[msg], &dsts)
+                );
+                assert_eq!(
+                    MockCurve::encode_from_bytes_prepared::<ExpandMsgXmd<Sha256>>(&[msg], &xmd),
+                    MockCurve::encode_from_bytes::<ExpandMsgXmd<Sha256>>(&[msg], &dsts)
+                );
+                assert_eq!(
+                    MockCurve::hash_to_scalar_prepared::<ExpandMsgXmd<Sha256>>(&[msg], &xmd),
+                    MockCurve::hash_to_scalar::<ExpandMsgXmd<Sha256>>(&[msg], &dsts)
+                );
+                assert_eq!(
+                    MockCurve::hash_from_bytes_prepared::<ExpandMsgXof<Shake256>>(&[msg], &xof),
+                    MockCurve::hash_from_bytes::<ExpandMsgXof<Shake256>>(&[msg], &dsts)
+                );
+            }
+        }
+
+        assert!(ExpandMsgXmd::<Sha256>::prepare_dst(&[b""]).is_err());
+    }
+
+    #[test]
+    fn streaming_matches_one_shot() {
+        const LONG_DST: &[u8] = &[b'Q'; 300];
+        let msg: Vec<u8> = (0..=255u8).cycle().take(1000).collect();
+
+        for dst in [DST_RO, LONG_DST] {
+            let dsts = [dst];
+
+            for split in [0, 1, 63, 64, 65, 500, 1000] {
+                let (head, tail) = msg.split_at(split);
+                let parts: [&[u8]; 4] = [head, &[], tail, &[]];
+
+                let mut xmd = MockCurve::hasher::<ExpandMsgXmd<Sha256>>(&dsts).unwrap();
+                parts.iter().for_each(|part| {
+                    xmd.update(part);
+                });
+                assert_eq!(
+                    xmd.finalize_to_curve().unwrap(),
+                    MockCurve::hash_from_bytes::<ExpandMsgXmd<Sha256>>(&[&msg], &dsts).unwrap()
+                );
+
+                let mut xmd = MockCurve::hasher::<ExpandMsgXmd<Sha256>>(&dsts).unwrap();
+                xmd.update(head).update(tail);
+                assert_eq!(
+                    xmd.finalize_encode_to_curve().unwrap(),
+                    MockCurve::encode_from_bytes::<ExpandMsgXmd<Sha256>>(&[&msg], &dsts).unwrap()
+                );
+
+                let mut xmd = MockCurve::hasher::<ExpandMsgXmd<Sha256>>(&dsts).unwrap();
+                xmd.update(head).update(tail);
+                assert_eq!(
+                    xmd.finalize_to_scalar().unwrap(),
+                    MockCurve::hash_to_scalar::<ExpandMsgXmd<Sha256>>(&[&msg], &dsts).unwrap()
+                );
+
+                let mut xof = MockCurve::hasher::<ExpandMsgXof<Shake256>>(&dsts).unwrap();
+                parts.iter().for_each(|part| {
+                    xof.update(part);
+                });
+                assert_eq!(
+                    xof.finalize_to_curve().unwrap(),
+                    MockCurve::hash_from_bytes::<ExpandMsgXof<Shake256>>(&[&msg], &dsts).unwrap()
+                );
+            }
+        }
+
+        let hasher = MockCurve::hasher::<ExpandMsgXmd<Sha256>>(&[DST_RO]).unwrap();
+        assert_eq!(
+            hasher.finalize_to_curve().unwrap(),
+            MockCurve::hash_from_bytes::<ExpandMsgXmd<Sha256>>(&[], &[DST_RO]).unwrap()
+        );
+        assert!(MockCurve::hasher::<ExpandMsgXmd<Sha256>>(&[b""]).is_err());
+        assert!(MockCurve::hasher::<ExpandMsgXof<Shake256>>(&[b""]).is_err());
+    }
+
+    #[test]
+    fn suite_id() {
+        let ro = MockCurve::suite_id::<ExpandMsgXmd<Sha256>>(Encoding::Uniform).unwrap();
+        let nu = MockCurve::suite_id::<ExpandMsgXmd<Sha256>>(Encoding::NonUniform).unwrap();
+        let xof = MockCurve::suite_id::<ExpandMsgXof<Shake256>>(Encoding::Uniform).unwrap();
+
+        assert_eq!(ro.to_string(), "P256_XMD:SHA-256_SSWU_RO_");
+        assert_eq!(nu.to_string(), "P256_XMD:SHA-256_SSWU_NU_");
+        assert_eq!(xof.to_string(), "P256_XOF:SHAKE256_SSWU_RO_");
+        assert_eq!(
+            MockCurve::suite_id::<ExpandMsgXof<Shake128>>(Encoding::Uniform)
+                .unwrap()
+                .to_string(),
+            "P256_XOF:SHAKE128_SSWU_RO_"
+        );
+        assert_ne!(ro, nu);
+        assert_eq!(ro.parts().concat(), ro.to_string().as_bytes());
+
+        let dst = ro.dst(b"QUUX-V01-CS02-with-");
+        assert_eq!(dst.concat(), DST_RO);
+        assert_eq!(nu.dst(b"QUUX-V01-CS02-with-").concat(), DST_NU);
+        assert_eq!(
+            MockCurve::hash_from_bytes::<ExpandMsgXmd<Sha256>>(&[b"abc"], &dst),
+            MockCurve::hash_from_bytes::<ExpandMsgXmd<Sha256>>(&[b"abc"], &[DST_RO])
+        );
+    }
+
+    #[test]
+    fn encode_differs_from_hash() {
+        for dst in [DST_RO, DST_NU] {
+            let hashed =
+                MockCurve::hash_from_bytes::<ExpandMsgXmd<Sha256>>(&[b"abc"], &[dst]).unwrap();
+            let encoded =
+                MockCurve::encode_from_bytes::<ExpandMsgXmd<Sha256>>(&[b"abc"], &[dst]).unwrap();
+            assert_ne!(hashed, encoded);
+        }
+    }
+}
@@ -0,0 +1,367 @@
+//! Curve-generic runner for JSON test vectors, e.g. for interoperability
+//! testing against other implementations.
+//!
+//! A vector file is a JSON array of cases, each an object whose `type` field
+//! selects one of the schemas below. Byte strings are hex encoded with an
+//! optional `0x` prefix, and SEC1 points may be compressed or uncompressed.
+//!
+//! - `scalar_reduce`: `input` (field bytes) reduces modulo the order to the
+//!   scalar with the canonical encoding `output`.
+//! - `scalar_repr`: `input` (field bytes) decodes to a scalar and encodes back
+//!   to `input` if and only if `valid` is true.
+//! - `point_decode`: `input` decodes as a SEC1 point if and only if `valid` is
+//!   true. Valid points encode to the optional `compressed` and
+//!   `uncompressed` SEC1 encodings.
+//! - `ecdh`: the affine x-coordinate of `secret * public` is `shared`, where
+//!   `secret` is a canonical non-zero scalar and `public` a SEC1 point. With
+//!   `"valid": false` (default `true`) the inputs must be rejected instead and
+//!   `shared` is ignored.
+//! - `hash_to_curve`: hashing the ASCII `msg` with the ASCII `dst` gives the
+//!   point with affine coordinates `x` and `y`, using the uniform (`"RO"`) or
+//!   non-uniform (`"NU"`) `encoding`.
+//! - `hash_to_scalar`: hashing the ASCII `msg` with the ASCII `dst` gives the
+//!   scalar with the canonical encoding `output`.
+//!
+//! For example:
+//!
+//! ```json
+//! [
+//!   { "type": "scalar_reduce", "input": "0x00ff", "output": "0x00ff" },
+//!   { "type": "point_decode", "input": "00", "valid": false },
+//!   {
+//!     "type": "hash_to_curve",
+//!     "encoding": "RO",
+//!     "dst": "QUUX-V01-CS02-with-P256_XMD:SHA-256_SSWU_RO_",
+//!     "msg": "abc",
+//!     "x": "0x0bb8b87485551aa43ed54f009230450b492fead5f1cc91658775dac4a3388a0f",
+//!     "y": "0x5c41b3d0731a27a7b14bc0bf0ccded2d8751f83493404c84a88e71ffd424212e"
+//!   }
+//! ]
+//! ```
+//!
+//! The RFC 9380 vectors map onto `hash_to_curve` cases with one case per
+//! message, taking `dst` from the suite and `x` and `y` from `P`.
+
+use crate::{
+    point::AffineCoordinates,
+    sec1::{FromEncodedPoint, ModulusSize, ToEncodedPoint},
+    AffinePoint, CurveArithmetic, FieldBytes, FieldBytesSize, ProjectivePoint,
+};
+use alloc::vec::Vec;
+use ff::PrimeField;
+use group::{Curve, Group};
+use serde_json::Value;
+
+#[cfg(feature = "hash2curve")]
+use {
+    crate::hash2curve::{ExpandMsg, FromOkm, GroupDigest},
+    group::cofactor::CofactorGroup,
+};
+
+/// Schema of a test vector case, selected by its `type` field.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VectorKind {
+    /// `scalar_reduce`
+    ScalarReduce,
+
+    /// `scalar_repr`
+    ScalarRepr,
+
+    /// `point_decode`
+    PointDecode,
+
+    /// `ecdh`
+    Ecdh,
+
+    /// `hash_to_curve`
+    HashToCurve,
+
+    /// `hash_to_scalar`
+    HashToScalar,
+}
+
+impl VectorKind {
+    /// Parse the `type` field of a case.
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "scalar_reduce" => Some(Self::ScalarReduce),
+            "scalar_repr" => Some(Self::ScalarRepr),
+            "point_decode" => Some(Self::PointDecode),
+            "ecdh" => Some(Self::Ecdh),
+            "hash_to_curve" => Some(Self::HashToCurve),
+            "hash_to_scalar" => Some(Self::HashToScalar),
+            _ => None,
+        }
+    }
+}
+
+/// Outcome of running a test vector case.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    /// The implementation matches the case.
+    Pass,
+
+    /// The implementation does not match the case.
+    Fail,
+
+    /// The case was not run, e.g. hashing cases by [`run_vectors`].
+    Skip,
+
+    /// The case has an unknown `type`, or missing or invalid fields.
+    Malformed,
+}
+
+/// Result of running a single test vector case.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CaseResult {
+    /// Index of the case in the vector file.
+    pub index: usize,
+
+    /// Schema of the case, if known.
+    pub kind: Option<VectorKind>,
+
+    /// Outcome of the case.
+    pub outcome: Outcome,
+}
+
+/// Results of running a vector file, one per case in order.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VectorReport {
+    results: Vec<CaseResult>,
+}
+
+impl VectorReport {
+    /// Get the results of all cases.
+    pub fn results(&self) -> &[CaseResult] {
+        &self.results
+    }
+
+    /// Number of cases with the given outcome.
+    pub fn count(&self, outcome: Outcome) -> usize {
+        self.results
+            .iter()
+            .filter(|result| result.outcome == outcome)
+            .count()
+    }
+
+    /// Iterate over the cases which failed or are malformed.
+    pub fn failures(&self) -> impl Iterator<Item = &CaseResult> {
+        self.results
+            .iter()
+            .filter(|result| matches!(result.outcome, Outcome::Fail | Outcome::Malformed))
+    }
+
+    /// Did no case fail or turn out malformed?
+    pub fn is_success(&self) -> bool {
+        self.failures().next().is_none()
+    }
+
+    /// Assert that no case failed or turned out malformed.
+    ///
+    /// # Panics
+    ///
+    /// If any case failed or is malformed, listing them.
+    #[track_caller]
+    pub fn assert_success(&self) {
+        let failures: Vec<_> = self.failures().collect();
+        assert!(failures.is_empty(), "test vector failures: {:?}", failures);
+    }
+}
+
+/// Run the vectors in `json` against the curve `C`.
+///
+/// Hashing cases are skipped, see [`run_vectors_with_hash`].
+///
+/// # Panics
+///
+/// If `json` is not a JSON array.
+pub fn run_vectors<C>(json: &str) -> VectorReport
+where
+    C: CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    run::<C>(json, |_, _| Some(Outcome::Skip))
+}
+
+/// Run the vectors in `json` against the curve `C`, including the hashing
+/// cases with the expander `X`.
+///
+/// # Panics
+///
+/// If `json` is not a JSON array.
+#[cfg(feature = "hash2curve")]
+pub fn run_vectors_with_hash<C, X>(json: &str) -> VectorReport
+where
+    C: GroupDigest,
+    C::Scalar: FromOkm,
+    ProjectivePoint<C>: CofactorGroup,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+    X: for<'a> ExpandMsg<'a>,
+{
+    run::<C>(json, |kind, case| {
+        let msg = case.get("msg")?.as_str()?.as_bytes();
+        let dst = case.get("dst")?.as_str()?.as_bytes();
+
+        let pass = match kind {
+            VectorKind::HashToCurve => {
+                let point = match case.get("encoding")?.as_str()? {
+                    "RO" => C::hash_from_bytes::<X>(&[msg], &[dst]),
+                    "NU" => C::encode_from_bytes::<X>(&[msg], &[dst]),
+                    _ => return None,
+                };
+                let (x, y) = (hex(case, "x")?, hex(case, "y")?);
+                point.map_or(false, |point| {
+                    let point = point.to_affine();
+                    point.x().as_slice() == x && point.y().as_slice() == y
+                })
+            }
+            VectorKind::HashToScalar => {
+                let output = hex(case, "output")?;
+                C::hash_to_scalar::<X>(&[msg], &[dst])
+                    .map_or(false, |scalar| scalar.to_repr().as_slice() == output)
+            }
+            _ => return None,
+        };
+
+        Some(outcome(pass))
+    })
+}
+
+/// Run the vectors in `json`, delegating hashing cases to `hash`, which
+/// returns `None` for malformed cases.
+fn run<C>(json: &str, hash: impl Fn(VectorKind, &Value) -> Option<Outcome>) -> VectorReport
+where
+    C: CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    let cases: Vec<Value> = serde_json::from_str(json).expect("invalid test vector file");
+
+    let results = cases
+        .iter()
+        .enumerate()
+        .map(|(index, case)| {
+            let kind = case
+                .get("type")
+                .and_then(Value::as_str)
+                .and_then(VectorKind::from_name);
+
+            let outcome = kind
+                .and_then(|kind| match kind {
+                    VectorKind::ScalarReduce => scalar_reduce::<C>(case),
+                    VectorKind::ScalarRepr => scalar_repr::<C>(case),
+                    VectorKind::PointDecode => point_decode::<C>(case),
+                    VectorKind::Ecdh => ecdh::<C>(case),
+                    VectorKind::HashToCurve | VectorKind::HashToScalar => hash(kind, case),
+                })
+                .unwrap_or(Outcome::Malformed);
+
+            CaseResult {
+                index,
+                kind,
+                outcome,
+            }
+        })
+        .collect();
+
+    VectorReport { results }
+}
+
+fn scalar_reduce<C: CurveArithmetic>(case: &Value) -> Option<Outcome> {
+    let input = field_bytes::<C>(case, "input")?;
+    let output = hex(case, "output")?;
+    let scalar = C::scalar_from_bytes::<false>(&input).unwrap();
+    Some(outcome(scalar.to_repr().as_slice() == output))
+}
+
+fn scalar_repr<C: CurveArithmetic>(case: &Value) -> Option<Outcome> {
+    let input = field_bytes::<C>(case, "input")?;
+    let valid = case.get("valid")?.as_bool()?;
+    let scalar: Option<C::Scalar> = C::scalar_from_bytes::<true>(&input).into();
+    Some(outcome(match scalar {
+        Some(scalar) => valid && scalar.to_repr() == input,
+        None => !valid,
+    }))
+}
+
+fn point_decode<C>(case: &Value) -> Option<Outcome>
+where
+    C: CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    let input = hex(case, "input")?;
+    let valid = case.get("valid")?.as_bool()?;
+
+    let mut encodings = Vec::new();
+    for (key, compress) in [("compressed", true), ("uncompressed", false)] {
+        if case.get(key).is_some() {
+            encodings.push((hex(case, key)?, compress));
+        }
+    }
+
+    Some(outcome(match AffinePoint::<C>::from_sec1_bytes(&input) {
+        Ok(point) => {
+            valid
+                && encodings.iter().all(|(expected, compress)| {
+                    point.to_encoded_point(*compress).as_bytes() == expected.as_slice()
+                })
+        }
+        Err(_) => !valid,
+    }))
+}
+
+fn ecdh<C>(case: &Value) -> Option<Outcome>
+where
+    C: CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    let secret = field_bytes::<C>(case, "secret")?;
+    let public = hex(case, "public")?;
+    let valid = match case.get("valid") {
+        Some(valid) => valid.as_bool()?,
+        None => true,
+    };
+    let shared = if valid {
+        hex(case, "shared")?
+    } else {
+        Vec::new()
+    };
+
+    let secret: Option<C::Scalar> = C::scalar_from_bytes::<true>(&secret).into();
+    let public = AffinePoint::<C>::from_sec1_bytes(&public).ok();
+
+    let product = secret
+        .zip(public)
+        .filter(|(secret, _)| !bool::from(ff::Field::is_zero(secret)))
+        .map(|(secret, public)| ProjectivePoint::<C>::from(public) * secret)
+        .filter(|product| !bool::from(product.is_identity()));
+
+    Some(outcome(match product {
+        Some(product) => valid && product.to_affine().x().as_slice() == shared,
+        None => !valid,
+    }))
+}
+
+fn outcome(pass: bool) -> Outcome {
+    if pass {
+        Outcome::Pass
+    } else {
+        Outcome::Fail
+    }
+}
+
+/// Decode the hex string field `key` of `case`.
+fn hex(case: &Value, key: &str) -> Option<Vec<u8>> {
+    let hex = case.get(key)?.as_str()?;
+    base16ct::mixed::decode_vec(hex.trim_start_matches("0x")).ok()
+}
+
+/// Decode the hex string field `key` of `case` as field bytes.
+fn field_bytes<C: CurveArithmetic>(case: &Value, key: &str) -> Option<FieldBytes<C>> {
+    let bytes = hex(case, key)?;
+    (bytes.len() == C::FIELD_BYTES_SIZE).then(|| FieldBytes::<C>::clone_from_slice(&bytes))
+}
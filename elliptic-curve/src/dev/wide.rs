@@ -0,0 +1,1160 @@
+//! Mock curve with 48-byte field elements.
+//!
+//! This curve uses the NIST P-384 parameters. It exists to exercise code paths
+//! whose sizes depend on [`Curve::FieldBytesSize`], e.g. SEC1 point sizes and
+//! wide reductions, with a width other than the 32 bytes of [`MockCurve`].
+//!
+//! [`MockCurve`]: super::MockCurve
+
+use super::cofactor::impl_op_variants;
+use crate::{
+    bigint::{
+        modular::constant_mod::{Residue, ResidueParams},
+        ArrayEncoding, Integer, U384,
+    },
+    error::{Error, ErrorKind, Result},
+    generic_array::typenum::U48,
+    ops::{
+        sum_tree, sum_tree_mixed, AddMixed, Invert, LinearCombination, MulByGenerator, Reduce,
+        ShrAssign,
+    },
+    point::AffineCoordinates,
+    rand_core::RngCore,
+    scalar::{CanonicalUint, FromUintUnchecked, IsHigh},
+    sec1::{CompressedPoint, Coordinates, FromEncodedPoint, ToEncodedPoint},
+    subtle::{Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeLess, CtOption},
+    zeroize::DefaultIsZeroes,
+    Curve, CurveArithmetic, FieldBytesEncoding, PrimeCurve, ScalarArithmetic,
+};
+use core::{
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+use ff::{Field, PrimeField};
+use pkcs8::AssociatedOid;
+
+#[cfg(feature = "hash2curve")]
+use crate::{
+    generic_array::{typenum::U72, GenericArray},
+    hash2curve::FromOkm,
+};
+
+#[cfg(feature = "jwk")]
+use crate::JwkParameters;
+
+/// SEC1 encoded point.
+pub type EncodedPoint = crate::sec1::EncodedPoint<MockWideCurve>;
+
+/// Field element bytes.
+pub type FieldBytes = crate::FieldBytes<MockWideCurve>;
+
+/// Non-zero scalar value.
+pub type NonZeroScalar = crate::NonZeroScalar<MockWideCurve>;
+
+/// Public key.
+pub type PublicKey = crate::PublicKey<MockWideCurve>;
+
+/// Scalar primitive type.
+pub type ScalarPrimitive = crate::ScalarPrimitive<MockWideCurve>;
+
+/// Moduli used by the mock curve's Montgomery-form residues.
+mod moduli {
+    use crate::bigint::{impl_modulus, U384};
+
+    impl_modulus!(
+        FieldModulus,
+        U384,
+        "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffff0000000000000000ffffffff"
+    );
+
+    impl_modulus!(
+        ScalarModulus,
+        U384,
+        "ffffffffffffffffffffffffffffffffffffffffffffffffc7634d81f4372ddf581a0db248b0a77aecec196accc52973"
+    );
+}
+
+/// Base field residue.
+type FieldResidue = Residue<moduli::FieldModulus, { U384::LIMBS }>;
+
+/// Scalar field residue.
+type ScalarResidue = Residue<moduli::ScalarModulus, { U384::LIMBS }>;
+
+/// Mock elliptic curve type with 48-byte field elements.
+///
+/// Note: this type provides a simple, unoptimized arithmetic implementation
+/// in affine coordinates which is only suitable for tests.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+pub struct MockWideCurve;
+
+impl Curve for MockWideCurve {
+    type FieldBytesSize = U48;
+    type Uint = U384;
+
+    const ORDER: U384 = U384::from_be_hex(
+        "ffffffffffffffffffffffffffffffffffffffffffffffffc7634d81f4372ddf581a0db248b0a77aecec196accc52973",
+    );
+}
+
+impl PrimeCurve for MockWideCurve {}
+
+impl ScalarArithmetic for MockWideCurve {
+    type Scalar = Scalar;
+}
+
+impl CurveArithmetic for MockWideCurve {
+    type AffinePoint = AffinePoint;
+    type FieldElement = FieldElement;
+    type ProjectivePoint = ProjectivePoint;
+    type CompressedPoint = CompressedPoint<Self>;
+    type UncompressedPoint = crate::sec1::UncompressedPoint<Self>;
+
+    const EQUATION_A: FieldElement = FieldElement::from_hex(
+        "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffff0000000000000000fffffffc",
+    );
+    const EQUATION_B: FieldElement = FieldElement::from_hex(
+        "b3312fa7e23ee7e4988e056be3f82d19181d9c6efe8141120314088f5013875ac656398d8a2ed19d2a85c8edd3ec2aef",
+    );
+    const FIELD_MODULUS: U384 = moduli::FieldModulus::MODULUS;
+}
+
+impl AssociatedOid for MockWideCurve {
+    /// OID for NIST P-384
+    const OID: pkcs8::ObjectIdentifier = pkcs8::ObjectIdentifier::new_unwrap("1.3.132.0.34");
+}
+
+#[cfg(feature = "ecdh")]
+impl crate::ecdh::ValidatePublicKey for MockWideCurve {}
+
+#[cfg(feature = "jwk")]
+impl JwkParameters for MockWideCurve {
+    const CRV: &'static str = "P-384";
+}
+
+impl FieldBytesEncoding<MockWideCurve> for U384 {}
+
+/// Example scalar type
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+pub struct Scalar(ScalarPrimitive);
+
+impl Scalar {
+    /// Parse a scalar from a big endian hex string.
+    ///
+    /// Does *not* perform a check that the value is in range.
+    const fn from_hex(hex: &str) -> Self {
+        Self(ScalarPrimitive::from_uint_unchecked(U384::from_be_hex(hex)))
+    }
+
+    /// Convert to a Montgomery-form residue.
+    fn to_residue(self) -> ScalarResidue {
+        ScalarResidue::new(self.0.as_uint())
+    }
+
+    /// Convert from a Montgomery-form residue.
+    fn from_residue(residue: ScalarResidue) -> Self {
+        Self(ScalarPrimitive::from_uint_unchecked(residue.retrieve()))
+    }
+}
+
+impl Field for Scalar {
+    const ZERO: Self = Self(ScalarPrimitive::ZERO);
+    const ONE: Self = Self(ScalarPrimitive::ONE);
+
+    fn random(mut rng: impl RngCore) -> Self {
+        let mut bytes = FieldBytes::default();
+
+        loop {
+            rng.fill_bytes(&mut bytes);
+            if let Some(scalar) = Self::from_repr(bytes).into() {
+                return scalar;
+            }
+        }
+    }
+
+    fn is_zero(&self) -> Choice {
+        self.0.is_zero()
+    }
+
+    fn square(&self) -> Self {
+        Self::from_residue(self.to_residue().square())
+    }
+
+    fn double(&self) -> Self {
+        self.add(self)
+    }
+
+    fn invert(&self) -> CtOption<Self> {
+        let (inverse, is_some) = self.to_residue().invert();
+        CtOption::new(Self::from_residue(inverse), is_some.into())
+    }
+
+    fn sqrt(&self) -> CtOption<Self> {
+        // n = 3 mod 4, so the square root is `self^((n + 1) / 4)`
+        let sqrt = Self::from_residue(self.to_residue().pow(&U384::from_be_hex(
+            "3ffffffffffffffffffffffffffffffffffffffffffffffff1d8d3607d0dcb77d606836c922c29debb3b065ab3314a5d",
+        )));
+        CtOption::new(sqrt, sqrt.square().ct_eq(self))
+    }
+
+    fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+        ff::helpers::sqrt_ratio_generic(num, div)
+    }
+}
+
+impl PrimeField for Scalar {
+    type Repr = FieldBytes;
+
+    const MODULUS: &'static str = "0xffffffffffffffffffffffffffffffffffffffffffffffffc7634d81f4372ddf581a0db248b0a77aecec196accc52973";
+    const NUM_BITS: u32 = 384;
+    const CAPACITY: u32 = 383;
+    const TWO_INV: Self = Self::from_hex(
+        "7fffffffffffffffffffffffffffffffffffffffffffffffe3b1a6c0fa1b96efac0d06d9245853bd76760cb5666294ba",
+    );
+    const MULTIPLICATIVE_GENERATOR: Self = Self::from_hex(
+        "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002",
+    );
+    const S: u32 = 1;
+    const ROOT_OF_UNITY: Self = Self::from_hex(
+        "ffffffffffffffffffffffffffffffffffffffffffffffffc7634d81f4372ddf581a0db248b0a77aecec196accc52972",
+    );
+    const ROOT_OF_UNITY_INV: Self = Self::from_hex(
+        "ffffffffffffffffffffffffffffffffffffffffffffffffc7634d81f4372ddf581a0db248b0a77aecec196accc52972",
+    );
+    const DELTA: Self = Self::from_hex(
+        "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004",
+    );
+
+    fn from_repr(bytes: FieldBytes) -> CtOption<Self> {
+        ScalarPrimitive::from_bytes(&bytes).map(Self)
+    }
+
+    fn to_repr(&self) -> FieldBytes {
+        self.0.to_bytes()
+    }
+
+    fn is_odd(&self) -> Choice {
+        self.0.is_odd()
+    }
+}
+
+impl AsRef<Scalar> for Scalar {
+    fn as_ref(&self) -> &Scalar {
+        self
+    }
+}
+
+impl ConditionallySelectable for Scalar {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(ScalarPrimitive::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+impl ConstantTimeEq for Scalar {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl DefaultIsZeroes for Scalar {}
+
+impl Add<&Scalar> for Scalar {
+    type Output = Scalar;
+
+    fn add(self, other: &Scalar) -> Scalar {
+        Self(self.0.add(&other.0))
+    }
+}
+
+impl Sub<&Scalar> for Scalar {
+    type Output = Scalar;
+
+    fn sub(self, other: &Scalar) -> Scalar {
+        Self(self.0.sub(&other.0))
+    }
+}
+
+impl Mul<&Scalar> for Scalar {
+    type Output = Scalar;
+
+    fn mul(self, other: &Scalar) -> Scalar {
+        Self::from_residue(self.to_residue() * other.to_residue())
+    }
+}
+
+impl_op_variants!(Scalar, Add, add, AddAssign, add_assign);
+impl_op_variants!(Scalar, Sub, sub, SubAssign, sub_assign);
+impl_op_variants!(Scalar, Mul, mul, MulAssign, mul_assign);
+
+impl Neg for Scalar {
+    type Output = Scalar;
+
+    fn neg(self) -> Scalar {
+        Self(self.0.neg())
+    }
+}
+
+impl ShrAssign<usize> for Scalar {
+    fn shr_assign(&mut self, rhs: usize) {
+        self.0 >>= rhs;
+    }
+}
+
+impl Sum for Scalar {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self(iter.map(|scalar| scalar.0).sum())
+    }
+}
+
+impl<'a> Sum<&'a Scalar> for Scalar {
+    fn sum<I: Iterator<Item = &'a Scalar>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl Product for Scalar {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, Mul::mul)
+    }
+}
+
+impl<'a> Product<&'a Scalar> for Scalar {
+    fn product<I: Iterator<Item = &'a Scalar>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+impl Invert for Scalar {
+    type Output = CtOption<Scalar>;
+
+    fn invert(&self) -> CtOption<Scalar> {
+        Field::invert(self)
+    }
+}
+
+impl Reduce<U384> for Scalar {
+    type Bytes = FieldBytes;
+
+    fn reduce(w: U384) -> Self {
+        Self(ScalarPrimitive::from_uint_unchecked(
+            MockWideCurve::reduce_uint(&w),
+        ))
+    }
+
+    fn reduce_bytes(bytes: &FieldBytes) -> Self {
+        Self::reduce(U384::from_be_byte_array(*bytes))
+    }
+}
+
+impl From<u64> for Scalar {
+    fn from(n: u64) -> Scalar {
+        Self(n.into())
+    }
+}
+
+impl From<ScalarPrimitive> for Scalar {
+    fn from(scalar: ScalarPrimitive) -> Scalar {
+        Self(scalar)
+    }
+}
+
+impl From<Scalar> for ScalarPrimitive {
+    fn from(scalar: Scalar) -> ScalarPrimitive {
+        scalar.0
+    }
+}
+
+impl From<Scalar> for U384 {
+    fn from(scalar: Scalar) -> U384 {
+        scalar.0.to_uint()
+    }
+}
+
+impl TryFrom<U384> for Scalar {
+    type Error = Error;
+
+    fn try_from(w: U384) -> Result<Self> {
+        Option::from(ScalarPrimitive::new(w))
+            .map(Self)
+            .ok_or(Error::new(ErrorKind::Other))
+    }
+}
+
+impl CanonicalUint for Scalar {
+    type Uint = U384;
+
+    fn to_canonical_uint(&self) -> U384 {
+        self.0.to_canonical_uint()
+    }
+
+    fn from_canonical_uint(uint: U384) -> CtOption<Self> {
+        ScalarPrimitive::new(uint).map(Self)
+    }
+}
+
+impl FromUintUnchecked for Scalar {
+    type Uint = U384;
+
+    fn from_uint_unchecked(uint: U384) -> Self {
+        Self(ScalarPrimitive::from_uint_unchecked(uint))
+    }
+}
+
+impl From<Scalar> for FieldBytes {
+    fn from(scalar: Scalar) -> Self {
+        scalar.to_repr()
+    }
+}
+
+impl IsHigh for Scalar {
+    fn is_high(&self) -> Choice {
+        self.0.is_high()
+    }
+}
+
+/// Example base field element type.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct FieldElement(FieldResidue);
+
+impl FieldElement {
+    /// Parse a field element from a big endian hex string.
+    ///
+    /// Does *not* perform a check that the value is in range.
+    pub(super) const fn from_hex(hex: &str) -> Self {
+        Self(FieldResidue::new(&U384::from_be_hex(hex)))
+    }
+
+    /// Compute `x³ + ax + b`, i.e. the right hand side of the curve equation.
+    fn curve_equation_rhs(x: &Self) -> Self {
+        (x.square() + MockWideCurve::EQUATION_A) * x + MockWideCurve::EQUATION_B
+    }
+}
+
+impl Field for FieldElement {
+    const ZERO: Self = Self(FieldResidue::ZERO);
+    const ONE: Self = Self(FieldResidue::ONE);
+
+    fn random(mut rng: impl RngCore) -> Self {
+        let mut bytes = FieldBytes::default();
+
+        loop {
+            rng.fill_bytes(&mut bytes);
+            if let Some(fe) = Self::from_repr(bytes).into() {
+                return fe;
+            }
+        }
+    }
+
+    fn is_zero(&self) -> Choice {
+        self.0.ct_eq(&FieldResidue::ZERO)
+    }
+
+    fn square(&self) -> Self {
+        Self(self.0.square())
+    }
+
+    fn double(&self) -> Self {
+        Self(self.0 + self.0)
+    }
+
+    fn invert(&self) -> CtOption<Self> {
+        let (inverse, is_some) = self.0.invert();
+        CtOption::new(Self(inverse), is_some.into())
+    }
+
+    fn sqrt(&self) -> CtOption<Self> {
+        // p = 3 mod 4, so the square root is `self^((p + 1) / 4)`
+        let sqrt = Self(self.0.pow(&U384::from_be_hex(
+            "3fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffbfffffffc00000000000000040000000",
+        )));
+        CtOption::new(sqrt, sqrt.square().ct_eq(self))
+    }
+
+    fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+        ff::helpers::sqrt_ratio_generic(num, div)
+    }
+}
+
+impl PrimeField for FieldElement {
+    type Repr = FieldBytes;
+
+    const MODULUS: &'static str = "0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffff0000000000000000ffffffff";
+    const NUM_BITS: u32 = 384;
+    const CAPACITY: u32 = 383;
+    const TWO_INV: Self = Self::from_hex(
+        "7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7fffffff800000000000000080000000",
+    );
+    const MULTIPLICATIVE_GENERATOR: Self = Self::from_hex(
+        "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000013",
+    );
+    const S: u32 = 1;
+    const ROOT_OF_UNITY: Self = Self::from_hex(
+        "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffff0000000000000000fffffffe",
+    );
+    const ROOT_OF_UNITY_INV: Self = Self::from_hex(
+        "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffff0000000000000000fffffffe",
+    );
+    const DELTA: Self = Self::from_hex(
+        "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000169",
+    );
+
+    fn from_repr(bytes: FieldBytes) -> CtOption<Self> {
+        let uint = U384::from_be_byte_array(bytes);
+        let is_some = uint.ct_lt(&moduli::FieldModulus::MODULUS);
+        CtOption::new(Self(FieldResidue::new(&uint)), is_some)
+    }
+
+    fn to_repr(&self) -> FieldBytes {
+        self.0.retrieve().to_be_byte_array()
+    }
+
+    fn is_odd(&self) -> Choice {
+        self.0.retrieve().is_odd()
+    }
+}
+
+impl From<u64> for FieldElement {
+    fn from(n: u64) -> FieldElement {
+        Self(FieldResidue::new(&U384::from_u64(n)))
+    }
+}
+
+impl ConditionallySelectable for FieldElement {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(FieldResidue::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+impl ConstantTimeEq for FieldElement {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl Add<&FieldElement> for FieldElement {
+    type Output = FieldElement;
+
+    fn add(self, other: &FieldElement) -> FieldElement {
+        Self(self.0 + other.0)
+    }
+}
+
+impl Sub<&FieldElement> for FieldElement {
+    type Output = FieldElement;
+
+    fn sub(self, other: &FieldElement) -> FieldElement {
+        Self(self.0 - other.0)
+    }
+}
+
+impl Mul<&FieldElement> for FieldElement {
+    type Output = FieldElement;
+
+    fn mul(self, other: &FieldElement) -> FieldElement {
+        Self(self.0 * other.0)
+    }
+}
+
+impl_op_variants!(FieldElement, Add, add, AddAssign, add_assign);
+impl_op_variants!(FieldElement, Sub, sub, SubAssign, sub_assign);
+impl_op_variants!(FieldElement, Mul, mul, MulAssign, mul_assign);
+
+impl Neg for FieldElement {
+    type Output = FieldElement;
+
+    fn neg(self) -> FieldElement {
+        Self(-self.0)
+    }
+}
+
+impl Sum for FieldElement {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+impl<'a> Sum<&'a FieldElement> for FieldElement {
+    fn sum<I: Iterator<Item = &'a FieldElement>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl Product for FieldElement {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, Mul::mul)
+    }
+}
+
+impl<'a> Product<&'a FieldElement> for FieldElement {
+    fn product<I: Iterator<Item = &'a FieldElement>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+/// Example affine point type
+#[derive(Clone, Copy, Debug)]
+pub struct AffinePoint {
+    /// x-coordinate
+    x: FieldElement,
+
+    /// y-coordinate
+    y: FieldElement,
+
+    /// Is this point the point at infinity? 0 = no, 1 = yes
+    infinity: u8,
+}
+
+impl AffinePoint {
+    /// Additive identity of the group a.k.a. the point at infinity.
+    pub const IDENTITY: Self = Self {
+        x: FieldElement::ZERO,
+        y: FieldElement::ZERO,
+        infinity: 1,
+    };
+
+    /// Base point of the curve.
+    pub const GENERATOR: Self = Self {
+        x: FieldElement::from_hex(
+            "aa87ca22be8b05378eb1c71ef320ad746e1d3b628ba79b9859f741e082542a385502f25dbf55296c3a545e3872760ab7",
+        ),
+        y: FieldElement::from_hex(
+            "3617de4a96262c6f5d9e98bf9292dc29f8f41dbd289a147ce9da3113b5f0b8c00a60b1ce1d7e819d7a431d7c90ea0e5f",
+        ),
+        infinity: 0,
+    };
+
+    /// Is this point the identity point?
+    pub fn is_identity(&self) -> Choice {
+        Choice::from(self.infinity)
+    }
+
+    /// Add two points using the affine chord-and-tangent formulas.
+    fn add(&self, other: &Self) -> Self {
+        let same_x = self.x.ct_eq(&other.x);
+        let opposite = same_x & (self.y + other.y).is_zero();
+
+        let chord = (other.y - self.y) * (other.x - self.x).invert().unwrap_or(FieldElement::ZERO);
+        let tangent = (self.x.square().double() + self.x.square() + MockWideCurve::EQUATION_A)
+            * self.y.double().invert().unwrap_or(FieldElement::ZERO);
+        let lambda = FieldElement::conditional_select(&chord, &tangent, same_x);
+
+        let x = lambda.square() - self.x - other.x;
+        let y = lambda * (self.x - x) - self.y;
+        let sum = Self::conditional_select(&Self { x, y, infinity: 0 }, &Self::IDENTITY, opposite);
+
+        let sum = Self::conditional_select(&sum, other, self.is_identity());
+        Self::conditional_select(&sum, self, other.is_identity())
+    }
+}
+
+impl AffineCoordinates for AffinePoint {
+    type FieldRepr = FieldBytes;
+
+    const IDENTITY: Self = AffinePoint::IDENTITY;
+
+    fn x(&self) -> FieldBytes {
+        self.x.to_repr()
+    }
+
+    fn y(&self) -> FieldBytes {
+        self.y.to_repr()
+    }
+
+    fn y_is_odd(&self) -> Choice {
+        self.y.is_odd()
+    }
+}
+
+impl ConstantTimeEq for AffinePoint {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.x.ct_eq(&other.x) & self.y.ct_eq(&other.y) & self.infinity.ct_eq(&other.infinity)
+    }
+}
+
+impl ConditionallySelectable for AffinePoint {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self {
+            x: FieldElement::conditional_select(&a.x, &b.x, choice),
+            y: FieldElement::conditional_select(&a.y, &b.y, choice),
+            infinity: u8::conditional_select(&a.infinity, &b.infinity, choice),
+        }
+    }
+}
+
+impl Default for AffinePoint {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl DefaultIsZeroes for AffinePoint {}
+
+impl Eq for AffinePoint {}
+
+impl PartialEq for AffinePoint {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Neg for AffinePoint {
+    type Output = AffinePoint;
+
+    fn neg(self) -> AffinePoint {
+        -&self
+    }
+}
+
+impl Neg for &AffinePoint {
+    type Output = AffinePoint;
+
+    fn neg(self) -> AffinePoint {
+        AffinePoint {
+            x: self.x,
+            y: -self.y,
+            infinity: self.infinity,
+        }
+    }
+}
+
+impl FromEncodedPoint<MockWideCurve> for AffinePoint {
+    fn from_encoded_point(encoded_point: &EncodedPoint) -> CtOption<Self> {
+        match encoded_point.coordinates() {
+            Coordinates::Identity => CtOption::new(Self::IDENTITY, Choice::from(1)),
+            Coordinates::Compact { .. } => CtOption::new(Self::IDENTITY, Choice::from(0)),
+            Coordinates::Compressed { x, y_is_odd } => FieldElement::from_repr(*x).and_then(|x| {
+                FieldElement::curve_equation_rhs(&x).sqrt().map(|y| {
+                    let y_is_odd = Choice::from(u8::from(y_is_odd));
+                    let y = FieldElement::conditional_select(&-y, &y, y.is_odd().ct_eq(&y_is_odd));
+                    Self { x, y, infinity: 0 }
+                })
+            }),
+            Coordinates::Uncompressed { x, y } => FieldElement::from_repr(*x).and_then(|x| {
+                FieldElement::from_repr(*y).and_then(|y| {
+                    let on_curve = y.square().ct_eq(&FieldElement::curve_equation_rhs(&x));
+                    CtOption::new(Self { x, y, infinity: 0 }, on_curve)
+                })
+            }),
+        }
+    }
+}
+
+impl ToEncodedPoint<MockWideCurve> for AffinePoint {
+    fn to_encoded_point(&self, compress: bool) -> EncodedPoint {
+        if self.is_identity().into() {
+            EncodedPoint::identity()
+        } else {
+            EncodedPoint::from_affine_coordinates(&self.x.to_repr(), &self.y.to_repr(), compress)
+        }
+    }
+}
+
+impl group::GroupEncoding for AffinePoint {
+    type Repr = CompressedPoint<MockWideCurve>;
+
+    fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
+        EncodedPoint::from_bytes(bytes)
+            .map(|point| CtOption::new(point, Choice::from(1)))
+            .unwrap_or_else(|_| {
+                let is_identity = bytes.ct_eq(&Self::Repr::default());
+                CtOption::new(EncodedPoint::identity(), is_identity)
+            })
+            .and_then(|point| Self::from_encoded_point(&point))
+    }
+
+    fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
+        Self::from_bytes(bytes)
+    }
+
+    fn to_bytes(&self) -> Self::Repr {
+        let encoded = self.to_encoded_point(true);
+        let mut result = CompressedPoint::<MockWideCurve>::default();
+        result[..encoded.len()].copy_from_slice(encoded.as_bytes());
+        result
+    }
+}
+
+/// Example projective point type.
+///
+/// Points are kept normalized, i.e. this is a thin wrapper around
+/// [`AffinePoint`] which performs one inversion per group operation.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ProjectivePoint(AffinePoint);
+
+impl ProjectivePoint {
+    /// Additive identity of the group a.k.a. the point at infinity.
+    pub const IDENTITY: Self = Self(AffinePoint::IDENTITY);
+
+    /// Base point of the curve.
+    pub const GENERATOR: Self = Self(AffinePoint::GENERATOR);
+}
+
+impl ConstantTimeEq for ProjectivePoint {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl ConditionallySelectable for ProjectivePoint {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(AffinePoint::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+impl DefaultIsZeroes for ProjectivePoint {}
+
+impl From<AffinePoint> for ProjectivePoint {
+    fn from(point: AffinePoint) -> ProjectivePoint {
+        Self(point)
+    }
+}
+
+impl From<ProjectivePoint> for AffinePoint {
+    fn from(point: ProjectivePoint) -> AffinePoint {
+        point.0
+    }
+}
+
+impl FromEncodedPoint<MockWideCurve> for ProjectivePoint {
+    fn from_encoded_point(point: &EncodedPoint) -> CtOption<Self> {
+        AffinePoint::from_encoded_point(point).map(Self)
+    }
+}
+
+impl ToEncodedPoint<MockWideCurve> for ProjectivePoint {
+    fn to_encoded_point(&self, compress: bool) -> EncodedPoint {
+        self.0.to_encoded_point(compress)
+    }
+}
+
+impl group::Group for ProjectivePoint {
+    type Scalar = Scalar;
+
+    fn random(rng: impl RngCore) -> Self {
+        Self::GENERATOR * Scalar::random(rng)
+    }
+
+    fn identity() -> Self {
+        Self::IDENTITY
+    }
+
+    fn generator() -> Self {
+        Self::GENERATOR
+    }
+
+    fn is_identity(&self) -> Choice {
+        self.0.is_identity()
+    }
+
+    fn double(&self) -> Self {
+        Self(AffinePoint::add(&self.0, &self.0))
+    }
+}
+
+impl group::GroupEncoding for ProjectivePoint {
+    type Repr = CompressedPoint<MockWideCurve>;
+
+    fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
+        AffinePoint::from_bytes(bytes).map(Self)
+    }
+
+    fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
+        Self::from_bytes(bytes)
+    }
+
+    fn to_bytes(&self) -> Self::Repr {
+        self.0.to_bytes()
+    }
+}
+
+impl group::Curve for ProjectivePoint {
+    type AffineRepr = AffinePoint;
+
+    fn to_affine(&self) -> AffinePoint {
+        self.0
+    }
+}
+
+impl AddMixed<AffinePoint> for ProjectivePoint {}
+
+impl LinearCombination for ProjectivePoint {}
+
+impl MulByGenerator for ProjectivePoint {}
+
+impl Add<&ProjectivePoint> for ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    fn add(self, other: &ProjectivePoint) -> ProjectivePoint {
+        Self(AffinePoint::add(&self.0, &other.0))
+    }
+}
+
+impl Sub<&ProjectivePoint> for ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    fn sub(self, other: &ProjectivePoint) -> ProjectivePoint {
+        self.add(&other.neg())
+    }
+}
+
+impl_op_variants!(ProjectivePoint, Add, add, AddAssign, add_assign);
+impl_op_variants!(ProjectivePoint, Sub, sub, SubAssign, sub_assign);
+
+impl Add<AffinePoint> for AffinePoint {
+    type Output = ProjectivePoint;
+
+    fn add(self, other: AffinePoint) -> ProjectivePoint {
+        ProjectivePoint::from(self) + other
+    }
+}
+
+impl Add<&AffinePoint> for AffinePoint {
+    type Output = ProjectivePoint;
+
+    fn add(self, other: &AffinePoint) -> ProjectivePoint {
+        ProjectivePoint::from(self) + other
+    }
+}
+
+impl Sub<AffinePoint> for AffinePoint {
+    type Output = ProjectivePoint;
+
+    fn sub(self, other: AffinePoint) -> ProjectivePoint {
+        ProjectivePoint::from(self) - other
+    }
+}
+
+impl Sub<&AffinePoint> for AffinePoint {
+    type Output = ProjectivePoint;
+
+    fn sub(self, other: &AffinePoint) -> ProjectivePoint {
+        ProjectivePoint::from(self) - other
+    }
+}
+
+impl Add<AffinePoint> for ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    fn add(self, other: AffinePoint) -> ProjectivePoint {
+        self.add(&other)
+    }
+}
+
+impl Add<&AffinePoint> for ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    fn add(self, other: &AffinePoint) -> ProjectivePoint {
+        Self(AffinePoint::add(&self.0, other))
+    }
+}
+
+impl AddAssign<AffinePoint> for ProjectivePoint {
+    fn add_assign(&mut self, rhs: AffinePoint) {
+        *self = *self + rhs;
+    }
+}
+
+impl AddAssign<&AffinePoint> for ProjectivePoint {
+    fn add_assign(&mut self, rhs: &AffinePoint) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<AffinePoint> for ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    fn sub(self, other: AffinePoint) -> ProjectivePoint {
+        self.sub(&other)
+    }
+}
+
+impl Sub<&AffinePoint> for ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    fn sub(self, other: &AffinePoint) -> ProjectivePoint {
+        self.add(&other.neg())
+    }
+}
+
+impl SubAssign<AffinePoint> for ProjectivePoint {
+    fn sub_assign(&mut self, rhs: AffinePoint) {
+        *self = *self - rhs;
+    }
+}
+
+impl SubAssign<&AffinePoint> for ProjectivePoint {
+    fn sub_assign(&mut self, rhs: &AffinePoint) {
+        *self = *self - rhs;
+    }
+}
+
+impl Sum for ProjectivePoint {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        sum_tree(iter)
+    }
+}
+
+impl<'a> Sum<&'a ProjectivePoint> for ProjectivePoint {
+    fn sum<I: Iterator<Item = &'a ProjectivePoint>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl Sum<AffinePoint> for ProjectivePoint {
+    fn sum<I: Iterator<Item = AffinePoint>>(iter: I) -> Self {
+        sum_tree_mixed(iter)
+    }
+}
+
+impl<'a> Sum<&'a AffinePoint> for ProjectivePoint {
+    fn sum<I: Iterator<Item = &'a AffinePoint>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl Mul<Scalar> for ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    fn mul(self, scalar: Scalar) -> ProjectivePoint {
+        self * &scalar
+    }
+}
+
+impl Mul<&Scalar> for ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    /// Constant-time double-and-add (from the most significant bit).
+    #[allow(clippy::integer_arithmetic)]
+    fn mul(self, scalar: &Scalar) -> ProjectivePoint {
+        let mut acc = Self::IDENTITY;
+
+        for byte in scalar.to_repr() {
+            for i in (0..8).rev() {
+                acc = group::Group::double(&acc);
+                acc.conditional_assign(&(acc + self), Choice::from((byte >> i) & 1));
+            }
+        }
+
+        acc
+    }
+}
+
+impl MulAssign<Scalar> for ProjectivePoint {
+    fn mul_assign(&mut self, rhs: Scalar) {
+        *self = *self * rhs;
+    }
+}
+
+impl MulAssign<&Scalar> for ProjectivePoint {
+    fn mul_assign(&mut self, rhs: &Scalar) {
+        *self = *self * rhs;
+    }
+}
+
+impl Neg for ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    fn neg(self) -> ProjectivePoint {
+        -&self
+    }
+}
+
+impl Neg for &ProjectivePoint {
+    type Output = ProjectivePoint;
+
+    fn neg(self) -> ProjectivePoint {
+        ProjectivePoint(-&self.0)
+    }
+}
+
+#[cfg(feature = "hash2curve")]
+impl FromOkm for FieldElement {
+    type Length = U72;
+
+    fn from_okm(data: &GenericArray<u8, Self::Length>) -> Self {
+        // 2^384 mod p
+        const F_2_384: FieldElement = FieldElement::from_hex(
+            "000000000000000000000000000000000000000000000000000000000000000100000000ffffffffffffffff00000001",
+        );
+
+        let mut d0 = FieldBytes::default();
+        d0[24..].copy_from_slice(&data[..24]);
+        let d0 = Self(FieldResidue::new(&U384::from_be_byte_array(d0)));
+        let d1 = Self(FieldResidue::new(&U384::from_be_byte_array(
+            FieldBytes::clone_from_slice(&data[24..]),
+        )));
+
+        d0 * F_2_384 + d1
+    }
+}
+
+#[cfg(feature = "hash2curve")]
+impl FromOkm for Scalar {
+    type Length = U72;
+
+    fn from_okm(data: &GenericArray<u8, Self::Length>) -> Self {
+        // 2^384 mod n
+        let f_2_384 = Self::from_hex(
+            "000000000000000000000000000000000000000000000000389cb27e0bc8d220a7e5f24db74f58851313e695333ad68d",
+        );
+
+        let mut d0 = FieldBytes::default();
+        d0[24..].copy_from_slice(&data[..24]);
+        let d0 = Self::from_residue(ScalarResidue::new(&U384::from_be_byte_array(d0)));
+        let d1 = Self::from_residue(ScalarResidue::new(&U384::from_be_byte_array(
+            FieldBytes::clone_from_slice(&data[24..]),
+        )));
+
+        d0 * f_2_384 + d1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AffinePoint, FieldElement, ProjectivePoint, Scalar};
+    use ff::{Field, PrimeField};
+    use group::{Curve, Group};
+    use hex_literal::hex;
+
+    #[test]
+    fn generator_satisfies_curve_equation() {
+        let AffinePoint { x, y, .. } = AffinePoint::GENERATOR;
+        assert_eq!(y.square(), FieldElement::curve_equation_rhs(&x));
+    }
+
+    #[test]
+    fn generator_order() {
+        let g = ProjectivePoint::GENERATOR;
+        assert!(bool::from((g * -Scalar::ONE + g).is_identity()));
+        assert_eq!(g * Scalar::from(3u64), g.double() + g);
+        assert_eq!(g * -Scalar::ONE, -g);
+    }
+
+    #[test]
+    fn double_generator() {
+        let point = ProjectivePoint::GENERATOR.double().to_affine();
+        assert_eq!(
+            point.x.to_repr().as_slice(),
+            hex!("08d999057ba3d2d969260045c55b97f089025959a6f434d651d207d19fb96e9e4fe0e86ebe0e64f85b96a9c75295df61")
+        );
+        assert_eq!(
+            point.y.to_repr().as_slice(),
+            hex!("8e80f1fa5b1b3cedb7bfe8dffd6dba74b275d875bc6cc43e904e505f256ab4255ffd43e94d39e22d61501e700a940e80")
+        );
+    }
+
+    #[test]
+    fn field_square_roots() {
+        for n in 1..32u64 {
+            let x = FieldElement::from(n);
+            assert_eq!(x.square().sqrt().unwrap().square(), x.square());
+
+            let s = Scalar::from(n);
+            assert_eq!(s.square().sqrt().unwrap().square(), s.square());
+        }
+
+        assert_eq!(FieldElement::ROOT_OF_UNITY, -FieldElement::ONE);
+        assert_eq!(Scalar::ROOT_OF_UNITY, -Scalar::ONE);
+        assert_eq!(FieldElement::TWO_INV.double(), FieldElement::ONE);
+        assert_eq!(Scalar::TWO_INV.double(), Scalar::ONE);
+    }
+}
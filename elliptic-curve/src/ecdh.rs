@@ -27,16 +27,27 @@
 //! [SIGMA]: https://webee.technion.ac.il/~hugo/sigma-pdf.pdf
 
 use crate::{
-    point::AffineCoordinates, AffinePoint, Curve, CurveArithmetic, FieldBytes, NonZeroScalar,
-    ProjectivePoint, PublicKey,
+    point::{AffineCoordinates, ToAffineBatch},
+    AffinePoint, Curve, CurveArithmetic, Error, ErrorKind, FieldBytes, NonZeroScalar,
+    ProjectivePoint, PublicKey, Result, SecretKey,
+};
+use core::{
+    borrow::Borrow,
+    fmt::{self, Debug},
 };
-use core::borrow::Borrow;
 use digest::{crypto_common::BlockSizeUser, Digest};
-use group::Curve as _;
+use group::{Curve as _, Group};
 use hkdf::{hmac::SimpleHmac, Hkdf};
 use rand_core::CryptoRngCore;
+use subtle::{Choice, ConstantTimeEq};
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(any(feature = "alloc", feature = "hazmat"))]
+use zeroize::Zeroizing;
+
 /// Low-level Elliptic Curve Diffie-Hellman (ECDH) function.
 ///
 /// Whenever possible, we recommend using the high-level ECDH ephemeral API
@@ -69,6 +80,80 @@ where
     SharedSecret::new(secret_point)
 }
 
+/// Compute [`diffie_hellman`] between one secret scalar and many public keys.
+///
+/// All of the shared points are converted to affine coordinates at once using
+/// [`ToAffineBatch`], which avoids a field inversion per public key.
+#[cfg(feature = "alloc")]
+pub fn diffie_hellman_batch<C>(
+    secret_key: &NonZeroScalar<C>,
+    public_keys: &[AffinePoint<C>],
+) -> Vec<SharedSecret<C>>
+where
+    C: ToAffineBatch,
+{
+    let secret_points = public_keys
+        .iter()
+        .map(|public_key| ProjectivePoint::<C>::from(*public_key) * secret_key.as_ref())
+        .collect::<Vec<_>>();
+
+    C::to_affine_batch_slice::<Vec<_>>(&secret_points)
+        .into_iter()
+        .map(SharedSecret::new)
+        .collect()
+}
+
+/// Compute [`diffie_hellman`] between one secret scalar and a fixed-size array
+/// of public keys, using a single batch affine conversion.
+pub fn diffie_hellman_batch_array<C, const N: usize>(
+    secret_key: &NonZeroScalar<C>,
+    public_keys: &[AffinePoint<C>; N],
+) -> [SharedSecret<C>; N]
+where
+    C: ToAffineBatch,
+{
+    let secret_points =
+        public_keys.map(|public_key| ProjectivePoint::<C>::from(public_key) * secret_key.as_ref());
+    let affine = C::to_affine_batch_array(&secret_points);
+    core::array::from_fn(|i| SharedSecret::new(affine[i]))
+}
+
+/// Low-level Elliptic Curve Diffie-Hellman (ECDH) function which validates
+/// `public_key` with [`ValidatePublicKey`] first.
+///
+/// # Errors
+/// If `public_key` is rejected by [`ValidatePublicKey::validate_public_key`].
+pub fn diffie_hellman_validated<C>(
+    secret_key: impl Borrow<NonZeroScalar<C>>,
+    public_key: impl Borrow<AffinePoint<C>>,
+) -> Result<SharedSecret<C>>
+where
+    C: ValidatePublicKey,
+{
+    // Validation only depends on the (public) peer key
+    if C::validate_public_key(public_key.borrow()).into() {
+        Ok(diffie_hellman(secret_key, public_key))
+    } else {
+        Err(Error::new(ErrorKind::Other))
+    }
+}
+
+/// Validation of a peer's public key prior to an ECDH exchange.
+///
+/// The provided impl rejects the identity as well as points outside the prime
+/// order subgroup, using [`CurveArithmetic::is_torsion_free`], so curves with a
+/// cofactor reject small-order and mixed-order points. Points are assumed to
+/// be on the curve, which is guaranteed by [`AffinePoint`] decoding.
+pub trait ValidatePublicKey: CurveArithmetic {
+    /// Is `public_key` safe to use in an ECDH exchange?
+    ///
+    /// This runs in constant time with respect to `public_key`.
+    fn validate_public_key(public_key: &AffinePoint<Self>) -> Choice {
+        let point = ProjectivePoint::<Self>::from(*public_key);
+        !point.is_identity() & Self::is_torsion_free(&point)
+    }
+}
+
 /// Ephemeral Diffie-Hellman Secret.
 ///
 /// These are ephemeral "secret key" values which are deliberately designed
@@ -102,10 +187,18 @@ where
     C: CurveArithmetic,
 {
     /// Generate a cryptographically random [`EphemeralSecret`].
+    ///
+    /// # Panics
+    ///
+    /// If `rng` fails, see [`EphemeralSecret::try_random`].
     pub fn random(rng: &mut impl CryptoRngCore) -> Self {
-        Self {
-            scalar: NonZeroScalar::random(rng),
-        }
+        Self::try_random(rng).expect("RNG failure")
+    }
+
+    /// Generate a cryptographically random [`EphemeralSecret`], returning an
+    /// error if `rng` fails.
+    pub fn try_random(rng: &mut impl CryptoRngCore) -> Result<Self> {
+        NonZeroScalar::try_random(rng).map(|scalar| Self { scalar })
     }
 
     /// Get the public key associated with this ephemeral secret.
@@ -120,6 +213,47 @@ where
     pub fn diffie_hellman(&self, public_key: &PublicKey<C>) -> SharedSecret<C> {
         diffie_hellman(self.scalar, public_key.as_affine())
     }
+
+    /// Compute a Diffie-Hellman shared secret like
+    /// [`EphemeralSecret::diffie_hellman`], but validate the public key of the
+    /// other participant with [`ValidatePublicKey`] first.
+    ///
+    /// # Errors
+    /// If `public_key` is rejected by [`ValidatePublicKey::validate_public_key`].
+    pub fn diffie_hellman_validated(&self, public_key: &PublicKey<C>) -> Result<SharedSecret<C>>
+    where
+        C: ValidatePublicKey,
+    {
+        diffie_hellman_validated(self.scalar, public_key.as_affine())
+    }
+
+    /// Serialize this ephemeral secret as [`FieldBytes`].
+    ///
+    /// # ⚠️ WARNING: HAZMAT! ⚠️
+    ///
+    /// Ephemeral secrets are meant to be used for a single exchange and then
+    /// discarded. Persisting one (e.g. to resume a handshake in another
+    /// process) extends its lifetime and exposure, and reusing it for more
+    /// than one exchange defeats forward secrecy. Only use this when the
+    /// protocol requires it, and make sure the stored copy is destroyed.
+    #[cfg(feature = "hazmat")]
+    pub fn to_bytes(&self) -> Zeroizing<FieldBytes<C>> {
+        Zeroizing::new(self.scalar.into())
+    }
+
+    /// Deserialize an ephemeral secret previously serialized with
+    /// [`EphemeralSecret::to_bytes`].
+    ///
+    /// See the warning on [`EphemeralSecret::to_bytes`].
+    ///
+    /// # Errors
+    /// If `bytes` does not encode a non-zero scalar.
+    #[cfg(feature = "hazmat")]
+    pub fn from_bytes(bytes: &FieldBytes<C>) -> Result<Self> {
+        Option::from(NonZeroScalar::from_repr(bytes.clone()))
+            .map(|scalar| Self { scalar })
+            .ok_or(Error::new(ErrorKind::Other))
+    }
 }
 
 impl<C> From<&EphemeralSecret<C>> for PublicKey<C>
@@ -131,6 +265,24 @@ where
     }
 }
 
+impl<C> SecretKey<C>
+where
+    C: CurveArithmetic,
+{
+    /// Compute a static-static Diffie-Hellman shared secret from this secret
+    /// key and the public key of the other participant in the exchange.
+    ///
+    /// This is equivalent to calling [`diffie_hellman`] with
+    /// [`SecretKey::to_nonzero_scalar`] and [`PublicKey::as_affine`].
+    ///
+    /// Static-static exchanges always produce the same shared secret for a
+    /// given pair of keys and provide no forward secrecy. Prefer
+    /// [`EphemeralSecret`] unless the protocol calls for static keys.
+    pub fn diffie_hellman(&self, public_key: &PublicKey<C>) -> SharedSecret<C> {
+        diffie_hellman(self.to_nonzero_scalar(), public_key.as_affine())
+    }
+}
+
 impl<C> Zeroize for EphemeralSecret<C>
 where
     C: CurveArithmetic,
@@ -152,6 +304,19 @@ where
 }
 
 /// Shared secret value computed via ECDH key agreement.
+///
+/// Key material should be derived using [`SharedSecret::extract`] or
+/// [`SharedSecret::derive_key`]. The raw secret bytes are only accessible with
+/// the `hazmat` feature enabled:
+///
+#[cfg_attr(feature = "hazmat", doc = "```")]
+#[cfg_attr(not(feature = "hazmat"), doc = "```compile_fail")]
+/// use elliptic_curve::{ecdh::SharedSecret, Curve, FieldBytes};
+///
+/// fn raw<C: Curve>(secret: &SharedSecret<C>) -> &FieldBytes<C> {
+///     secret.raw_secret_bytes()
+/// }
+/// ```
 pub struct SharedSecret<C: Curve> {
     /// Computed secret value
     secret_bytes: FieldBytes<C>,
@@ -169,6 +334,76 @@ impl<C: Curve> SharedSecret<C> {
         }
     }
 
+    /// Create a new [`SharedSecret`] from the affine x-coordinate of a shared
+    /// point computed by the caller, e.g. as a linear combination of points.
+    ///
+    /// # ⚠️ Warning: Hazmat!
+    ///
+    /// The x-coordinate alone is the shared secret, so this performs no
+    /// validation of how `point` was computed. Protocols are responsible for
+    /// validating their inputs (see [`ValidatePublicKey`]).
+    ///
+    /// # Errors
+    /// If `point` is the identity, which has no x-coordinate.
+    pub fn from_affine(point: &AffinePoint<C>) -> Result<Self>
+    where
+        C: CurveArithmetic,
+    {
+        if point.ct_eq(&AffinePoint::<C>::IDENTITY).into() {
+            Err(Error::new(ErrorKind::Other))
+        } else {
+            Ok(Self::new(*point))
+        }
+    }
+
+    /// Create a [`SharedSecret`] from each of the given projective points,
+    /// converting them to affine coordinates at once using [`ToAffineBatch`].
+    ///
+    /// # ⚠️ Warning: Hazmat!
+    ///
+    /// See [`SharedSecret::from_affine`].
+    ///
+    /// # Errors
+    /// If any of the points is the identity.
+    #[cfg(feature = "alloc")]
+    pub fn batch_from_projective(points: &[ProjectivePoint<C>]) -> Result<Vec<Self>>
+    where
+        C: ToAffineBatch,
+    {
+        let mut secrets = Vec::with_capacity(points.len());
+        Self::batch_from_projective_into(points, &mut secrets)?;
+        Ok(secrets)
+    }
+
+    /// Fill `secrets` with a [`SharedSecret`] for each of the given points.
+    ///
+    /// On error, all of the secrets which were computed are zeroized.
+    #[cfg(feature = "alloc")]
+    fn batch_from_projective_into(
+        points: &[ProjectivePoint<C>],
+        secrets: &mut Vec<Self>,
+    ) -> Result<()>
+    where
+        C: ToAffineBatch,
+    {
+        let affine = Zeroizing::new(C::to_affine_batch_slice::<Vec<_>>(points));
+        let mut has_identity = Choice::from(0);
+
+        for point in affine.iter() {
+            has_identity |= point.ct_eq(&AffinePoint::<C>::IDENTITY);
+            secrets.push(Self::new(*point));
+        }
+
+        if has_identity.into() {
+            for secret in secrets.iter_mut() {
+                secret.secret_bytes.zeroize();
+            }
+            Err(Error::new(ErrorKind::Other))
+        } else {
+            Ok(())
+        }
+    }
+
     /// Use [HKDF] (HMAC-based Extract-and-Expand Key Derivation Function) to
     /// extract entropy from this shared secret.
     ///
@@ -196,6 +431,25 @@ impl<C: Curve> SharedSecret<C> {
         Hkdf::new(salt, &self.secret_bytes)
     }
 
+    /// Use [HKDF] to derive output key material of arbitrary length from this
+    /// shared secret, filling `okm`.
+    ///
+    /// This runs [`SharedSecret::extract`] followed by [`Hkdf::expand`] with
+    /// the given `info` context string.
+    ///
+    /// # Errors
+    /// If `okm` is longer than 255 times the output size of `D`.
+    ///
+    /// [HKDF]: https://en.wikipedia.org/wiki/HKDF
+    pub fn derive_key<D>(&self, salt: Option<&[u8]>, info: &[u8], okm: &mut [u8]) -> Result<()>
+    where
+        D: BlockSizeUser + Clone + Digest,
+    {
+        self.extract::<D>(salt)
+            .expand(info, okm)
+            .map_err(|_| Error::new(ErrorKind::Other))
+    }
+
     /// This value contains the raw serialized x-coordinate of the elliptic curve
     /// point computed from a Diffie-Hellman exchange, serialized as bytes.
     ///
@@ -210,6 +464,10 @@ impl<C: Curve> SharedSecret<C> {
     /// Instead, the resulting value should be used as input to a Key Derivation
     /// Function (KDF) or cryptographic hash function to produce a symmetric key.
     /// The [`SharedSecret::extract`] function will do this for you.
+    ///
+    /// Compare shared secrets using [`ConstantTimeEq`] rather than comparing
+    /// these bytes, which isn't constant-time.
+    #[cfg(feature = "hazmat")]
     pub fn raw_secret_bytes(&self) -> &FieldBytes<C> {
         &self.secret_bytes
     }
@@ -227,6 +485,27 @@ impl<C: Curve> From<FieldBytes<C>> for SharedSecret<C> {
     }
 }
 
+impl<C: Curve> ConstantTimeEq for SharedSecret<C> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.secret_bytes.ct_eq(&other.secret_bytes)
+    }
+}
+
+impl<C: Curve> Debug for SharedSecret<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(core::any::type_name::<Self>())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<C: Curve> Eq for SharedSecret<C> {}
+
+impl<C: Curve> PartialEq for SharedSecret<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
 impl<C: Curve> ZeroizeOnDrop for SharedSecret<C> {}
 
 impl<C: Curve> Drop for SharedSecret<C> {
@@ -234,3 +513,242 @@ impl<C: Curve> Drop for SharedSecret<C> {
         self.secret_bytes.zeroize()
     }
 }
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::{
+        diffie_hellman, diffie_hellman_batch, diffie_hellman_batch_array, diffie_hellman_validated,
+        EphemeralSecret, SharedSecret,
+    };
+    use crate::{
+        dev::{self, cofactor},
+        point::AffineCoordinates,
+        PublicKey,
+    };
+    use alloc::{format, vec::Vec};
+    use group::{Curve, Group};
+    use hex_literal::hex;
+    use rand_core::OsRng;
+    use sha2::Sha256;
+    use subtle::ConstantTimeEq;
+    use zeroize::ZeroizeOnDrop;
+
+    #[test]
+    fn zeroize_on_drop() {
+        fn assert_zeroize_on_drop<T: ZeroizeOnDrop>() {}
+        assert_zeroize_on_drop::<EphemeralSecret<dev::MockCurve>>();
+        assert_zeroize_on_drop::<SharedSecret<dev::MockCurve>>();
+    }
+
+    #[test]
+    fn shared_secret_ct_eq() {
+        let alice = dev::SecretKey::random(&mut OsRng);
+        let bob = dev::SecretKey::random(&mut OsRng);
+        let shared = alice.diffie_hellman(&bob.public_key());
+
+        assert!(bool::from(
+            shared.ct_eq(&bob.diffie_hellman(&alice.public_key()))
+        ));
+        assert!(!bool::from(
+            shared.ct_eq(&alice.diffie_hellman(&alice.public_key()))
+        ));
+        assert_ne!(shared, SharedSecret::from(dev::FieldBytes::default()));
+    }
+
+    #[test]
+    fn shared_secret_debug_is_redacted() {
+        let bytes = dev::FieldBytes::from([0xabu8; 32]);
+        let debug = format!("{:?}", SharedSecret::<dev::MockCurve>::from(bytes));
+        assert!(debug.contains("SharedSecret"));
+        assert!(!debug.to_lowercase().contains("ab"));
+    }
+
+    #[test]
+    fn diffie_hellman_batch_matches_single() {
+        let secret = dev::NonZeroScalar::random(&mut OsRng);
+        let public_keys: [dev::AffinePoint; 8] = core::array::from_fn(|i| {
+            if i == 3 {
+                dev::AffinePoint::IDENTITY
+            } else {
+                dev::ProjectivePoint::random(&mut OsRng).to_affine()
+            }
+        });
+
+        let batch = diffie_hellman_batch(&secret, &public_keys);
+        let array = diffie_hellman_batch_array(&secret, &public_keys);
+        assert_eq!(batch.len(), public_keys.len());
+
+        for ((public_key, batch), array) in public_keys.iter().zip(&batch).zip(&array) {
+            let single = diffie_hellman(secret, public_key);
+            assert_eq!(*batch, single);
+            assert_eq!(*array, single);
+        }
+    }
+
+    #[test]
+    fn from_affine() {
+        let point = dev::ProjectivePoint::random(&mut OsRng).to_affine();
+        let secret = SharedSecret::<dev::MockCurve>::from_affine(&point).unwrap();
+        assert_eq!(secret.secret_bytes, point.x());
+        assert!(SharedSecret::<dev::MockCurve>::from_affine(&dev::AffinePoint::IDENTITY).is_err());
+    }
+
+    #[test]
+    fn batch_from_projective_matches_single() {
+        let points: Vec<dev::ProjectivePoint> = (0..8)
+            .map(|_| dev::ProjectivePoint::random(&mut OsRng))
+            .collect();
+        let batch = SharedSecret::<dev::MockCurve>::batch_from_projective(&points).unwrap();
+        assert_eq!(batch.len(), points.len());
+
+        for (point, secret) in points.iter().zip(&batch) {
+            let single = SharedSecret::<dev::MockCurve>::from_affine(&point.to_affine()).unwrap();
+            assert_eq!(*secret, single);
+        }
+
+        assert!(SharedSecret::<dev::MockCurve>::batch_from_projective(&[])
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn batch_from_projective_rejects_identity() {
+        let mut points: Vec<dev::ProjectivePoint> = (0..8)
+            .map(|_| dev::ProjectivePoint::random(&mut OsRng))
+            .collect();
+        points[5] = dev::ProjectivePoint::identity();
+        assert!(SharedSecret::<dev::MockCurve>::batch_from_projective(&points).is_err());
+
+        let mut secrets = Vec::new();
+        assert!(
+            SharedSecret::<dev::MockCurve>::batch_from_projective_into(&points, &mut secrets)
+                .is_err()
+        );
+        assert_eq!(secrets.len(), points.len());
+        for secret in &secrets {
+            assert!(secret.secret_bytes.iter().all(|&byte| byte == 0));
+        }
+    }
+
+    /// HKDF-SHA256 outputs computed independently, using x(2G) as the IKM
+    #[test]
+    fn derive_key_vectors() {
+        let secret = dev::NonZeroScalar::new(dev::Scalar::from(2u64)).unwrap();
+        let shared = diffie_hellman(secret, dev::AffinePoint::GENERATOR);
+        assert_eq!(
+            shared.secret_bytes.as_slice(),
+            hex!("7CF27B188D034F7E8A52380304B51AC3C08969E277F21B35A60B48FC47669978")
+        );
+
+        let mut okm = [0u8; 42];
+        shared
+            .derive_key::<Sha256>(Some(b"salt"), b"info", &mut okm)
+            .unwrap();
+        assert_eq!(
+            okm,
+            hex!("f0a885e4d45e2a1b8d0903810e5854afc606e1262dadd4a2969e3c918e0a7a845201f328cb77959621b1")
+        );
+
+        let mut okm = [0u8; 32];
+        shared.derive_key::<Sha256>(None, b"", &mut okm).unwrap();
+        assert_eq!(
+            okm,
+            hex!("32c14f38e411dc17e495b9b3f93d55d04251ca23c6090b90c2b3806cd5c100c2")
+        );
+    }
+
+    #[test]
+    fn derive_key_output_lengths() {
+        let secret = dev::NonZeroScalar::new(dev::Scalar::from(2u64)).unwrap();
+        let shared = diffie_hellman(secret, dev::AffinePoint::GENERATOR);
+        assert!(shared.derive_key::<Sha256>(None, b"info", &mut []).is_ok());
+
+        let mut okm = [0u8; 255 * 32 + 1];
+        assert!(shared
+            .derive_key::<Sha256>(None, b"info", &mut okm)
+            .is_err());
+        assert!(shared
+            .derive_key::<Sha256>(None, b"info", &mut okm[..255 * 32])
+            .is_ok());
+
+        let mut okm = [0u8; 255 * 32];
+        shared
+            .derive_key::<Sha256>(Some(b"salt"), b"info", &mut okm)
+            .unwrap();
+        assert_eq!(
+            okm[255 * 32 - 32..],
+            hex!("f7ee7e33c935a48509cba19d27efbec22e659822cd28b1284e0943b525a94949")
+        );
+    }
+
+    #[test]
+    fn secret_key_diffie_hellman() {
+        let alice = dev::SecretKey::random(&mut OsRng);
+        let bob = dev::SecretKey::random(&mut OsRng);
+        let shared = alice.diffie_hellman(&bob.public_key());
+
+        assert_eq!(
+            shared,
+            diffie_hellman(alice.to_nonzero_scalar(), bob.public_key().as_affine())
+        );
+        assert_eq!(shared, bob.diffie_hellman(&alice.public_key()));
+    }
+
+    #[cfg(feature = "hazmat")]
+    #[test]
+    fn ephemeral_secret_bytes_round_trip() {
+        use zeroize::Zeroize;
+
+        let secret = EphemeralSecret::<dev::MockCurve>::random(&mut OsRng);
+        let peer = dev::PublicKey::from_secret_scalar(&dev::NonZeroScalar::random(&mut OsRng));
+
+        let mut bytes = secret.to_bytes();
+        let restored = EphemeralSecret::<dev::MockCurve>::from_bytes(&bytes).unwrap();
+        assert_eq!(restored.public_key(), secret.public_key());
+        assert_eq!(restored.diffie_hellman(&peer), secret.diffie_hellman(&peer));
+
+        bytes.zeroize();
+        assert!(bytes.iter().all(|&b| b == 0));
+        assert!(EphemeralSecret::<dev::MockCurve>::from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn diffie_hellman_validated_accepts_valid_keys() {
+        let alice = EphemeralSecret::<dev::MockCurve>::random(&mut OsRng);
+        let bob = EphemeralSecret::<dev::MockCurve>::random(&mut OsRng);
+        let shared = alice.diffie_hellman_validated(&bob.public_key()).unwrap();
+        assert_eq!(shared, bob.diffie_hellman(&alice.public_key()));
+
+        let alice = EphemeralSecret::<cofactor::MockCofactorCurve>::random(&mut OsRng);
+        let bob = EphemeralSecret::<cofactor::MockCofactorCurve>::random(&mut OsRng);
+        let shared = alice.diffie_hellman_validated(&bob.public_key()).unwrap();
+        assert_eq!(shared, bob.diffie_hellman(&alice.public_key()));
+    }
+
+    #[test]
+    fn diffie_hellman_validated_rejects_identity() {
+        let secret = dev::NonZeroScalar::random(&mut OsRng);
+        assert!(diffie_hellman_validated(secret, dev::AffinePoint::IDENTITY).is_err());
+
+        let secret = cofactor::NonZeroScalar::random(&mut OsRng);
+        assert!(diffie_hellman_validated(secret, cofactor::AffinePoint::IDENTITY).is_err());
+    }
+
+    #[test]
+    fn diffie_hellman_validated_rejects_small_order() {
+        let alice = EphemeralSecret::<cofactor::MockCofactorCurve>::random(&mut OsRng);
+        let torsion = cofactor::ProjectivePoint::TORSION;
+        let generator = cofactor::ProjectivePoint::GENERATOR;
+
+        for point in [
+            torsion,
+            torsion.double(),
+            torsion.double().double(),
+            generator + torsion,
+        ] {
+            // Bypasses the subgroup check done when decoding a `PublicKey`
+            let public_key = PublicKey::from_affine_unchecked(point.to_affine());
+            assert!(alice.diffie_hellman_validated(&public_key).is_err());
+        }
+    }
+}
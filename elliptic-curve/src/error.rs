@@ -5,38 +5,266 @@ use core::fmt::{self, Display};
 #[cfg(feature = "pkcs8")]
 use crate::pkcs8;
 
-/// Result type with the `elliptic-curve` crate's [`Error`] type.
+/// Result type with the `elliptic-curve` crate's [`Error`](struct@Error) type.
 pub type Result<T> = core::result::Result<T, Error>;
 
 /// Elliptic curve errors.
+///
+/// The cause of an error is available via [`Error::kind`].
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
-pub struct Error;
+pub struct Error {
+    kind: ErrorKind,
+}
+
+impl Error {
+    /// Create a new [`Error`](struct@Error) of the given kind.
+    pub const fn new(kind: ErrorKind) -> Self {
+        Self { kind }
+    }
+
+    /// Create a new [`ErrorKind::InvalidLength`] error.
+    pub const fn invalid_length(expected: usize, actual: usize) -> Self {
+        Self::new(ErrorKind::InvalidLength { expected, actual })
+    }
+
+    /// Create a new [`ErrorKind::InvalidBatchElement`] error.
+    pub const fn invalid_batch_element(index: usize) -> Self {
+        Self::new(ErrorKind::InvalidBatchElement { index })
+    }
+
+    /// Get the kind of this error.
+    pub const fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+}
 
 impl Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str("crypto error")
+        self.kind.fmt(f)
+    }
+}
+
+impl From<ErrorKind> for Error {
+    fn from(kind: ErrorKind) -> Error {
+        Error::new(kind)
     }
 }
 
 impl From<base16ct::Error> for Error {
     fn from(_: base16ct::Error) -> Error {
-        Error
+        Error::new(ErrorKind::Decode)
     }
 }
 
 #[cfg(feature = "pkcs8")]
 impl From<pkcs8::Error> for Error {
     fn from(_: pkcs8::Error) -> Error {
-        Error
+        Error::new(ErrorKind::Pkcs8)
     }
 }
 
 #[cfg(feature = "sec1")]
 impl From<sec1::Error> for Error {
-    fn from(_: sec1::Error) -> Error {
-        Error
+    fn from(err: sec1::Error) -> Error {
+        match err {
+            sec1::Error::Crypto => Error::new(ErrorKind::Other),
+            _ => Error::new(ErrorKind::Decode),
+        }
     }
 }
 
 #[cfg(feature = "std")]
 impl std::error::Error for Error {}
+
+/// Kinds of [`Error`](struct@Error).
+///
+/// Failures which depend on secret values are reported with a single coarse
+/// kind, e.g. [`ErrorKind::ScalarOutOfRange`] covers both zero and
+/// out-of-range scalars, and mismatched key pairs are [`ErrorKind::Other`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// Input has the wrong length.
+    InvalidLength {
+        /// Expected length in bytes.
+        expected: usize,
+
+        /// Actual length in bytes.
+        actual: usize,
+    },
+
+    /// Element of a batch is invalid, e.g. a point in a concatenated stream of
+    /// encoded points.
+    InvalidBatchElement {
+        /// Index of the first invalid element.
+        index: usize,
+    },
+
+    /// Point is not a valid curve point, e.g. it is not on the curve or its
+    /// coordinates are not canonical.
+    PointNotOnCurve,
+
+    /// Point is the identity, i.e. the point at infinity.
+    PointAtInfinity,
+
+    /// Point is not in the prime order subgroup.
+    PointNotInSubgroup,
+
+    /// Scalar is not in the allowed range, e.g. it is zero or not less than
+    /// the curve order.
+    ScalarOutOfRange,
+
+    /// Input is not a valid encoding, e.g. malformed hex, Base64, SEC1 or JSON.
+    Decode,
+
+    /// PKCS#8 or SPKI error.
+    Pkcs8,
+
+    /// Key is for a different curve.
+    CurveMismatch,
+
+    /// Operation is not supported by this curve implementation, e.g. it needs
+    /// an arithmetic implementation.
+    Unsupported,
+
+    /// Other error.
+    Other,
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength { expected, actual } => {
+                write!(f, "invalid length: expected {expected} bytes, got {actual}")
+            }
+            Self::InvalidBatchElement { index } => {
+                write!(f, "invalid batch element at index {index}")
+            }
+            Self::PointNotOnCurve => f.write_str("invalid curve point"),
+            Self::PointAtInfinity => f.write_str("point at infinity"),
+            Self::PointNotInSubgroup => f.write_str("point not in prime order subgroup"),
+            Self::ScalarOutOfRange => f.write_str("scalar out of range"),
+            Self::Decode => f.write_str("decoding error"),
+            Self::Pkcs8 => f.write_str("PKCS#8 error"),
+            Self::CurveMismatch => f.write_str("curve mismatch"),
+            Self::Unsupported => f.write_str("unsupported operation"),
+            Self::Other => f.write_str("crypto error"),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::{Error, ErrorKind};
+    use crate::{
+        dev::{cofactor, NonZeroScalar, PublicKey, ScalarPrimitive, SecretKey},
+        sec1::ToEncodedPoint,
+    };
+    use alloc::string::ToString;
+    use core::{mem::size_of, str::FromStr};
+    use group::Curve;
+
+    fn kind<T>(result: crate::Result<T>) -> ErrorKind {
+        result.err().expect("expected an error").kind()
+    }
+
+    #[test]
+    fn error_kind() {
+        assert_eq!(Error::new(ErrorKind::Other).kind(), ErrorKind::Other);
+        assert_eq!(
+            Error::from(ErrorKind::Decode),
+            Error::new(ErrorKind::Decode)
+        );
+        assert_ne!(Error::new(ErrorKind::Decode), Error::new(ErrorKind::Other));
+        assert!(size_of::<Error>() <= 3 * size_of::<usize>());
+    }
+
+    #[test]
+    fn scalar_error_kinds() {
+        assert_eq!(
+            kind(ScalarPrimitive::from_slice(&[0; 31])),
+            ErrorKind::InvalidLength {
+                expected: 32,
+                actual: 31
+            }
+        );
+        assert_eq!(
+            kind(ScalarPrimitive::from_slice(&[0xff; 32])),
+            ErrorKind::ScalarOutOfRange
+        );
+        assert_eq!(
+            kind(NonZeroScalar::try_from(&[0u8; 32][..])),
+            ErrorKind::ScalarOutOfRange
+        );
+        assert_eq!(kind(NonZeroScalar::from_str("zz")), ErrorKind::Decode);
+    }
+
+    #[test]
+    fn secret_key_error_kinds() {
+        assert_eq!(
+            kind(SecretKey::from_bytes(&Default::default())),
+            ErrorKind::ScalarOutOfRange
+        );
+        assert_eq!(
+            kind(SecretKey::from_bytes(&[0xff; 32].into())),
+            ErrorKind::ScalarOutOfRange
+        );
+        assert_eq!(
+            kind(SecretKey::from_slice(&[1; 33])),
+            ErrorKind::InvalidLength {
+                expected: 32,
+                actual: 33
+            }
+        );
+        assert_eq!(
+            kind(SecretKey::from_sec1_pem("not a PEM document")),
+            ErrorKind::Decode
+        );
+    }
+
+    #[test]
+    fn public_key_error_kinds() {
+        assert_eq!(
+            kind(PublicKey::from_sec1_bytes(&[0])),
+            ErrorKind::PointAtInfinity
+        );
+        assert_eq!(
+            kind(PublicKey::from_sec1_bytes(&[8; 33])),
+            ErrorKind::Decode
+        );
+
+        let public_key =
+            PublicKey::from_secret_scalar(&NonZeroScalar::from_repr([1; 32].into()).unwrap());
+        let mut bytes = public_key.to_encoded_point(false).as_bytes().to_vec();
+        bytes[64] ^= 1;
+        assert_eq!(
+            kind(PublicKey::from_sec1_bytes(&bytes)),
+            ErrorKind::PointNotOnCurve
+        );
+
+        let torsion = cofactor::ProjectivePoint::TORSION.to_affine();
+        assert_eq!(
+            kind(cofactor::PublicKey::from_affine(torsion)),
+            ErrorKind::PointNotInSubgroup
+        );
+        assert_eq!(
+            kind(cofactor::PublicKey::from_affine(
+                cofactor::AffinePoint::IDENTITY
+            )),
+            ErrorKind::PointAtInfinity
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            Error::invalid_length(32, 31).to_string(),
+            "invalid length: expected 32 bytes, got 31"
+        );
+        assert_eq!(
+            Error::invalid_batch_element(3).to_string(),
+            "invalid batch element at index 3"
+        );
+        assert_eq!(Error::new(ErrorKind::Other).to_string(), "crypto error");
+    }
+}
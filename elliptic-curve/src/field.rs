@@ -1,10 +1,19 @@
 //! Field elements.
 
 use crate::{
-    bigint::{ArrayEncoding, ByteArray, Integer},
+    bigint::{ArrayEncoding, ByteArray, Integer, NonZero},
     Curve,
 };
-use generic_array::{typenum::Unsigned, GenericArray};
+use core::{any, fmt, marker::PhantomData};
+use generic_array::{typenum::Unsigned, ArrayLength, GenericArray};
+use subtle::{Choice, ConstantTimeEq, CtOption};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+#[cfg(feature = "zerocopy")]
+use {
+    crate::{Error, Result},
+    zerocopy::{AsBytes, FromBytes, FromZeroes},
+};
 
 /// Size of serialized field elements of this elliptic curve.
 pub type FieldBytesSize<C> = <C as Curve>::FieldBytesSize;
@@ -37,6 +46,27 @@ where
         Self::from_be_byte_array(byte_array)
     }
 
+    /// Decode unsigned integer from serialized field element, checking that it
+    /// is less than `modulus` (e.g. the field prime or the curve order).
+    ///
+    /// Returns `None` if the decoded value is not canonical. This runs in
+    /// constant time.
+    fn decode_field_bytes_checked(
+        field_bytes: &FieldBytes<C>,
+        modulus: &NonZero<Self>,
+    ) -> CtOption<Self> {
+        let uint = Self::decode_field_bytes(field_bytes);
+        CtOption::new(uint, uint.is_canonical(modulus))
+    }
+
+    /// Is this integer less than `modulus`, i.e. does its serialized field
+    /// element decode back to it with [`Self::decode_field_bytes_checked`]?
+    ///
+    /// This runs in constant time.
+    fn is_canonical(&self, modulus: &NonZero<Self>) -> Choice {
+        self.ct_lt(modulus)
+    }
+
     /// Encode unsigned integer into serialized field element.
     ///
     /// The default implementation assumes a big endian encoding.
@@ -49,3 +79,233 @@ where
         field_bytes
     }
 }
+
+/// Conversions between [`FieldBytes`] and `[u8; N]` arrays.
+///
+/// `N` is checked against the size of the [`GenericArray`] at compile time, so
+/// a mismatched length fails to build (during monomorphization, i.e. it may
+/// not be reported by `cargo check`):
+///
+/// ```compile_fail
+/// use elliptic_curve::{consts::U32, generic_array::GenericArray, FieldBytesArray};
+///
+/// let bytes = GenericArray::<u8, U32>::default();
+/// let array: [u8; 31] = bytes.to_array();
+/// ```
+pub trait FieldBytesArray: Sized {
+    /// Copy into a `[u8; N]` array.
+    fn to_array<const N: usize>(&self) -> [u8; N];
+
+    /// Copy from a `[u8; N]` array.
+    fn from_array<const N: usize>(array: [u8; N]) -> Self;
+
+    /// Borrow as a `[u8; N]` array.
+    fn as_array<const N: usize>(&self) -> &[u8; N];
+
+    /// Mutably borrow as a `[u8; N]` array.
+    fn as_mut_array<const N: usize>(&mut self) -> &mut [u8; N];
+}
+
+impl<U> FieldBytesArray for GenericArray<u8, U>
+where
+    U: ArrayLength<u8>,
+{
+    fn to_array<const N: usize>(&self) -> [u8; N] {
+        *self.as_array()
+    }
+
+    fn from_array<const N: usize>(array: [u8; N]) -> Self {
+        let () = SameSize::<N, U>::CHECK;
+        Self::clone_from_slice(&array)
+    }
+
+    fn as_array<const N: usize>(&self) -> &[u8; N] {
+        let () = SameSize::<N, U>::CHECK;
+        self.as_slice()
+            .try_into()
+            .expect("size checked at compile time")
+    }
+
+    fn as_mut_array<const N: usize>(&mut self) -> &mut [u8; N] {
+        let () = SameSize::<N, U>::CHECK;
+        self.as_mut_slice()
+            .try_into()
+            .expect("size checked at compile time")
+    }
+}
+
+/// Secret [`FieldBytes`] tagged with the curve `C` they belong to.
+///
+/// [`FieldBytes`] of curves with the same field size have the same type, so
+/// e.g. the bytes of a secret key on one curve are silently accepted by
+/// another curve. Tagged bytes are only accepted by the same curve, e.g. by
+/// [`SecretKey::from_tagged_bytes`], making such confusion a compile error:
+///
+#[cfg_attr(feature = "dev", doc = "```compile_fail")]
+#[cfg_attr(not(feature = "dev"), doc = "```ignore")]
+/// use elliptic_curve::{dev::{cofactor::MockCofactorCurve, SecretKey}, SecretKey as Key};
+///
+/// let secret_key = SecretKey::random(&mut rand_core::OsRng);
+/// Key::<MockCofactorCurve>::from_tagged_bytes(&secret_key.to_tagged_bytes());
+/// ```
+///
+/// The bytes are zeroized on drop.
+///
+/// [`SecretKey::from_tagged_bytes`]: crate::SecretKey::from_tagged_bytes
+#[derive(Clone)]
+pub struct TaggedFieldBytes<C: Curve> {
+    bytes: FieldBytes<C>,
+    curve: PhantomData<C>,
+}
+
+impl<C: Curve> TaggedFieldBytes<C> {
+    /// Tag `bytes` as belonging to the curve `C`.
+    pub fn new(bytes: FieldBytes<C>) -> Self {
+        Self {
+            bytes,
+            curve: PhantomData,
+        }
+    }
+
+    /// Borrow the bytes, without their curve tag.
+    pub fn as_untagged(&self) -> &FieldBytes<C> {
+        &self.bytes
+    }
+
+    /// Remove the curve tag, i.e. get the bytes to pass to untagged APIs.
+    ///
+    /// ⚠️ The caller is responsible for only using the result with `C`, and
+    /// for zeroizing it.
+    pub fn into_untagged(mut self) -> FieldBytes<C> {
+        let bytes = self.bytes.clone();
+        self.bytes.zeroize();
+        bytes
+    }
+}
+
+impl<C: Curve> ConstantTimeEq for TaggedFieldBytes<C> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.bytes.ct_eq(&other.bytes)
+    }
+}
+
+impl<C: Curve> Eq for TaggedFieldBytes<C> {}
+
+impl<C: Curve> PartialEq for TaggedFieldBytes<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl<C: Curve> fmt::Debug for TaggedFieldBytes<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(any::type_name::<Self>())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<C: Curve> Drop for TaggedFieldBytes<C> {
+    fn drop(&mut self) {
+        self.bytes.zeroize();
+    }
+}
+
+impl<C: Curve> ZeroizeOnDrop for TaggedFieldBytes<C> {}
+
+/// Compile-time check that `N` equals `U`.
+struct SameSize<const N: usize, U>(PhantomData<U>);
+
+impl<const N: usize, U: Unsigned> SameSize<N, U> {
+    const CHECK: () = assert!(N == U::USIZE, "array size mismatch");
+}
+
+/// Serialized field element of `N` bytes which can be cast to and from byte
+/// buffers without copying, using the [`zerocopy`] traits.
+///
+/// [`FieldBytes`] is a [`GenericArray`], which doesn't impl these traits, so
+/// this wraps a plain array of the same length instead. It has no alignment
+/// requirement, so e.g. a buffer of concatenated scalars can be viewed as a
+/// `[FieldBytesRepr<N>]` with [`FromBytes::slice_from`].
+#[cfg(feature = "zerocopy")]
+#[derive(AsBytes, FromBytes, FromZeroes, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct FieldBytesRepr<const N: usize>(pub [u8; N]);
+
+#[cfg(feature = "zerocopy")]
+impl<const N: usize> FieldBytesRepr<N> {
+    /// Copy the given [`FieldBytes`], returning an error if they aren't
+    /// `N` bytes long.
+    pub fn from_field_bytes<C: Curve>(field_bytes: &FieldBytes<C>) -> Result<Self> {
+        field_bytes
+            .as_slice()
+            .try_into()
+            .map(Self)
+            .map_err(|_| Error::invalid_length(N, field_bytes.len()))
+    }
+
+    /// Copy these bytes into [`FieldBytes`], returning an error if they
+    /// aren't [`FieldBytesSize`] bytes long.
+    pub fn to_field_bytes<C: Curve>(&self) -> Result<FieldBytes<C>> {
+        if N == FieldBytesSize::<C>::USIZE {
+            Ok(GenericArray::clone_from_slice(&self.0))
+        } else {
+            Err(Error::invalid_length(FieldBytesSize::<C>::USIZE, N))
+        }
+    }
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::{FieldBytesArray, FieldBytesEncoding};
+    use crate::{
+        bigint::{ArrayEncoding, NonZero, U256},
+        dev::{FieldBytes, FieldElement, MockCurve},
+        Curve,
+    };
+    use ff::PrimeField;
+    use hex_literal::hex;
+
+    const BYTES: [u8; 32] =
+        hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721");
+
+    #[test]
+    fn array_round_trip() {
+        let mut bytes = FieldBytes::from_array(BYTES);
+        assert_eq!(bytes.as_slice(), BYTES);
+        assert_eq!(bytes.to_array(), BYTES);
+        assert_eq!(bytes.as_array(), &BYTES);
+
+        bytes.as_mut_array::<32>()[0] = 0;
+        assert_eq!(bytes[0], 0);
+        assert_eq!(bytes[1..], BYTES[1..]);
+    }
+
+    #[test]
+    fn decode_field_bytes_checked() {
+        let field_modulus = U256::from_be_hex(FieldElement::MODULUS.trim_start_matches("0x"));
+
+        for modulus in [MockCurve::ORDER, field_modulus] {
+            let nz = NonZero::new(modulus).unwrap();
+            let decode = |uint: U256| {
+                let bytes = uint.to_be_byte_array();
+                let decoded = <U256 as FieldBytesEncoding<MockCurve>>::decode_field_bytes_checked(
+                    &bytes, &nz,
+                );
+                assert_eq!(
+                    bool::from(decoded.is_some()),
+                    bool::from(FieldBytesEncoding::<MockCurve>::is_canonical(&uint, &nz))
+                );
+                Option::<U256>::from(decoded)
+            };
+
+            assert_eq!(decode(modulus), None);
+            assert_eq!(
+                decode(modulus.wrapping_sub(&U256::ONE)),
+                Some(modulus.wrapping_sub(&U256::ONE))
+            );
+            assert_eq!(decode(modulus.wrapping_add(&U256::ONE)), None);
+            assert_eq!(decode(U256::MAX), None);
+            assert_eq!(decode(U256::ZERO), Some(U256::ZERO));
+        }
+    }
+}
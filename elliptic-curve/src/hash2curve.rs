@@ -7,9 +7,11 @@ mod hash2field;
 mod isogeny;
 mod map2curve;
 mod osswu;
+mod suite;
 
 pub use group_digest::*;
 pub use hash2field::*;
 pub use isogeny::*;
 pub use map2curve::*;
 pub use osswu::*;
+pub use suite::*;
@@ -1,7 +1,13 @@
 //! Traits for handling hash to curve.
 
-use super::{hash_to_field, ExpandMsg, FromOkm, MapToCurve};
-use crate::{CurveArithmetic, ProjectivePoint, Result};
+use super::{
+    fill_from_expander, hash_to_field, hash_to_field_prepared, map_to_curve, map_to_curve_sum,
+    Encoding, ExpandMsg, ExpandMsgId, ExpandMsgPrepared, ExpandMsgStreaming, FromOkm, MapToCurve,
+    SuiteId,
+};
+use crate::{CurveArithmetic, Error, ErrorKind, NonZeroScalar, ProjectivePoint, Result};
+use core::marker::PhantomData;
+use generic_array::typenum::Unsigned;
 use group::cofactor::CofactorGroup;
 
 /// Adds hashing arbitrary byte sequences to a valid group element
@@ -12,6 +18,26 @@ where
     /// The field element representation for a group value with multiple elements
     type FieldElement: FromOkm + MapToCurve<Output = ProjectivePoint<Self>> + Default + Copy;
 
+    /// RFC 9380 identifier of the curve, e.g. `P256`.
+    const CURVE_ID: &'static str;
+
+    /// Derive the RFC 9380 suite identifier for hashing to this curve with
+    /// the expander `X` and the given encoding variant.
+    ///
+    /// # Errors
+    /// If the name of the hash function used by `X` is not known.
+    fn suite_id<X: ExpandMsgId>(encoding: Encoding) -> Result<SuiteId> {
+        let hash = X::hash_id().ok_or(Error::new(ErrorKind::Other))?;
+        let map = <<Self as GroupDigest>::FieldElement as MapToCurve>::MAP_ID;
+        Ok(SuiteId::new(
+            Self::CURVE_ID,
+            X::VARIANT,
+            hash,
+            map,
+            encoding,
+        ))
+    }
+
     /// Computes the hash to curve routine.
     ///
     /// From <https://www.ietf.org/archive/id/draft-irtf-cfrg-hash-to-curve-13.html>:
@@ -42,7 +68,7 @@ where
     /// - [`ExpandMsgXmd`]
     /// - [`ExpandMsgXof`]
     ///
-    /// `len_in_bytes = <Self::FieldElement as FromOkm>::Length * 2`
+    /// `len_in_bytes = <<Self as GroupDigest>::FieldElement as FromOkm>::Length * 2`
     ///
     /// [`ExpandMsgXmd`]: crate::hash2curve::ExpandMsgXmd
     /// [`ExpandMsgXof`]: crate::hash2curve::ExpandMsgXof
@@ -50,21 +76,11 @@ where
         msgs: &[&[u8]],
         dsts: &'a [&'a [u8]],
     ) -> Result<ProjectivePoint<Self>> {
-        let mut u = [Self::FieldElement::default(), Self::FieldElement::default()];
+        let mut u = [<Self as GroupDigest>::FieldElement::default(); 2];
         hash_to_field::<X, _>(msgs, dsts, &mut u)?;
-        let q0 = u[0].map_to_curve();
-        let q1 = u[1].map_to_curve();
-        // Ideally we could add and then clear cofactor once
-        // thus saving a call but the field elements may not
-        // add properly due to the underlying implementation
-        // which could result in an incorrect subgroup.
-        // This is caused curve coefficients being different than
-        // what is usually implemented.
-        // FieldElement expects the `a` and `b` to be the original values
-        // isogenies are different with curves like k256 and bls12-381.
-        // This problem doesn't manifest for curves with no isogeny like p256.
-        // For k256 and p256 clear_cofactor doesn't do anything anyway so it will be a no-op.
-        Ok(q0.clear_cofactor().into() + q1.clear_cofactor())
+        // Both points are mapped through the isogeny before they are added,
+        // so the addition always happens on the target curve.
+        Ok(map_to_curve_sum(&u).clear_cofactor().into())
     }
 
     /// Computes the encode to curve routine.
@@ -77,12 +93,16 @@ where
     /// > encode_to_curve is only a fraction of the points in G, and some
     /// > points in this set are more likely to be output than others.
     ///
+    /// This is the `encode_to_curve` (`_NU_`) variant: a single field element
+    /// is mapped to the curve, unlike [`GroupDigest::hash_from_bytes`] which
+    /// implements `hash_to_curve` (`_RO_`).
+    ///
     /// # Errors
     /// See implementors of [`ExpandMsg`] for errors:
     /// - [`ExpandMsgXmd`]
     /// - [`ExpandMsgXof`]
     ///
-    /// `len_in_bytes = <Self::FieldElement as FromOkm>::Length`
+    /// `len_in_bytes = <<Self as GroupDigest>::FieldElement as FromOkm>::Length`
     ///
     /// [`ExpandMsgXmd`]: crate::hash2curve::ExpandMsgXmd
     /// [`ExpandMsgXof`]: crate::hash2curve::ExpandMsgXof
@@ -90,10 +110,35 @@ where
         msgs: &[&[u8]],
         dsts: &'a [&'a [u8]],
     ) -> Result<ProjectivePoint<Self>> {
-        let mut u = [Self::FieldElement::default()];
+        let mut u = [<Self as GroupDigest>::FieldElement::default()];
         hash_to_field::<X, _>(msgs, dsts, &mut u)?;
-        let q0 = u[0].map_to_curve();
-        Ok(q0.clear_cofactor().into())
+        Ok(map_to_curve(&u[0]).clear_cofactor().into())
+    }
+
+    /// Computes the hash to field routine according to
+    /// <https://www.ietf.org/archive/id/draft-irtf-cfrg-hash-to-curve-13.html#section-5>
+    /// and returns `N` base field elements derived from a single
+    /// `expand_message` invocation.
+    ///
+    /// With `N = 2` this produces the same field elements as used internally
+    /// by [`GroupDigest::hash_from_bytes`].
+    ///
+    /// # Errors
+    /// See implementors of [`ExpandMsg`] for errors:
+    /// - [`ExpandMsgXmd`]
+    /// - [`ExpandMsgXof`]
+    ///
+    /// `len_in_bytes = <<Self as GroupDigest>::FieldElement as FromOkm>::Length * N`
+    ///
+    /// [`ExpandMsgXmd`]: crate::hash2curve::ExpandMsgXmd
+    /// [`ExpandMsgXof`]: crate::hash2curve::ExpandMsgXof
+    fn hash_to_field<'a, X: ExpandMsg<'a>, const N: usize>(
+        msgs: &[&[u8]],
+        dsts: &'a [&'a [u8]],
+    ) -> Result<[<Self as GroupDigest>::FieldElement; N]> {
+        let mut u = [<Self as GroupDigest>::FieldElement::default(); N];
+        hash_to_field::<X, _>(msgs, dsts, &mut u)?;
+        Ok(u)
     }
 
     /// Computes the hash to field routine according to
@@ -120,4 +165,185 @@ where
         hash_to_field::<X, _>(msgs, dsts, &mut u)?;
         Ok(u[0])
     }
+
+    /// Computes [`GroupDigest::hash_to_scalar`] and returns the result as a
+    /// [`NonZeroScalar`].
+    ///
+    /// A zero result is rejected rather than re-hashed, so any non-zero output
+    /// is exactly the output of [`GroupDigest::hash_to_scalar`]. Protocols
+    /// which need a total function must define their own retry rule on top of
+    /// this, e.g. the counter of [RFC 9497 `DeriveKeyPair`].
+    ///
+    /// # Errors
+    /// - [`ErrorKind::ScalarOutOfRange`] if the scalar is zero
+    /// - See [`GroupDigest::hash_to_scalar`] for other errors
+    ///
+    /// [RFC 9497 `DeriveKeyPair`]: https://www.rfc-editor.org/rfc/rfc9497.html#section-3.2.1
+    fn hash_to_nonzero_scalar<'a, X: ExpandMsg<'a>>(
+        msgs: &[&[u8]],
+        dsts: &'a [&'a [u8]],
+    ) -> Result<NonZeroScalar<Self>>
+    where
+        Self::Scalar: FromOkm,
+    {
+        let scalar = Self::hash_to_scalar::<X>(msgs, dsts)?;
+        Option::from(NonZeroScalar::new(scalar)).ok_or(ErrorKind::ScalarOutOfRange.into())
+    }
+
+    /// Computes the hash to field routine according to
+    /// <https://www.ietf.org/archive/id/draft-irtf-cfrg-hash-to-curve-13.html#section-5>
+    /// and returns `N` scalars derived from a single `expand_message`
+    /// invocation.
+    ///
+    /// # Errors
+    /// See implementors of [`ExpandMsg`] for errors:
+    /// - [`ExpandMsgXmd`]
+    /// - [`ExpandMsgXof`]
+    ///
+    /// `len_in_bytes = <Self::Scalar as FromOkm>::Length * N`
+    ///
+    /// [`ExpandMsgXmd`]: crate::hash2curve::ExpandMsgXmd
+    /// [`ExpandMsgXof`]: crate::hash2curve::ExpandMsgXof
+    fn hash_to_scalars<'a, X: ExpandMsg<'a>, const N: usize>(
+        msgs: &[&[u8]],
+        dsts: &'a [&'a [u8]],
+    ) -> Result<[Self::Scalar; N]>
+    where
+        Self::Scalar: FromOkm,
+    {
+        let mut u = [Self::Scalar::default(); N];
+        hash_to_field::<X, _>(msgs, dsts, &mut u)?;
+        Ok(u)
+    }
+
+    /// Computes the hash to curve routine using a domain separation tag
+    /// prepared with [`ExpandMsgPrepared::prepare_dst`].
+    ///
+    /// The output is identical to [`GroupDigest::hash_from_bytes`] called with
+    /// the DST `dst` was prepared from.
+    ///
+    /// # Errors
+    /// See [`GroupDigest::hash_from_bytes`].
+    fn hash_from_bytes_prepared<'a, X: ExpandMsgPrepared<'a>>(
+        msgs: &[&[u8]],
+        dst: &X::PreparedDst,
+    ) -> Result<ProjectivePoint<Self>> {
+        let mut u = [<Self as GroupDigest>::FieldElement::default(); 2];
+        hash_to_field_prepared::<X, _>(msgs, dst, &mut u)?;
+        Ok(map_to_curve_sum(&u).clear_cofactor().into())
+    }
+
+    /// Computes the encode to curve routine using a domain separation tag
+    /// prepared with [`ExpandMsgPrepared::prepare_dst`].
+    ///
+    /// The output is identical to [`GroupDigest::encode_from_bytes`] called
+    /// with the DST `dst` was prepared from.
+    ///
+    /// # Errors
+    /// See [`GroupDigest::encode_from_bytes`].
+    fn encode_from_bytes_prepared<'a, X: ExpandMsgPrepared<'a>>(
+        msgs: &[&[u8]],
+        dst: &X::PreparedDst,
+    ) -> Result<ProjectivePoint<Self>> {
+        let mut u = [<Self as GroupDigest>::FieldElement::default()];
+        hash_to_field_prepared::<X, _>(msgs, dst, &mut u)?;
+        Ok(map_to_curve(&u[0]).clear_cofactor().into())
+    }
+
+    /// Computes the hash to field routine using a domain separation tag
+    /// prepared with [`ExpandMsgPrepared::prepare_dst`] and returns a scalar.
+    ///
+    /// The output is identical to [`GroupDigest::hash_to_scalar`] called with
+    /// the DST `dst` was prepared from.
+    ///
+    /// # Errors
+    /// See [`GroupDigest::hash_to_scalar`].
+    fn hash_to_scalar_prepared<'a, X: ExpandMsgPrepared<'a>>(
+        msgs: &[&[u8]],
+        dst: &X::PreparedDst,
+    ) -> Result<Self::Scalar>
+    where
+        Self::Scalar: FromOkm,
+    {
+        let mut u = [Self::Scalar::default()];
+        hash_to_field_prepared::<X, _>(msgs, dst, &mut u)?;
+        Ok(u[0])
+    }
+
+    /// Start hashing a message which is provided incrementally with
+    /// [`H2cHasher::update`].
+    ///
+    /// # Errors
+    /// See [`ExpandMsgStreaming::start`].
+    fn hasher<'a, X: ExpandMsgStreaming<'a>>(
+        dsts: &'a [&'a [u8]],
+    ) -> Result<H2cHasher<'a, Self, X>> {
+        Ok(H2cHasher {
+            state: X::start(dsts)?,
+            curve: PhantomData,
+        })
+    }
+}
+
+/// Incremental hash to curve and hash to scalar, created with
+/// [`GroupDigest::hasher`].
+///
+/// The outputs are identical to the corresponding [`GroupDigest`] methods
+/// called with the concatenation of all parts of the message.
+pub struct H2cHasher<'a, C, X: ExpandMsgStreaming<'a>> {
+    state: X::State,
+    curve: PhantomData<C>,
+}
+
+impl<'a, C, X> H2cHasher<'a, C, X>
+where
+    C: GroupDigest,
+    ProjectivePoint<C>: CofactorGroup,
+    X: ExpandMsgStreaming<'a>,
+{
+    /// Absorb the next part of the message.
+    pub fn update(&mut self, msg: &[u8]) -> &mut Self {
+        X::update(&mut self.state, msg);
+        self
+    }
+
+    /// Finish hashing to the curve, see [`GroupDigest::hash_from_bytes`].
+    ///
+    /// # Errors
+    /// See [`GroupDigest::hash_from_bytes`].
+    pub fn finalize_to_curve(self) -> Result<ProjectivePoint<C>> {
+        let u = self.finalize_to_field::<<C as GroupDigest>::FieldElement, 2>()?;
+        Ok(map_to_curve_sum(&u).clear_cofactor().into())
+    }
+
+    /// Finish encoding to the curve, see [`GroupDigest::encode_from_bytes`].
+    ///
+    /// # Errors
+    /// See [`GroupDigest::encode_from_bytes`].
+    pub fn finalize_encode_to_curve(self) -> Result<ProjectivePoint<C>> {
+        let [u] = self.finalize_to_field::<<C as GroupDigest>::FieldElement, 1>()?;
+        Ok(map_to_curve(&u).clear_cofactor().into())
+    }
+
+    /// Finish hashing to a scalar, see [`GroupDigest::hash_to_scalar`].
+    ///
+    /// # Errors
+    /// See [`GroupDigest::hash_to_scalar`].
+    pub fn finalize_to_scalar(self) -> Result<C::Scalar>
+    where
+        C::Scalar: FromOkm,
+    {
+        let [scalar] = self.finalize_to_field::<C::Scalar, 1>()?;
+        Ok(scalar)
+    }
+
+    fn finalize_to_field<T: FromOkm + Default + Copy, const N: usize>(self) -> Result<[T; N]> {
+        let len_in_bytes = T::Length::to_usize()
+            .checked_mul(N)
+            .ok_or(Error::new(ErrorKind::Other))?;
+        let expander = X::finalize(self.state, len_in_bytes)?;
+        let mut u = [T::default(); N];
+        fill_from_expander(expander, &mut u);
+        Ok(u)
+    }
 }
@@ -6,7 +6,7 @@ mod expand_msg;
 
 pub use expand_msg::{xmd::*, xof::*, *};
 
-use crate::{Error, Result};
+use crate::{Error, ErrorKind, Result};
 use generic_array::{typenum::Unsigned, ArrayLength, GenericArray};
 
 /// The trait for helping to convert to a field element.
@@ -27,22 +27,53 @@ pub trait FromOkm {
 /// - [`ExpandMsgXmd`]
 /// - [`ExpandMsgXof`]
 ///
-/// `len_in_bytes = T::Length * out.len()`
+/// `len_in_bytes = T::Length * out.len()`, which must not exceed the limit
+/// of the chosen expander.
 ///
-/// [`ExpandMsgXmd`]: crate::hash2field::ExpandMsgXmd
-/// [`ExpandMsgXof`]: crate::hash2field::ExpandMsgXof
-#[doc(hidden)]
+/// [`ExpandMsgXmd`]: crate::hash2curve::ExpandMsgXmd
+/// [`ExpandMsgXof`]: crate::hash2curve::ExpandMsgXof
 pub fn hash_to_field<'a, E, T>(data: &[&[u8]], domain: &'a [&'a [u8]], out: &mut [T]) -> Result<()>
 where
     E: ExpandMsg<'a>,
     T: FromOkm + Default,
 {
-    let len_in_bytes = T::Length::to_usize().checked_mul(out.len()).ok_or(Error)?;
+    let len_in_bytes = T::Length::to_usize()
+        .checked_mul(out.len())
+        .ok_or(Error::new(ErrorKind::Other))?;
+    let expander = E::expand_message(data, domain, len_in_bytes)?;
+    fill_from_expander(expander, out);
+    Ok(())
+}
+
+/// Convert an arbitrary byte sequence into a field element using a domain
+/// separation tag prepared with [`ExpandMsgPrepared::prepare_dst`].
+///
+/// The output is identical to [`hash_to_field`] called with the DST `dst` was
+/// prepared from.
+///
+/// # Errors
+/// See [`hash_to_field`].
+pub fn hash_to_field_prepared<'a, E, T>(
+    data: &[&[u8]],
+    dst: &E::PreparedDst,
+    out: &mut [T],
+) -> Result<()>
+where
+    E: ExpandMsgPrepared<'a>,
+    T: FromOkm + Default,
+{
+    let len_in_bytes = T::Length::to_usize()
+        .checked_mul(out.len())
+        .ok_or(Error::new(ErrorKind::Other))?;
+    let expander = E::expand_message_prepared(data, dst, len_in_bytes)?;
+    fill_from_expander(expander, out);
+    Ok(())
+}
+
+pub(crate) fn fill_from_expander<T: FromOkm>(mut expander: impl Expander, out: &mut [T]) {
     let mut tmp = GenericArray::<u8, <T as FromOkm>::Length>::default();
-    let mut expander = E::expand_message(data, domain, len_in_bytes)?;
     for o in out.iter_mut() {
         expander.fill_bytes(&mut tmp);
         *o = T::from_okm(&tmp);
     }
-    Ok(())
 }
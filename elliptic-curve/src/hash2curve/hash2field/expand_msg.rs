@@ -3,7 +3,7 @@
 pub(super) mod xmd;
 pub(super) mod xof;
 
-use crate::{Error, Result};
+use crate::{Error, ErrorKind, Result};
 use digest::{Digest, ExtendableOutput, Update, XofReader};
 use generic_array::typenum::{IsLess, U256};
 use generic_array::{ArrayLength, GenericArray};
@@ -32,6 +32,55 @@ pub trait ExpandMsg<'a> {
     ) -> Result<Self::Expander>;
 }
 
+/// [`ExpandMsg`] implementations which can preprocess a domain separation tag
+/// once and reuse it across many `expand_message` invocations.
+pub trait ExpandMsgPrepared<'a>: ExpandMsg<'a> {
+    /// Preprocessed domain separation tag.
+    type PreparedDst;
+
+    /// Preprocess the given domain separation tag, applying the oversize DST
+    /// reduction if needed.
+    ///
+    /// # Errors
+    /// - `dst.is_empty()`
+    fn prepare_dst(dsts: &'a [&'a [u8]]) -> Result<Self::PreparedDst>;
+
+    /// Expands `msg` to the required number of bytes using a prepared domain
+    /// separation tag.
+    ///
+    /// The output is identical to [`ExpandMsg::expand_message`] called with
+    /// the DST `dst` was prepared from.
+    fn expand_message_prepared(
+        msgs: &[&[u8]],
+        dst: &Self::PreparedDst,
+        len_in_bytes: usize,
+    ) -> Result<Self::Expander>;
+}
+
+/// [`ExpandMsg`] implementations which can absorb the message incrementally,
+/// e.g. when it is too large to hold in memory at once.
+pub trait ExpandMsgStreaming<'a>: ExpandMsg<'a> {
+    /// Hash state holding the domain separation tag and the message absorbed
+    /// so far.
+    type State;
+
+    /// Start absorbing a message to be expanded with the given domain
+    /// separation tag.
+    ///
+    /// # Errors
+    /// - `dst.is_empty()`
+    fn start(dsts: &'a [&'a [u8]]) -> Result<Self::State>;
+
+    /// Absorb the next part of the message.
+    fn update(state: &mut Self::State, msg: &[u8]);
+
+    /// Expand the absorbed message to the required number of bytes.
+    ///
+    /// The output is identical to [`ExpandMsg::expand_message`] called with
+    /// the concatenation of all parts of the message.
+    fn finalize(state: Self::State, len_in_bytes: usize) -> Result<Self::Expander>;
+}
+
 /// Expander that, call `read` until enough bytes have been consumed.
 pub trait Expander {
     /// Fill the array with the expanded bytes
@@ -43,6 +92,7 @@ pub trait Expander {
 /// Implements [section 5.4.3 of `draft-irtf-cfrg-hash-to-curve-13`][dst].
 ///
 /// [dst]: https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-hash-to-curve-13#section-5.4.3
+#[derive(Clone)]
 pub(crate) enum Domain<'a, L>
 where
     L: ArrayLength<u8> + IsLess<U256>,
@@ -61,9 +111,11 @@ where
     where
         X: Default + ExtendableOutput + Update,
     {
-        if dsts.is_empty() {
-            Err(Error)
-        } else if dsts.iter().map(|dst| dst.len()).sum::<usize>() > MAX_DST_LEN {
+        let dst_len = dsts.iter().map(|dst| dst.len()).sum::<usize>();
+
+        if dst_len == 0 {
+            Err(Error::new(ErrorKind::Other))
+        } else if dst_len > MAX_DST_LEN {
             let mut data = GenericArray::<u8, L>::default();
             let mut hash = X::default();
             hash.update(OVERSIZE_DST_SALT);
@@ -84,9 +136,11 @@ where
     where
         X: Digest<OutputSize = L>,
     {
-        if dsts.is_empty() {
-            Err(Error)
-        } else if dsts.iter().map(|dst| dst.len()).sum::<usize>() > MAX_DST_LEN {
+        let dst_len = dsts.iter().map(|dst| dst.len()).sum::<usize>();
+
+        if dst_len == 0 {
+            Err(Error::new(ErrorKind::Other))
+        } else if dst_len > MAX_DST_LEN {
             Ok(Self::Hashed({
                 let mut hash = X::new();
                 hash.update(OVERSIZE_DST_SALT);
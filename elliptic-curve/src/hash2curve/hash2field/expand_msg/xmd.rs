@@ -5,8 +5,8 @@
 
 use core::marker::PhantomData;
 
-use super::{Domain, ExpandMsg, Expander};
-use crate::{Error, Result};
+use super::{Domain, ExpandMsg, ExpandMsgPrepared, ExpandMsgStreaming, Expander};
+use crate::{Error, ErrorKind, Result};
 use digest::{
     core_api::BlockSizeUser,
     generic_array::{
@@ -48,45 +48,86 @@ where
         dsts: &'a [&'a [u8]],
         len_in_bytes: usize,
     ) -> Result<Self::Expander> {
-        if len_in_bytes == 0 {
-            return Err(Error);
-        }
+        let domain = Domain::xmd::<HashT>(dsts)?;
+        let mut z_pad = HashT::default();
+        z_pad.update(&GenericArray::<u8, HashT::BlockSize>::default());
+        ExpanderXmd::new(msgs, domain, z_pad, len_in_bytes)
+    }
+}
 
-        let len_in_bytes_u16 = u16::try_from(len_in_bytes).map_err(|_| Error)?;
+impl<'a, HashT> ExpandMsgPrepared<'a> for ExpandMsgXmd<HashT>
+where
+    HashT: BlockSizeUser + Clone + Default + FixedOutput + HashMarker,
+    HashT::OutputSize: IsLess<U256>,
+    HashT::OutputSize: IsLessOrEqual<HashT::BlockSize>,
+{
+    type PreparedDst = PreparedDstXmd<'a, HashT>;
 
-        let b_in_bytes = HashT::OutputSize::to_usize();
-        let ell = u8::try_from((len_in_bytes + b_in_bytes - 1) / b_in_bytes).map_err(|_| Error)?;
+    fn prepare_dst(dsts: &'a [&'a [u8]]) -> Result<Self::PreparedDst> {
+        let domain = Domain::xmd::<HashT>(dsts)?;
+        let mut z_pad = HashT::default();
+        z_pad.update(&GenericArray::<u8, HashT::BlockSize>::default());
+        Ok(PreparedDstXmd { domain, z_pad })
+    }
 
+    fn expand_message_prepared(
+        msgs: &[&[u8]],
+        dst: &Self::PreparedDst,
+        len_in_bytes: usize,
+    ) -> Result<Self::Expander> {
+        ExpanderXmd::new(msgs, dst.domain.clone(), dst.z_pad.clone(), len_in_bytes)
+    }
+}
+
+impl<'a, HashT> ExpandMsgStreaming<'a> for ExpandMsgXmd<HashT>
+where
+    HashT: BlockSizeUser + Default + FixedOutput + HashMarker,
+    HashT::OutputSize: IsLess<U256>,
+    HashT::OutputSize: IsLessOrEqual<HashT::BlockSize>,
+{
+    type State = StreamingXmd<'a, HashT>;
+
+    fn start(dsts: &'a [&'a [u8]]) -> Result<Self::State> {
         let domain = Domain::xmd::<HashT>(dsts)?;
         let mut b_0 = HashT::default();
         b_0.update(&GenericArray::<u8, HashT::BlockSize>::default());
+        Ok(StreamingXmd { domain, b_0 })
+    }
 
-        for msg in msgs {
-            b_0.update(msg);
-        }
+    fn update(state: &mut Self::State, msg: &[u8]) {
+        state.b_0.update(msg);
+    }
 
-        b_0.update(&len_in_bytes_u16.to_be_bytes());
-        b_0.update(&[0]);
-        domain.update_hash(&mut b_0);
-        b_0.update(&[domain.len()]);
-        let b_0 = b_0.finalize_fixed();
+    fn finalize(state: Self::State, len_in_bytes: usize) -> Result<Self::Expander> {
+        ExpanderXmd::new(&[], state.domain, state.b_0, len_in_bytes)
+    }
+}
 
-        let mut b_vals = HashT::default();
-        b_vals.update(&b_0[..]);
-        b_vals.update(&[1u8]);
-        domain.update_hash(&mut b_vals);
-        b_vals.update(&[domain.len()]);
-        let b_vals = b_vals.finalize_fixed();
+/// Streaming state of [`ExpandMsgXmd`], i.e. the hash computing `b_0`
+/// after absorbing `Z_pad` and the message so far.
+pub struct StreamingXmd<'a, HashT>
+where
+    HashT: BlockSizeUser + Default + FixedOutput + HashMarker,
+    HashT::OutputSize: IsLess<U256>,
+    HashT::OutputSize: IsLessOrEqual<HashT::BlockSize>,
+{
+    domain: Domain<'a, HashT::OutputSize>,
+    b_0: HashT,
+}
 
-        Ok(ExpanderXmd {
-            b_0,
-            b_vals,
-            domain,
-            index: 1,
-            offset: 0,
-            ell,
-        })
-    }
+/// Prepared domain separation tag for [`ExpandMsgXmd`].
+///
+/// Also caches the hash state after absorbing the all-zero block which
+/// prefixes every message.
+#[derive(Clone)]
+pub struct PreparedDstXmd<'a, HashT>
+where
+    HashT: BlockSizeUser + Clone + Default + FixedOutput + HashMarker,
+    HashT::OutputSize: IsLess<U256>,
+    HashT::OutputSize: IsLessOrEqual<HashT::BlockSize>,
+{
+    domain: Domain<'a, HashT::OutputSize>,
+    z_pad: HashT,
 }
 
 /// [`Expander`] type for [`ExpandMsgXmd`].
@@ -110,6 +151,51 @@ where
     HashT::OutputSize: IsLess<U256>,
     HashT::OutputSize: IsLessOrEqual<HashT::BlockSize>,
 {
+    /// Compute `b_0` and `b_1` given a hash which has already absorbed `Z_pad`.
+    fn new(
+        msgs: &[&[u8]],
+        domain: Domain<'a, HashT::OutputSize>,
+        mut b_0: HashT,
+        len_in_bytes: usize,
+    ) -> Result<Self> {
+        if len_in_bytes == 0 {
+            return Err(Error::new(ErrorKind::Other));
+        }
+
+        let len_in_bytes_u16 =
+            u16::try_from(len_in_bytes).map_err(|_| Error::new(ErrorKind::Other))?;
+
+        let b_in_bytes = HashT::OutputSize::to_usize();
+        let ell = u8::try_from((len_in_bytes + b_in_bytes - 1) / b_in_bytes)
+            .map_err(|_| Error::new(ErrorKind::Other))?;
+
+        for msg in msgs {
+            b_0.update(msg);
+        }
+
+        b_0.update(&len_in_bytes_u16.to_be_bytes());
+        b_0.update(&[0]);
+        domain.update_hash(&mut b_0);
+        b_0.update(&[domain.len()]);
+        let b_0 = b_0.finalize_fixed();
+
+        let mut b_vals = HashT::default();
+        b_vals.update(&b_0[..]);
+        b_vals.update(&[1u8]);
+        domain.update_hash(&mut b_vals);
+        b_vals.update(&[domain.len()]);
+        let b_vals = b_vals.finalize_fixed();
+
+        Ok(Self {
+            b_0,
+            b_vals,
+            domain,
+            index: 1,
+            offset: 0,
+            ell,
+        })
+    }
+
     fn next(&mut self) -> bool {
         if self.index < self.ell {
             self.index += 1;
@@ -208,7 +294,7 @@ mod test {
             domain: &Domain<'_, HashT::OutputSize>,
         ) -> Result<()>
         where
-            HashT: BlockSizeUser + Default + FixedOutput + HashMarker,
+            HashT: BlockSizeUser + Clone + Default + FixedOutput + HashMarker,
             HashT::OutputSize: IsLess<U256> + IsLessOrEqual<HashT::BlockSize>,
         {
             assert_message::<HashT>(self.msg, domain, L::to_u16(), self.msg_prime);
@@ -220,6 +306,18 @@ mod test {
             let mut uniform_bytes = GenericArray::<u8, L>::default();
             expander.fill_bytes(&mut uniform_bytes);
 
+            assert_eq!(uniform_bytes.as_slice(), self.uniform_bytes);
+
+            let prepared = ExpandMsgXmd::<HashT>::prepare_dst(&dst)?;
+            let mut expander = ExpandMsgXmd::<HashT>::expand_message_prepared(
+                &[self.msg],
+                &prepared,
+                L::to_usize(),
+            )?;
+
+            let mut uniform_bytes = GenericArray::<u8, L>::default();
+            expander.fill_bytes(&mut uniform_bytes);
+
             assert_eq!(uniform_bytes.as_slice(), self.uniform_bytes);
             Ok(())
         }
@@ -450,4 +548,26 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn expand_message_xmd_empty_dst() {
+        assert!(ExpandMsgXmd::<Sha256>::expand_message(&[b"abc"], &[], 32).is_err());
+        assert!(ExpandMsgXmd::<Sha256>::expand_message(&[b"abc"], &[b""], 32).is_err());
+        assert!(ExpandMsgXmd::<Sha256>::expand_message(&[b"abc"], &[b"", b""], 32).is_err());
+    }
+
+    #[test]
+    fn expand_message_xmd_split_long_dst() -> Result<()> {
+        // The oversize DST reduction applies to the concatenation of all parts
+        const DST_PRIME: &[u8] =
+            &hex!("412717974da474d0f8c420f320ff81e8432adb7c927d9bd082b4fb4d16c0a23620");
+
+        let dst_prime = Domain::xmd::<Sha256>(&[
+            b"QUUX-V01-CS02-with-expander-SHA256-128-long-DST-",
+            &[b'1'; 208],
+        ])?;
+        dst_prime.assert_dst(DST_PRIME);
+
+        Ok(())
+    }
 }
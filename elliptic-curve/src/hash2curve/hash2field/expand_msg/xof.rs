@@ -1,27 +1,38 @@
 //! `expand_message_xof` for the `ExpandMsg` trait
 
-use super::{Domain, ExpandMsg, Expander};
-use crate::{Error, Result};
+use super::{Domain, ExpandMsg, ExpandMsgPrepared, ExpandMsgStreaming, Expander};
+use crate::{Error, ErrorKind, Result};
+use core::marker::PhantomData;
 use digest::{ExtendableOutput, Update, XofReader};
-use generic_array::typenum::U32;
+use generic_array::{
+    typenum::{IsLess, PartialDiv, PartialQuot, U128, U256, U4},
+    ArrayLength,
+};
 
 /// Placeholder type for implementing `expand_message_xof` based on an extendable output function
 ///
+/// `K` is the target security level in bits, which defaults to 128. Oversized
+/// domain separation tags are reduced to `ceil(2 * K / 8)` bytes, so `K` must
+/// be a multiple of 4.
+///
 /// # Errors
 /// - `dst.is_empty()`
 /// - `len_in_bytes == 0`
 /// - `len_in_bytes > u16::MAX`
-pub struct ExpandMsgXof<HashT>
+pub struct ExpandMsgXof<HashT, K = U128>
 where
     HashT: Default + ExtendableOutput + Update,
 {
     reader: <HashT as ExtendableOutput>::Reader,
+    security_level: PhantomData<K>,
 }
 
 /// ExpandMsgXof implements `expand_message_xof` for the [`ExpandMsg`] trait
-impl<'a, HashT> ExpandMsg<'a> for ExpandMsgXof<HashT>
+impl<'a, HashT, K> ExpandMsg<'a> for ExpandMsgXof<HashT, K>
 where
     HashT: Default + ExtendableOutput + Update,
+    K: PartialDiv<U4>,
+    PartialQuot<K, U4>: ArrayLength<u8> + IsLess<U256>,
 {
     type Expander = Self;
 
@@ -30,14 +41,96 @@ where
         dsts: &'a [&'a [u8]],
         len_in_bytes: usize,
     ) -> Result<Self::Expander> {
+        let domain = Domain::<PartialQuot<K, U4>>::xof::<HashT>(dsts)?;
+        Self::expand(msgs, &domain, len_in_bytes)
+    }
+}
+
+impl<'a, HashT, K> ExpandMsgPrepared<'a> for ExpandMsgXof<HashT, K>
+where
+    HashT: Default + ExtendableOutput + Update,
+    K: PartialDiv<U4>,
+    PartialQuot<K, U4>: ArrayLength<u8> + IsLess<U256>,
+{
+    type PreparedDst = PreparedDstXof<'a, K>;
+
+    fn prepare_dst(dsts: &'a [&'a [u8]]) -> Result<Self::PreparedDst> {
+        Domain::xof::<HashT>(dsts).map(|domain| PreparedDstXof { domain })
+    }
+
+    fn expand_message_prepared(
+        msgs: &[&[u8]],
+        dst: &Self::PreparedDst,
+        len_in_bytes: usize,
+    ) -> Result<Self::Expander> {
+        Self::expand(msgs, &dst.domain, len_in_bytes)
+    }
+}
+
+impl<'a, HashT, K> ExpandMsgStreaming<'a> for ExpandMsgXof<HashT, K>
+where
+    HashT: Default + ExtendableOutput + Update,
+    K: PartialDiv<U4>,
+    PartialQuot<K, U4>: ArrayLength<u8> + IsLess<U256>,
+{
+    type State = StreamingXof<'a, HashT, K>;
+
+    fn start(dsts: &'a [&'a [u8]]) -> Result<Self::State> {
+        let domain = Domain::xof::<HashT>(dsts)?;
+        Ok(StreamingXof {
+            domain,
+            hash: HashT::default(),
+        })
+    }
+
+    fn update(state: &mut Self::State, msg: &[u8]) {
+        state.hash.update(msg);
+    }
+
+    fn finalize(state: Self::State, len_in_bytes: usize) -> Result<Self::Expander> {
+        Self::expand_with(state.hash, &[], &state.domain, len_in_bytes)
+    }
+}
+
+/// Streaming state of [`ExpandMsgXof`], i.e. the XOF after absorbing the
+/// message so far.
+pub struct StreamingXof<'a, HashT, K = U128>
+where
+    HashT: Default + ExtendableOutput + Update,
+    K: PartialDiv<U4>,
+    PartialQuot<K, U4>: ArrayLength<u8> + IsLess<U256>,
+{
+    domain: Domain<'a, PartialQuot<K, U4>>,
+    hash: HashT,
+}
+
+impl<HashT, K> ExpandMsgXof<HashT, K>
+where
+    HashT: Default + ExtendableOutput + Update,
+    K: PartialDiv<U4>,
+    PartialQuot<K, U4>: ArrayLength<u8> + IsLess<U256>,
+{
+    fn expand(
+        msgs: &[&[u8]],
+        domain: &Domain<'_, PartialQuot<K, U4>>,
+        len_in_bytes: usize,
+    ) -> Result<Self> {
+        Self::expand_with(HashT::default(), msgs, domain, len_in_bytes)
+    }
+
+    /// Expand `msgs` given a hash which may have already absorbed a prefix
+    /// of the message.
+    fn expand_with(
+        mut reader: HashT,
+        msgs: &[&[u8]],
+        domain: &Domain<'_, PartialQuot<K, U4>>,
+        len_in_bytes: usize,
+    ) -> Result<Self> {
         if len_in_bytes == 0 {
-            return Err(Error);
+            return Err(Error::new(ErrorKind::Other));
         }
 
-        let len_in_bytes = u16::try_from(len_in_bytes).map_err(|_| Error)?;
-
-        let domain = Domain::<U32>::xof::<HashT>(dsts)?;
-        let mut reader = HashT::default();
+        let len_in_bytes = u16::try_from(len_in_bytes).map_err(|_| Error::new(ErrorKind::Other))?;
 
         for msg in msgs {
             reader = reader.chain(msg);
@@ -47,11 +140,24 @@ where
         domain.update_hash(&mut reader);
         reader.update(&[domain.len()]);
         let reader = reader.finalize_xof();
-        Ok(Self { reader })
+        Ok(Self {
+            reader,
+            security_level: PhantomData,
+        })
     }
 }
 
-impl<HashT> Expander for ExpandMsgXof<HashT>
+/// Prepared domain separation tag for [`ExpandMsgXof`].
+#[derive(Clone)]
+pub struct PreparedDstXof<'a, K = U128>
+where
+    K: PartialDiv<U4>,
+    PartialQuot<K, U4>: ArrayLength<u8> + IsLess<U256>,
+{
+    domain: Domain<'a, PartialQuot<K, U4>>,
+}
+
+impl<HashT, K> Expander for ExpandMsgXof<HashT, K>
 where
     HashT: Default + ExtendableOutput + Update,
 {
@@ -65,18 +171,16 @@ mod test {
     use super::*;
     use core::mem;
     use generic_array::{
-        typenum::{U128, U32},
-        ArrayLength, GenericArray,
+        typenum::{U32, U64},
+        GenericArray,
     };
     use hex_literal::hex;
     use sha3::Shake128;
 
-    fn assert_message<HashT>(
-        msg: &[u8],
-        domain: &Domain<'_, U32>,
-        len_in_bytes: u16,
-        bytes: &[u8],
-    ) {
+    fn assert_message<HashT, L>(msg: &[u8], domain: &Domain<'_, L>, len_in_bytes: u16, bytes: &[u8])
+    where
+        L: ArrayLength<u8> + IsLess<U256>,
+    {
         let msg_len = msg.len();
         assert_eq!(msg, &bytes[..msg_len]);
 
@@ -102,15 +206,34 @@ mod test {
     }
 
     impl TestVector {
-        fn assert<HashT, L>(&self, dst: &'static [u8], domain: &Domain<'_, U32>) -> Result<()>
+        fn assert<HashT, L, K>(
+            &self,
+            dst: &'static [u8],
+            domain: &Domain<'_, PartialQuot<K, U4>>,
+        ) -> Result<()>
         where
             HashT: Default + ExtendableOutput + Update,
             L: ArrayLength<u8>,
+            K: PartialDiv<U4>,
+            PartialQuot<K, U4>: ArrayLength<u8> + IsLess<U256>,
         {
-            assert_message::<HashT>(self.msg, domain, L::to_u16(), self.msg_prime);
+            assert_message::<HashT, _>(self.msg, domain, L::to_u16(), self.msg_prime);
 
             let mut expander =
-                ExpandMsgXof::<HashT>::expand_message(&[self.msg], &[dst], L::to_usize())?;
+                ExpandMsgXof::<HashT, K>::expand_message(&[self.msg], &[dst], L::to_usize())?;
+
+            let mut uniform_bytes = GenericArray::<u8, L>::default();
+            expander.fill_bytes(&mut uniform_bytes);
+
+            assert_eq!(uniform_bytes.as_slice(), self.uniform_bytes);
+
+            let dst = [dst];
+            let prepared = ExpandMsgXof::<HashT, K>::prepare_dst(&dst)?;
+            let mut expander = ExpandMsgXof::<HashT, K>::expand_message_prepared(
+                &[self.msg],
+                &prepared,
+                L::to_usize(),
+            )?;
 
             let mut uniform_bytes = GenericArray::<u8, L>::default();
             expander.fill_bytes(&mut uniform_bytes);
@@ -158,7 +281,7 @@ mod test {
         ];
 
         for test_vector in TEST_VECTORS_32 {
-            test_vector.assert::<Shake128, U32>(DST, &dst_prime)?;
+            test_vector.assert::<Shake128, U32, U128>(DST, &dst_prime)?;
         }
 
         const TEST_VECTORS_128: &[TestVector] = &[
@@ -190,7 +313,7 @@ mod test {
         ];
 
         for test_vector in TEST_VECTORS_128 {
-            test_vector.assert::<Shake128, U128>(DST, &dst_prime)?;
+            test_vector.assert::<Shake128, U128, U128>(DST, &dst_prime)?;
         }
 
         Ok(())
@@ -234,7 +357,7 @@ mod test {
         ];
 
         for test_vector in TEST_VECTORS_32 {
-            test_vector.assert::<Shake128, U32>(DST, &dst_prime)?;
+            test_vector.assert::<Shake128, U32, U128>(DST, &dst_prime)?;
         }
 
         const TEST_VECTORS_128: &[TestVector] = &[
@@ -266,7 +389,7 @@ mod test {
         ];
 
         for test_vector in TEST_VECTORS_128 {
-            test_vector.assert::<Shake128, U128>(DST, &dst_prime)?;
+            test_vector.assert::<Shake128, U128, U128>(DST, &dst_prime)?;
         }
 
         Ok(())
@@ -280,7 +403,7 @@ mod test {
         const DST_PRIME: &[u8] =
             &hex!("515555582d5630312d435330322d776974682d657870616e6465722d5348414b4532353624");
 
-        let dst_prime = Domain::<U32>::xof::<Shake256>(&[DST])?;
+        let dst_prime = Domain::<U64>::xof::<Shake256>(&[DST])?;
         dst_prime.assert_dst(DST_PRIME);
 
         const TEST_VECTORS_32: &[TestVector] = &[
@@ -312,7 +435,7 @@ mod test {
         ];
 
         for test_vector in TEST_VECTORS_32 {
-            test_vector.assert::<Shake256, U32>(DST, &dst_prime)?;
+            test_vector.assert::<Shake256, U32, U256>(DST, &dst_prime)?;
         }
 
         const TEST_VECTORS_128: &[TestVector] = &[
@@ -344,9 +467,63 @@ mod test {
         ];
 
         for test_vector in TEST_VECTORS_128 {
-            test_vector.assert::<Shake256, U128>(DST, &dst_prime)?;
+            test_vector.assert::<Shake256, U128, U256>(DST, &dst_prime)?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn expand_message_xof_shake_256_long() -> Result<()> {
+        use sha3::Shake256;
+
+        const DST: &[u8] = b"QUUX-V01-CS02-with-expander-SHAKE256-long-DST-111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111";
+        const DST_PRIME: &[u8] =
+            &hex!("8731221895c7e13fa995a8551e678d13256ababd8f57f7086f7926e0084ac3e0bbc770c1e19b20bd02c0057dcc6e4ee4cc21c67c6e379a53a2349cb19dc500ea40");
+
+        // With k = 256 an oversized DST is reduced to 64 bytes
+        let dst_prime = Domain::<U64>::xof::<Shake256>(&[DST])?;
+        dst_prime.assert_dst(DST_PRIME);
+
+        const TEST_VECTORS_32: &[TestVector] = &[
+            TestVector {
+                msg: b"",
+                msg_prime: &hex!("00208731221895c7e13fa995a8551e678d13256ababd8f57f7086f7926e0084ac3e0bbc770c1e19b20bd02c0057dcc6e4ee4cc21c67c6e379a53a2349cb19dc500ea40"),
+                uniform_bytes: &hex!("298dc0cf58b9c68810e45a4047f38c1eb562bcc2d31b1d2ea594e0f0ef9a2b7c"),
+            },
+            TestVector {
+                msg: b"abc",
+                msg_prime: &hex!("61626300208731221895c7e13fa995a8551e678d13256ababd8f57f7086f7926e0084ac3e0bbc770c1e19b20bd02c0057dcc6e4ee4cc21c67c6e379a53a2349cb19dc500ea40"),
+                uniform_bytes: &hex!("eee96d14891c97703feec48d64408db3efb3fa7d5c12bdc0932aae44e5805219"),
+            },
+        ];
+
+        for test_vector in TEST_VECTORS_32 {
+            test_vector.assert::<Shake256, U32, U256>(DST, &dst_prime)?;
         }
 
         Ok(())
     }
+
+    #[test]
+    fn expand_message_xof_empty_dst() {
+        assert!(ExpandMsgXof::<Shake128>::expand_message(&[b"abc"], &[], 32).is_err());
+        assert!(ExpandMsgXof::<Shake128>::expand_message(&[b"abc"], &[b""], 32).is_err());
+        assert!(ExpandMsgXof::<Shake128>::expand_message(&[b"abc"], &[b"", b""], 32).is_err());
+    }
+
+    #[test]
+    fn expand_message_xof_split_long_dst() -> Result<()> {
+        // The oversize DST reduction applies to the concatenation of all parts
+        const DST_PRIME: &[u8] =
+            &hex!("acb9736c0867fdfbd6385519b90fc8c034b5af04a958973212950132d035792f20");
+
+        let dst_prime = Domain::<U32>::xof::<Shake128>(&[
+            b"QUUX-V01-CS02-with-expander-SHAKE128-long-DST-",
+            &[b'1'; 210],
+        ])?;
+        dst_prime.assert_dst(DST_PRIME);
+
+        Ok(())
+    }
 }
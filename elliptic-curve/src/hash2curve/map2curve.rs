@@ -1,12 +1,48 @@
 //! Traits for mapping field elements to points on the curve.
 
+use core::ops::Add;
+
 /// Trait for converting field elements into a point
 /// via a mapping method like Simplified Shallue-van de Woestijne-Ulas
 /// or Elligator
+///
+/// Curves which map to an isogenous curve, e.g. SSWU for secp256k1, return a
+/// point on that curve from [`MapToCurve::map_to_curve`] and convert it with
+/// [`MapToCurve::isogeny`]. Points are only added after the isogeny has been
+/// applied, as in RFC 9380 §6.6.3.
 pub trait MapToCurve {
+    /// The point returned by the mapping, before the isogeny is applied.
+    ///
+    /// Curves without an isogeny set this to [`MapToCurve::Output`].
+    type IntermediatePoint: Clone + Into<Self::Output>;
+
     /// The output point
     type Output;
 
+    /// RFC 9380 identifier of the mapping, e.g. `SSWU`.
+    const MAP_ID: &'static str;
+
     /// Map a field element into a point
-    fn map_to_curve(&self) -> Self::Output;
+    fn map_to_curve(&self) -> Self::IntermediatePoint;
+
+    /// Map a point returned by [`MapToCurve::map_to_curve`] to the curve.
+    ///
+    /// Defaults to the identity map.
+    fn isogeny(point: &Self::IntermediatePoint) -> Self::Output {
+        point.clone().into()
+    }
+}
+
+/// Map a field element to the curve, applying the isogeny.
+pub(crate) fn map_to_curve<F: MapToCurve>(u: &F) -> F::Output {
+    F::isogeny(&u.map_to_curve())
+}
+
+/// Map two field elements to the curve and add the resulting points.
+pub(crate) fn map_to_curve_sum<F>(u: &[F; 2]) -> F::Output
+where
+    F: MapToCurve,
+    F::Output: Add<Output = F::Output>,
+{
+    map_to_curve(&u[0]) + map_to_curve(&u[1])
 }
@@ -0,0 +1,211 @@
+//! Hash-to-curve suite identifiers.
+//!
+//! <https://www.rfc-editor.org/rfc/rfc9380.html#section-8.10>
+
+use super::{ExpandMsgXmd, ExpandMsgXof};
+use core::fmt;
+use digest::{
+    const_oid::{AssociatedOid, ObjectIdentifier},
+    core_api::BlockSizeUser,
+    generic_array::typenum::{IsLess, IsLessOrEqual, Unsigned, U256},
+    ExtendableOutput, FixedOutput, HashMarker, Update,
+};
+
+/// RFC 9380 names of the hash functions with a known OID.
+const HASH_IDS: &[(ObjectIdentifier, &str)] = &[
+    (
+        ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.1"),
+        "SHA-256",
+    ),
+    (
+        ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.2"),
+        "SHA-384",
+    ),
+    (
+        ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.3"),
+        "SHA-512",
+    ),
+    (
+        ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.4"),
+        "SHA-224",
+    ),
+    (
+        ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.5"),
+        "SHA-512/224",
+    ),
+    (
+        ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.6"),
+        "SHA-512/256",
+    ),
+    (
+        ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.7"),
+        "SHA3-224",
+    ),
+    (
+        ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.8"),
+        "SHA3-256",
+    ),
+    (
+        ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.9"),
+        "SHA3-384",
+    ),
+    (
+        ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.10"),
+        "SHA3-512",
+    ),
+    (
+        ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.11"),
+        "SHAKE128",
+    ),
+    (
+        ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.12"),
+        "SHAKE256",
+    ),
+];
+
+/// Look up the RFC 9380 name of a hash function by its OID.
+fn hash_id(oid: &ObjectIdentifier) -> Option<&'static str> {
+    HASH_IDS
+        .iter()
+        .find(|(known, _)| known == oid)
+        .map(|(_, name)| *name)
+}
+
+/// Identifier of an `expand_message` variant and its hash function, used to
+/// derive a [`SuiteId`].
+pub trait ExpandMsgId {
+    /// The `expand_message` variant, i.e. `XMD` or `XOF`.
+    const VARIANT: &'static str;
+
+    /// RFC 9380 name of the underlying hash function, e.g. `SHA-256`, or
+    /// `None` if it is not known.
+    fn hash_id() -> Option<&'static str>;
+}
+
+impl<HashT> ExpandMsgId for ExpandMsgXmd<HashT>
+where
+    HashT: AssociatedOid + BlockSizeUser + Default + FixedOutput + HashMarker,
+    HashT::OutputSize: IsLess<U256>,
+    HashT::OutputSize: IsLessOrEqual<HashT::BlockSize>,
+{
+    const VARIANT: &'static str = "XMD";
+
+    fn hash_id() -> Option<&'static str> {
+        hash_id(&HashT::OID)
+    }
+}
+
+impl<HashT, K> ExpandMsgId for ExpandMsgXof<HashT, K>
+where
+    HashT: AssociatedOid + BlockSizeUser + Default + ExtendableOutput + Update,
+{
+    const VARIANT: &'static str = "XOF";
+
+    fn hash_id() -> Option<&'static str> {
+        // `sha3` v0.10 assigns the SHAKE128 OID to SHAKE256 as well, so the
+        // rate is used to tell the two apart
+        match (hash_id(&HashT::OID), HashT::BlockSize::USIZE) {
+            (Some("SHAKE128" | "SHAKE256"), 168) => Some("SHAKE128"),
+            (Some("SHAKE128" | "SHAKE256"), 136) => Some("SHAKE256"),
+            (Some("SHAKE128" | "SHAKE256"), _) => None,
+            (id, _) => id,
+        }
+    }
+}
+
+/// Hash-to-curve encoding variant.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Encoding {
+    /// `hash_to_curve`, i.e. [`GroupDigest::hash_from_bytes`](super::GroupDigest::hash_from_bytes).
+    Uniform,
+
+    /// `encode_to_curve`, i.e. [`GroupDigest::encode_from_bytes`](super::GroupDigest::encode_from_bytes).
+    NonUniform,
+}
+
+impl Encoding {
+    /// Suite ID tag for this encoding, i.e. `RO` or `NU`.
+    pub const fn tag(self) -> &'static str {
+        match self {
+            Self::Uniform => "RO",
+            Self::NonUniform => "NU",
+        }
+    }
+}
+
+/// RFC 9380 suite identifier, e.g. `P256_XMD:SHA-256_SSWU_RO_`.
+///
+/// Obtained from [`GroupDigest::suite_id`](super::GroupDigest::suite_id),
+/// which derives it from the curve, expander and map actually used.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SuiteId {
+    curve: &'static str,
+    variant: &'static str,
+    hash: &'static str,
+    map: &'static str,
+    encoding: Encoding,
+}
+
+impl SuiteId {
+    pub(crate) fn new(
+        curve: &'static str,
+        variant: &'static str,
+        hash: &'static str,
+        map: &'static str,
+        encoding: Encoding,
+    ) -> Self {
+        Self {
+            curve,
+            variant,
+            hash,
+            map,
+            encoding,
+        }
+    }
+
+    /// Get the encoding variant of this suite.
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    /// Get the components of this suite identifier, which concatenate to the
+    /// full identifier.
+    pub fn parts(&self) -> [&'static [u8]; 10] {
+        [
+            self.curve.as_bytes(),
+            b"_",
+            self.variant.as_bytes(),
+            b":",
+            self.hash.as_bytes(),
+            b"_",
+            self.map.as_bytes(),
+            b"_",
+            self.encoding.tag().as_bytes(),
+            b"_",
+        ]
+    }
+
+    /// Build a domain separation tag consisting of the given application tag
+    /// followed by this suite identifier.
+    ///
+    /// The result can be passed as the `dsts` argument of the
+    /// [`GroupDigest`](super::GroupDigest) methods.
+    pub fn dst<'a>(&self, tag: &'a [u8]) -> [&'a [u8]; 11] {
+        let [a, b, c, d, e, f, g, h, i, j] = self.parts();
+        [tag, a, b, c, d, e, f, g, h, i, j]
+    }
+}
+
+impl fmt::Display for SuiteId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}_{}:{}_{}_{}_",
+            self.curve,
+            self.variant,
+            self.hash,
+            self.map,
+            self.encoding.tag()
+        )
+    }
+}
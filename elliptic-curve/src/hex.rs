@@ -0,0 +1,238 @@
+//! Constant-time hexadecimal encoding and decoding.
+//!
+//! These routines don't branch on or index tables by the characters or bytes
+//! being processed, making them suitable for secret values such as scalars.
+//! Only the lengths of the inputs are treated as public.
+
+use crate::{Curve, Error, ErrorKind, FieldBytes, Result};
+use core::{fmt, str};
+use subtle::{ConstantTimeEq, CtOption};
+
+/// Decode big endian mixed-case hex into [`FieldBytes`].
+///
+/// Returns `None` if `hex` isn't exactly twice the size of [`FieldBytes`] or
+/// contains non-hex characters.
+#[allow(clippy::cast_possible_truncation, clippy::integer_arithmetic)]
+pub fn decode_to_field_bytes<C: Curve>(hex: &str) -> CtOption<FieldBytes<C>> {
+    let mut bytes = FieldBytes::<C>::default();
+    let valid_len = u8::from(hex.len() == bytes.len() * 2);
+    let mut err = 0u16;
+
+    for (byte, pair) in bytes.iter_mut().zip(hex.as_bytes().chunks_exact(2)) {
+        let hi = decode_nibble(pair[0]);
+        let lo = decode_nibble(pair[1]);
+        err |= (hi | lo) >> 4;
+        *byte = ((hi << 4) | lo) as u8;
+    }
+
+    CtOption::new(bytes, err.ct_eq(&0) & valid_len.into())
+}
+
+/// Encode [`FieldBytes`] as lower case hex into `out`, returning the encoded
+/// string.
+///
+/// # Errors
+///
+/// If `out` is shorter than twice the size of [`FieldBytes`].
+pub fn encode_field_bytes<'o, C: Curve>(
+    bytes: &FieldBytes<C>,
+    out: &'o mut [u8],
+) -> Result<&'o str> {
+    encode(bytes, out, LOWER)
+}
+
+/// Encode [`FieldBytes`] as upper case hex into `out`, returning the encoded
+/// string.
+///
+/// # Errors
+///
+/// If `out` is shorter than twice the size of [`FieldBytes`].
+pub fn encode_field_bytes_upper<'o, C: Curve>(
+    bytes: &FieldBytes<C>,
+    out: &'o mut [u8],
+) -> Result<&'o str> {
+    encode(bytes, out, UPPER)
+}
+
+/// Write [`FieldBytes`] as lower case hex to `out`.
+///
+/// # Errors
+///
+/// If writing to `out` fails.
+pub fn write_field_bytes<C: Curve>(bytes: &FieldBytes<C>, out: &mut dyn fmt::Write) -> fmt::Result {
+    write(bytes, out, LOWER)
+}
+
+/// Write [`FieldBytes`] as upper case hex to `out`.
+///
+/// # Errors
+///
+/// If writing to `out` fails.
+pub fn write_field_bytes_upper<C: Curve>(
+    bytes: &FieldBytes<C>,
+    out: &mut dyn fmt::Write,
+) -> fmt::Result {
+    write(bytes, out, UPPER)
+}
+
+/// Offset from `'0' + 10` to `'a'`.
+const LOWER: i16 = 0x27;
+
+/// Offset from `'0' + 10` to `'A'`.
+const UPPER: i16 = 0x07;
+
+#[allow(clippy::integer_arithmetic)]
+fn encode<'o>(bytes: &[u8], out: &'o mut [u8], case: i16) -> Result<&'o str> {
+    let len = bytes.len() * 2;
+    let out_len = out.len();
+    let out = out
+        .get_mut(..len)
+        .ok_or(Error::invalid_length(len, out_len))?;
+
+    for (byte, pair) in bytes.iter().zip(out.chunks_exact_mut(2)) {
+        pair[0] = encode_nibble(byte >> 4, case);
+        pair[1] = encode_nibble(byte & 0xf, case);
+    }
+
+    str::from_utf8(out).map_err(|_| Error::new(ErrorKind::Other))
+}
+
+#[allow(clippy::integer_arithmetic)]
+fn write(bytes: &[u8], out: &mut dyn fmt::Write, case: i16) -> fmt::Result {
+    for byte in bytes {
+        out.write_char(char::from(encode_nibble(byte >> 4, case)))?;
+        out.write_char(char::from(encode_nibble(byte & 0xf, case)))?;
+    }
+
+    Ok(())
+}
+
+/// Decode a hex character, returning its value or a value with bits above
+/// the low nibble set if it is not a hex character.
+#[allow(clippy::cast_sign_loss, clippy::integer_arithmetic)]
+fn decode_nibble(c: u8) -> u16 {
+    let c = i16::from(c);
+
+    // Each range check is all ones if `c` is in the range and zero otherwise,
+    // and selects `c`'s value plus one
+    let digit = (((0x2f - c) & (c - 0x3a)) >> 8) & (c - 0x2f);
+    let upper = (((0x40 - c) & (c - 0x47)) >> 8) & (c - 0x36);
+    let lower = (((0x60 - c) & (c - 0x67)) >> 8) & (c - 0x56);
+
+    (digit + upper + lower - 1) as u16
+}
+
+/// Encode the low nibble of `n` as a hex character.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::integer_arithmetic
+)]
+fn encode_nibble(n: u8, case: i16) -> u8 {
+    let n = i16::from(n);
+    (n + 0x30 + (((0x09 - n) >> 8) & case)) as u8
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use crate::dev::{FieldBytes, MockCurve, NonZeroScalar, ScalarPrimitive};
+    use alloc::{format, string::String};
+    use core::str::FromStr;
+    use hex_literal::hex;
+
+    const BYTES: [u8; 32] =
+        hex!("0123456789abcdefABCDEF00ff7f80c0de0f1e2d3c4b5a69788796a5b4c3d2e1");
+    const LOWER: &str = "0123456789abcdefabcdef00ff7f80c0de0f1e2d3c4b5a69788796a5b4c3d2e1";
+    const UPPER: &str = "0123456789ABCDEFABCDEF00FF7F80C0DE0F1E2D3C4B5A69788796A5B4C3D2E1";
+
+    #[test]
+    fn decode_mixed_case() {
+        let expected = FieldBytes::from(BYTES);
+        assert_eq!(
+            super::decode_to_field_bytes::<MockCurve>(LOWER).unwrap(),
+            expected
+        );
+        assert_eq!(
+            super::decode_to_field_bytes::<MockCurve>(UPPER).unwrap(),
+            expected
+        );
+
+        let mixed = "0123456789abcdefABCDEF00Ff7f80c0de0f1e2d3c4b5a69788796a5b4c3d2E1";
+        assert_eq!(
+            super::decode_to_field_bytes::<MockCurve>(mixed).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn decode_rejects_bad_lengths() {
+        for len in [0, 1, 63, 65] {
+            let hex: String = LOWER.chars().cycle().take(len).collect();
+            assert!(bool::from(
+                super::decode_to_field_bytes::<MockCurve>(&hex).is_none()
+            ));
+        }
+    }
+
+    #[test]
+    fn decode_rejects_bad_characters() {
+        // The bad character is detected via the returned `CtOption` no matter
+        // where it is, rather than by bailing out partway through the input
+        for pos in 0..LOWER.len() {
+            for bad in ['g', 'G', 'z', ' ', '/', ':', '@', '`', '\0', '\u{7f}'] {
+                let mut hex = String::from(LOWER);
+                hex.replace_range(pos..=pos, bad.encode_utf8(&mut [0; 4]));
+                assert!(bool::from(
+                    super::decode_to_field_bytes::<MockCurve>(&hex).is_none()
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn encode() {
+        let bytes = FieldBytes::from(BYTES);
+        let mut buf = [0u8; 65];
+        assert_eq!(
+            super::encode_field_bytes::<MockCurve>(&bytes, &mut buf).unwrap(),
+            LOWER
+        );
+        assert_eq!(
+            super::encode_field_bytes_upper::<MockCurve>(&bytes, &mut buf).unwrap(),
+            UPPER
+        );
+        assert!(super::encode_field_bytes::<MockCurve>(&bytes, &mut buf[..63]).is_err());
+
+        let mut s = String::new();
+        super::write_field_bytes::<MockCurve>(&bytes, &mut s).unwrap();
+        super::write_field_bytes_upper::<MockCurve>(&bytes, &mut s).unwrap();
+        assert_eq!(s, [LOWER, UPPER].concat());
+    }
+
+    #[test]
+    fn round_trip_all_bytes() {
+        for b in 0..=u8::MAX {
+            let bytes = FieldBytes::from([b; 32]);
+            let mut buf = [0u8; 64];
+            let hex = super::encode_field_bytes::<MockCurve>(&bytes, &mut buf).unwrap();
+            assert_eq!(
+                super::decode_to_field_bytes::<MockCurve>(hex).unwrap(),
+                bytes
+            );
+        }
+    }
+
+    #[test]
+    fn scalar_impls() {
+        let scalar = NonZeroScalar::from_str(UPPER).unwrap();
+        assert_eq!(format!("{scalar:x}"), LOWER);
+        assert_eq!(format!("{scalar:X}"), UPPER);
+
+        let primitive = ScalarPrimitive::from_str(UPPER).unwrap();
+        assert_eq!(format!("{primitive:x}"), LOWER);
+        assert_eq!(format!("{primitive:X}"), UPPER);
+
+        assert!(ScalarPrimitive::from_str(&LOWER[..62]).is_err());
+        assert!(NonZeroScalar::from_str(&LOWER[..62]).is_err());
+    }
+}
@@ -6,7 +6,7 @@
 use crate::{
     sec1::{Coordinates, EncodedPoint, ModulusSize, ValidatePublicKey},
     secret_key::SecretKey,
-    Curve, Error, FieldBytes, FieldBytesSize, Result,
+    Curve, Error, ErrorKind, FieldBytes, FieldBytesSize, Result,
 };
 use alloc::{
     borrow::ToOwned,
@@ -141,7 +141,7 @@ impl JwkEcKey {
         FieldBytesSize<C>: ModulusSize,
     {
         if self.crv != C::CRV {
-            return Err(Error);
+            return Err(ErrorKind::CurveMismatch.into());
         }
 
         let x = decode_base64url_fe::<C>(&self.x)?;
@@ -164,7 +164,7 @@ impl FromStr for JwkEcKey {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self> {
-        serde_json::from_str(s).map_err(|_| Error)
+        serde_json::from_str(s).map_err(|_| ErrorKind::Decode.into())
     }
 }
 
@@ -205,7 +205,7 @@ where
                 Ok(secret_key)
             })
         } else {
-            Err(Error)
+            Err(ErrorKind::Decode.into())
         }
     }
 }
@@ -559,7 +559,7 @@ impl Serialize for JwkEcKey {
 /// Decode a Base64url-encoded field element
 fn decode_base64url_fe<C: Curve>(s: &str) -> Result<FieldBytes<C>> {
     let mut result = FieldBytes::<C>::default();
-    Base64Url::decode(s, &mut result).map_err(|_| Error)?;
+    Base64Url::decode(s, &mut result).map_err(|_| ErrorKind::Decode)?;
     Ok(result)
 }
 
@@ -632,7 +632,10 @@ mod tests {
 
     #[test]
     fn parse_unsupported() {
-        assert_eq!(JwkEcKey::from_str(UNSUPPORTED_JWK), Err(Error));
+        assert_eq!(
+            JwkEcKey::from_str(UNSUPPORTED_JWK),
+            Err(ErrorKind::Decode.into())
+        );
     }
 
     #[test]
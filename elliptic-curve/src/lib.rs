@@ -65,6 +65,20 @@
 //!
 //! Please see type-specific documentation for more information.
 //!
+//! ## `arbitrary` support
+//!
+//! When the `arbitrary` feature of this crate is enabled, `Arbitrary` impls
+//! are provided for the following types:
+//!
+//! - [`NonZeroScalar`]
+//! - [`PublicKey`]
+//! - [`ScalarPrimitive`]
+//! - [`SecretKey`]
+//!
+//! [`point::arbitrary_affine_point`] can be used to implement `Arbitrary` for
+//! a curve's affine point type, and [`sec1::arbitrary_encoded_point`]
+//! generates SEC1 encodings from raw, unchecked coordinate bytes.
+//!
 //! [`RustCrypto/elliptic-curves`]: https://github.com/RustCrypto/elliptic-curves
 //! [`bp256`]: https://github.com/RustCrypto/elliptic-curves/tree/master/bp256
 //! [`bp384`]: https://github.com/RustCrypto/elliptic-curves/tree/master/bp384
@@ -82,6 +96,10 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+#[cfg(all(feature = "alloc", feature = "arithmetic"))]
+pub mod batch_verify;
+pub mod codec;
+pub mod hex;
 pub mod point;
 pub mod scalar;
 
@@ -93,8 +111,18 @@ pub mod ecdh;
 pub mod hash2curve;
 #[cfg(feature = "arithmetic")]
 pub mod ops;
+#[cfg(feature = "arithmetic")]
+pub mod precompute;
+#[cfg(feature = "arithmetic")]
+pub mod recovery;
 #[cfg(feature = "sec1")]
 pub mod sec1;
+#[cfg(all(feature = "arithmetic", feature = "serde"))]
+pub mod serde;
+#[cfg(feature = "voprf")]
+pub mod voprf;
+#[cfg(all(feature = "alloc", feature = "arithmetic"))]
+pub mod vss;
 #[cfg(feature = "arithmetic")]
 pub mod weierstrass;
 
@@ -110,12 +138,9 @@ mod public_key;
 #[cfg(feature = "jwk")]
 mod jwk;
 
-#[cfg(feature = "voprf")]
-mod voprf;
-
 pub use crate::{
-    error::{Error, Result},
-    field::{FieldBytes, FieldBytesEncoding, FieldBytesSize},
+    error::{Error, ErrorKind, Result},
+    field::{FieldBytes, FieldBytesArray, FieldBytesEncoding, FieldBytesSize, TaggedFieldBytes},
     scalar::ScalarPrimitive,
     secret_key::SecretKey,
 };
@@ -128,29 +153,51 @@ pub use zeroize;
 #[cfg(feature = "arithmetic")]
 pub use {
     crate::{
-        arithmetic::{CurveArithmetic, PrimeCurveArithmetic},
+        arithmetic::{
+            CofactorArithmetic, CurveArithmetic, EdwardsCurveArithmetic, MontgomeryXOnly,
+            PrimeCurveArithmetic, ScalarArithmetic,
+        },
         point::{AffinePoint, ProjectivePoint},
-        public_key::PublicKey,
+        public_key::{PublicKey, XOnlyPublicKey},
         scalar::{NonZeroScalar, Scalar},
     },
     ff::{self, Field, PrimeField},
     group::{self, Group},
 };
 
+#[cfg(feature = "bits")]
+pub use crate::arithmetic::CurveArithmeticBits;
+
+#[cfg(feature = "arbitrary")]
+pub use arbitrary;
+
 #[cfg(feature = "jwk")]
 pub use crate::jwk::{JwkEcKey, JwkParameters};
 
 #[cfg(feature = "pkcs8")]
 pub use pkcs8;
 
+#[cfg(feature = "rayon")]
+pub use rayon;
+
 #[cfg(feature = "voprf")]
 pub use crate::voprf::VoprfParameters;
 
+#[cfg(feature = "rkyv")]
+pub use {crate::scalar::ArchivedScalarPrimitive, rkyv};
+
+#[cfg(all(feature = "arithmetic", feature = "rkyv"))]
+pub use crate::public_key::ArchivedPublicKey;
+
+#[cfg(feature = "zerocopy")]
+pub use {crate::field::FieldBytesRepr, zerocopy};
+
 use core::{
     fmt::Debug,
     ops::{Add, ShrAssign},
 };
 use generic_array::ArrayLength;
+use subtle::Choice;
 
 /// Algorithm [`ObjectIdentifier`][`pkcs8::ObjectIdentifier`] for elliptic
 /// curve public key cryptography (`id-ecPublicKey`).
@@ -191,7 +238,39 @@ pub trait Curve: 'static + Copy + Clone + Debug + Default + Eq + Ord + Send + Sy
     /// Order of this elliptic curve, i.e. number of elements in the scalar
     /// field.
     const ORDER: Self::Uint;
+
+    /// Order of this elliptic curve as a [`NonZero`](bigint::NonZero) value.
+    fn order_nz() -> bigint::NonZero<Self::Uint> {
+        bigint::NonZero::new(Self::ORDER).unwrap()
+    }
+
+    /// Is the given integer a canonical scalar, i.e. less than [`Curve::ORDER`]?
+    fn is_canonical_scalar(uint: &Self::Uint) -> Choice {
+        uint.is_canonical(&Self::order_nz())
+    }
+
+    /// Reduce the given integer modulo [`Curve::ORDER`].
+    #[allow(clippy::integer_arithmetic)]
+    fn reduce_uint(uint: &Self::Uint) -> Self::Uint {
+        *uint % Self::order_nz()
+    }
 }
 
 /// Marker trait for elliptic curves with prime order.
 pub trait PrimeCurve: Curve {}
+
+/// Twisted Edwards curve `ax² + y² = 1 + dx²y²`.
+///
+/// Points on these curves are serialized using the compressed encoding
+/// described in [RFC 8032] rather than SEC1.
+///
+/// [RFC 8032]: https://www.rfc-editor.org/rfc/rfc8032
+pub trait EdwardsCurve: Curve {
+    /// Size of a compressed point in bytes, e.g. 32 bytes for edwards25519 or
+    /// 57 bytes for edwards448.
+    type PointBytesSize: ArrayLength<u8>;
+}
+
+/// Compressed encoding of a point on a twisted Edwards curve.
+pub type EdwardsPointBytes<C> =
+    generic_array::GenericArray<u8, <C as EdwardsCurve>::PointBytesSize>;
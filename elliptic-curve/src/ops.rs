@@ -1,9 +1,25 @@
 //! Traits for arithmetic operations on elliptic curve field elements.
 
+pub mod mul;
+
 pub use core::ops::{Add, AddAssign, Mul, Neg, Shr, ShrAssign, Sub, SubAssign};
 
-use crypto_bigint::Integer;
+use crate::{CurveArithmetic, NonZeroScalar, Scalar, ScalarArithmetic};
+use crypto_bigint::{Integer, Limb, Zero};
 use group::Group;
+use rand_core::CryptoRngCore;
+
+use subtle::{Choice, ConditionallySelectable, CtOption};
+use zeroize::{Zeroize, Zeroizing};
+#[cfg(feature = "alloc")]
+use {
+    crate::precompute::comb::MulByGeneratorIter,
+    alloc::vec::Vec,
+    subtle::{ConditionallyNegatable, ConstantTimeEq},
+};
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
 
 /// Perform an inversion on a field element (i.e. base field element or scalar)
 pub trait Invert {
@@ -23,8 +39,450 @@ pub trait Invert {
         // Fall back on constant-time implementation by default.
         self.invert()
     }
+
+    /// Divide `self` by `rhs` in constant time, i.e. compute `self * rhs⁻¹`.
+    ///
+    /// Returns `None` if `rhs` is zero.
+    fn div(&self, rhs: &Self) -> CtOption<Self>
+    where
+        Self: Invert<Output = CtOption<Self>>
+            + ConditionallySelectable
+            + Default
+            + for<'a> Mul<&'a Self, Output = Self>,
+    {
+        rhs.invert().map(|rhs_inv| *self * &rhs_inv)
+    }
+
+    /// Divide each element of `num` by the corresponding element of `den` in
+    /// constant time, sharing a single inversion across the whole batch via
+    /// [`InvertBatch`].
+    ///
+    /// Returns `None` if any element of `den` is zero.
+    fn div_batch<const N: usize>(num: &[Self; N], den: &[Self; N]) -> CtOption<[Self; N]>
+    where
+        Self: Invert<Output = CtOption<Self>>
+            + ConditionallySelectable
+            + Default
+            + for<'a> Mul<&'a Self, Output = Self>,
+    {
+        let (mut out, is_some) = invert_batch_array(den);
+
+        for (o, n) in out.iter_mut().zip(num) {
+            *o = *n * &*o;
+        }
+
+        CtOption::new(out, is_some)
+    }
+
+    /// Invert `self` with random multiplicative blinding, i.e. compute
+    /// `(self * r)⁻¹ * r` for a random nonzero `r`, so the inversion routine
+    /// only ever sees a uniformly random value.
+    ///
+    /// The blinding factor is zeroized afterwards. Returns `None` if `self` is
+    /// zero.
+    fn invert_blinded(&self, rng: &mut impl CryptoRngCore) -> CtOption<Self>
+    where
+        Self: Invert<Output = CtOption<Self>> + ff::Field + Zeroize,
+    {
+        let mut r = Zeroizing::new(Self::random(&mut *rng));
+        while bool::from(r.is_zero()) {
+            *r = Self::random(&mut *rng);
+        }
+
+        invert_with_mask(self, &r)
+    }
+}
+
+/// Invert the secret scalar `x` masked with a fresh random nonzero `r`, i.e.
+/// compute `(x * r)⁻¹ * r`, so the inversion never operates on `x` itself.
+///
+/// As `x * r` is uniformly random and independent of `x`, it is inverted with
+/// [`Invert::invert_vartime`], which may be faster. The intermediate values
+/// are zeroized.
+pub fn invert_masked<C: ScalarArithmetic>(
+    x: &NonZeroScalar<C>,
+    rng: &mut impl CryptoRngCore,
+) -> NonZeroScalar<C> {
+    let mask = Zeroizing::new(NonZeroScalar::<C>::random(rng));
+    let inverse = Zeroizing::new(invert_with_mask::<Scalar<C>>(x, &mask).unwrap());
+
+    // This will always succeed since `x` and `mask` are nonzero
+    NonZeroScalar::new(*inverse).unwrap()
+}
+
+/// Compute `(x * mask)⁻¹ * mask`, returning `None` if `x` or `mask` is zero.
+///
+/// This is the masked inversion behind [`Invert::invert_blinded`],
+/// [`invert_masked`] and [`BlindedScalar`](crate::scalar::BlindedScalar).
+pub(crate) fn invert_with_mask<F>(x: &F, mask: &F) -> CtOption<F>
+where
+    F: Invert<Output = CtOption<F>> + ff::Field + Zeroize,
+{
+    let masked = Zeroizing::new(*x * mask);
+    masked.invert_vartime().map(|inverse| {
+        let inverse = Zeroizing::new(inverse);
+        *inverse * mask
+    })
+}
+
+/// Marker for [`Invert`] impls which always succeed, i.e. whose output is the
+/// inverse itself, e.g. for [`NonZeroScalar`].
+pub trait InvertInfallible: Invert<Output = Self> {}
+
+/// Output of an [`Invert`] impl which can be converted into a [`CtOption`],
+/// i.e. either a [`CtOption`] or the output of an [`InvertInfallible`] impl.
+pub trait InvertOutput<T> {
+    /// Convert the output into a [`CtOption`].
+    fn into_ct_option(self) -> CtOption<T>;
+}
+
+impl<T> InvertOutput<T> for CtOption<T> {
+    fn into_ct_option(self) -> CtOption<T> {
+        self
+    }
+}
+
+impl<T: InvertInfallible> InvertOutput<T> for T {
+    fn into_ct_option(self) -> CtOption<T> {
+        CtOption::new(self, Choice::from(1))
+    }
+}
+
+/// Inversion with a uniform signature for fallible and infallible [`Invert`]
+/// impls, so generic code can be written over both e.g. scalars and
+/// non-zero scalars.
+///
+/// This is blanket impl'd for all types whose [`Invert::Output`] impls
+/// [`InvertOutput`].
+pub trait TryInvert: Sized {
+    /// Invert `self`, returning `None` if it isn't invertible.
+    fn try_invert(&self) -> CtOption<Self>;
+}
+
+impl<T> TryInvert for T
+where
+    T: Invert,
+    T::Output: InvertOutput<T>,
+{
+    fn try_invert(&self) -> CtOption<T> {
+        self.invert().into_ct_option()
+    }
+}
+
+/// Perform a batched inversion on a sequence of field elements (i.e. base
+/// field elements or scalars) at an amortized cost of a single inversion.
+pub trait InvertBatch<FieldElements: ?Sized> {
+    /// The output of batch inversion, e.g. an array or vector of field elements.
+    type Output;
+
+    /// Invert a batch of field elements in constant time.
+    ///
+    /// Returns `None` if any of the elements is zero.
+    fn invert_batch(field_elements: &FieldElements) -> CtOption<Self::Output>;
+}
+
+impl<const N: usize, T> InvertBatch<[T; N]> for T
+where
+    T: Invert<Output = CtOption<T>>
+        + ConditionallySelectable
+        + Default
+        + for<'a> Mul<&'a T, Output = T>,
+{
+    type Output = [T; N];
+
+    fn invert_batch(field_elements: &[T; N]) -> CtOption<[T; N]> {
+        let (inverses, is_some) = invert_batch_array(field_elements);
+        CtOption::new(inverses, is_some)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> InvertBatch<[T]> for T
+where
+    T: Invert<Output = CtOption<T>>
+        + ConditionallySelectable
+        + Default
+        + for<'a> Mul<&'a T, Output = T>,
+{
+    type Output = Vec<T>;
+
+    fn invert_batch(field_elements: &[T]) -> CtOption<Vec<T>> {
+        let mut products = vec![T::default(); field_elements.len()];
+        let mut inverses = vec![T::default(); field_elements.len()];
+        let is_some = invert_batch_internal(field_elements, &mut products, &mut inverses);
+        CtOption::new(inverses, is_some)
+    }
+}
+
+/// Invert every element yielded by `iter` using [`InvertBatch`], collecting
+/// the inverses into a [`Vec`].
+///
+/// Returns `None` if any of the elements is zero.
+#[cfg(feature = "alloc")]
+pub fn invert_all<T, I>(iter: I) -> CtOption<Vec<T>>
+where
+    I: IntoIterator<Item = T>,
+    T: Invert<Output = CtOption<T>>
+        + ConditionallySelectable
+        + Default
+        + for<'a> Mul<&'a T, Output = T>,
+{
+    let field_elements = iter.into_iter().collect::<Vec<_>>();
+    T::invert_batch(field_elements.as_slice())
+}
+
+/// Invert a slice of field elements using [`InvertBatch`], inverting chunks of
+/// at least `threshold` elements in parallel when the `rayon` feature is
+/// enabled.
+///
+/// Slices shorter than `threshold` are inverted serially, as are all slices
+/// when the `rayon` feature is disabled. Either way the inverses are identical
+/// to those computed by [`InvertBatch::invert_batch`].
+///
+/// Returns `None` if any of the elements is zero.
+#[cfg(feature = "alloc")]
+pub fn batch_invert_par<T>(field_elements: &[T], threshold: usize) -> CtOption<Vec<T>>
+where
+    T: Invert<Output = CtOption<T>>
+        + ConditionallySelectable
+        + Default
+        + Send
+        + Sync
+        + for<'a> Mul<&'a T, Output = T>,
+{
+    #[cfg(feature = "rayon")]
+    if let Some(chunk_len) = par_chunk_len(field_elements.len(), threshold) {
+        let mut inverses = vec![T::default(); field_elements.len()];
+        let is_some = inverses
+            .par_chunks_mut(chunk_len)
+            .zip(field_elements.par_chunks(chunk_len))
+            .map(|(inverses, chunk)| {
+                let mut products = vec![T::default(); chunk.len()];
+                invert_batch_internal(chunk, &mut products, inverses)
+            })
+            .reduce(|| Choice::from(1), |a, b| a & b);
+
+        return CtOption::new(inverses, is_some);
+    }
+
+    #[cfg(not(feature = "rayon"))]
+    let _ = threshold;
+
+    T::invert_batch(field_elements)
+}
+
+/// Length of the chunks to split `len` elements into so that each thread of
+/// the current rayon pool gets one, or `None` if there are fewer than
+/// `threshold` elements and they should be processed serially.
+///
+/// Chunks are never shorter than `threshold`.
+#[cfg(feature = "rayon")]
+#[allow(clippy::integer_arithmetic)]
+pub(crate) fn par_chunk_len(len: usize, threshold: usize) -> Option<usize> {
+    let threshold = threshold.max(1);
+    let threads = rayon::current_num_threads();
+
+    if len < threshold || threads == 1 {
+        return None;
+    }
+
+    Some(threshold.max((len + threads - 1) / threads))
+}
+
+/// Iterator adapter for [`invert_all`].
+#[cfg(feature = "alloc")]
+pub trait CollectInvert: Iterator + Sized
+where
+    Self::Item: Invert<Output = CtOption<Self::Item>>
+        + ConditionallySelectable
+        + Default
+        + for<'a> Mul<&'a Self::Item, Output = Self::Item>,
+{
+    /// Collect the inverses of all elements of this iterator, sharing a single
+    /// inversion across all of them.
+    ///
+    /// Returns `None` if any of the elements is zero.
+    fn collect_invert(self) -> CtOption<Vec<Self::Item>> {
+        invert_all(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I> CollectInvert for I
+where
+    I: Iterator,
+    I::Item: Invert<Output = CtOption<I::Item>>
+        + ConditionallySelectable
+        + Default
+        + for<'a> Mul<&'a I::Item, Output = I::Item>,
+{
+}
+
+/// Batch invert an array, returning the inverses along with a [`Choice`]
+/// which is set iff all of the elements were non-zero.
+fn invert_batch_array<const N: usize, T>(field_elements: &[T; N]) -> ([T; N], Choice)
+where
+    T: Invert<Output = CtOption<T>>
+        + ConditionallySelectable
+        + Default
+        + for<'a> Mul<&'a T, Output = T>,
+{
+    let mut products = [T::default(); N];
+    let mut inverses = [T::default(); N];
+    let is_some = invert_batch_internal(field_elements, &mut products, &mut inverses);
+    (inverses, is_some)
+}
+
+/// Montgomery's trick: invert the product of all of the elements once, then
+/// recover the individual inverses using the running products.
+fn invert_batch_internal<T>(field_elements: &[T], products: &mut [T], inverses: &mut [T]) -> Choice
+where
+    T: Invert<Output = CtOption<T>>
+        + ConditionallySelectable
+        + Default
+        + for<'a> Mul<&'a T, Output = T>,
+{
+    let (first, rest) = match field_elements.split_first() {
+        Some(split) => split,
+        None => return Choice::from(1),
+    };
+
+    let mut acc = *first;
+    products[0] = acc;
+
+    for (product, fe) in products[1..].iter_mut().zip(rest) {
+        acc = acc * fe;
+        *product = acc;
+    }
+
+    let acc_inv = acc.invert();
+    let is_some = acc_inv.is_some();
+    let mut acc = acc_inv.unwrap_or(T::default());
+
+    for i in (1..field_elements.len()).rev() {
+        inverses[i] = acc * &products[i - 1];
+        acc = acc * &field_elements[i];
+    }
+
+    inverses[0] = acc;
+    is_some
+}
+
+/// Mixed addition of a projective and an affine point.
+///
+/// This trait enables crates to provide a dedicated mixed addition formula
+/// (i.e. one exploiting `Z2 = 1`), or otherwise provides a default which lifts
+/// the affine point and uses projective addition.
+pub trait AddMixed<Affine: Copy>: Copy + From<Affine> + Add<Output = Self> {
+    /// Calculates `self + affine`.
+    fn add_mixed(&self, affine: &Affine) -> Self {
+        *self + Self::from(*affine)
+    }
+
+    /// Calculates `self - affine`.
+    ///
+    /// The provided impl negates the affine point, which only flips its
+    /// y-coordinate, and uses [`AddMixed::add_mixed`].
+    fn sub_mixed(&self, affine: &Affine) -> Self
+    where
+        Affine: Neg<Output = Affine>,
+    {
+        self.add_mixed(&-*affine)
+    }
+}
+
+/// Sum `points` with a pairwise (tree) reduction, which keeps the partial
+/// sums balanced rather than accumulating into a single point.
+///
+/// This needs no allocation, storing one partial sum per level of the tree.
+/// Summing an empty iterator yields the identity.
+pub fn sum_tree<P, I>(points: I) -> P
+where
+    P: Group,
+    I: IntoIterator<Item = P>,
+{
+    let mut tree = SumTree::new();
+    points.into_iter().for_each(|point| tree.push(point));
+    tree.finish()
+}
+
+/// Sum affine `points` with a pairwise (tree) reduction as in [`sum_tree`],
+/// using [`AddMixed::add_mixed`] for the leaves of the tree, i.e. adding
+/// pairs of affine points.
+pub fn sum_tree_mixed<P, A, I>(points: I) -> P
+where
+    P: Group + AddMixed<A>,
+    A: Copy,
+    I: IntoIterator<Item = A>,
+{
+    let mut tree = SumTree::new();
+    let mut points = points.into_iter();
+
+    while let Some(a) = points.next() {
+        tree.push(match points.next() {
+            Some(b) => P::from(a).add_mixed(&b),
+            None => P::from(a),
+        });
+    }
+
+    tree.finish()
+}
+
+/// Partial sums of a pairwise reduction, where level `i` holds the sum of
+/// `2^i` consecutive inputs if bit `i` of `len` is set.
+struct SumTree<P> {
+    levels: [P; 64],
+    len: u64,
+}
+
+impl<P: Group> SumTree<P> {
+    fn new() -> Self {
+        Self {
+            levels: [P::identity(); 64],
+            len: 0,
+        }
+    }
+
+    #[allow(clippy::integer_arithmetic)]
+    fn push(&mut self, point: P) {
+        let mut carry = point;
+        let mut level = 0;
+
+        while self.len & (1 << level) != 0 {
+            carry = self.levels[level] + carry;
+            level += 1;
+        }
+
+        self.levels[level] = carry;
+        self.len = self.len.wrapping_add(1);
+    }
+
+    #[allow(clippy::integer_arithmetic)]
+    fn finish(self) -> P {
+        self.levels
+            .iter()
+            .enumerate()
+            .filter(|(level, _)| self.len & (1 << level) != 0)
+            .fold(P::identity(), |acc, (_, partial)| *partial + acc)
+    }
+}
+
+/// Constant-time conditional addition.
+///
+/// Useful for Montgomery ladders and masked accumulation, where whether a
+/// term is added must not be revealed by branching.
+pub trait ConditionalAdd: Group + ConditionallySelectable {
+    /// Calculates `self + rhs` if `choice` is set, or returns `self`
+    /// otherwise.
+    ///
+    /// The addition is always performed, with the result selected afterwards.
+    fn conditional_add(&self, rhs: &Self, choice: Choice) -> Self {
+        Self::conditional_select(self, &(*self + rhs), choice)
+    }
 }
 
+impl<T: Group + ConditionallySelectable> ConditionalAdd for T {}
+
 /// Linear combination.
 ///
 /// This trait enables crates to provide an optimized implementation of
@@ -38,6 +496,551 @@ pub trait LinearCombination: Group {
     }
 }
 
+/// Multi-scalar multiplication, i.e. computing `Σ points[i] * scalars[i]`.
+///
+/// This trait enables crates to provide optimized implementations (e.g.
+/// Pippenger's or Straus' algorithm), or otherwise provides default
+/// non-optimized implementations.
+pub trait MultiScalarMul: CurveArithmetic {
+    /// Calculates `Σ points[i] * scalars[i]` in constant time.
+    ///
+    /// # Panics
+    ///
+    /// If `points` and `scalars` have different lengths.
+    fn msm(points: &[Self::AffinePoint], scalars: &[Self::Scalar]) -> Self::ProjectivePoint {
+        assert_eq!(points.len(), scalars.len(), "length mismatch");
+
+        points
+            .iter()
+            .zip(scalars)
+            .map(|(point, scalar)| Self::ProjectivePoint::from(*point) * scalar)
+            .sum()
+    }
+
+    /// Calculates `Σ points[i] * scalars[i]` in constant time for a fixed
+    /// number of terms, without allocating.
+    ///
+    /// This uses Straus' algorithm with a table of multiples of each point
+    /// sized at compile time, sharing the doublings across all the terms.
+    /// The tables live on the stack, so this is meant for small `N`.
+    fn msm_array<const N: usize>(
+        points: &[Self::AffinePoint; N],
+        scalars: &[Self::Scalar; N],
+    ) -> Self::ProjectivePoint {
+        let mut tables = [[Self::ProjectivePoint::identity(); 1 << MSM_ARRAY_WINDOW_BITS]; N];
+        for (table, point) in tables.iter_mut().zip(points) {
+            mul::fill_table::<Self>(&(*point).into(), table);
+        }
+
+        let mut digits: [_; N] =
+            core::array::from_fn(|i| mul::digits::<Self>(&scalars[i], MSM_ARRAY_WINDOW_BITS));
+        let mut acc = Self::ProjectivePoint::identity();
+
+        for _ in 0..mul::window_count::<Self>(MSM_ARRAY_WINDOW_BITS) {
+            for _ in 0..MSM_ARRAY_WINDOW_BITS {
+                acc = acc.double();
+            }
+
+            for (table, digits) in tables.iter().zip(digits.iter_mut()) {
+                acc += mul::select(table, digits.next().unwrap_or(0));
+            }
+        }
+
+        acc
+    }
+
+    /// Calculates `Σ points[i] * scalars[i]` in variable time.
+    ///
+    /// ⚠️ WARNING!
+    ///
+    /// This function may branch on the values of both the points and the
+    /// scalars and is therefore **NOT** constant-time. It must only be used
+    /// when all of the inputs are public, e.g. when verifying signatures.
+    ///
+    /// # Panics
+    ///
+    /// If `points` and `scalars` have different lengths.
+    fn msm_vartime(
+        points: &[Self::AffinePoint],
+        scalars: &[Self::Scalar],
+    ) -> Self::ProjectivePoint {
+        assert_eq!(points.len(), scalars.len(), "length mismatch");
+        let mut acc = Self::ProjectivePoint::identity();
+
+        for (point, scalar) in points.iter().zip(scalars) {
+            if ff::Field::is_zero(scalar).into() {
+                continue;
+            }
+
+            let uint: Self::Uint = (*scalar).into();
+            let limbs = uint.as_ref();
+            let mut term = Self::ProjectivePoint::identity();
+
+            for i in (0..Self::Uint::BITS).rev() {
+                term = term.double();
+
+                if bit_vartime(limbs, i) {
+                    term += point;
+                }
+            }
+
+            acc += term;
+        }
+
+        acc
+    }
+
+    /// Calculates `s * G + e * P`, where `G` is the generator.
+    ///
+    /// This is the shape of many signature verification equations. The
+    /// default implementation uses [`MulByGenerator`] for the first term, but
+    /// curves can override it to interleave a precomputed generator table
+    /// with a variable-base multiplication of `P`.
+    fn lincomb_generator(
+        s: &Self::Scalar,
+        p: &Self::AffinePoint,
+        e: &Self::Scalar,
+    ) -> Self::ProjectivePoint {
+        Self::ProjectivePoint::mul_by_generator(s) + Self::ProjectivePoint::from(*p) * e
+    }
+
+    /// Checks whether `Σ points[i] * scalars[i]` is the identity in variable
+    /// time.
+    ///
+    /// This is the core of batch verification, which doesn't need the sum
+    /// itself. The default implementation computes [`MultiScalarMul::msm_vartime`],
+    /// but curves can override it with cheaper checks, e.g. comparing two
+    /// halves of the sum or using precomputed negations.
+    ///
+    /// ⚠️ WARNING!
+    ///
+    /// This function may branch on the values of both the points and the
+    /// scalars and is therefore **NOT** constant-time. It must only be used
+    /// when all of the inputs are public, e.g. when verifying signatures.
+    ///
+    /// # Panics
+    ///
+    /// If `points` and `scalars` have different lengths.
+    fn lincomb_is_identity_vartime(points: &[Self::AffinePoint], scalars: &[Self::Scalar]) -> bool {
+        Self::msm_vartime(points, scalars).is_identity().into()
+    }
+
+    /// Checks whether `Σ lhs[i].0 * lhs[i].1 == rhs` in constant time, e.g.
+    /// `a * G + b * Q == R` when verifying signatures.
+    ///
+    /// This computes `Σ lhs[i].0 * lhs[i].1 - rhs` and compares it against
+    /// the identity, without normalizing either side to affine coordinates.
+    fn lincomb_eq(
+        lhs: &[(Self::ProjectivePoint, Self::Scalar)],
+        rhs: &Self::ProjectivePoint,
+    ) -> Choice {
+        lhs.iter()
+            .fold(-*rhs, |acc, (point, scalar)| acc + *point * scalar)
+            .is_identity()
+    }
+
+    /// Checks whether `Σ lhs[i].0 * lhs[i].1 == rhs` in variable time, like
+    /// [`MultiScalarMul::lincomb_eq`].
+    ///
+    /// The terms are processed in groups of up to four, whose scalars are
+    /// converted to integers once up front and which share their doublings,
+    /// as in Straus' algorithm.
+    ///
+    /// ⚠️ WARNING!
+    ///
+    /// This function may branch on the values of both the points and the
+    /// scalars and is therefore **NOT** constant-time. It must only be used
+    /// when all of the inputs are public, e.g. when verifying signatures.
+    fn lincomb_eq_vartime(
+        lhs: &[(Self::ProjectivePoint, Self::Scalar)],
+        rhs: &Self::ProjectivePoint,
+    ) -> bool {
+        let mut acc = -*rhs;
+
+        for terms in lhs.chunks(4) {
+            let mut uints = [Self::Uint::ZERO; 4];
+            for (uint, (_, scalar)) in uints.iter_mut().zip(terms) {
+                *uint = (*scalar).into();
+            }
+
+            let mut sum = Self::ProjectivePoint::identity();
+
+            for i in (0..Self::Uint::BITS).rev() {
+                sum = sum.double();
+
+                for ((point, _), uint) in terms.iter().zip(&uints) {
+                    if bit_vartime(uint.as_ref(), i) {
+                        sum += point;
+                    }
+                }
+            }
+
+            acc += sum;
+        }
+
+        acc.is_identity().into()
+    }
+
+    /// Calculates `Σ points[i] * scalars[i]` in variable time using the
+    /// curve's [`Endomorphism`].
+    ///
+    /// Each scalar is decomposed into two halves, and Pippenger's algorithm
+    /// is run over `points` and their images under the endomorphism with
+    /// windows covering only [`Endomorphism::DECOMPOSED_BITS`] bits.
+    ///
+    /// ⚠️ WARNING!
+    ///
+    /// Bucket selection depends on the scalar values, so this is **NOT**
+    /// constant-time. It must only be used with public inputs.
+    ///
+    /// # Panics
+    ///
+    /// If `points` and `scalars` have different lengths.
+    #[cfg(feature = "alloc")]
+    fn msm_glv(points: &[Self::AffinePoint], scalars: &[Self::Scalar]) -> Self::ProjectivePoint
+    where
+        Self: Endomorphism,
+    {
+        assert_eq!(points.len(), scalars.len(), "length mismatch");
+        let mut acc = MsmAccumulator::<Self>::with_bits(Self::DECOMPOSED_BITS);
+
+        for (point, scalar) in points.iter().zip(scalars) {
+            let [(k1, k1_neg), (k2, k2_neg)] = Self::decompose(scalar);
+
+            let mut p1 = *point;
+            p1.conditional_negate(k1_neg);
+            acc.add(&p1, &k1);
+
+            let mut p2 = Self::endomorphism(point);
+            p2.conditional_negate(k2_neg);
+            acc.add(&p2, &k2);
+        }
+
+        acc.finalize()
+    }
+
+    /// Calculates `Σ points[i] * scalars[i]` in constant time, splitting the
+    /// inputs into chunks of at least `threshold` terms which are computed in
+    /// parallel with [`MultiScalarMul::msm`] when the `rayon` feature is
+    /// enabled.
+    ///
+    /// Inputs shorter than `threshold` are computed serially, as are all
+    /// inputs when the `rayon` feature is disabled. Either way the result is
+    /// equal to that of [`MultiScalarMul::msm`].
+    ///
+    /// # Panics
+    ///
+    /// If `points` and `scalars` have different lengths.
+    #[cfg(feature = "alloc")]
+    fn msm_par(
+        points: &[Self::AffinePoint],
+        scalars: &[Self::Scalar],
+        threshold: usize,
+    ) -> Self::ProjectivePoint {
+        assert_eq!(points.len(), scalars.len(), "length mismatch");
+
+        #[cfg(feature = "rayon")]
+        if let Some(chunk_len) = par_chunk_len(points.len(), threshold) {
+            return points
+                .par_chunks(chunk_len)
+                .zip(scalars.par_chunks(chunk_len))
+                .map(|(points, scalars)| Self::msm(points, scalars))
+                .reduce(Self::ProjectivePoint::identity, |a, b| a + b);
+        }
+
+        #[cfg(not(feature = "rayon"))]
+        let _ = threshold;
+
+        Self::msm(points, scalars)
+    }
+
+    /// Calculates `Σ points[i] * scalars[i]` in variable time, accumulating
+    /// chunks of at least `threshold` terms into per-thread
+    /// [`MsmAccumulator`] buckets in parallel when the `rayon` feature is
+    /// enabled.
+    ///
+    /// Inputs shorter than `threshold` are computed serially with
+    /// [`MultiScalarMul::msm_vartime`], as are all inputs when the `rayon`
+    /// feature is disabled. Either way the result is equal to that of
+    /// [`MultiScalarMul::msm_vartime`].
+    ///
+    /// ⚠️ WARNING!
+    ///
+    /// This function is **NOT** constant-time. It must only be used with
+    /// public inputs.
+    ///
+    /// # Panics
+    ///
+    /// If `points` and `scalars` have different lengths.
+    #[cfg(feature = "alloc")]
+    fn msm_vartime_par(
+        points: &[Self::AffinePoint],
+        scalars: &[Self::Scalar],
+        threshold: usize,
+    ) -> Self::ProjectivePoint {
+        assert_eq!(points.len(), scalars.len(), "length mismatch");
+
+        #[cfg(feature = "rayon")]
+        if let Some(chunk_len) = par_chunk_len(points.len(), threshold) {
+            return points
+                .par_chunks(chunk_len)
+                .zip(scalars.par_chunks(chunk_len))
+                .map(|(points, scalars)| {
+                    let mut acc = MsmAccumulator::<Self>::new();
+
+                    for (point, scalar) in points.iter().zip(scalars) {
+                        acc.add(point, scalar);
+                    }
+
+                    acc
+                })
+                .reduce(MsmAccumulator::new, MsmAccumulator::merge)
+                .finalize();
+        }
+
+        #[cfg(not(feature = "rayon"))]
+        let _ = threshold;
+
+        Self::msm_vartime(points, scalars)
+    }
+}
+
+/// Batch variable-base multiplication returning the individual products,
+/// i.e. many points times one scalar or one point times many scalars.
+///
+/// The provided implementations use the fixed-window method of
+/// [`mul::ct_mul`], are constant-time with respect to the scalars, and only
+/// do the work depending on the common operand once.
+#[cfg(feature = "alloc")]
+pub trait MulBatch: CurveArithmetic {
+    /// Calculates `points[i] * scalar` for each point.
+    ///
+    /// The provided impl splits `scalar` into windows once.
+    fn mul_batch_same_scalar(
+        points: &[Self::ProjectivePoint],
+        scalar: &Self::Scalar,
+    ) -> Vec<Self::ProjectivePoint> {
+        let digits =
+            Zeroizing::new(mul::digits::<Self>(scalar, BATCH_WINDOW_BITS).collect::<Vec<_>>());
+        let mut table = [Self::ProjectivePoint::identity(); 1 << BATCH_WINDOW_BITS];
+
+        points
+            .iter()
+            .map(|point| {
+                mul::fill_table::<Self>(point, &mut table);
+                mul::mul_with_table::<Self>(&table, BATCH_WINDOW_BITS, digits.iter().copied())
+            })
+            .collect()
+    }
+
+    /// Calculates `point * scalars[i]` for each scalar.
+    ///
+    /// The provided impl precomputes the table of multiples of `point` once.
+    fn mul_batch_same_point(
+        point: &Self::ProjectivePoint,
+        scalars: &[Self::Scalar],
+    ) -> Vec<Self::ProjectivePoint> {
+        let mut table = [Self::ProjectivePoint::identity(); 1 << BATCH_WINDOW_BITS];
+        mul::fill_table::<Self>(point, &mut table);
+
+        scalars
+            .iter()
+            .map(|scalar| {
+                let digits = mul::digits::<Self>(scalar, BATCH_WINDOW_BITS);
+                mul::mul_with_table::<Self>(&table, BATCH_WINDOW_BITS, digits)
+            })
+            .collect()
+    }
+
+    /// Calculates `G * scalar` for each scalar of a stream, where `G` is the
+    /// generator, without collecting the scalars first.
+    ///
+    /// The provided impl precomputes a single [`GeneratorComb`] table which is
+    /// reused for the whole stream.
+    ///
+    /// [`GeneratorComb`]: crate::precompute::comb::GeneratorComb
+    fn mul_by_generator_iter<I>(scalars: I) -> MulByGeneratorIter<Self, I::IntoIter>
+    where
+        I: IntoIterator<Item = Self::Scalar>,
+    {
+        MulByGeneratorIter::new(scalars.into_iter())
+    }
+
+    /// Calculates `points[i] * scalar` for each point using a Montgomery
+    /// ladder, collecting the products into `B`.
+    ///
+    /// The provided impl steps all of the ladders in lockstep, so the bits of
+    /// `scalar` are only extracted once and every point follows the same
+    /// schedule of one addition and one doubling per bit.
+    fn mul_uniform_batch<B: FromIterator<Self::ProjectivePoint>>(
+        points: &[Self::AffinePoint],
+        scalar: &Self::Scalar,
+    ) -> B {
+        let bits = Zeroizing::new(mul::digits::<Self>(scalar, 1).collect::<Vec<_>>());
+        let mut r0 = vec![Self::ProjectivePoint::identity(); points.len()];
+        let mut r1 = points
+            .iter()
+            .copied()
+            .map(Self::ProjectivePoint::from)
+            .collect::<Vec<_>>();
+
+        for bit in bits.iter() {
+            let swap = bit.ct_eq(&1);
+
+            for (a, b) in r0.iter_mut().zip(r1.iter_mut()) {
+                Self::ProjectivePoint::conditional_swap(a, b, swap);
+                *b += *a;
+                *a = a.double();
+                Self::ProjectivePoint::conditional_swap(a, b, swap);
+            }
+        }
+
+        r0.into_iter().collect()
+    }
+}
+
+/// Width of a [`MultiScalarMul::msm_array`] window in bits.
+const MSM_ARRAY_WINDOW_BITS: usize = 4;
+
+/// Width of a [`MulBatch`] window in bits.
+#[cfg(feature = "alloc")]
+const BATCH_WINDOW_BITS: usize = 4;
+
+/// Efficiently computable endomorphism `φ(P) = λ * P`, as used by the GLV
+/// method (e.g. on secp256k1).
+///
+/// Splitting a scalar `k` into `k1 + k2 * λ` with `k1` and `k2` of roughly
+/// half the bit length allows scalar multiplications to be computed with half
+/// as many doublings.
+pub trait Endomorphism: CurveArithmetic {
+    /// Upper bound on the bit length of the halves returned by
+    /// [`Endomorphism::decompose`].
+    const DECOMPOSED_BITS: usize;
+
+    /// Compute `φ(point) = λ * point`.
+    fn endomorphism(point: &Self::AffinePoint) -> Self::AffinePoint;
+
+    /// Decompose `scalar` into `k1 + k2 * λ`.
+    ///
+    /// Each half is returned as its absolute value, which must be at most
+    /// [`Endomorphism::DECOMPOSED_BITS`] bits long, along with a flag which
+    /// is set if the half is negative.
+    fn decompose(scalar: &Self::Scalar) -> [(Self::Scalar, Choice); 2];
+}
+
+/// Streaming variable-time multi-scalar multiplication.
+///
+/// Accumulates `(point, scalar)` pairs one at a time into Pippenger-style
+/// buckets, so that `(point, scalar)` pairs don't need to be collected into
+/// slices first. [`MsmAccumulator::finalize`] computes `Σ points[i] * scalars[i]`.
+///
+/// ⚠️ WARNING!
+///
+/// Bucket selection depends on the scalar values, so this is **NOT**
+/// constant-time. It must only be used with public inputs.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct MsmAccumulator<C: CurveArithmetic> {
+    /// Buckets for each window, indexed by `window * MSM_BUCKETS + digit - 1`.
+    buckets: Vec<C::ProjectivePoint>,
+}
+
+/// Width of an [`MsmAccumulator`] window in bits.
+#[cfg(feature = "alloc")]
+const MSM_WINDOW_BITS: usize = 4;
+
+/// Number of non-zero buckets per [`MsmAccumulator`] window.
+#[cfg(feature = "alloc")]
+const MSM_BUCKETS: usize = (1 << MSM_WINDOW_BITS) - 1;
+
+#[cfg(feature = "alloc")]
+#[allow(clippy::integer_arithmetic)]
+impl<C: CurveArithmetic> MsmAccumulator<C> {
+    /// Create a new, empty accumulator.
+    pub fn new() -> Self {
+        Self::with_bits(C::Uint::BITS)
+    }
+
+    /// Create a new, empty accumulator for scalars of at most `bits` bits.
+    fn with_bits(bits: usize) -> Self {
+        let windows = (bits + MSM_WINDOW_BITS - 1) / MSM_WINDOW_BITS;
+
+        Self {
+            buckets: vec![C::ProjectivePoint::identity(); windows * MSM_BUCKETS],
+        }
+    }
+
+    /// Accumulate `point * scalar`.
+    pub fn add(&mut self, point: &C::AffinePoint, scalar: &C::Scalar) {
+        let uint: C::Uint = (*scalar).into();
+        let limbs = uint.as_ref();
+
+        for (window, buckets) in self.buckets.chunks_mut(MSM_BUCKETS).enumerate() {
+            let digit = (0..MSM_WINDOW_BITS)
+                .filter(|&j| bit_vartime(limbs, window * MSM_WINDOW_BITS + j))
+                .fold(0, |digit, j| digit | (1 << j));
+
+            if digit != 0 {
+                buckets[digit - 1] += point;
+            }
+        }
+    }
+
+    /// Merge the terms accumulated by `other` into this accumulator, e.g. to
+    /// combine accumulators filled on different threads.
+    ///
+    /// # Panics
+    ///
+    /// If the accumulators were created for scalars of different sizes.
+    #[must_use]
+    pub fn merge(mut self, other: Self) -> Self {
+        assert_eq!(self.buckets.len(), other.buckets.len(), "size mismatch");
+
+        for (bucket, other) in self.buckets.iter_mut().zip(&other.buckets) {
+            *bucket += other;
+        }
+
+        self
+    }
+
+    /// Compute the sum of all accumulated terms.
+    pub fn finalize(self) -> C::ProjectivePoint {
+        let mut acc = C::ProjectivePoint::identity();
+
+        for buckets in self.buckets.chunks(MSM_BUCKETS).rev() {
+            for _ in 0..MSM_WINDOW_BITS {
+                acc = acc.double();
+            }
+
+            // Computes `Σ (i + 1) * buckets[i]` using running sums
+            let mut running = C::ProjectivePoint::identity();
+
+            for bucket in buckets.iter().rev() {
+                running += bucket;
+                acc += running;
+            }
+        }
+
+        acc
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<C: CurveArithmetic> Default for MsmAccumulator<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Get bit `i` of a little endian limb slice, returning `false` if out of range.
+#[allow(clippy::integer_arithmetic)]
+fn bit_vartime(limbs: &[Limb], i: usize) -> bool {
+    limbs
+        .get(i / Limb::BITS)
+        .map(|limb| (limb.0 >> (i % Limb::BITS)) & 1 == 1)
+        .unwrap_or(false)
+}
+
 /// Multiplication by the generator.
 ///
 /// May use optimizations (e.g. precomputed tables) when available.
@@ -48,6 +1051,19 @@ pub trait MulByGenerator: Group {
     fn mul_by_generator(scalar: &Self::Scalar) -> Self {
         Self::generator() * scalar
     }
+
+    /// Multiply by the generator of the prime-order subgroup, splitting the
+    /// scalar into two random shares `k = (k - r) + r` to hinder side-channel
+    /// attacks on fixed-base multiplication such as template attacks.
+    ///
+    /// The default implementation performs two calls to
+    /// [`MulByGenerator::mul_by_generator`] and an addition. Curves may
+    /// override it with a faster or stronger blinding scheme.
+    #[must_use]
+    fn mul_by_generator_blinded(scalar: &Self::Scalar, rng: &mut impl CryptoRngCore) -> Self {
+        let mask = <Self::Scalar as ff::Field>::random(&mut *rng);
+        Self::mul_by_generator(&(*scalar - mask)) + Self::mul_by_generator(&mask)
+    }
 }
 
 /// Modular reduction.
@@ -59,7 +1075,41 @@ pub trait Reduce<Uint: Integer>: Sized {
     fn reduce(n: Uint) -> Self;
 
     /// Interpret the given bytes as an integer and perform a modular reduction.
+    ///
+    /// For curve scalars and field elements the bytes are big endian, i.e.
+    /// this is equivalent to [`Reduce::reduce_bytes_be`].
     fn reduce_bytes(bytes: &Self::Bytes) -> Self;
+
+    /// Interpret the given bytes as a big endian integer and perform a modular
+    /// reduction.
+    fn reduce_bytes_be(bytes: &Self::Bytes) -> Self {
+        Self::reduce_bytes(bytes)
+    }
+
+    /// Interpret the given bytes as a little endian integer and perform a
+    /// modular reduction.
+    fn reduce_bytes_le(bytes: &Self::Bytes) -> Self
+    where
+        Self::Bytes: AsMut<[u8]> + Clone + Zeroize,
+    {
+        let mut bytes = Zeroizing::new(bytes.clone());
+        bytes.as_mut().reverse();
+        Self::reduce_bytes(&bytes)
+    }
+
+    /// Perform a modular reduction, also returning a [`Choice`] which is set
+    /// iff `n` was already reduced, i.e. less than the modulus.
+    ///
+    /// This allows strict parsers to reject non-canonical encodings while
+    /// still obtaining the reduced value.
+    fn reduce_checked(n: Uint) -> (Self, Choice)
+    where
+        Self: Copy + Into<Uint>,
+    {
+        let reduced = Self::reduce(n);
+        let is_canonical = reduced.into().ct_eq(&n);
+        (reduced, is_canonical)
+    }
 }
 
 /// Modular reduction to a non-zero output.
@@ -77,3 +1127,622 @@ pub trait ReduceNonZero<Uint: Integer>: Reduce<Uint> + Sized {
     /// to a non-zero output.
     fn reduce_nonzero_bytes(bytes: &Self::Bytes) -> Self;
 }
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::{
+        batch_invert_par, sum_tree, sum_tree_mixed, AddMixed, ConditionalAdd, Endomorphism, Invert,
+        InvertBatch, MsmAccumulator, MulBatch, MulByGenerator, MultiScalarMul, Reduce,
+    };
+    use crate::{
+        bigint::{ArrayEncoding, U256},
+        dev::{AffinePoint, FieldBytes, MockCurve, NonZeroScalar, ProjectivePoint, Scalar},
+        point::ToAffineBatch,
+    };
+    use alloc::vec::Vec;
+    use ff::Field;
+    use group::{Curve, Group};
+    use rand_core::{OsRng, RngCore};
+    use subtle::ConditionallyNegatable;
+
+    /// Deterministic xorshift RNG, for reproducible tests only.
+    struct XorShiftRng(u64);
+
+    impl RngCore for XorShiftRng {
+        fn next_u32(&mut self) -> u32 {
+            (self.next_u64() >> 32) as u32
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            rand_core::impls::fill_bytes_via_next(self, dest)
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    impl rand_core::CryptoRng for XorShiftRng {}
+
+    #[test]
+    fn sum_projective() {
+        let g = ProjectivePoint::generator();
+        assert_eq!(
+            [g, g, g].iter().sum::<ProjectivePoint>(),
+            g * Scalar::from(3u64)
+        );
+        assert_eq!(
+            [g, g, g].into_iter().sum::<ProjectivePoint>(),
+            g * Scalar::from(3u64)
+        );
+        assert_eq!(
+            core::iter::empty::<ProjectivePoint>().sum::<ProjectivePoint>(),
+            ProjectivePoint::IDENTITY
+        );
+    }
+
+    #[test]
+    fn sum_tree_matches_fold() {
+        let points: Vec<_> = (1..=70u64)
+            .map(|i| ProjectivePoint::generator() * Scalar::from(i * i))
+            .collect();
+        let affine: Vec<AffinePoint> = points.iter().map(|point| point.to_affine()).collect();
+
+        for n in 0..points.len() {
+            let expected = points[..n]
+                .iter()
+                .fold(ProjectivePoint::IDENTITY, |acc, point| acc + point);
+            assert_eq!(sum_tree(points[..n].iter().copied()), expected);
+            assert_eq!(
+                sum_tree_mixed::<ProjectivePoint, _, _>(affine[..n].iter().copied()),
+                expected
+            );
+            assert_eq!(affine[..n].iter().sum::<ProjectivePoint>(), expected);
+        }
+    }
+
+    #[test]
+    fn reduce_bytes_endianness() {
+        // Larger than the order, so the reduction is exercised
+        let n = U256::MAX.wrapping_sub(&U256::from_u64(42));
+        let be = n.to_be_byte_array();
+        let le = FieldBytes::from(n.to_le_byte_array());
+
+        let expected = Scalar::reduce(n);
+        assert_eq!(Scalar::reduce_bytes(&be), expected);
+        assert_eq!(Scalar::reduce_bytes_be(&be), expected);
+        assert_eq!(Scalar::reduce_bytes_le(&le), expected);
+    }
+
+    #[test]
+    fn div() {
+        let a = Scalar::random(&mut OsRng);
+        let b = Scalar::random(&mut OsRng);
+
+        assert_eq!(a.div(&b).unwrap(), a * Invert::invert(&b).unwrap());
+        assert_eq!(a.div(&a).unwrap(), Scalar::ONE);
+        assert!(bool::from(a.div(&Scalar::ZERO).is_none()));
+    }
+
+    #[test]
+    fn try_invert() {
+        use super::TryInvert;
+        use crate::dev::NonZeroScalar;
+
+        fn invert_generic<T: TryInvert>(element: &T) -> Option<T> {
+            element.try_invert().into()
+        }
+
+        let scalar = NonZeroScalar::random(&mut OsRng);
+        assert_eq!(
+            *invert_generic(&scalar).unwrap(),
+            invert_generic(&*scalar).unwrap()
+        );
+        assert_eq!(*invert_generic(&scalar).unwrap() * *scalar, Scalar::ONE);
+        assert!(invert_generic(&Scalar::ZERO).is_none());
+    }
+
+    #[test]
+    fn invert_batch() {
+        let mut scalars: [Scalar; 8] = core::array::from_fn(|_| Scalar::random(&mut OsRng));
+        let inverses = Scalar::invert_batch(&scalars).unwrap();
+
+        for (scalar, inverse) in scalars.iter().zip(&inverses) {
+            assert_eq!(*inverse, Invert::invert(scalar).unwrap());
+        }
+
+        assert_eq!(
+            Scalar::invert_batch(&scalars[..]).unwrap(),
+            inverses.to_vec()
+        );
+        assert!(bool::from(
+            Scalar::invert_batch(&[] as &[Scalar; 0]).is_some()
+        ));
+
+        scalars[3] = Scalar::ZERO;
+        assert!(bool::from(Scalar::invert_batch(&scalars).is_none()));
+        assert!(bool::from(Scalar::invert_batch(&scalars[..]).is_none()));
+    }
+
+    #[test]
+    fn invert_all() {
+        use super::CollectInvert;
+
+        let mut scalars: [Scalar; 8] = core::array::from_fn(|_| Scalar::random(&mut OsRng));
+        let inverses = super::invert_all(scalars).unwrap();
+        assert_eq!(inverses.len(), scalars.len());
+
+        for (scalar, inverse) in scalars.iter().zip(&inverses) {
+            assert_eq!(*inverse, Invert::invert(scalar).unwrap());
+        }
+
+        assert_eq!(scalars.into_iter().collect_invert().unwrap(), inverses);
+        assert!(super::invert_all(core::iter::empty::<Scalar>())
+            .unwrap()
+            .is_empty());
+
+        scalars[5] = Scalar::ZERO;
+        assert!(bool::from(super::invert_all(scalars).is_none()));
+        assert!(bool::from(scalars.into_iter().collect_invert().is_none()));
+    }
+
+    #[test]
+    fn div_batch() {
+        let num: [Scalar; 8] = core::array::from_fn(|_| Scalar::random(&mut OsRng));
+        let mut den: [Scalar; 8] = core::array::from_fn(|_| Scalar::random(&mut OsRng));
+        let quotients = Scalar::div_batch(&num, &den).unwrap();
+
+        for ((n, d), q) in num.iter().zip(&den).zip(&quotients) {
+            assert_eq!(*q, n.div(d).unwrap());
+        }
+
+        den[5] = Scalar::ZERO;
+        assert!(bool::from(Scalar::div_batch(&num, &den).is_none()));
+    }
+
+    #[test]
+    fn invert_masked() {
+        for _ in 0..64 {
+            let x = NonZeroScalar::random(&mut OsRng);
+            let inverse = super::invert_masked::<MockCurve>(&x, &mut OsRng);
+            assert_eq!(*inverse, Invert::invert(&*x).unwrap());
+        }
+
+        let x = NonZeroScalar::random(&mut OsRng);
+        let mut rng = XorShiftRng(0x5eed);
+        let first = super::invert_masked::<MockCurve>(&x, &mut rng);
+        let state = rng.0;
+
+        let mut rng = XorShiftRng(0x5eed);
+        let second = super::invert_masked::<MockCurve>(&x, &mut rng);
+        assert_eq!(*first, *second);
+        assert_eq!(rng.0, state);
+    }
+
+    #[test]
+    fn invert_blinded() {
+        for _ in 0..16 {
+            let scalar = Scalar::random(&mut OsRng);
+            assert_eq!(
+                scalar.invert_blinded(&mut OsRng).unwrap(),
+                Invert::invert(&scalar).unwrap()
+            );
+        }
+
+        assert_eq!(Scalar::ONE.invert_blinded(&mut OsRng).unwrap(), Scalar::ONE);
+        assert!(bool::from(
+            Scalar::ZERO.invert_blinded(&mut OsRng).is_none()
+        ));
+    }
+
+    #[test]
+    fn sub_mixed() {
+        let p = ProjectivePoint::random(&mut OsRng);
+        let q = ProjectivePoint::random(&mut OsRng).to_affine();
+
+        assert_eq!(p.sub_mixed(&q), p + ProjectivePoint::from(-q));
+        assert_eq!(p.sub_mixed(&q), p - q);
+        assert!(bool::from(
+            ProjectivePoint::from(q).sub_mixed(&q).is_identity()
+        ));
+        assert_eq!(p.sub_mixed(&AffinePoint::IDENTITY), p);
+    }
+
+    #[test]
+    fn conditional_add() {
+        let p = ProjectivePoint::random(&mut OsRng);
+        let q = ProjectivePoint::random(&mut OsRng);
+
+        assert_eq!(p.conditional_add(&q, 1.into()), p + q);
+        assert_eq!(p.conditional_add(&q, 0.into()), p);
+        assert_eq!(ProjectivePoint::identity().conditional_add(&q, 1.into()), q);
+    }
+
+    #[test]
+    fn msm_vartime_matches_msm() {
+        let points: [AffinePoint; 8] =
+            core::array::from_fn(|_| ProjectivePoint::random(&mut OsRng).to_affine());
+        let mut scalars: [Scalar; 8] = core::array::from_fn(|_| Scalar::random(&mut OsRng));
+
+        let expected = points
+            .iter()
+            .zip(&scalars)
+            .fold(ProjectivePoint::identity(), |acc, (p, s)| {
+                acc + ProjectivePoint::from(*p) * s
+            });
+        assert_eq!(MockCurve::msm(&points, &scalars), expected);
+        assert_eq!(MockCurve::msm_vartime(&points, &scalars), expected);
+
+        scalars[0] = Scalar::ZERO;
+        scalars[5] = Scalar::ZERO;
+        assert_eq!(
+            MockCurve::msm_vartime(&points, &scalars),
+            MockCurve::msm(&points, &scalars)
+        );
+        assert_eq!(
+            MockCurve::msm_vartime(&[], &[]),
+            ProjectivePoint::identity()
+        );
+    }
+
+    #[test]
+    fn msm_array() {
+        fn check<const N: usize>() {
+            let points: [AffinePoint; N] =
+                core::array::from_fn(|_| ProjectivePoint::random(&mut OsRng).to_affine());
+            let scalars: [Scalar; N] = core::array::from_fn(|_| Scalar::random(&mut OsRng));
+
+            let expected = points
+                .iter()
+                .zip(&scalars)
+                .fold(ProjectivePoint::identity(), |acc, (p, s)| {
+                    acc + ProjectivePoint::from(*p) * s
+                });
+            assert_eq!(MockCurve::msm_array(&points, &scalars), expected);
+        }
+
+        check::<0>();
+        check::<1>();
+        check::<2>();
+        check::<5>();
+
+        let points = [
+            AffinePoint::IDENTITY,
+            ProjectivePoint::generator().to_affine(),
+        ];
+        let scalars = [Scalar::random(&mut OsRng), -Scalar::ONE];
+        assert_eq!(
+            MockCurve::msm_array(&points, &scalars),
+            -ProjectivePoint::generator()
+        );
+    }
+
+    #[test]
+    fn mul_batch() {
+        let mut points: Vec<ProjectivePoint> = (0..4)
+            .map(|_| ProjectivePoint::random(&mut OsRng))
+            .collect();
+        points.push(ProjectivePoint::identity());
+        let mut scalars: Vec<Scalar> = (0..4).map(|_| Scalar::random(&mut OsRng)).collect();
+        scalars.push(Scalar::ZERO);
+
+        for scalar in &scalars {
+            let expected: Vec<_> = points.iter().map(|p| *p * scalar).collect();
+            assert_eq!(MockCurve::mul_batch_same_scalar(&points, scalar), expected);
+        }
+
+        for point in &points {
+            let expected: Vec<_> = scalars.iter().map(|k| *point * k).collect();
+            assert_eq!(MockCurve::mul_batch_same_point(point, &scalars), expected);
+        }
+
+        let affine: Vec<AffinePoint> = points.iter().map(|p| p.to_affine()).collect();
+        for scalar in &scalars {
+            let expected: Vec<_> = points.iter().map(|p| *p * scalar).collect();
+            assert_eq!(
+                MockCurve::mul_uniform_batch::<Vec<_>>(&affine, scalar),
+                expected
+            );
+        }
+
+        let max = -Scalar::ONE;
+        let expected: Vec<_> = points.iter().map(|p| -*p).collect();
+        assert_eq!(
+            MockCurve::mul_uniform_batch::<Vec<_>>(&affine, &max),
+            expected
+        );
+        assert!(MockCurve::mul_uniform_batch::<Vec<_>>(&[], &max).is_empty());
+
+        assert!(MockCurve::mul_batch_same_scalar(&[], &Scalar::ONE).is_empty());
+        assert!(MockCurve::mul_batch_same_point(&points[0], &[]).is_empty());
+    }
+
+    #[test]
+    fn mul_by_generator_iter() {
+        let scalars = [
+            Scalar::ZERO,
+            Scalar::ONE,
+            -Scalar::ONE,
+            Scalar::from(0xdead_beef_u64),
+            Scalar::random(&mut OsRng),
+        ];
+        let expected = MockCurve::mul_batch_same_point(&ProjectivePoint::generator(), &scalars);
+
+        let iter = MockCurve::mul_by_generator_iter(scalars);
+        assert_eq!(iter.size_hint(), (scalars.len(), Some(scalars.len())));
+        assert_eq!(iter.collect::<Vec<_>>(), expected);
+        assert_eq!(MockCurve::mul_by_generator_iter([]).count(), 0);
+    }
+
+    #[test]
+    fn lincomb_generator() {
+        let g = ProjectivePoint::generator();
+        let p = ProjectivePoint::random(&mut OsRng).to_affine();
+        let s = Scalar::random(&mut OsRng);
+        let e = Scalar::random(&mut OsRng);
+
+        for (s, e) in [(s, e), (Scalar::ZERO, e), (s, Scalar::ZERO)] {
+            assert_eq!(
+                MockCurve::lincomb_generator(&s, &p, &e),
+                g * s + ProjectivePoint::from(p) * e
+            );
+        }
+
+        assert!(bool::from(
+            MockCurve::lincomb_generator(&Scalar::ZERO, &p, &Scalar::ZERO).is_identity()
+        ));
+    }
+
+    #[test]
+    fn lincomb_is_identity_vartime() {
+        let mut points: [AffinePoint; 8] =
+            core::array::from_fn(|_| ProjectivePoint::random(&mut OsRng).to_affine());
+        let mut scalars: [Scalar; 8] = core::array::from_fn(|_| Scalar::random(&mut OsRng));
+
+        // Choose the last term to cancel out the rest of the sum
+        let partial = MockCurve::msm(&points[..7], &scalars[..7]);
+        points[7] = (-partial).to_affine();
+        scalars[7] = Scalar::ONE;
+        assert!(MockCurve::lincomb_is_identity_vartime(&points, &scalars));
+
+        scalars[3] += Scalar::ONE;
+        assert!(!MockCurve::lincomb_is_identity_vartime(&points, &scalars));
+        assert!(MockCurve::lincomb_is_identity_vartime(&[], &[]));
+    }
+
+    #[test]
+    fn lincomb_eq() {
+        let g = ProjectivePoint::generator();
+        let q = ProjectivePoint::random(&mut OsRng);
+        let (a, b) = (Scalar::random(&mut OsRng), Scalar::random(&mut OsRng));
+        let lhs = [(g, a), (q, b)];
+        let eq = |lhs: &[(ProjectivePoint, Scalar)], rhs: &ProjectivePoint| {
+            let result = bool::from(MockCurve::lincomb_eq(lhs, rhs));
+            assert_eq!(MockCurve::lincomb_eq_vartime(lhs, rhs), result);
+            result
+        };
+
+        // Same point with a different representation
+        let r = g * a + q * b;
+        assert!(eq(&lhs, &r));
+        assert!(eq(&lhs, &(r.double() - r)));
+        assert!(eq(&lhs, &ProjectivePoint::from(r.to_affine())));
+
+        // Mismatches
+        assert!(!eq(&lhs, &(r + g)));
+        assert!(!eq(&lhs, &-r));
+        assert!(!eq(&[(g, a), (q, b + Scalar::ONE)], &r));
+
+        // Identity on the right hand side
+        assert!(eq(&[], &ProjectivePoint::IDENTITY));
+        assert!(eq(&[(g, a), (g, -a)], &ProjectivePoint::IDENTITY));
+        assert!(eq(&[(q, Scalar::ZERO)], &ProjectivePoint::IDENTITY));
+        assert!(!eq(&lhs, &ProjectivePoint::IDENTITY));
+        assert!(!eq(&[], &g));
+
+        // More terms than are converted at once
+        let terms: [(ProjectivePoint, Scalar); 9] = core::array::from_fn(|_| {
+            (
+                ProjectivePoint::random(&mut OsRng),
+                Scalar::random(&mut OsRng),
+            )
+        });
+        let sum = terms.iter().map(|(point, scalar)| *point * scalar).sum();
+        assert!(eq(&terms, &sum));
+        assert!(!eq(&terms[..8], &sum));
+    }
+
+    #[test]
+    fn msm_accumulator_matches_msm() {
+        let points: [AffinePoint; 16] =
+            core::array::from_fn(|_| ProjectivePoint::random(&mut OsRng).to_affine());
+        let mut scalars: [Scalar; 16] = core::array::from_fn(|_| Scalar::random(&mut OsRng));
+        scalars[3] = Scalar::ZERO;
+        scalars[7] = -Scalar::ONE;
+
+        let mut acc = MsmAccumulator::<MockCurve>::new();
+
+        for (point, scalar) in points.iter().zip(&scalars) {
+            acc.add(point, scalar);
+        }
+
+        assert_eq!(acc.finalize(), MockCurve::msm(&points, &scalars));
+        assert_eq!(
+            MsmAccumulator::<MockCurve>::default().finalize(),
+            ProjectivePoint::identity()
+        );
+    }
+
+    #[test]
+    fn msm_accumulator_merge() {
+        let points: [AffinePoint; 8] =
+            core::array::from_fn(|_| ProjectivePoint::random(&mut OsRng).to_affine());
+        let scalars: [Scalar; 8] = core::array::from_fn(|_| Scalar::random(&mut OsRng));
+
+        let (mut lo, mut hi) = (MsmAccumulator::<MockCurve>::new(), MsmAccumulator::new());
+
+        for (point, scalar) in points[..3].iter().zip(&scalars[..3]) {
+            lo.add(point, scalar);
+        }
+
+        for (point, scalar) in points[3..].iter().zip(&scalars[3..]) {
+            hi.add(point, scalar);
+        }
+
+        assert_eq!(lo.merge(hi).finalize(), MockCurve::msm(&points, &scalars));
+    }
+
+    /// Run `f` on a four thread pool so the parallel code paths are taken.
+    #[cfg(feature = "rayon")]
+    fn with_threads<R: Send>(f: impl FnOnce() -> R + Send) -> R {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(4)
+            .build()
+            .unwrap()
+            .install(f)
+    }
+
+    #[cfg(not(feature = "rayon"))]
+    fn with_threads<R>(f: impl FnOnce() -> R) -> R {
+        f()
+    }
+
+    /// Input lengths covering the serial fallback, a single chunk, and
+    /// uneven chunks for a threshold of 4.
+    const PAR_LENS: [usize; 5] = [0, 1, 3, 9, 50];
+
+    #[test]
+    fn batch_invert_par_matches_serial() {
+        for len in PAR_LENS {
+            let scalars = (0..len)
+                .map(|_| Scalar::random(&mut OsRng))
+                .collect::<Vec<_>>();
+
+            let serial = Scalar::invert_batch(scalars.as_slice()).unwrap();
+            let parallel = with_threads(|| batch_invert_par(&scalars, 4)).unwrap();
+            assert_eq!(parallel, serial);
+        }
+
+        let mut scalars = [Scalar::ONE; 50];
+        scalars[37] = Scalar::ZERO;
+        assert!(bool::from(
+            with_threads(|| batch_invert_par(&scalars, 4)).is_none()
+        ));
+    }
+
+    #[test]
+    fn msm_par_matches_serial() {
+        for len in PAR_LENS {
+            let points = (0..len)
+                .map(|_| ProjectivePoint::random(&mut OsRng).to_affine())
+                .collect::<Vec<_>>();
+            let scalars = (0..len)
+                .map(|_| Scalar::random(&mut OsRng))
+                .collect::<Vec<_>>();
+
+            let expected = MockCurve::msm(&points, &scalars).to_affine();
+            let parallel = with_threads(|| MockCurve::msm_par(&points, &scalars, 4));
+            assert_eq!(parallel.to_affine(), expected);
+
+            let parallel = with_threads(|| MockCurve::msm_vartime_par(&points, &scalars, 4));
+            assert_eq!(parallel.to_affine(), expected);
+        }
+    }
+
+    #[test]
+    fn to_affine_batch_par_matches_serial() {
+        for len in PAR_LENS {
+            let mut points = (0..len)
+                .map(|_| ProjectivePoint::random(&mut OsRng))
+                .collect::<Vec<_>>();
+
+            if let Some(point) = points.get_mut(2) {
+                *point = ProjectivePoint::identity();
+            }
+
+            let serial: Vec<AffinePoint> = MockCurve::to_affine_batch_slice(&points);
+            let parallel: Vec<AffinePoint> =
+                with_threads(|| MockCurve::to_affine_batch_par(&points, 4));
+            assert_eq!(parallel, serial);
+        }
+    }
+
+    #[test]
+    fn endomorphism_decompose() {
+        let [(k1, k1_neg), (k2, k2_neg)] = MockCurve::decompose(&Scalar::ONE);
+        assert_eq!((k1, k2), (Scalar::ONE, Scalar::ZERO));
+        assert!(!bool::from(k1_neg | k2_neg));
+
+        for _ in 0..32 {
+            let k = Scalar::random(&mut OsRng);
+            let [(k1, k1_neg), (k2, k2_neg)] = MockCurve::decompose(&k);
+
+            for half in [k1, k2] {
+                let uint = U256::from(half);
+                assert!(uint.bits_vartime() <= MockCurve::DECOMPOSED_BITS);
+            }
+
+            let point = ProjectivePoint::random(&mut OsRng).to_affine();
+            let mut p1 = ProjectivePoint::from(point) * k1;
+            let mut p2 = ProjectivePoint::from(MockCurve::endomorphism(&point)) * k2;
+            p1.conditional_negate(k1_neg);
+            p2.conditional_negate(k2_neg);
+            assert_eq!(p1 + p2, ProjectivePoint::from(point) * k);
+        }
+    }
+
+    #[test]
+    fn msm_glv_matches_msm() {
+        let points: [AffinePoint; 16] =
+            core::array::from_fn(|_| ProjectivePoint::random(&mut OsRng).to_affine());
+        let mut scalars: [Scalar; 16] = core::array::from_fn(|_| Scalar::random(&mut OsRng));
+        scalars[3] = Scalar::ZERO;
+        scalars[7] = -Scalar::ONE;
+
+        assert_eq!(
+            MockCurve::msm_glv(&points, &scalars),
+            MockCurve::msm(&points, &scalars)
+        );
+        assert_eq!(MockCurve::msm_glv(&[], &[]), ProjectivePoint::identity());
+    }
+
+    #[test]
+    fn mul_by_generator_blinded() {
+        for _ in 0..32 {
+            let k = Scalar::random(&mut OsRng);
+            assert_eq!(
+                ProjectivePoint::mul_by_generator_blinded(&k, &mut OsRng),
+                ProjectivePoint::mul_by_generator(&k)
+            );
+        }
+
+        let blinded = ProjectivePoint::mul_by_generator_blinded(&Scalar::ZERO, &mut OsRng);
+        assert_eq!(blinded, ProjectivePoint::identity());
+    }
+
+    #[test]
+    fn mul_by_generator_blinded_deterministic_rng() {
+        let k = Scalar::random(&mut OsRng);
+        let mut rng = XorShiftRng(0x2545_f491_4f6c_dd1d);
+        let result = ProjectivePoint::mul_by_generator_blinded(&k, &mut rng);
+
+        // Same seed, same shares
+        let mut replay = XorShiftRng(0x2545_f491_4f6c_dd1d);
+        let mask = Scalar::random(&mut replay);
+        assert_eq!(
+            ProjectivePoint::mul_by_generator(&(k - mask))
+                + ProjectivePoint::mul_by_generator(&mask),
+            result
+        );
+        assert_eq!(rng.next_u64(), replay.next_u64());
+        assert_eq!(result, ProjectivePoint::mul_by_generator(&k));
+    }
+}
@@ -0,0 +1,227 @@
+//! Generic constant-time scalar multiplication.
+//!
+//! Curve implementations can delegate their `Mul<Scalar>` impls to
+//! [`ct_mul`] instead of writing their own constant-time ladder.
+
+use crate::{Curve, CurveArithmetic, ProjectivePoint, Scalar, ScalarArithmetic};
+use crypto_bigint::{Integer, Limb};
+use group::Group;
+use subtle::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq};
+
+/// Largest supported window size for [`ct_mul`].
+pub const MAX_WINDOW_BITS: usize = 6;
+
+/// Compute `point * scalar` in constant time using a fixed window of `W` bits.
+///
+/// The `2^W` multiples of `point` are precomputed, and each window of the
+/// scalar selects one of them by scanning the whole table with
+/// [`ConditionallySelectable`], so neither the control flow nor the memory
+/// access pattern depends on the scalar.
+///
+/// `W` must be between 1 and [`MAX_WINDOW_BITS`], which is checked at compile
+/// time.
+pub fn ct_mul<C, const W: usize>(
+    point: &ProjectivePoint<C>,
+    scalar: &Scalar<C>,
+) -> ProjectivePoint<C>
+where
+    C: CurveArithmetic,
+{
+    #[allow(clippy::let_unit_value)]
+    let () = WindowBits::<W>::VALID;
+
+    let mut table = [ProjectivePoint::<C>::identity(); 1 << MAX_WINDOW_BITS];
+    let table = &mut table[..1 << W];
+    fill_table::<C>(point, table);
+    mul_with_table::<C>(table, W, digits::<C>(scalar, W))
+}
+
+/// Fill `table` with the multiples `[0, P, 2P, ...]` of `point`.
+#[allow(clippy::integer_arithmetic)]
+pub(crate) fn fill_table<C: CurveArithmetic>(
+    point: &ProjectivePoint<C>,
+    table: &mut [ProjectivePoint<C>],
+) {
+    let mut acc = ProjectivePoint::<C>::identity();
+
+    for entry in table.iter_mut() {
+        *entry = acc;
+        acc += point;
+    }
+}
+
+/// Multiply using a table filled by [`fill_table`] with `2^w` entries, given
+/// the `w`-bit windows of the scalar from most to least significant.
+pub(crate) fn mul_with_table<C: CurveArithmetic>(
+    table: &[ProjectivePoint<C>],
+    w: usize,
+    digits: impl Iterator<Item = u32>,
+) -> ProjectivePoint<C> {
+    let mut acc = ProjectivePoint::<C>::identity();
+
+    for digit in digits {
+        for _ in 0..w {
+            acc = acc.double();
+        }
+
+        acc += select(table, digit);
+    }
+
+    acc
+}
+
+/// Select `table[index]` in constant time, e.g. a multiple of a point from
+/// a precomputed table for windowed scalar multiplication.
+///
+/// The whole table is scanned with [`ConditionallySelectable`], so neither the
+/// control flow nor the memory access pattern depends on `index`. Returns
+/// `T::default()` if `index` is out of bounds.
+pub fn ct_select<T, const N: usize>(table: &[T; N], index: u8) -> T
+where
+    T: ConditionallySelectable + Default,
+{
+    select(table, index.into())
+}
+
+/// Select `table[|index|]` in constant time, negated if `index` is negative,
+/// e.g. a multiple of a point from a table for signed (wNAF) digits.
+///
+/// See [`ct_select`].
+#[allow(clippy::cast_sign_loss)]
+pub fn ct_select_signed<T, const N: usize>(table: &[T; N], index: i8) -> T
+where
+    T: ConditionallyNegatable + ConditionallySelectable + Default,
+{
+    let mask = index >> 7;
+    let abs = (index ^ mask).wrapping_sub(mask) as u8;
+
+    let mut entry = ct_select(table, abs);
+    entry.conditional_negate(Choice::from((mask & 1) as u8));
+    entry
+}
+
+/// Select `table[index]` in constant time from a table of any length.
+///
+/// See [`ct_select`].
+pub(crate) fn select<T>(table: &[T], index: u32) -> T
+where
+    T: ConditionallySelectable + Default,
+{
+    let mut entry = T::default();
+
+    for (i, candidate) in (0u32..).zip(table) {
+        entry.conditional_assign(candidate, i.ct_eq(&index));
+    }
+
+    entry
+}
+
+/// Number of `w`-bit windows in a scalar of the curve `C`.
+#[allow(clippy::integer_arithmetic)]
+pub(crate) fn window_count<C: Curve>(w: usize) -> usize {
+    (C::Uint::BITS + w - 1) / w
+}
+
+/// Split a scalar into `w`-bit windows, from most to least significant.
+#[allow(clippy::integer_arithmetic)]
+pub(crate) fn digits<C: ScalarArithmetic>(
+    scalar: &Scalar<C>,
+    w: usize,
+) -> impl Iterator<Item = u32> {
+    let uint: C::Uint = (*scalar).into();
+
+    (0..window_count::<C>(w))
+        .rev()
+        .map(move |window| window_digit(uint.as_ref(), window * w, w))
+}
+
+/// Compile-time check of the window size.
+struct WindowBits<const W: usize>;
+
+impl<const W: usize> WindowBits<W> {
+    const VALID: () = assert!(W >= 1 && W <= MAX_WINDOW_BITS, "invalid window size");
+}
+
+/// Get the `len` bits starting at bit `start` of a little endian limb slice,
+/// treating bits past the end as zero.
+///
+/// Only the (public) bit positions affect control flow.
+#[allow(clippy::cast_possible_truncation, clippy::integer_arithmetic)]
+fn window_digit(limbs: &[Limb], start: usize, len: usize) -> u32 {
+    (0..len).fold(0, |digit, j| {
+        let i = start + j;
+        let bit = limbs
+            .get(i / Limb::BITS)
+            .map(|limb| (limb.0 >> (i % Limb::BITS)) & 1)
+            .unwrap_or(0);
+        digit | ((bit as u32) << j)
+    })
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::{ct_mul, ct_select, ct_select_signed};
+    use crate::dev::{MockCurve, ProjectivePoint, Scalar};
+    use ff::Field;
+    use group::Group;
+    use rand_core::OsRng;
+
+    #[test]
+    fn matches_group_mul() {
+        let p = ProjectivePoint::random(&mut OsRng);
+
+        for _ in 0..8 {
+            let k = Scalar::random(&mut OsRng);
+            let expected = p * k;
+            assert_eq!(ct_mul::<MockCurve, 3>(&p, &k), expected);
+            assert_eq!(ct_mul::<MockCurve, 4>(&p, &k), expected);
+            assert_eq!(ct_mul::<MockCurve, 5>(&p, &k), expected);
+        }
+    }
+
+    #[test]
+    fn edge_cases() {
+        let p = ProjectivePoint::random(&mut OsRng);
+
+        for k in [Scalar::ZERO, Scalar::ONE, -Scalar::ONE] {
+            assert_eq!(ct_mul::<MockCurve, 1>(&p, &k), p * k);
+            assert_eq!(ct_mul::<MockCurve, 6>(&p, &k), p * k);
+        }
+
+        let identity = ProjectivePoint::identity();
+        let k = Scalar::random(&mut OsRng);
+        assert_eq!(ct_mul::<MockCurve, 4>(&identity, &k), identity);
+    }
+
+    #[test]
+    fn ct_select_every_index() {
+        let table16: [Scalar; 16] = core::array::from_fn(|i| Scalar::from(i as u64 + 1));
+        let table32: [Scalar; 32] = core::array::from_fn(|i| Scalar::from(i as u64 + 1));
+
+        for i in 0..16u8 {
+            assert_eq!(ct_select(&table16, i), table16[usize::from(i)]);
+        }
+        for i in 0..32u8 {
+            assert_eq!(ct_select(&table32, i), table32[usize::from(i)]);
+        }
+
+        assert_eq!(ct_select(&table16, 16), Scalar::default());
+        assert_eq!(ct_select(&table32, u8::MAX), Scalar::default());
+    }
+
+    #[test]
+    fn ct_select_signed_negative_indices() {
+        let g = ProjectivePoint::generator();
+        let table: [ProjectivePoint; 16] = core::array::from_fn(|i| g * Scalar::from(i as u64));
+
+        for (i, entry) in (0i8..).zip(&table) {
+            assert_eq!(ct_select_signed(&table, i), *entry);
+            assert_eq!(ct_select_signed(&table, -i), -entry);
+        }
+
+        assert_eq!(
+            ct_select_signed(&table, i8::MIN),
+            -ProjectivePoint::default()
+        );
+    }
+}
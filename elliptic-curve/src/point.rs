@@ -1,14 +1,34 @@
 //! Traits for elliptic curve points.
 
+#[cfg(feature = "arithmetic")]
+mod batch;
+#[cfg(feature = "arithmetic")]
+mod blinded;
 #[cfg(feature = "arithmetic")]
 mod non_identity;
+#[cfg(feature = "hash2curve")]
+mod pedersen;
 
+#[cfg(all(feature = "arithmetic", feature = "alloc", feature = "sec1"))]
+pub use self::batch::{batch_from_sec1, batch_to_sec1_compressed};
+#[cfg(feature = "hash2curve")]
+pub use self::pedersen::{alternate_generator, Commitment};
 #[cfg(feature = "arithmetic")]
-pub use {self::non_identity::NonIdentity, crate::CurveArithmetic};
+pub use {
+    self::{batch::ToAffineBatch, blinded::BlindedPoint, non_identity::NonIdentity},
+    crate::CurveArithmetic,
+};
 
 use crate::{Curve, FieldBytes};
 use subtle::{Choice, CtOption};
 
+#[cfg(all(feature = "arbitrary", feature = "arithmetic"))]
+use {
+    crate::{ops::MulByGenerator, ScalarPrimitive},
+    arbitrary::{Arbitrary, Unstructured},
+    group::Curve as _,
+};
+
 /// Affine point type for a given curve with a [`CurveArithmetic`]
 /// implementation.
 #[cfg(feature = "arithmetic")]
@@ -19,19 +39,93 @@ pub type AffinePoint<C> = <C as CurveArithmetic>::AffinePoint;
 #[cfg(feature = "arithmetic")]
 pub type ProjectivePoint<C> = <C as CurveArithmetic>::ProjectivePoint;
 
+/// Generate an arbitrary affine point as `k * G` for an arbitrary scalar `k`.
+///
+/// Points produced this way are always members of the prime-order subgroup
+/// generated by `G`. This is intended for implementing
+/// [`Arbitrary`] on a curve's [`AffinePoint`] type.
+#[cfg(all(feature = "arbitrary", feature = "arithmetic"))]
+pub fn arbitrary_affine_point<C>(u: &mut Unstructured<'_>) -> arbitrary::Result<AffinePoint<C>>
+where
+    C: CurveArithmetic,
+{
+    let k = C::Scalar::from(ScalarPrimitive::<C>::arbitrary(u)?);
+    Ok(ProjectivePoint::<C>::mul_by_generator(&k).to_affine())
+}
+
 /// Access to the affine coordinates of an elliptic curve point.
 // TODO: use zkcrypto/group#30 coordinate API when available
-pub trait AffineCoordinates {
+pub trait AffineCoordinates: Sized {
     /// Field element representation.
     type FieldRepr: AsRef<[u8]>;
 
+    /// Identity of the group, i.e. the neutral element of point addition (a.k.a.
+    /// the point at infinity).
+    const IDENTITY: Self;
+
+    /// Get the identity of the group, i.e. [`AffineCoordinates::IDENTITY`].
+    fn identity() -> Self {
+        Self::IDENTITY
+    }
+
     /// Get the affine x-coordinate as a serialized field element.
     fn x(&self) -> Self::FieldRepr;
 
+    /// Get the affine y-coordinate as a serialized field element.
+    fn y(&self) -> Self::FieldRepr;
+
     /// Is the affine y-coordinate odd?
     fn y_is_odd(&self) -> Choice;
 }
 
+/// Access to the affine coordinates of a point on a twisted Edwards curve.
+///
+/// For these points [`AffineCoordinates::x`] and [`AffineCoordinates::y`]
+/// return the Edwards `(x, y)` coordinates.
+pub trait EdwardsCoordinates: AffineCoordinates {
+    /// Is the affine x-coordinate odd?
+    ///
+    /// This is the sign bit of the compressed point encoding.
+    fn x_is_odd(&self) -> Choice;
+}
+
+/// Access to the coordinates of a point in homogeneous projective coordinates,
+/// i.e. representing the affine point `(X / Z, Y / Z)`.
+pub trait ProjectiveCoordinates {
+    /// Field element type of the coordinates.
+    type FieldElement;
+
+    /// Get the projective X-coordinate.
+    fn x(&self) -> Self::FieldElement;
+
+    /// Get the projective Y-coordinate.
+    fn y(&self) -> Self::FieldElement;
+
+    /// Get the projective Z-coordinate, which is zero for the identity.
+    fn z(&self) -> Self::FieldElement;
+}
+
+/// Affine coordinates of the standard generator (a.k.a. base point) of a curve.
+///
+/// This allows generic code to e.g. embed the generator in a transcript
+/// deterministically without performing any arithmetic.
+pub trait GeneratorCoordinates<C: Curve>:
+    AffineCoordinates<FieldRepr = FieldBytes<C>> + Sized
+{
+    /// Generator of the curve in affine coordinates.
+    const GENERATOR: Self;
+
+    /// Get the generator's affine x-coordinate as a serialized field element.
+    fn generator_x() -> FieldBytes<C> {
+        Self::GENERATOR.x()
+    }
+
+    /// Get the generator's affine y-coordinate as a serialized field element.
+    fn generator_y() -> FieldBytes<C> {
+        Self::GENERATOR.y()
+    }
+}
+
 /// Double a point (i.e. add it to itself)
 pub trait Double {
     /// Double this point.
@@ -0,0 +1,360 @@
+//! Batch conversions of projective points.
+
+use super::{AffineCoordinates, ProjectiveCoordinates};
+use crate::CurveArithmetic;
+use ff::{BatchInverter, Field};
+use subtle::{Choice, ConditionallySelectable, CtOption};
+
+#[cfg(feature = "alloc")]
+use {
+    crate::{ops::MulByGenerator, Error, Result},
+    alloc::vec::Vec,
+};
+
+#[cfg(feature = "rayon")]
+use {crate::ops::par_chunk_len, rayon::prelude::*};
+
+#[cfg(all(feature = "alloc", feature = "sec1"))]
+use {
+    super::{AffinePoint, ProjectivePoint},
+    crate::{
+        sec1::{
+            self, CompressInto, CompressedPoint, CompressedPointSize, EncodedPoint,
+            FromEncodedPoint, ModulusSize, ToEncodedPoint,
+        },
+        FieldBytesSize,
+    },
+    generic_array::{typenum::Unsigned, GenericArray},
+    group::Group,
+};
+
+/// Convert many projective points to affine coordinates at once.
+///
+/// The provided implementations use [`group::Curve::batch_normalize`], which
+/// curve implementations can override to share a single field inversion
+/// across the whole batch.
+///
+/// This trait is impl'd for every [`CurveArithmetic`] type, so curves only
+/// need to implement [`group::Curve::batch_normalize`] and generic code can
+/// use either API to get the optimized conversion.
+pub trait ToAffineBatch: CurveArithmetic {
+    /// Convert a fixed-size array of projective points to affine coordinates.
+    fn to_affine_batch_array<const N: usize>(
+        points: &[Self::ProjectivePoint; N],
+    ) -> [Self::AffinePoint; N] {
+        let mut affine = [Self::AffinePoint::default(); N];
+        Self::batch_normalize_into(points, &mut affine);
+        affine
+    }
+
+    /// Convert a fixed-size array of projective points to affine coordinates,
+    /// also returning a mask of which outputs are valid, i.e. not the identity.
+    ///
+    /// Identities are mapped to [`AffineCoordinates::IDENTITY`] with a cleared
+    /// [`Choice`], rather than failing the whole batch.
+    fn to_affine_batch_masked<const N: usize>(
+        points: &[Self::ProjectivePoint; N],
+    ) -> ([Self::AffinePoint; N], [Choice; N]) {
+        let mask = points.map(|point| !Self::is_identity(&point));
+        let mut affine = Self::to_affine_batch_array(points);
+
+        for (point, is_valid) in affine.iter_mut().zip(&mask) {
+            point.conditional_assign(&Self::AffinePoint::IDENTITY, !*is_valid);
+        }
+
+        (affine, mask)
+    }
+
+    /// Convert a fixed-size array of projective points to affine coordinates,
+    /// moving the identities to the end, and also returning the number of
+    /// valid, i.e. non-identity, points before them.
+    ///
+    /// The valid points keep their relative order. The conversion uses a single
+    /// batch inversion, and the compaction is a sequence of `O(N log N)`
+    /// conditional swaps whose count depends only on `N`, although the returned
+    /// count reveals how many of the points are the identity.
+    #[allow(clippy::integer_arithmetic)]
+    fn to_affine_batch_partition<const N: usize>(
+        points: &[Self::ProjectivePoint; N],
+    ) -> ([Self::AffinePoint; N], usize) {
+        let (mut affine, mut mask) = Self::to_affine_batch_masked(points);
+
+        // Each valid point moves towards the front by the number of identities
+        // before it. Moving by one bit of that distance at a time, starting
+        // from the lowest, never moves a point onto another valid point.
+        let mut shifts = [0u64; N];
+        let mut identities = 0u64;
+        for (shift, is_valid) in shifts.iter_mut().zip(&mask) {
+            *shift = identities;
+            identities += u64::from((!*is_valid).unwrap_u8());
+        }
+
+        for level in 0..usize::BITS - N.leading_zeros() {
+            let distance = 1 << level;
+
+            for i in distance..N {
+                let j = i - distance;
+                let swap = mask[i] & Choice::from(((shifts[i] >> level) & 1) as u8);
+                let (front, back) = affine.split_at_mut(i);
+                Self::AffinePoint::conditional_swap(&mut front[j], &mut back[0], swap);
+
+                let shift = shifts[i];
+                shifts[j].conditional_assign(&shift, swap);
+                mask[j] |= swap;
+                mask[i] &= !swap;
+            }
+        }
+
+        let valid = mask
+            .iter()
+            .map(|is_valid| usize::from(is_valid.unwrap_u8()))
+            .sum();
+
+        (affine, valid)
+    }
+
+    /// Convert a fixed-size array of projective points to affine coordinates,
+    /// also returning the inverses of their Z-coordinates as computed by
+    /// [`ToAffineBatch::batch_z_inverses`].
+    ///
+    /// Returns `None` if any of the points is the identity, i.e. has `Z = 0`.
+    #[allow(clippy::type_complexity)]
+    fn to_affine_batch_with_zinv<const N: usize>(
+        points: &[Self::ProjectivePoint; N],
+    ) -> CtOption<([Self::AffinePoint; N], [Self::FieldElement; N])>
+    where
+        Self::ProjectivePoint: ProjectiveCoordinates<FieldElement = Self::FieldElement>,
+    {
+        let mut inverses = points.map(|p| p.z());
+        let is_some = inverses
+            .iter()
+            .fold(Choice::from(1), |acc, z| acc & !z.is_zero());
+
+        let mut scratch = [Self::FieldElement::ZERO; N];
+        BatchInverter::invert_with_external_scratch(&mut inverses, &mut scratch);
+        CtOption::new((Self::to_affine_batch_array(points), inverses), is_some)
+    }
+
+    /// Convert a slice of projective points to affine coordinates, writing the
+    /// results into a caller-provided buffer without allocating.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `points` and `out` have different lengths.
+    fn batch_normalize_into(points: &[Self::ProjectivePoint], out: &mut [Self::AffinePoint]) {
+        assert_eq!(points.len(), out.len(), "output buffer length mismatch");
+        group::Curve::batch_normalize(points, out);
+    }
+
+    /// Convert a slice of projective points to affine coordinates, collecting
+    /// the results into `B`.
+    #[cfg(feature = "alloc")]
+    fn to_affine_batch_slice<B: FromIterator<Self::AffinePoint>>(
+        points: &[Self::ProjectivePoint],
+    ) -> B {
+        Self::to_affine_batch_iter(points.iter().copied())
+            .expect("slice iterators report their exact length")
+    }
+
+    /// Convert an iterator of projective points to affine coordinates,
+    /// collecting the results into `B`.
+    ///
+    /// The iterator's reported length is used to size buffers up front.
+    /// Returns [`ErrorKind::InvalidLength`](crate::ErrorKind::InvalidLength)
+    /// if it yields a different number of points.
+    #[cfg(feature = "alloc")]
+    fn to_affine_batch_iter<I, B>(points: I) -> Result<B>
+    where
+        I: IntoIterator<Item = Self::ProjectivePoint>,
+        I::IntoIter: ExactSizeIterator,
+        B: FromIterator<Self::AffinePoint>,
+    {
+        let points = points.into_iter();
+        let expected = points.len();
+
+        let mut projective = Vec::with_capacity(expected);
+        projective.extend(points);
+        if projective.len() != expected {
+            return Err(Error::invalid_length(expected, projective.len()));
+        }
+
+        let mut affine = vec![Self::AffinePoint::default(); expected];
+        Self::batch_normalize_into(&projective, &mut affine);
+        Ok(affine.into_iter().collect())
+    }
+
+    /// Convert a slice of projective points to affine coordinates, splitting
+    /// it into chunks of at least `threshold` points which are converted in
+    /// parallel when the `rayon` feature is enabled, collecting the results
+    /// into `B`.
+    ///
+    /// Each chunk shares a single field inversion. Slices shorter than
+    /// `threshold` are converted serially, as are all slices when the `rayon`
+    /// feature is disabled. Either way the results are identical to those of
+    /// [`ToAffineBatch::to_affine_batch_slice`].
+    #[cfg(feature = "alloc")]
+    fn to_affine_batch_par<B: FromIterator<Self::AffinePoint>>(
+        points: &[Self::ProjectivePoint],
+        threshold: usize,
+    ) -> B {
+        #[cfg(feature = "rayon")]
+        if let Some(chunk_len) = par_chunk_len(points.len(), threshold) {
+            let mut affine = vec![Self::AffinePoint::default(); points.len()];
+            affine
+                .par_chunks_mut(chunk_len)
+                .zip(points.par_chunks(chunk_len))
+                .for_each(|(affine, points)| Self::batch_normalize_into(points, affine));
+
+            return affine.into_iter().collect();
+        }
+
+        #[cfg(not(feature = "rayon"))]
+        let _ = threshold;
+
+        Self::to_affine_batch_slice(points)
+    }
+
+    /// Multiply each point by the corresponding scalar and convert the results
+    /// to affine coordinates using a single batch conversion, collecting them
+    /// into `B`.
+    ///
+    /// # Panics
+    ///
+    /// If `points` and `scalars` have different lengths.
+    #[cfg(feature = "alloc")]
+    fn mul_to_affine_batch<B: FromIterator<Self::AffinePoint>>(
+        points: &[Self::AffinePoint],
+        scalars: &[Self::Scalar],
+    ) -> B {
+        assert_eq!(points.len(), scalars.len(), "length mismatch");
+
+        let projective = points
+            .iter()
+            .zip(scalars)
+            .map(|(point, scalar)| Self::ProjectivePoint::from(*point) * scalar)
+            .collect::<Vec<_>>();
+
+        Self::to_affine_batch_slice(&projective)
+    }
+
+    /// Multiply the generator by each scalar and convert the results to affine
+    /// coordinates using a single batch conversion, collecting them into `B`.
+    ///
+    /// This derives many public keys from their secret scalars, using
+    /// [`MulByGenerator`] so curves with a precomputed generator table benefit.
+    #[cfg(feature = "alloc")]
+    fn mul_by_generator_to_affine_batch<B: FromIterator<Self::AffinePoint>>(
+        scalars: &[Self::Scalar],
+    ) -> B {
+        let projective = scalars
+            .iter()
+            .map(Self::ProjectivePoint::mul_by_generator)
+            .collect::<Vec<_>>();
+
+        Self::to_affine_batch_slice(&projective)
+    }
+
+    /// Compute the inverses of the Z-coordinates of a slice of projective
+    /// points using a single field inversion.
+    ///
+    /// These are the factors [`ToAffineBatch`] conversions multiply the X and
+    /// Y-coordinates by, exposed for building custom conversions.
+    ///
+    /// Returns `None` if any of the points is the identity, i.e. has `Z = 0`.
+    #[cfg(feature = "alloc")]
+    fn batch_z_inverses(points: &[Self::ProjectivePoint]) -> CtOption<Vec<Self::FieldElement>>
+    where
+        Self::ProjectivePoint: ProjectiveCoordinates<FieldElement = Self::FieldElement>,
+    {
+        let mut inverses = points.iter().map(|p| p.z()).collect::<Vec<_>>();
+        let is_some = inverses
+            .iter()
+            .fold(Choice::from(1), |acc, z| acc & !z.is_zero());
+
+        let mut scratch = vec![Self::FieldElement::ZERO; inverses.len()];
+        BatchInverter::invert_with_external_scratch(&mut inverses, &mut scratch);
+        CtOption::new(inverses, is_some)
+    }
+
+    /// Serialize a slice of projective points as SEC1 compressed points using
+    /// a single batch affine conversion.
+    ///
+    /// The identity has no compressed encoding and is serialized as all-zeroes,
+    /// matching [`ToCompressedPoint`](crate::sec1::ToCompressedPoint).
+    #[cfg(all(feature = "alloc", feature = "sec1"))]
+    fn to_compressed_batch<B: FromIterator<CompressedPoint<Self>>>(
+        points: &[Self::ProjectivePoint],
+    ) -> B
+    where
+        Self::AffinePoint: ToEncodedPoint<Self>,
+        FieldBytesSize<Self>: ModulusSize,
+    {
+        Self::to_affine_batch_slice::<Vec<_>>(points)
+            .iter()
+            .map(sec1::compress)
+            .collect()
+    }
+}
+
+impl<C: CurveArithmetic> ToAffineBatch for C {}
+
+/// Serialize projective points as concatenated SEC1 compressed points,
+/// appending them to `out` using a single batch affine conversion.
+///
+/// The identity is serialized as all-zeroes, as in
+/// [`ToAffineBatch::to_compressed_batch`].
+#[cfg(all(feature = "alloc", feature = "sec1"))]
+pub fn batch_to_sec1_compressed<C>(points: &[ProjectivePoint<C>], out: &mut Vec<u8>)
+where
+    C: CurveArithmetic,
+    AffinePoint<C>: ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    let size = CompressedPointSize::<C>::USIZE;
+    let start = out.len();
+    out.resize(start.saturating_add(points.len().saturating_mul(size)), 0);
+
+    let affine = C::to_affine_batch_slice::<Vec<_>>(points);
+    for (chunk, point) in out[start..].chunks_exact_mut(size).zip(&affine) {
+        point.compress_into(GenericArray::from_mut_slice(chunk));
+    }
+}
+
+/// Parse concatenated SEC1 compressed points as serialized by
+/// [`batch_to_sec1_compressed`], validating each of them.
+///
+/// All-zeroes chunks are parsed as the identity. Returns
+/// [`ErrorKind::InvalidLength`](crate::ErrorKind::InvalidLength) if the
+/// length of `bytes` isn't a multiple of the compressed point size, or
+/// [`ErrorKind::InvalidBatchElement`](crate::ErrorKind::InvalidBatchElement)
+/// with the index of the first invalid point.
+#[cfg(all(feature = "alloc", feature = "sec1"))]
+pub fn batch_from_sec1<C>(bytes: &[u8]) -> Result<Vec<ProjectivePoint<C>>>
+where
+    C: CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    let size = CompressedPointSize::<C>::USIZE;
+    let remainder = bytes.len() % size;
+    if remainder != 0 {
+        let expected = bytes.len().saturating_add(size - remainder);
+        return Err(Error::invalid_length(expected, bytes.len()));
+    }
+
+    bytes
+        .chunks_exact(size)
+        .enumerate()
+        .map(|(index, chunk)| {
+            if chunk.iter().all(|&byte| byte == 0) {
+                return Ok(ProjectivePoint::<C>::identity());
+            }
+
+            EncodedPoint::<C>::from_bytes(chunk)
+                .ok()
+                .and_then(|point| Option::from(AffinePoint::<C>::from_encoded_point(&point)))
+                .map(ProjectivePoint::<C>::from)
+                .ok_or(Error::invalid_batch_element(index))
+        })
+        .collect()
+}
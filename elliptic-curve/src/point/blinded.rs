@@ -0,0 +1,155 @@
+//! Random blinding support for variable-base scalar multiplication.
+
+use crate::{CurveArithmetic, ProjectivePoint, Scalar};
+use ff::Field;
+use group::Group;
+use rand_core::CryptoRngCore;
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+/// Secret point blinded with a randomly generated masking point.
+///
+/// This provides [`BlindedPoint::mul_blinded`], a variable-base scalar
+/// multiplication for when the point is secret or the multiplication should be
+/// masked, e.g. ECDH on an embedded target.
+///
+/// # Side-channel model
+///
+/// Blinding targets passive side-channel attacks (timing, power, and EM
+/// analysis) which correlate leakage of a scalar multiplication with its
+/// inputs. Only the masked point `P + R` and the mask `R`, a random multiple
+/// of the generator, are stored, so the raw point `P` is never used after
+/// [`BlindedPoint::new`]. [`BlindedPoint::mul_blinded`] also splits the
+/// scalar as `k1 + k2` with `k1` random, so the masked point is never
+/// multiplied by the raw scalar.
+///
+/// It only requires the curve's scalar multiplication to be constant-time,
+/// and needs no cooperation from the curve implementation. It assumes the
+/// mask remains secret and offers no protection against fault injection or
+/// against leakage of the unmasked values by the caller.
+///
+/// Calling [`BlindedPoint::rerandomize`] between sensitive operations
+/// prevents an attacker from correlating leakage across them.
+#[derive(Clone)]
+pub struct BlindedPoint<C>
+where
+    C: CurveArithmetic,
+{
+    /// Masked point value `P + R`.
+    masked: ProjectivePoint<C>,
+
+    /// Mask value `R`.
+    mask: ProjectivePoint<C>,
+}
+
+impl<C> BlindedPoint<C>
+where
+    C: CurveArithmetic,
+{
+    /// Create a new [`BlindedPoint`] from a point and a [`CryptoRngCore`].
+    pub fn new(point: ProjectivePoint<C>, rng: &mut impl CryptoRngCore) -> Self {
+        let mask = ProjectivePoint::<C>::generator() * Scalar::<C>::random(rng);
+        Self {
+            masked: point + mask,
+            mask,
+        }
+    }
+
+    /// Re-randomize the mask by adding a fresh random multiple of the
+    /// generator to both the masked point and the mask.
+    pub fn rerandomize(&mut self, rng: &mut impl CryptoRngCore) {
+        let delta = Zeroizing::new(ProjectivePoint::<C>::generator() * Scalar::<C>::random(rng));
+        self.masked += *delta;
+        self.mask += *delta;
+    }
+
+    /// Unmask the point, i.e. compute `(P + R) - R`.
+    pub fn to_point(&self) -> ProjectivePoint<C> {
+        self.masked - self.mask
+    }
+
+    /// Multiply the point by `scalar` using additive masking of both.
+    ///
+    /// Computes `(P + R) * k1 + (P + R) * k2 - R * scalar` where `k1` is
+    /// freshly sampled from `rng` and `k2 = scalar - k1`, i.e. this costs three
+    /// variable-base scalar multiplications. The intermediate values are
+    /// zeroized.
+    pub fn mul_blinded(
+        &self,
+        scalar: &Scalar<C>,
+        rng: &mut impl CryptoRngCore,
+    ) -> ProjectivePoint<C> {
+        let k1 = Zeroizing::new(Scalar::<C>::random(rng));
+        let k2 = Zeroizing::new(*scalar - *k1);
+        let product = Zeroizing::new(self.masked * *k1 + self.masked * *k2);
+        *product - self.mask * scalar
+    }
+}
+
+impl<C> Drop for BlindedPoint<C>
+where
+    C: CurveArithmetic,
+{
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<C> Zeroize for BlindedPoint<C>
+where
+    C: CurveArithmetic,
+{
+    fn zeroize(&mut self) {
+        self.masked.zeroize();
+        self.mask.zeroize();
+    }
+}
+
+impl<C> ZeroizeOnDrop for BlindedPoint<C> where C: CurveArithmetic {}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::BlindedPoint;
+    use crate::dev::{MockCurve, ProjectivePoint, Scalar};
+    use ff::Field;
+    use group::Group;
+    use rand_core::OsRng;
+    use zeroize::Zeroize;
+
+    #[test]
+    fn mul_blinded() {
+        let point = ProjectivePoint::random(&mut OsRng);
+        let mut blinded = BlindedPoint::<MockCurve>::new(point, &mut OsRng);
+
+        for _ in 0..8 {
+            let scalar = Scalar::random(&mut OsRng);
+            assert_eq!(blinded.mul_blinded(&scalar, &mut OsRng), point * scalar);
+            blinded.rerandomize(&mut OsRng);
+        }
+
+        assert!(bool::from(
+            blinded.mul_blinded(&Scalar::ZERO, &mut OsRng).is_identity()
+        ));
+    }
+
+    #[test]
+    fn rerandomize() {
+        let point = ProjectivePoint::random(&mut OsRng);
+        let mut blinded = BlindedPoint::<MockCurve>::new(point, &mut OsRng);
+        let (masked, mask) = (blinded.masked, blinded.mask);
+        assert_ne!(masked, point);
+        assert_eq!(blinded.to_point(), point);
+
+        blinded.rerandomize(&mut OsRng);
+        assert_ne!(blinded.masked, masked);
+        assert_ne!(blinded.mask, mask);
+        assert_eq!(blinded.to_point(), point);
+    }
+
+    #[test]
+    fn zeroize() {
+        let mut blinded = BlindedPoint::<MockCurve>::new(ProjectivePoint::generator(), &mut OsRng);
+        blinded.zeroize();
+        assert!(bool::from(blinded.masked.is_identity()));
+        assert!(bool::from(blinded.mask.is_identity()));
+    }
+}
@@ -0,0 +1,150 @@
+//! Alternate generators and Pedersen commitments.
+
+use crate::{
+    hash2curve::{Encoding, ExpandMsg, ExpandMsgId, GroupDigest},
+    ops::LinearCombination,
+    CurveArithmetic, ProjectivePoint, Result, Scalar,
+};
+use group::{cofactor::CofactorGroup, Group};
+
+/// Domain separation tag prefix for [`alternate_generator`].
+const ALTERNATE_GENERATOR_TAG: &[u8] = b"ALTGEN-V1-";
+
+/// Derive a generator `H` independent of the standard generator `G`, i.e.
+/// with no known discrete logarithm relative to `G`, from a `label`.
+///
+/// The label is hashed to the curve with the expander `X` and the uniform
+/// encoding, using the domain separation tag `ALTGEN-V1-` followed by the
+/// RFC 9380 suite ID of the curve, e.g. `ALTGEN-V1-P256_XMD:SHA-256_SSWU_RO_`.
+/// The message is the label followed by a big endian `u32` counter starting
+/// at zero, which is incremented in the (negligibly likely) case the result
+/// is the identity or `G`.
+///
+/// This derivation is normative and is kept stable across releases, so the
+/// same curve, expander and label always give the same `H`.
+///
+/// # Errors
+/// If the suite ID of the curve and `X` is unknown, or hashing fails (see
+/// [`GroupDigest::hash_from_bytes`]).
+pub fn alternate_generator<C, X>(label: &[u8]) -> Result<ProjectivePoint<C>>
+where
+    C: GroupDigest,
+    ProjectivePoint<C>: CofactorGroup,
+    X: ExpandMsgId + for<'a> ExpandMsg<'a>,
+{
+    let suite_id = C::suite_id::<X>(Encoding::Uniform)?;
+    let dst = suite_id.dst(ALTERNATE_GENERATOR_TAG);
+    let generator = ProjectivePoint::<C>::generator();
+
+    let mut counter = 0u32;
+    loop {
+        let point = C::hash_from_bytes::<X>(&[label, &counter.to_be_bytes()], &dst)?;
+
+        if !bool::from(point.is_identity()) && point != generator {
+            return Ok(point);
+        }
+
+        counter = counter.wrapping_add(1);
+    }
+}
+
+/// Pedersen commitments `value * G + blinding * H` to scalars, where `G` is
+/// the standard generator and `H` an alternate generator.
+///
+/// Commitments are additively homomorphic: the sum of two commitments opens
+/// to the sums of their values and blinding factors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Commitment<C: CurveArithmetic> {
+    h: ProjectivePoint<C>,
+}
+
+impl<C> Commitment<C>
+where
+    C: CurveArithmetic,
+{
+    /// Create commitments with the alternate generator `h`.
+    ///
+    /// No discrete logarithm of `h` relative to the standard generator may be
+    /// known, otherwise commitments are not binding. Prefer
+    /// [`Commitment::from_label`].
+    pub fn new(h: ProjectivePoint<C>) -> Self {
+        Self { h }
+    }
+
+    /// Create commitments with the alternate generator derived from `label`
+    /// with [`alternate_generator`].
+    ///
+    /// # Errors
+    /// See [`alternate_generator`].
+    pub fn from_label<X>(label: &[u8]) -> Result<Self>
+    where
+        C: GroupDigest,
+        ProjectivePoint<C>: CofactorGroup,
+        X: ExpandMsgId + for<'a> ExpandMsg<'a>,
+    {
+        alternate_generator::<C, X>(label).map(Self::new)
+    }
+
+    /// Get the alternate generator `H`.
+    pub fn h(&self) -> ProjectivePoint<C> {
+        self.h
+    }
+
+    /// Commit to `value` with the `blinding` factor, i.e. compute
+    /// `value * G + blinding * H`.
+    pub fn commit(&self, value: &Scalar<C>, blinding: &Scalar<C>) -> ProjectivePoint<C> {
+        ProjectivePoint::<C>::lincomb(&ProjectivePoint::<C>::generator(), value, &self.h, blinding)
+    }
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::{alternate_generator, Commitment};
+    use crate::{
+        dev::{MockCurve, ProjectivePoint, Scalar},
+        hash2curve::ExpandMsgXmd,
+    };
+    use ff::Field;
+    use group::Group;
+    use rand_core::OsRng;
+    use sha2::Sha256;
+
+    type X = ExpandMsgXmd<Sha256>;
+
+    #[test]
+    fn alternate_generator_deterministic() {
+        let h = alternate_generator::<MockCurve, X>(b"pedersen").unwrap();
+        assert_eq!(h, alternate_generator::<MockCurve, X>(b"pedersen").unwrap());
+        assert_ne!(h, ProjectivePoint::generator());
+        assert_ne!(h, ProjectivePoint::IDENTITY);
+        assert_eq!(
+            Commitment::<MockCurve>::from_label::<X>(b"pedersen")
+                .unwrap()
+                .h(),
+            h
+        );
+    }
+
+    #[test]
+    fn alternate_generator_labels() {
+        let h = alternate_generator::<MockCurve, X>(b"pedersen").unwrap();
+        assert_ne!(
+            h,
+            alternate_generator::<MockCurve, X>(b"pedersen2").unwrap()
+        );
+        assert_ne!(h, alternate_generator::<MockCurve, X>(b"").unwrap());
+    }
+
+    #[test]
+    fn commitment_homomorphic() {
+        let pedersen = Commitment::<MockCurve>::from_label::<X>(b"pedersen").unwrap();
+        let (v1, r1) = (Scalar::random(&mut OsRng), Scalar::random(&mut OsRng));
+        let (v2, r2) = (Scalar::random(&mut OsRng), Scalar::random(&mut OsRng));
+
+        let c1 = pedersen.commit(&v1, &r1);
+        let c2 = pedersen.commit(&v2, &r2);
+        assert_eq!(c1 + c2, pedersen.commit(&(v1 + v2), &(r1 + r2)));
+        assert_eq!(c1, ProjectivePoint::generator() * v1 + pedersen.h() * r1);
+        assert_ne!(c1, pedersen.commit(&v1, &r2));
+    }
+}
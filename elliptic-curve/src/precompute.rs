@@ -0,0 +1,58 @@
+//! Precomputed tables of point multiples.
+
+pub mod comb;
+
+mod basepoint;
+mod generator;
+
+pub use self::basepoint::{BasepointTable, TableGuard};
+pub use self::generator::GeneratorTable;
+#[cfg(feature = "critical-section")]
+pub use self::generator::{LazyGeneratorTable, SharedGeneratorTable};
+
+use crate::{CurveArithmetic, ProjectivePoint};
+use group::Group;
+
+/// Compute the table of odd multiples `[P, 3P, 5P, ..., (2N-1)P]`.
+///
+/// Entry `i` is `(2i + 1) * P`, computed using one doubling and `N - 1`
+/// additions. This is a building block for windowed scalar multiplication
+/// algorithms like wNAF.
+pub fn odd_multiples<C, const N: usize>(p: &ProjectivePoint<C>) -> [ProjectivePoint<C>; N]
+where
+    C: CurveArithmetic,
+{
+    let double = p.double();
+    let mut acc = *p;
+    let mut table = [acc; N];
+
+    for entry in table.iter_mut().skip(1) {
+        acc += double;
+        *entry = acc;
+    }
+
+    table
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::odd_multiples;
+    use crate::dev::{MockCurve, ProjectivePoint, Scalar};
+    use group::Group;
+
+    #[test]
+    fn odd_multiples_match_scalar_mul() {
+        let p = ProjectivePoint::generator() * Scalar::from(42u64);
+        let table = odd_multiples::<MockCurve, 8>(&p);
+
+        for (i, entry) in (0u64..).zip(table.iter()) {
+            assert_eq!(*entry, p * Scalar::from(2 * i + 1));
+        }
+    }
+
+    #[test]
+    fn odd_multiples_empty() {
+        let table = odd_multiples::<MockCurve, 0>(&ProjectivePoint::generator());
+        assert!(table.is_empty());
+    }
+}
@@ -0,0 +1,200 @@
+//! Precomputed tables of generator multiples with an explicit lifecycle.
+
+use super::comb;
+use crate::{CurveArithmetic, ProjectivePoint};
+use core::{fmt, marker::PhantomData, mem::size_of};
+use zeroize::Zeroize;
+
+/// Precomputed table of multiples of the generator for fixed-base
+/// multiplication.
+///
+/// Tables are large, and some (e.g. blinded) table schemes derive them from
+/// secret data, so they are always zeroized when dropped by a [`TableGuard`].
+///
+/// The provided methods use a comb with `log2(TABLE_LEN)` teeth, see
+/// [`comb`]. Curves may override them with an optimized implementation.
+pub trait BasepointTable: CurveArithmetic {
+    /// Owned storage for a table, e.g. `[ProjectivePoint<Self>; TABLE_LEN]`.
+    type Table: AsRef<[ProjectivePoint<Self>]> + AsMut<[ProjectivePoint<Self>]> + Default + Zeroize;
+
+    /// Number of points in a table.
+    const TABLE_LEN: usize;
+
+    /// Memory size of the entries of a table in bytes, e.g. for budgeting
+    /// static allocations on embedded targets.
+    const TABLE_SIZE: usize = Self::TABLE_LEN * size_of::<ProjectivePoint<Self>>();
+
+    /// Fill `entries` with the table.
+    ///
+    /// # Panics
+    ///
+    /// If the length of `entries` is not [`BasepointTable::TABLE_LEN`].
+    fn fill_table(entries: &mut [ProjectivePoint<Self>]) {
+        assert_eq!(entries.len(), Self::TABLE_LEN, "invalid table length");
+        comb::fill_table::<Self>(entries);
+    }
+
+    /// Multiply the generator by `scalar` in constant time using the table
+    /// `entries` filled by [`BasepointTable::fill_table`].
+    ///
+    /// # Panics
+    ///
+    /// If the length of `entries` is not [`BasepointTable::TABLE_LEN`].
+    fn mul_by_table(
+        entries: &[ProjectivePoint<Self>],
+        scalar: &Self::Scalar,
+    ) -> ProjectivePoint<Self> {
+        assert_eq!(entries.len(), Self::TABLE_LEN, "invalid table length");
+        comb::mul_with_table::<Self>(entries, scalar)
+    }
+}
+
+/// Owner of a [`BasepointTable`] which zeroizes the table when dropped.
+///
+/// The table is either stored inline in a [`BasepointTable::Table`], see
+/// [`TableGuard::new`], or in a caller-provided buffer, see
+/// [`TableGuard::new_in`], e.g. for `no_std` targets with static allocation.
+pub struct TableGuard<C, S = <C as BasepointTable>::Table>
+where
+    C: BasepointTable,
+    S: AsMut<[ProjectivePoint<C>]>,
+{
+    storage: S,
+    curve: PhantomData<C>,
+}
+
+impl<C> TableGuard<C>
+where
+    C: BasepointTable,
+{
+    /// Precompute the table into its owned storage.
+    pub fn new() -> Self {
+        let mut storage = C::Table::default();
+        C::fill_table(storage.as_mut());
+        Self {
+            storage,
+            curve: PhantomData,
+        }
+    }
+}
+
+impl<'a, C> TableGuard<C, &'a mut [ProjectivePoint<C>]>
+where
+    C: BasepointTable,
+{
+    /// Precompute the table into `buffer`, which is zeroized when the guard
+    /// is dropped.
+    ///
+    /// # Panics
+    ///
+    /// If the length of `buffer` is not [`BasepointTable::TABLE_LEN`].
+    pub fn new_in(buffer: &'a mut [ProjectivePoint<C>]) -> Self {
+        C::fill_table(buffer);
+        Self {
+            storage: buffer,
+            curve: PhantomData,
+        }
+    }
+}
+
+impl<C, S> TableGuard<C, S>
+where
+    C: BasepointTable,
+    S: AsRef<[ProjectivePoint<C>]> + AsMut<[ProjectivePoint<C>]>,
+{
+    /// Get the entries of the table.
+    pub fn entries(&self) -> &[ProjectivePoint<C>] {
+        self.storage.as_ref()
+    }
+
+    /// Multiply the generator by `scalar` in constant time.
+    pub fn mul(&self, scalar: &C::Scalar) -> ProjectivePoint<C> {
+        C::mul_by_table(self.entries(), scalar)
+    }
+}
+
+impl<C> Default for TableGuard<C>
+where
+    C: BasepointTable,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C, S> fmt::Debug for TableGuard<C, S>
+where
+    C: BasepointTable,
+    S: AsMut<[ProjectivePoint<C>]>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TableGuard").finish_non_exhaustive()
+    }
+}
+
+impl<C, S> Drop for TableGuard<C, S>
+where
+    C: BasepointTable,
+    S: AsMut<[ProjectivePoint<C>]>,
+{
+    fn drop(&mut self) {
+        self.storage.as_mut().zeroize();
+    }
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::{BasepointTable, TableGuard};
+    use crate::dev::{MockCurve, ProjectivePoint, Scalar};
+    use core::mem::size_of;
+    use ff::Field;
+    use group::Group;
+    use rand_core::OsRng;
+
+    #[test]
+    fn table_size() {
+        assert_eq!(MockCurve::TABLE_LEN, 16);
+        assert_eq!(MockCurve::TABLE_SIZE, 16 * size_of::<ProjectivePoint>());
+    }
+
+    #[test]
+    fn guard_matches_mul_by_generator() {
+        let table = TableGuard::<MockCurve>::new();
+        for _ in 0..16 {
+            let scalar = Scalar::random(&mut OsRng);
+            assert_eq!(table.mul(&scalar), ProjectivePoint::generator() * scalar);
+        }
+        assert_eq!(table.mul(&Scalar::ZERO), ProjectivePoint::IDENTITY);
+        assert_eq!(table.mul(&-Scalar::ONE), -ProjectivePoint::generator());
+    }
+
+    #[test]
+    fn buffer_matches_owned() {
+        let owned = TableGuard::<MockCurve>::new();
+        let mut buffer = [ProjectivePoint::IDENTITY; 16];
+        let borrowed = TableGuard::<MockCurve, _>::new_in(&mut buffer);
+        assert_eq!(borrowed.entries(), owned.entries());
+
+        let scalar = Scalar::random(&mut OsRng);
+        assert_eq!(borrowed.mul(&scalar), owned.mul(&scalar));
+    }
+
+    #[test]
+    fn drop_zeroizes() {
+        let mut buffer = [ProjectivePoint::IDENTITY; 16];
+        let guard = TableGuard::<MockCurve, _>::new_in(&mut buffer);
+        assert!(guard.entries()[1..]
+            .iter()
+            .all(|entry| !bool::from(entry.is_identity())));
+
+        drop(guard);
+        assert!(buffer.iter().all(|entry| bool::from(entry.is_identity())));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid table length")]
+    fn buffer_wrong_length() {
+        let mut buffer = [ProjectivePoint::IDENTITY; 8];
+        TableGuard::<MockCurve, _>::new_in(&mut buffer);
+    }
+}
@@ -0,0 +1,472 @@
+//! Helpers for fixed-base comb multiplication.
+//!
+//! A comb with `W` teeth spaced `D` bits apart splits a scalar `k` with bits
+//! `k_i` into a `W × D` bit matrix where row `w` holds bits `w * D` through
+//! `w * D + D - 1`, so that
+//!
+//! ```text
+//! k = Σ_d 2^d * Σ_w k[w][d] * 2^(w * D)
+//! ```
+//!
+//! Column `d` then selects the table entry `Σ_w k[w][d] * 2^(w * D) * G`,
+//! and the product is computed with `D - 1` doublings.
+
+use crate::{ops::mul, CurveArithmetic, ProjectivePoint, Scalar};
+use crypto_bigint::Limb;
+use ff::Field;
+use group::Group;
+use subtle::{Choice, ConditionallyNegatable};
+
+/// Number of teeth of a [`GeneratorComb`].
+const GENERATOR_COMB_TEETH: usize = 4;
+
+/// Largest supported number of teeth for [`SignedComb`].
+pub const MAX_SIGNED_COMB_TEETH: usize = 6;
+
+/// Recode `scalar` into the comb bit matrix with `W` teeth spaced `D` bits
+/// apart, i.e. `recoded[w][d]` is bit `w * D + d` of the scalar.
+///
+/// # Panics
+///
+/// If `W * D` is less than [`CurveArithmetic::ORDER_BITS`].
+pub fn recode<C, const W: usize, const D: usize>(scalar: &C::Scalar) -> [[bool; D]; W]
+where
+    C: CurveArithmetic,
+{
+    assert!(
+        W.checked_mul(D)
+            .map_or(true, |bits| bits >= C::ORDER_BITS as usize),
+        "comb too small"
+    );
+
+    let uint: C::Uint = (*scalar).into();
+    let limbs = uint.as_ref();
+    let mut recoded = [[false; D]; W];
+
+    for (w, row) in recoded.iter_mut().enumerate() {
+        for (d, bit) in row.iter_mut().enumerate() {
+            let i = w * D + d;
+            *bit = limbs
+                .get(i / Limb::BITS)
+                .map_or(false, |limb| (limb.0 >> (i % Limb::BITS)) & 1 == 1);
+        }
+    }
+
+    recoded
+}
+
+/// Comb table for multiplying the generator by many scalars, with
+/// `2^4` entries and teeth spaced `⌈ORDER_BITS / 4⌉` bits apart.
+///
+/// Each multiplication takes one doubling and one constant-time table lookup
+/// and addition per column of the comb.
+#[derive(Clone, Debug)]
+pub struct GeneratorComb<C: CurveArithmetic> {
+    /// `table[i] = Σ_w i[w] * 2^(w * D) * G`
+    table: [ProjectivePoint<C>; 1 << GENERATOR_COMB_TEETH],
+}
+
+impl<C: CurveArithmetic> GeneratorComb<C> {
+    /// Precompute the comb table for the generator.
+    pub fn new() -> Self {
+        let mut table = [ProjectivePoint::<C>::identity(); 1 << GENERATOR_COMB_TEETH];
+        fill_table::<C>(&mut table);
+        Self { table }
+    }
+
+    /// Multiply the generator by `scalar` in constant time.
+    pub fn mul(&self, scalar: &C::Scalar) -> ProjectivePoint<C> {
+        mul_with_table::<C>(&self.table, scalar)
+    }
+}
+
+impl<C: CurveArithmetic> Default for GeneratorComb<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Number of teeth and spacing between them for a comb table with `len`
+/// entries, i.e. `log2(len)` teeth spaced `⌈ORDER_BITS / teeth⌉` bits apart.
+///
+/// # Panics
+///
+/// If `len` is not a power of two greater than one.
+fn table_shape<C: CurveArithmetic>(len: usize) -> (usize, usize) {
+    assert!(
+        len > 1 && len.is_power_of_two(),
+        "invalid comb table length"
+    );
+
+    let teeth = len.trailing_zeros() as usize;
+    (teeth, (C::ORDER_BITS as usize + teeth - 1) / teeth)
+}
+
+/// Fill `table` with the comb table for the generator, with the shape given
+/// by [`table_shape`].
+///
+/// # Panics
+///
+/// If the length of `table` is not a power of two greater than one.
+pub(crate) fn fill_table<C: CurveArithmetic>(table: &mut [ProjectivePoint<C>]) {
+    let (teeth, spacing) = table_shape::<C>(table.len());
+    let mut tooth = ProjectivePoint::<C>::generator();
+    table[0] = ProjectivePoint::<C>::identity();
+
+    for w in 0..teeth {
+        let bit = 1 << w;
+        for i in bit..(bit << 1) {
+            table[i] = table[i ^ bit] + tooth;
+        }
+
+        for _ in 0..spacing {
+            tooth = tooth.double();
+        }
+    }
+}
+
+/// Multiply the generator by `scalar` in constant time with a comb `table`
+/// filled by [`fill_table`].
+///
+/// # Panics
+///
+/// If the length of `table` is not a power of two greater than one.
+#[allow(clippy::cast_possible_truncation)]
+pub(crate) fn mul_with_table<C: CurveArithmetic>(
+    table: &[ProjectivePoint<C>],
+    scalar: &C::Scalar,
+) -> ProjectivePoint<C> {
+    let (teeth, spacing) = table_shape::<C>(table.len());
+    let uint: C::Uint = (*scalar).into();
+    let limbs = uint.as_ref();
+    let mut acc = ProjectivePoint::<C>::identity();
+
+    for d in (0..spacing).rev() {
+        acc = acc.double();
+
+        let digit = (0..teeth).fold(0, |digit, w| {
+            let i = w * spacing + d;
+            let bit = limbs
+                .get(i / Limb::BITS)
+                .map_or(0, |limb| (limb.0 >> (i % Limb::BITS)) & 1);
+            digit | ((bit as u32) << w)
+        });
+
+        acc += mul::select(table, digit);
+    }
+
+    acc
+}
+
+/// Multiply the generator by `scalar` in constant time using a [`SignedComb`]
+/// with `W` teeth spaced `D` bits apart.
+///
+/// This precomputes the comb table on every call: use [`SignedComb`] directly
+/// to reuse it.
+///
+/// # Panics
+///
+/// If `W * D` is less than [`CurveArithmetic::ORDER_BITS`].
+pub fn mul_by_generator_ct<C, const W: usize, const D: usize>(
+    scalar: &Scalar<C>,
+) -> ProjectivePoint<C>
+where
+    C: CurveArithmetic,
+{
+    SignedComb::<C, W, D>::new().mul(scalar)
+}
+
+/// Signed-digit comb table for constant-time multiplication of the generator,
+/// with `W` teeth spaced `D` bits apart.
+///
+/// Scalars are recoded into digits `±1` for all of the `W * D` bit positions,
+/// so every column of the comb is a nonzero signed combination of the teeth.
+/// Only the combinations with a positive first tooth are stored, for a table
+/// of `2^(W - 1)` entries, and the others are computed by negation.
+///
+/// Each column costs one doubling, one table lookup scanning every entry, one
+/// conditional negation and one addition, independently of the scalar.
+///
+/// `W` must be between 1 and [`MAX_SIGNED_COMB_TEETH`], which is checked at
+/// compile time.
+#[derive(Clone, Debug)]
+pub struct SignedComb<C: CurveArithmetic, const W: usize, const D: usize> {
+    /// `table[j] = T_0 + Σ_w ±T_w` where `T_w = 2^(w * D) * G`, with the sign
+    /// of `T_w` given by bit `w - 1` of `j`.
+    table: [ProjectivePoint<C>; 1 << (MAX_SIGNED_COMB_TEETH - 1)],
+
+    /// `2^(W * D) - 1`, the offset for recoding scalars.
+    offset: Scalar<C>,
+
+    /// Inverse of two.
+    half: Scalar<C>,
+}
+
+impl<C, const W: usize, const D: usize> SignedComb<C, W, D>
+where
+    C: CurveArithmetic,
+{
+    const VALID: () = assert!(
+        W >= 1 && W <= MAX_SIGNED_COMB_TEETH,
+        "invalid number of teeth"
+    );
+
+    /// Precompute the comb table for the generator.
+    ///
+    /// # Panics
+    ///
+    /// If `W * D` is less than [`CurveArithmetic::ORDER_BITS`].
+    #[allow(clippy::integer_arithmetic)]
+    pub fn new() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID;
+
+        let bits = W
+            .checked_mul(D)
+            .filter(|bits| *bits >= C::ORDER_BITS as usize)
+            .expect("comb too small");
+
+        let mut tooth = ProjectivePoint::<C>::generator();
+        let mut teeth = [tooth; MAX_SIGNED_COMB_TEETH];
+        for entry in teeth.iter_mut().take(W).skip(1) {
+            tooth = (0..D).fold(tooth, |acc, _| acc.double());
+            *entry = tooth;
+        }
+
+        let mut table = [ProjectivePoint::<C>::identity(); 1 << (MAX_SIGNED_COMB_TEETH - 1)];
+        table[0] = teeth[1..W].iter().fold(teeth[0], |acc, tooth| acc - tooth);
+
+        for (w, tooth) in teeth.iter().enumerate().take(W).skip(1) {
+            let bit = 1 << (w - 1);
+            let tooth = tooth.double();
+            for j in bit..(bit << 1) {
+                table[j] = table[j ^ bit] + tooth;
+            }
+        }
+
+        let offset = (0..bits).fold(Scalar::<C>::ONE, |acc, _| acc.double()) - Scalar::<C>::ONE;
+
+        // This will always succeed since the curve order is odd
+        let half = Scalar::<C>::from(2u64).invert().unwrap();
+
+        Self {
+            table,
+            offset,
+            half,
+        }
+    }
+
+    /// Multiply the generator by `scalar` in constant time.
+    pub fn mul(&self, scalar: &Scalar<C>) -> ProjectivePoint<C> {
+        self.mul_with_selector(scalar, mul::select)
+    }
+
+    /// Multiply the generator by `scalar`, looking up table entries with
+    /// `select`.
+    ///
+    /// The scalar is recoded as `t = (k + 2^(W * D) - 1) / 2`, whose bits `t_i`
+    /// give the digits `2 * t_i - 1` of `k`.
+    #[allow(clippy::cast_possible_truncation, clippy::integer_arithmetic)]
+    fn mul_with_selector(
+        &self,
+        scalar: &Scalar<C>,
+        mut select: impl FnMut(&[ProjectivePoint<C>], u32) -> ProjectivePoint<C>,
+    ) -> ProjectivePoint<C> {
+        let recoded: C::Uint = ((*scalar + self.offset) * self.half).into();
+        let limbs = recoded.as_ref();
+        let bit = |i: usize| {
+            limbs
+                .get(i / Limb::BITS)
+                .map_or(0, |limb| ((limb.0 >> (i % Limb::BITS)) & 1) as u32)
+        };
+
+        let table = &self.table[..1 << (W - 1)];
+        let mut acc = ProjectivePoint::<C>::identity();
+
+        for d in (0..D).rev() {
+            acc = acc.double();
+
+            // The digit of the first tooth is the sign of the column, and the
+            // other teeth are positive when their digit matches it.
+            let sign = bit(d);
+            let index = (1..W).fold(0, |index, w| {
+                index | ((!(bit(w * D + d) ^ sign) & 1) << (w - 1))
+            });
+
+            let mut entry = select(table, index);
+            entry.conditional_negate(Choice::from((sign ^ 1) as u8));
+            acc += entry;
+        }
+
+        acc
+    }
+}
+
+impl<C, const W: usize, const D: usize> Default for SignedComb<C, W, D>
+where
+    C: CurveArithmetic,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Iterator over the products of the generator by a stream of scalars,
+/// returned by [`MulBatch::mul_by_generator_iter`].
+///
+/// [`MulBatch::mul_by_generator_iter`]: crate::ops::MulBatch::mul_by_generator_iter
+#[derive(Clone, Debug)]
+pub struct MulByGeneratorIter<C: CurveArithmetic, I> {
+    comb: GeneratorComb<C>,
+    scalars: I,
+}
+
+impl<C, I> MulByGeneratorIter<C, I>
+where
+    C: CurveArithmetic,
+    I: Iterator<Item = C::Scalar>,
+{
+    /// Create a new iterator, precomputing the comb table once.
+    pub fn new(scalars: I) -> Self {
+        Self {
+            comb: GeneratorComb::new(),
+            scalars,
+        }
+    }
+}
+
+impl<C, I> Iterator for MulByGeneratorIter<C, I>
+where
+    C: CurveArithmetic,
+    I: Iterator<Item = C::Scalar>,
+{
+    type Item = ProjectivePoint<C>;
+
+    fn next(&mut self) -> Option<ProjectivePoint<C>> {
+        self.scalars.next().map(|scalar| self.comb.mul(&scalar))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.scalars.size_hint()
+    }
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::{mul_by_generator_ct, recode, GeneratorComb, SignedComb};
+    use crate::dev::{wide::MockWideCurve, MockCurve, ProjectivePoint, Scalar};
+    use crate::ops::{mul, MulByGenerator};
+    use ff::Field;
+    use group::Group;
+    use rand_core::OsRng;
+
+    /// Reconstruct `Σ_w Σ_d recoded[w][d] * 2^(w * D + d)`.
+    fn reconstruct<const W: usize, const D: usize>(recoded: &[[bool; D]; W]) -> Scalar {
+        let mut acc = Scalar::ZERO;
+
+        for bit in recoded.iter().rev().flat_map(|row| row.iter().rev()) {
+            acc = acc.double();
+
+            if *bit {
+                acc += Scalar::ONE;
+            }
+        }
+
+        acc
+    }
+
+    #[test]
+    fn recode_round_trip() {
+        for scalar in [Scalar::ZERO, Scalar::ONE, -Scalar::ONE]
+            .into_iter()
+            .chain((0..16).map(|_| Scalar::random(&mut OsRng)))
+        {
+            assert_eq!(reconstruct(&recode::<MockCurve, 4, 64>(&scalar)), scalar);
+            assert_eq!(reconstruct(&recode::<MockCurve, 5, 52>(&scalar)), scalar);
+            assert_eq!(reconstruct(&recode::<MockCurve, 1, 256>(&scalar)), scalar);
+        }
+    }
+
+    #[test]
+    fn recode_layout() {
+        let recoded = recode::<MockCurve, 4, 64>(&Scalar::from(0b101u64));
+        assert!(recoded[0][0] && !recoded[0][1] && recoded[0][2]);
+
+        let recoded = recode::<MockCurve, 4, 64>(&Scalar::from(1u64 << 63).double());
+        assert!(recoded[1][0]);
+        assert_eq!(recoded.iter().flatten().filter(|bit| **bit).count(), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn recode_too_small() {
+        recode::<MockCurve, 4, 63>(&Scalar::ONE);
+    }
+
+    #[test]
+    fn generator_comb_matches_scalar_mul() {
+        let comb = GeneratorComb::<MockCurve>::new();
+
+        for scalar in [Scalar::ZERO, Scalar::ONE, -Scalar::ONE]
+            .into_iter()
+            .chain((0..16).map(|_| Scalar::random(&mut OsRng)))
+        {
+            assert_eq!(comb.mul(&scalar), ProjectivePoint::generator() * scalar);
+        }
+
+        let comb = GeneratorComb::<MockWideCurve>::new();
+        let scalar = crate::dev::wide::Scalar::random(&mut OsRng);
+        assert_eq!(
+            comb.mul(&scalar),
+            crate::dev::wide::ProjectivePoint::generator() * scalar
+        );
+    }
+
+    #[test]
+    fn signed_comb_matches_mul_by_generator() {
+        for scalar in [Scalar::ZERO, Scalar::ONE, -Scalar::ONE, Scalar::from(2u64)]
+            .into_iter()
+            .chain((0..8).map(|_| Scalar::random(&mut OsRng)))
+        {
+            let expected = ProjectivePoint::mul_by_generator(&scalar);
+            assert_eq!(mul_by_generator_ct::<MockCurve, 1, 256>(&scalar), expected);
+            assert_eq!(mul_by_generator_ct::<MockCurve, 4, 64>(&scalar), expected);
+            assert_eq!(mul_by_generator_ct::<MockCurve, 5, 52>(&scalar), expected);
+            assert_eq!(mul_by_generator_ct::<MockCurve, 6, 43>(&scalar), expected);
+        }
+
+        let scalar = crate::dev::wide::Scalar::random(&mut OsRng);
+        assert_eq!(
+            mul_by_generator_ct::<MockWideCurve, 4, 96>(&scalar),
+            crate::dev::wide::ProjectivePoint::generator() * scalar
+        );
+    }
+
+    #[test]
+    fn signed_comb_is_data_independent() {
+        let comb = SignedComb::<MockCurve, 4, 64>::new();
+
+        for scalar in [Scalar::ZERO, Scalar::ONE, -Scalar::ONE]
+            .into_iter()
+            .chain((0..4).map(|_| Scalar::random(&mut OsRng)))
+        {
+            let mut lookups = 0;
+            let product = comb.mul_with_selector(&scalar, |table, index| {
+                // Every lookup scans the whole table
+                assert_eq!(table.len(), 8);
+                assert!(index < 8);
+                lookups += 1;
+                mul::select(table, index)
+            });
+
+            assert_eq!(lookups, 64);
+            assert_eq!(product, ProjectivePoint::generator() * scalar);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn signed_comb_too_small() {
+        SignedComb::<MockCurve, 4, 63>::new();
+    }
+}
@@ -0,0 +1,203 @@
+//! Fixed-base comb tables for the generator, optionally shared by a whole
+//! process.
+
+use crate::{CurveArithmetic, ProjectivePoint};
+use crypto_bigint::Limb;
+use group::Group;
+use subtle::{ConditionallySelectable, ConstantTimeEq};
+
+#[cfg(feature = "critical-section")]
+use once_cell::sync::OnceCell;
+
+/// Number of comb teeth used by [`GeneratorTable`].
+const TEETH: usize = 4;
+
+/// Fixed-base comb table for the generator `G` of a curve.
+///
+/// Uses the layout described in the [`comb`](super::comb) module with 4 teeth
+/// spaced `D = ⌈ORDER_BITS / 4⌉` bits apart, so the table has 16 entries and
+/// [`GeneratorTable::mul`] takes `D` doublings and `D` additions.
+#[derive(Clone, Debug)]
+pub struct GeneratorTable<C: CurveArithmetic> {
+    /// Entry `i` is `Σ_w bit_w(i) * 2^(w * D) * G`.
+    entries: [C::AffinePoint; 1 << TEETH],
+}
+
+#[allow(clippy::integer_arithmetic)]
+impl<C: CurveArithmetic> GeneratorTable<C> {
+    /// Compute the table.
+    pub fn new() -> Self {
+        let mut teeth = [ProjectivePoint::<C>::generator(); TEETH];
+
+        for w in 1..TEETH {
+            teeth[w] = teeth[w - 1];
+
+            for _ in 0..Self::spacing() {
+                teeth[w] = teeth[w].double();
+            }
+        }
+
+        let mut entries = [ProjectivePoint::<C>::identity(); 1 << TEETH];
+
+        for i in 1..entries.len() {
+            entries[i] = entries[i & (i - 1)] + teeth[i.trailing_zeros() as usize];
+        }
+
+        let mut affine = [C::AffinePoint::default(); 1 << TEETH];
+        group::Curve::batch_normalize(&entries, &mut affine);
+        Self { entries: affine }
+    }
+
+    /// Multiply the generator by `scalar` in constant time.
+    pub fn mul(&self, scalar: &C::Scalar) -> ProjectivePoint<C> {
+        let uint: C::Uint = (*scalar).into();
+        let limbs = uint.as_ref();
+        let mut acc = ProjectivePoint::<C>::identity();
+
+        for d in (0..Self::spacing()).rev() {
+            acc = acc.double();
+
+            let index = (0..TEETH).fold(0u8, |index, w| {
+                let i = w * Self::spacing() + d;
+                let bit = limbs
+                    .get(i / Limb::BITS)
+                    .map_or(0, |limb| ((limb.0 >> (i % Limb::BITS)) & 1) as u8);
+                index | (bit << w)
+            });
+
+            let mut entry = C::AffinePoint::default();
+
+            for (j, candidate) in (0u8..).zip(&self.entries) {
+                entry.conditional_assign(candidate, j.ct_eq(&index));
+            }
+
+            acc += entry;
+        }
+
+        acc
+    }
+
+    /// Spacing `D` between the comb teeth in bits.
+    fn spacing() -> usize {
+        (C::ORDER_BITS as usize + TEETH - 1) / TEETH
+    }
+}
+
+impl<C: CurveArithmetic> Default for GeneratorTable<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Curve with a process-wide [`GeneratorTable`].
+///
+/// This saves each caller from building (and holding) its own table. Curves
+/// usually implement it with a [`LazyGeneratorTable`] static:
+///
+/// ```ignore
+/// impl SharedGeneratorTable for MyCurve {
+///     fn generator_table() -> &'static GeneratorTable<Self> {
+///         static TABLE: LazyGeneratorTable<MyCurve> = LazyGeneratorTable::new();
+///         TABLE.get()
+///     }
+/// }
+/// ```
+#[cfg(feature = "critical-section")]
+pub trait SharedGeneratorTable: CurveArithmetic {
+    /// Get the shared table, computing it on first use.
+    fn generator_table() -> &'static GeneratorTable<Self>;
+}
+
+/// Storage for a [`GeneratorTable`] which is computed by the first call to
+/// [`LazyGeneratorTable::get`] and shared by all subsequent calls.
+///
+/// Initialization is guarded by a critical section on `no_std` targets, or by
+/// the standard library's synchronization primitives when the `std` feature
+/// is enabled too. Once the table has been computed, [`LazyGeneratorTable::get`]
+/// is a single atomic load.
+#[cfg(feature = "critical-section")]
+pub struct LazyGeneratorTable<C: CurveArithmetic> {
+    table: OnceCell<GeneratorTable<C>>,
+}
+
+#[cfg(feature = "critical-section")]
+impl<C: CurveArithmetic> LazyGeneratorTable<C> {
+    /// Create empty storage, e.g. for a `static`.
+    pub const fn new() -> Self {
+        Self {
+            table: OnceCell::new(),
+        }
+    }
+
+    /// Get the table, computing it if this is the first call.
+    ///
+    /// Concurrent first calls block until the table has been computed once.
+    pub fn get(&self) -> &GeneratorTable<C> {
+        self.table.get_or_init(GeneratorTable::new)
+    }
+}
+
+#[cfg(feature = "critical-section")]
+impl<C: CurveArithmetic> Default for LazyGeneratorTable<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::GeneratorTable;
+    use crate::{
+        dev::{MockCurve, ProjectivePoint, Scalar},
+        ops::MulByGenerator,
+    };
+    use ff::Field;
+    use rand_core::OsRng;
+
+    #[test]
+    fn matches_mul_by_generator() {
+        let table = GeneratorTable::<MockCurve>::new();
+
+        for scalar in [Scalar::ZERO, Scalar::ONE, -Scalar::ONE]
+            .into_iter()
+            .chain((0..16).map(|_| Scalar::random(&mut OsRng)))
+        {
+            assert_eq!(
+                table.mul(&scalar),
+                ProjectivePoint::mul_by_generator(&scalar)
+            );
+        }
+    }
+
+    #[cfg(feature = "critical-section")]
+    #[test]
+    fn same_instance() {
+        use super::SharedGeneratorTable;
+        use core::ptr;
+
+        let table = MockCurve::generator_table();
+        assert!(ptr::eq(table, MockCurve::generator_table()));
+
+        let scalar = Scalar::random(&mut OsRng);
+        assert_eq!(
+            table.mul(&scalar),
+            ProjectivePoint::mul_by_generator(&scalar)
+        );
+    }
+
+    #[cfg(all(feature = "critical-section", feature = "std"))]
+    #[test]
+    fn same_instance_across_threads() {
+        use super::SharedGeneratorTable;
+        use std::{thread, vec::Vec};
+
+        let address = MockCurve::generator_table() as *const _ as usize;
+        let addresses = (0..4)
+            .map(|_| thread::spawn(|| MockCurve::generator_table() as *const _ as usize))
+            .collect::<Vec<_>>();
+
+        for handle in addresses {
+            assert_eq!(handle.join().unwrap(), address);
+        }
+    }
+}
@@ -1,10 +1,16 @@
 //! Elliptic curve public keys.
 
+mod x_only;
+
+pub use self::x_only::XOnlyPublicKey;
+
 use crate::{
-    point::NonIdentity, AffinePoint, CurveArithmetic, Error, NonZeroScalar, ProjectivePoint, Result,
+    point::NonIdentity, AffinePoint, CurveArithmetic, Error, ErrorKind, NonZeroScalar,
+    ProjectivePoint, Result,
 };
 use core::fmt::Debug;
-use group::{Curve, Group};
+use group::{Curve, Group, GroupEncoding};
+use subtle::CtOption;
 
 #[cfg(feature = "jwk")]
 use crate::{JwkEcKey, JwkParameters};
@@ -22,8 +28,11 @@ use {
         sec1::{CompressedPoint, EncodedPoint, FromEncodedPoint, ModulusSize, ToEncodedPoint},
         FieldBytesSize,
     },
-    core::cmp::Ordering,
-    subtle::{Choice, CtOption},
+    core::{
+        cmp::Ordering,
+        hash::{Hash, Hasher},
+    },
+    subtle::Choice,
 };
 
 #[cfg(all(feature = "alloc", feature = "pkcs8"))]
@@ -32,9 +41,26 @@ use pkcs8::EncodePublicKey;
 #[cfg(all(feature = "alloc", feature = "sec1"))]
 use alloc::boxed::Box;
 
+#[cfg(feature = "alloc")]
+use {crate::point::ToAffineBatch, alloc::vec::Vec, zeroize::Zeroizing};
+
 #[cfg(any(feature = "jwk", feature = "pem"))]
 use alloc::string::{String, ToString};
 
+#[cfg(feature = "rkyv")]
+use {
+    core::marker::PhantomData,
+    generic_array::typenum::Unsigned,
+    rkyv::{
+        bytecheck::CheckBytes,
+        munge::munge,
+        rancor::{Failure, Fallible, Source},
+        ser::{Allocator, Writer},
+        vec::{ArchivedVec, VecResolver},
+        Archive, Place, Portable,
+    },
+};
+
 #[cfg(feature = "serde")]
 use serdect::serde::{de, ser, Deserialize, Serialize};
 
@@ -55,6 +81,8 @@ use {
 /// This is a wrapper type for [`AffinePoint`] which ensures an inner
 /// non-identity point and provides a common place to handle encoding/decoding.
 ///
+/// Public keys are not secret, and are deliberately not zeroized on drop.
+///
 /// # Parsing "SPKI" Keys
 ///
 /// X.509 `SubjectPublicKeyInfo` (SPKI) is a commonly used format for encoding
@@ -86,6 +114,18 @@ use {
 /// Subject Public Key Info (SPKI) as the encoding format.
 ///
 /// For a more text-friendly encoding of public keys, use [`JwkEcKey`] instead.
+///
+/// # `arbitrary` support
+///
+/// When the optional `arbitrary` feature is enabled, an `Arbitrary` impl is
+/// provided which multiplies the generator by an arbitrary [`NonZeroScalar`],
+/// so every generated key is a valid point.
+///
+/// # `rkyv` support
+///
+/// When the optional `rkyv` feature is enabled, this type can be archived
+/// with [`rkyv`] as an [`ArchivedPublicKey`], which holds the SEC1 compressed
+/// point encoding.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PublicKey<C>
 where
@@ -99,14 +139,28 @@ where
     C: CurveArithmetic,
 {
     /// Convert an [`AffinePoint`] into a [`PublicKey`]
+    ///
+    /// Rejects the identity, as well as points outside the prime order
+    /// subgroup (see [`CurveArithmetic::is_torsion_free`]).
     pub fn from_affine(point: AffinePoint<C>) -> Result<Self> {
-        if ProjectivePoint::<C>::from(point).is_identity().into() {
-            Err(Error)
+        let projective = ProjectivePoint::<C>::from(point);
+
+        if projective.is_identity().into() {
+            Err(ErrorKind::PointAtInfinity.into())
+        } else if (!C::is_torsion_free(&projective)).into() {
+            Err(ErrorKind::PointNotInSubgroup.into())
         } else {
             Ok(Self { point })
         }
     }
 
+    /// Convert an [`AffinePoint`] into a [`PublicKey`] without any checks,
+    /// e.g. to test handling of small-order points.
+    #[cfg(test)]
+    pub(crate) fn from_affine_unchecked(point: AffinePoint<C>) -> Self {
+        Self { point }
+    }
+
     /// Compute a [`PublicKey`] from a secret [`NonZeroScalar`] value
     /// (i.e. a secret key represented as a raw scalar value)
     pub fn from_secret_scalar(scalar: &NonZeroScalar<C>) -> Self {
@@ -116,6 +170,45 @@ where
         }
     }
 
+    /// Compute the [`PublicKey`]s for many secret [`NonZeroScalar`] values
+    ///
+    /// Each multiplication by the generator is constant-time, while the
+    /// conversions to affine share a single [`ToAffineBatch`] pass.
+    #[cfg(feature = "alloc")]
+    pub fn batch_from_secret_scalars(scalars: &[NonZeroScalar<C>]) -> Vec<Self>
+    where
+        C: ToAffineBatch,
+    {
+        let scalars: Zeroizing<Vec<C::Scalar>> =
+            Zeroizing::new(scalars.iter().map(|scalar| **scalar).collect());
+
+        // `NonZeroScalar` ensures none of the resulting points are the identity
+        C::mul_by_generator_to_affine_batch::<Vec<_>>(&scalars)
+            .into_iter()
+            .map(|point| Self { point })
+            .collect()
+    }
+
+    /// Decode [`PublicKey`] from the curve's [`GroupEncoding`].
+    ///
+    /// For Weierstrass curves this is the SEC1 compressed form, and it is
+    /// available without the `sec1` feature. Rejects the identity and points
+    /// outside the prime order subgroup.
+    pub fn from_bytes(bytes: &<AffinePoint<C> as GroupEncoding>::Repr) -> CtOption<Self> {
+        AffinePoint::<C>::from_bytes(bytes).and_then(|point| {
+            let projective = ProjectivePoint::<C>::from(point);
+            let is_valid = !projective.is_identity() & C::is_torsion_free(&projective);
+            CtOption::new(Self { point }, is_valid)
+        })
+    }
+
+    /// Encode this [`PublicKey`] using the curve's [`GroupEncoding`].
+    ///
+    /// For Weierstrass curves this is the SEC1 compressed form.
+    pub fn to_bytes(&self) -> <AffinePoint<C> as GroupEncoding>::Repr {
+        self.point.to_bytes()
+    }
+
     /// Decode [`PublicKey`] (compressed or uncompressed) from the
     /// `Elliptic-Curve-Point-to-Octet-String` encoding described in
     /// SEC 1: Elliptic Curve Cryptography (Version 2.0) section
@@ -128,8 +221,13 @@ where
         FieldBytesSize<C>: ModulusSize,
         AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
     {
-        let point = EncodedPoint::<C>::from_bytes(bytes).map_err(|_| Error)?;
-        Option::from(Self::from_encoded_point(&point)).ok_or(Error)
+        let point = EncodedPoint::<C>::from_bytes(bytes).map_err(|_| ErrorKind::Decode)?;
+
+        if point.is_identity() {
+            return Err(ErrorKind::PointAtInfinity.into());
+        }
+
+        Option::from(Self::from_encoded_point(&point)).ok_or(ErrorKind::PointNotOnCurve.into())
     }
 
     /// Convert this [`PublicKey`] into the
@@ -232,7 +330,8 @@ where
     fn from_encoded_point(encoded_point: &EncodedPoint<C>) -> CtOption<Self> {
         AffinePoint::<C>::from_encoded_point(encoded_point).and_then(|point| {
             let is_identity = Choice::from(encoded_point.is_identity() as u8);
-            CtOption::new(PublicKey { point }, !is_identity)
+            let is_torsion_free = C::is_torsion_free(&point.into());
+            CtOption::new(PublicKey { point }, !is_identity & is_torsion_free)
         })
     }
 }
@@ -299,25 +398,31 @@ where
     }
 }
 
-impl<C, P> From<NonIdentity<P>> for PublicKey<C>
+/// Rejects points outside the prime order subgroup, see
+/// [`PublicKey::from_affine`].
+impl<C, P> TryFrom<NonIdentity<P>> for PublicKey<C>
 where
     C: CurveArithmetic,
     P: Copy + Into<AffinePoint<C>>,
 {
-    fn from(value: NonIdentity<P>) -> Self {
-        Self::from(&value)
+    type Error = Error;
+
+    fn try_from(value: NonIdentity<P>) -> Result<Self> {
+        Self::try_from(&value)
     }
 }
 
-impl<C, P> From<&NonIdentity<P>> for PublicKey<C>
+/// Rejects points outside the prime order subgroup, see
+/// [`PublicKey::from_affine`].
+impl<C, P> TryFrom<&NonIdentity<P>> for PublicKey<C>
 where
     C: CurveArithmetic,
     P: Copy + Into<AffinePoint<C>>,
 {
-    fn from(value: &NonIdentity<P>) -> Self {
-        Self {
-            point: value.to_point().into(),
-        }
+    type Error = Error;
+
+    fn try_from(value: &NonIdentity<P>) -> Result<Self> {
+        Self::from_affine(value.to_point().into())
     }
 }
 
@@ -366,6 +471,20 @@ where
     }
 }
 
+/// Hashes the SEC1 compressed encoding of the public key, so that equal
+/// public keys always hash equally.
+#[cfg(feature = "sec1")]
+impl<C> Hash for PublicKey<C>
+where
+    C: CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_encoded_point(true).as_bytes().hash(state);
+    }
+}
+
 #[cfg(feature = "sec1")]
 impl<C> TryFrom<CompressedPoint<C>> for PublicKey<C>
 where
@@ -500,7 +619,7 @@ where
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self> {
-        Self::from_public_key_pem(s).map_err(|_| Error)
+        Self::from_public_key_pem(s).map_err(|_| ErrorKind::Pkcs8.into())
     }
 }
 
@@ -517,6 +636,21 @@ where
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a, C> arbitrary::Arbitrary<'a> for PublicKey<C>
+where
+    C: CurveArithmetic,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        <NonZeroScalar<C> as arbitrary::Arbitrary<'a>>::arbitrary(u)
+            .map(|scalar| Self::from_secret_scalar(&scalar))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <NonZeroScalar<C> as arbitrary::Arbitrary<'a>>::size_hint(depth)
+    }
+}
+
 #[cfg(feature = "serde")]
 impl<C> Serialize for PublicKey<C>
 where
@@ -549,13 +683,208 @@ where
     }
 }
 
+/// Archived [`PublicKey`], i.e. its SEC1 compressed point encoding stored
+/// inline in an [`rkyv`] archive.
+///
+/// The structure of the archive is checked by [`rkyv::access`], but the
+/// encoding may still be invalid, so archives from untrusted sources should be
+/// accessed with [`ArchivedPublicKey::access`], which also checks that the
+/// encoding is a canonical compressed point and a valid public key.
+#[cfg(feature = "rkyv")]
+#[derive(CheckBytes, Portable)]
+#[bytecheck(crate = rkyv::bytecheck)]
+#[rkyv(crate = rkyv)]
+#[repr(C)]
+pub struct ArchivedPublicKey<C> {
+    bytes: ArchivedVec<u8>,
+    curve: PhantomData<C>,
+}
+
+#[cfg(feature = "rkyv")]
+impl<C> ArchivedPublicKey<C>
+where
+    C: CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    /// Access an archived public key in `bytes`, checking both the structure
+    /// of the archive and that it holds a valid public key.
+    pub fn access(bytes: &[u8]) -> Result<&Self> {
+        let archived = rkyv::access::<Self, Failure>(bytes).map_err(|_| ErrorKind::Decode)?;
+        archived.to_public_key()?;
+        Ok(archived)
+    }
+
+    /// Borrow the archived SEC1 compressed point encoding.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+
+    /// Decode the [`PublicKey`], checking that the encoding is a compressed
+    /// point.
+    pub fn to_public_key(&self) -> Result<PublicKey<C>> {
+        let point =
+            EncodedPoint::<C>::from_bytes(self.as_bytes()).map_err(|_| ErrorKind::Decode)?;
+
+        if !point.is_compressed() {
+            return Err(ErrorKind::Decode.into());
+        }
+
+        PublicKey::from_sec1_bytes(point.as_bytes())
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<C> Archive for PublicKey<C>
+where
+    C: CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    type Archived = ArchivedPublicKey<C>;
+    type Resolver = VecResolver;
+
+    fn resolve(&self, resolver: VecResolver, out: Place<Self::Archived>) {
+        let len = <FieldBytesSize<C> as ModulusSize>::CompressedPointSize::USIZE;
+        munge!(let ArchivedPublicKey { bytes, curve: _ } = out);
+        ArchivedVec::resolve_from_len(len, resolver, bytes);
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<C, S> rkyv::Serialize<S> for PublicKey<C>
+where
+    C: CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+    S: Fallible + Allocator + Writer + ?Sized,
+{
+    fn serialize(&self, serializer: &mut S) -> core::result::Result<VecResolver, S::Error> {
+        ArchivedVec::<u8>::serialize_from_slice(self.to_encoded_point(true).as_bytes(), serializer)
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<C, D> rkyv::Deserialize<PublicKey<C>, D> for ArchivedPublicKey<C>
+where
+    C: CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+    D: Fallible + ?Sized,
+    D::Error: Source,
+{
+    fn deserialize(&self, _: &mut D) -> core::result::Result<PublicKey<C>, D::Error> {
+        self.to_public_key().map_err(Source::new)
+    }
+}
+
 #[cfg(all(feature = "dev", test))]
 mod tests {
-    use crate::{dev::MockCurve, sec1::FromEncodedPoint};
+    use crate::{
+        dev::MockCurve,
+        sec1::{FromEncodedPoint, ToEncodedPoint},
+    };
+    use hex_literal::hex;
 
     type EncodedPoint = crate::sec1::EncodedPoint<MockCurve>;
     type PublicKey = super::PublicKey<MockCurve>;
 
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary() {
+        use crate::{
+            dev::{ProjectivePoint, SecretKey},
+            sec1::{validate, ValidationPolicy},
+        };
+        use arbitrary::{Arbitrary, Unstructured};
+        use group::{Curve, Group};
+
+        let inputs: [&[u8]; 4] = [&[0; 32], &[0xff; 32], &[0x5a; 64], &[0x01; 33]];
+        for input in inputs {
+            let public_key = PublicKey::arbitrary(&mut Unstructured::new(input)).unwrap();
+
+            for compress in [false, true] {
+                let encoded = public_key.to_encoded_point(compress);
+                validate::<MockCurve>(&encoded, ValidationPolicy::Full).unwrap();
+                assert_eq!(PublicKey::from_encoded_point(&encoded).unwrap(), public_key);
+            }
+
+            // Secret keys read the same input in the same way
+            let secret_key = SecretKey::arbitrary(&mut Unstructured::new(input)).unwrap();
+            assert_eq!(secret_key.public_key(), public_key);
+        }
+
+        let public_key = PublicKey::arbitrary(&mut Unstructured::new(&[0; 32])).unwrap();
+        assert_eq!(
+            public_key.as_affine(),
+            &ProjectivePoint::generator().to_affine()
+        );
+
+        assert!(PublicKey::arbitrary(&mut Unstructured::new(&[0xff; 31])).is_err());
+        assert!(SecretKey::arbitrary(&mut Unstructured::new(&[0xff; 31])).is_err());
+    }
+
+    #[test]
+    fn try_from_non_identity() {
+        use crate::{
+            dev::cofactor::{MockCofactorCurve, ProjectivePoint},
+            point::NonIdentity,
+            ErrorKind,
+        };
+        use group::Curve;
+
+        let generator = NonIdentity::new(ProjectivePoint::GENERATOR).unwrap();
+        assert_eq!(
+            super::PublicKey::<MockCofactorCurve>::try_from(generator)
+                .unwrap()
+                .to_projective(),
+            ProjectivePoint::GENERATOR
+        );
+
+        let torsion = ProjectivePoint::GENERATOR + ProjectivePoint::TORSION;
+        let torsion = NonIdentity::new(torsion.to_affine()).unwrap();
+        assert_eq!(
+            super::PublicKey::<MockCofactorCurve>::try_from(&torsion).map_err(|err| err.kind()),
+            Err(ErrorKind::PointNotInSubgroup)
+        );
+    }
+
+    #[test]
+    fn batch_from_secret_scalars() {
+        use crate::dev::SecretKey;
+        use alloc::vec::Vec;
+        use rand_core::OsRng;
+
+        let secret_keys: Vec<SecretKey> = (0..8).map(|_| SecretKey::random(&mut OsRng)).collect();
+        let scalars: Vec<_> = secret_keys
+            .iter()
+            .map(SecretKey::to_nonzero_scalar)
+            .collect();
+        let expected: Vec<_> = secret_keys.iter().map(SecretKey::public_key).collect();
+
+        assert_eq!(PublicKey::batch_from_secret_scalars(&scalars), expected);
+        assert!(PublicKey::batch_from_secret_scalars(&[]).is_empty());
+    }
+
+    #[test]
+    fn group_encoding_matches_sec1_compressed() {
+        use crate::dev::SecretKey;
+        use rand_core::OsRng;
+
+        let public_key = SecretKey::random(&mut OsRng).public_key();
+        let bytes = public_key.to_bytes();
+        assert_eq!(
+            bytes.as_slice(),
+            public_key.to_encoded_point(true).as_bytes()
+        );
+        assert_eq!(PublicKey::from_bytes(&bytes).unwrap(), public_key);
+
+        // The identity encodes as all-zeroes, which isn't a valid public key
+        assert!(bool::from(
+            PublicKey::from_bytes(&Default::default()).is_none()
+        ));
+    }
+
     #[test]
     fn from_encoded_point_rejects_identity() {
         let identity = EncodedPoint::identity();
@@ -563,4 +892,87 @@ mod tests {
             PublicKey::from_encoded_point(&identity).is_none()
         ));
     }
+
+    #[test]
+    fn rejects_small_subgroup_points() {
+        use crate::dev::cofactor::{self, AffinePoint, ProjectivePoint};
+        use group::Curve;
+
+        let generator = AffinePoint::GENERATOR;
+        let torsion = AffinePoint::TORSION;
+        let mixed = (ProjectivePoint::GENERATOR + ProjectivePoint::TORSION).to_affine();
+
+        assert!(cofactor::PublicKey::from_affine(generator).is_ok());
+        assert!(cofactor::PublicKey::from_affine(torsion).is_err());
+        assert!(cofactor::PublicKey::from_affine(mixed).is_err());
+
+        for point in [torsion, mixed] {
+            for compress in [false, true] {
+                let encoded = point.to_encoded_point(compress);
+                assert!(bool::from(
+                    cofactor::PublicKey::from_encoded_point(&encoded).is_none()
+                ));
+            }
+        }
+
+        let encoded = generator.to_encoded_point(true);
+        assert!(bool::from(
+            cofactor::PublicKey::from_encoded_point(&encoded).is_some()
+        ));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hash_dedups_equal_keys() {
+        use std::collections::HashSet;
+
+        let encoded = EncodedPoint::from_bytes(hex!(
+            "041CACFFB55F2F2CEFD89D89EB374B2681152452802DEEA09916068137D839CF7FC481A44492304D7EF66AC117BEFE83A8D08F155F2B52F9F618DD447029048E0F"
+        ))
+        .unwrap();
+        let a = PublicKey::from_encoded_point(&encoded).unwrap();
+        let b = PublicKey::from_sec1_bytes(a.to_encoded_point(true).as_bytes()).unwrap();
+        let c = PublicKey::from_affine(crate::dev::AffinePoint::GENERATOR).unwrap();
+
+        let set: HashSet<_> = [a, b, c].into_iter().collect();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&a));
+        assert!(set.contains(&c));
+    }
+
+    #[cfg(feature = "rkyv")]
+    #[test]
+    fn rkyv() {
+        use crate::{dev::SecretKey, ArchivedPublicKey};
+        use rand_core::OsRng;
+        use rkyv::rancor;
+
+        let public_key = SecretKey::random(&mut OsRng).public_key();
+        let compressed = public_key.to_encoded_point(true);
+        let mut bytes = rkyv::to_bytes::<rancor::Error>(&public_key).unwrap();
+
+        let archived = ArchivedPublicKey::<MockCurve>::access(&bytes).unwrap();
+        assert_eq!(archived.as_bytes(), compressed.as_bytes());
+        assert_eq!(archived.to_public_key().unwrap(), public_key);
+        assert_eq!(
+            rkyv::from_bytes::<PublicKey, rancor::Error>(&bytes).unwrap(),
+            public_key
+        );
+
+        let offset = bytes
+            .windows(33)
+            .position(|window| window == compressed.as_bytes())
+            .unwrap();
+
+        // Uncompressed tag
+        let mut tampered = bytes.clone();
+        tampered[offset] = 0x04;
+        assert!(ArchivedPublicKey::<MockCurve>::access(&tampered).is_err());
+
+        // Non-canonical x-coordinate with an intact archive structure
+        bytes[offset + 1..offset + 33].fill(0xff);
+        assert!(rkyv::access::<ArchivedPublicKey<MockCurve>, rancor::Error>(&bytes).is_ok());
+        assert!(ArchivedPublicKey::<MockCurve>::access(&bytes).is_err());
+        assert!(rkyv::from_bytes::<PublicKey, rancor::Error>(&bytes).is_err());
+    }
 }
@@ -0,0 +1,201 @@
+//! X-only public keys, as used by BIP-340 style protocols.
+
+use super::PublicKey;
+use crate::{
+    point::{AffineCoordinates, DecompressPoint},
+    AffinePoint, CurveArithmetic, FieldBytes, ProjectivePoint,
+};
+use group::Group;
+use subtle::{Choice, ConditionallyNegatable, ConstantTimeEq, CtOption};
+
+#[cfg(feature = "serde")]
+use serdect::serde::{de, ser, Deserialize, Serialize};
+
+/// Public key serialized as only its affine x-coordinate, with the
+/// y-coordinate implicitly even.
+///
+/// Converting a [`PublicKey`] with an odd y-coordinate negates it, which the
+/// owner of the secret key must account for by negating the secret scalar.
+/// [`XOnlyPublicKey::from_public_key`] reports when this happens.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct XOnlyPublicKey<C: CurveArithmetic> {
+    /// Point with an even y-coordinate.
+    point: AffinePoint<C>,
+}
+
+impl<C> XOnlyPublicKey<C>
+where
+    C: CurveArithmetic,
+{
+    /// Convert a [`PublicKey`] into an [`XOnlyPublicKey`], also returning a
+    /// [`Choice`] which is set iff the point was negated to make its
+    /// y-coordinate even.
+    pub fn from_public_key(public_key: &PublicKey<C>) -> (Self, Choice) {
+        let mut point = *public_key.as_affine();
+        let negated = point.y_is_odd();
+        point.conditional_negate(negated);
+        (Self { point }, negated)
+    }
+
+    /// Lift an x-coordinate to the point with an even y-coordinate.
+    ///
+    /// Returns `None` if `x` isn't the x-coordinate of a point on the curve,
+    /// or if the point is outside the prime order subgroup.
+    pub fn from_bytes(x: &FieldBytes<C>) -> CtOption<Self>
+    where
+        AffinePoint<C>: DecompressPoint<C>,
+    {
+        AffinePoint::<C>::decompress(x, Choice::from(0)).and_then(|point| {
+            let projective = ProjectivePoint::<C>::from(point);
+            let is_valid = !projective.is_identity() & C::is_torsion_free(&projective);
+            CtOption::new(Self { point }, is_valid)
+        })
+    }
+
+    /// Serialize this key as its affine x-coordinate.
+    pub fn to_bytes(&self) -> FieldBytes<C> {
+        self.point.x()
+    }
+
+    /// Borrow the inner [`AffinePoint`], which has an even y-coordinate.
+    pub fn as_affine(&self) -> &AffinePoint<C> {
+        &self.point
+    }
+
+    /// Convert this key into a [`PublicKey`] with an even y-coordinate.
+    pub fn to_public_key(&self) -> PublicKey<C> {
+        PublicKey { point: self.point }
+    }
+}
+
+impl<C> ConstantTimeEq for XOnlyPublicKey<C>
+where
+    C: CurveArithmetic,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.point.ct_eq(&other.point)
+    }
+}
+
+impl<C> From<PublicKey<C>> for XOnlyPublicKey<C>
+where
+    C: CurveArithmetic,
+{
+    fn from(public_key: PublicKey<C>) -> Self {
+        Self::from_public_key(&public_key).0
+    }
+}
+
+impl<C> From<&PublicKey<C>> for XOnlyPublicKey<C>
+where
+    C: CurveArithmetic,
+{
+    fn from(public_key: &PublicKey<C>) -> Self {
+        Self::from_public_key(public_key).0
+    }
+}
+
+impl<C> From<XOnlyPublicKey<C>> for PublicKey<C>
+where
+    C: CurveArithmetic,
+{
+    fn from(x_only: XOnlyPublicKey<C>) -> Self {
+        x_only.to_public_key()
+    }
+}
+
+impl<C> From<&XOnlyPublicKey<C>> for PublicKey<C>
+where
+    C: CurveArithmetic,
+{
+    fn from(x_only: &XOnlyPublicKey<C>) -> Self {
+        x_only.to_public_key()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<C> Serialize for XOnlyPublicKey<C>
+where
+    C: CurveArithmetic,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serdect::array::serialize_hex_lower_or_bin(&self.to_bytes(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, C> Deserialize<'de> for XOnlyPublicKey<C>
+where
+    C: CurveArithmetic,
+    AffinePoint<C>: DecompressPoint<C>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        let mut bytes = FieldBytes::<C>::default();
+        serdect::array::deserialize_hex_or_bin(&mut bytes, deserializer)?;
+        Option::from(Self::from_bytes(&bytes))
+            .ok_or_else(|| de::Error::custom("invalid x-only public key"))
+    }
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use crate::{
+        dev::{FieldBytes, MockCurve, ProjectivePoint, SecretKey},
+        point::AffineCoordinates,
+    };
+    use group::{Curve, Group};
+    use hex_literal::hex;
+    use rand_core::OsRng;
+
+    type PublicKey = crate::PublicKey<MockCurve>;
+    type XOnlyPublicKey = super::XOnlyPublicKey<MockCurve>;
+
+    #[test]
+    fn round_trip() {
+        for _ in 0..8 {
+            let public_key = SecretKey::random(&mut OsRng).public_key();
+            let (x_only, negated) = XOnlyPublicKey::from_public_key(&public_key);
+            assert_eq!(
+                bool::from(negated),
+                bool::from(public_key.as_affine().y_is_odd())
+            );
+            assert!(!bool::from(x_only.as_affine().y_is_odd()));
+
+            let bytes = x_only.to_bytes();
+            assert_eq!(bytes, public_key.as_affine().x());
+            assert_eq!(XOnlyPublicKey::from_bytes(&bytes).unwrap(), x_only);
+        }
+    }
+
+    #[test]
+    fn odd_y_is_negated() {
+        let mut point = ProjectivePoint::generator();
+        while !bool::from(point.to_affine().y_is_odd()) {
+            point = point.double();
+        }
+
+        let public_key = PublicKey::from_affine(point.to_affine()).unwrap();
+        let (x_only, negated) = XOnlyPublicKey::from_public_key(&public_key);
+        assert!(bool::from(negated));
+        assert_eq!(*x_only.as_affine(), -*public_key.as_affine());
+        assert_eq!(
+            PublicKey::from(x_only),
+            PublicKey::from_affine((-point).to_affine()).unwrap()
+        );
+    }
+
+    #[test]
+    fn rejects_x_not_on_curve() {
+        // x³ - 3x + b is not a square for x = p - 2
+        let x = FieldBytes::from(hex!(
+            "ffffffff00000001000000000000000000000000fffffffffffffffffffffffd"
+        ));
+        assert!(bool::from(XOnlyPublicKey::from_bytes(&x).is_none()));
+    }
+}
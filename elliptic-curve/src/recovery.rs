@@ -0,0 +1,160 @@
+//! Recovery of public keys from signature components, e.g. for ECDSA.
+
+use crate::{ops::LinearCombination, point::DecompressPoint, CurveArithmetic, FieldBytesEncoding};
+use crypto_bigint::CheckedAdd;
+use ff::Field;
+use group::{Curve as _, Group};
+use subtle::{Choice, ConditionallySelectable, CtOption};
+
+/// Recover the point which produced a signature from its components.
+///
+/// This trait is impl'd for every [`CurveArithmetic`] type.
+pub trait RecoverPoint: CurveArithmetic {
+    /// Recover the public key point `Q` from an ECDSA-style signature `(r, s)`
+    /// over the (reduced) message hash `z`, where `R = k * G` was the nonce
+    /// point:
+    ///
+    /// ```text
+    /// Q = r⁻¹ * (s * R - z * G)
+    /// ```
+    ///
+    /// `R` is lifted from `r` using the recovery ID flags, which record
+    /// whether its y-coordinate is odd and whether its x-coordinate was
+    /// reduced modulo the order, i.e. [`CurveArithmetic::x_overflows_order`].
+    ///
+    /// Returns `None` if `r` or `s` is zero, if `R` isn't a point on the
+    /// curve, or if the recovered point is the identity.
+    fn recover_point(
+        r: &Self::Scalar,
+        s: &Self::Scalar,
+        z: &Self::Scalar,
+        recovery_is_y_odd: Choice,
+        recovery_x_overflow: Choice,
+    ) -> CtOption<Self::AffinePoint>
+    where
+        Self::AffinePoint: DecompressPoint<Self>,
+    {
+        let r_uint = Self::Uint::decode_field_bytes(&(*r).into());
+        let r_plus_n = r_uint.checked_add(&Self::ORDER);
+        let x = Self::Uint::conditional_select(
+            &r_uint,
+            &r_plus_n.unwrap_or(r_uint),
+            recovery_x_overflow,
+        );
+        let x_is_valid = !recovery_x_overflow | r_plus_n.is_some();
+
+        Self::AffinePoint::decompress(&x.encode_field_bytes(), recovery_is_y_odd).and_then(
+            |big_r| {
+                let r_inv = r.invert();
+                let u1 = -(*z * r_inv.unwrap_or(Self::Scalar::ZERO));
+                let u2 = *s * r_inv.unwrap_or(Self::Scalar::ZERO);
+                let q = Self::ProjectivePoint::lincomb(
+                    &Self::ProjectivePoint::generator(),
+                    &u1,
+                    &Self::ProjectivePoint::from(big_r),
+                    &u2,
+                );
+
+                let is_valid = x_is_valid & r_inv.is_some() & !s.is_zero() & !q.is_identity();
+                CtOption::new(q.to_affine(), is_valid)
+            },
+        )
+    }
+}
+
+impl<C: CurveArithmetic> RecoverPoint for C {}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::RecoverPoint;
+    use crate::{
+        dev::{MockCurve, ProjectivePoint, PublicKey, Scalar},
+        ops::Reduce,
+        point::AffineCoordinates,
+        CurveArithmetic,
+    };
+    use ff::{Field, PrimeField};
+    use group::{Curve, Group};
+    use hex_literal::hex;
+    use rand_core::OsRng;
+    use sha2::{Digest, Sha256};
+    use subtle::Choice;
+
+    fn scalar(bytes: [u8; 32]) -> Scalar {
+        Scalar::from_repr(bytes.into()).unwrap()
+    }
+
+    #[test]
+    fn rfc6979_vector() {
+        // RFC 6979 A.2.5: P-256 with SHA-256, message "sample"
+        let k = scalar(hex!(
+            "A6E3C57DD01ABE90086538398355DD4C3B17AA873382B0F24D6129493D8AAD60"
+        ));
+        let r = scalar(hex!(
+            "EFD48B2AACB6A8FD1140DD9CD45E81D69D2C877B56AAF991C34D0EA84EAF3716"
+        ));
+        let s = scalar(hex!(
+            "F7CB1C942D657C41D436C7A1B6E29F65F3E900DBB9AFF4064DC4AB2F843ACDA8"
+        ));
+        let z = Scalar::reduce_bytes(&Sha256::digest(b"sample"));
+        let public_key = hex!(
+            "0460FED4BA255A9D31C961EB74C6356D68C049B8923B61FA6CE669622E60F29FB6"
+            "7903FE1008B8BC99A41AE9E95628BC64F2F1B20C2D7E9F5177A3C294D4462299"
+        );
+
+        let big_r = (ProjectivePoint::generator() * k).to_affine();
+        let y_is_odd = big_r.y_is_odd();
+        let recovered = MockCurve::recover_point(&r, &s, &z, y_is_odd, Choice::from(0)).unwrap();
+        assert_eq!(
+            recovered,
+            *PublicKey::from_sec1_bytes(&public_key).unwrap().as_affine()
+        );
+
+        // The other y-coordinate recovers a different key
+        let other = MockCurve::recover_point(&r, &s, &z, !y_is_odd, Choice::from(0)).unwrap();
+        assert_ne!(other, recovered);
+    }
+
+    #[test]
+    fn round_trip() {
+        let g = ProjectivePoint::generator();
+
+        for _ in 0..8 {
+            let d = Scalar::random(&mut OsRng);
+            let k = Scalar::random(&mut OsRng);
+            let z = Scalar::random(&mut OsRng);
+
+            let big_r = (g * k).to_affine();
+            let r = MockCurve::x_reduced_to_scalar(&big_r);
+            let s = k.invert().unwrap() * (z + r * d);
+
+            let recovered = MockCurve::recover_point(
+                &r,
+                &s,
+                &z,
+                big_r.y_is_odd(),
+                MockCurve::x_overflows_order(&big_r),
+            );
+            assert_eq!(recovered.unwrap(), (g * d).to_affine());
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_components() {
+        let one = Scalar::ONE;
+        let zero = Scalar::ZERO;
+        let no = Choice::from(0);
+        assert!(bool::from(
+            MockCurve::recover_point(&zero, &one, &one, no, no).is_none()
+        ));
+        assert!(bool::from(
+            MockCurve::recover_point(&one, &zero, &one, no, no).is_none()
+        ));
+
+        // r + n overflows the field modulus for this r
+        let r = -Scalar::ONE;
+        assert!(bool::from(
+            MockCurve::recover_point(&r, &one, &one, no, Choice::from(1)).is_none()
+        ));
+    }
+}
@@ -1,29 +1,117 @@
 //! Scalar types.
 
+#[cfg(feature = "arithmetic")]
+pub mod indices;
+#[cfg(all(feature = "alloc", feature = "arithmetic"))]
+pub mod interpolate;
+
 #[cfg(feature = "arithmetic")]
 mod blinded;
+#[cfg(feature = "rfc6979")]
+mod deterministic;
 #[cfg(feature = "arithmetic")]
 mod nonzero;
+#[cfg(feature = "arithmetic")]
+mod poly;
 mod primitive;
 
+#[cfg(feature = "rfc6979")]
+pub use self::deterministic::{derive_deterministic, DeterministicNonce};
+#[cfg(feature = "arithmetic")]
+pub use self::poly::Polynomial;
+#[cfg(feature = "rkyv")]
+pub use self::primitive::ArchivedScalarPrimitive;
 pub use self::primitive::ScalarPrimitive;
 #[cfg(feature = "arithmetic")]
 pub use self::{blinded::BlindedScalar, nonzero::NonZeroScalar};
 
-use crypto_bigint::Integer;
-use subtle::Choice;
+use crate::Curve;
+use crypto_bigint::{Integer, Limb};
+use subtle::{Choice, ConstantTimeEq, CtOption};
 
 #[cfg(feature = "arithmetic")]
-use crate::CurveArithmetic;
+use crate::ScalarArithmetic;
 
 /// Scalar field element for a particular elliptic curve.
 #[cfg(feature = "arithmetic")]
-pub type Scalar<C> = <C as CurveArithmetic>::Scalar;
+pub type Scalar<C> = <C as ScalarArithmetic>::Scalar;
 
 /// Bit representation of a scalar field element of a given curve.
 #[cfg(feature = "bits")]
 pub type ScalarBits<C> = ff::FieldBits<<Scalar<C> as ff::PrimeFieldBits>::ReprBits>;
 
+/// Reduce a `u64` modulo the order of the curve `C`.
+///
+/// This is total for any curve, including test curves whose order is smaller
+/// than `n`.
+#[cfg(feature = "arithmetic")]
+pub fn reduce_u64<C: ScalarArithmetic>(n: u64) -> Scalar<C> {
+    Scalar::<C>::from(n)
+}
+
+/// Reduce a `u128` modulo the order of the curve `C`.
+///
+/// This is total for any curve, including test curves whose order is smaller
+/// than `n`.
+#[cfg(feature = "arithmetic")]
+#[allow(clippy::cast_possible_truncation, clippy::integer_arithmetic)]
+pub fn reduce_u128<C: ScalarArithmetic>(n: u128) -> Scalar<C> {
+    let two_64 = ff::Field::square(&Scalar::<C>::from(1 << 32));
+    Scalar::<C>::from((n >> 64) as u64) * two_64 + Scalar::<C>::from(n as u64)
+}
+
+/// Bit length of the order of `C`.
+#[allow(clippy::integer_arithmetic)]
+pub(crate) fn order_bits<C: Curve>() -> usize {
+    let order = C::ORDER;
+    let limbs = order.as_ref();
+    limbs
+        .iter()
+        .rposition(|limb| limb.0 != 0)
+        .map_or(0, |i| i * Limb::BITS + limbs[i].bits())
+}
+
+/// Invert a scalar of the curve `C` in variable time using Fermat's little
+/// theorem, i.e. computing `scalar^(n - 2)`.
+///
+/// Returns `None` if `scalar` is zero.
+///
+/// ⚠️ WARNING!
+///
+/// This function must only be used with public values, e.g. Lagrange
+/// denominators of public indices, as its running time may depend on the
+/// value being inverted. Curve implementations can use it for
+/// [`Invert::invert_vartime`](crate::ops::Invert::invert_vartime) if they
+/// have no faster method.
+#[cfg(feature = "arithmetic")]
+#[allow(clippy::integer_arithmetic)]
+pub fn invert_vartime<C: ScalarArithmetic>(scalar: &Scalar<C>) -> CtOption<Scalar<C>> {
+    use crypto_bigint::CheckedSub;
+    use ff::Field;
+
+    let exponent = C::ORDER
+        .checked_sub(&C::Uint::from(2u64))
+        .unwrap_or(C::ORDER);
+    let limbs = exponent.as_ref();
+    let bit = |i: usize| (limbs[i / Limb::BITS].0 >> (i % Limb::BITS)) & 1 == 1;
+
+    let mut acc = Scalar::<C>::ONE;
+    let mut started = false;
+
+    for i in (0..C::Uint::BITS).rev() {
+        if started {
+            acc = acc.square();
+        }
+
+        if bit(i) {
+            acc *= scalar;
+            started = true;
+        }
+    }
+
+    CtOption::new(acc, !Field::is_zero(scalar))
+}
+
 /// Instantiate a scalar from an unsigned integer without checking for overflow.
 pub trait FromUintUnchecked {
     /// Unsigned integer type (i.e. `Curve::Uint`)
@@ -41,6 +129,24 @@ pub trait FromUintUnchecked {
     fn from_uint_unchecked(uint: Self::Uint) -> Self;
 }
 
+/// Conversions between scalars and unsigned integers in canonical form.
+///
+/// The integers are the canonical representatives in `[0, n)` of the scalars,
+/// never their internal (e.g. Montgomery) representation, and can be used for
+/// integer arithmetic outside of the scalar field.
+pub trait CanonicalUint: Sized {
+    /// Unsigned integer type (i.e. `Curve::Uint`)
+    type Uint: Integer;
+
+    /// Convert this scalar to its canonical integer representative.
+    fn to_canonical_uint(&self) -> Self::Uint;
+
+    /// Convert a canonical integer to a scalar.
+    ///
+    /// Returns `None` if `uint` overflows the order.
+    fn from_canonical_uint(uint: Self::Uint) -> CtOption<Self>;
+}
+
 /// Is this scalar greater than n / 2?
 ///
 /// # Returns
@@ -51,3 +157,228 @@ pub trait IsHigh {
     /// Is this scalar greater than or equal to n / 2?
     fn is_high(&self) -> Choice;
 }
+
+/// Constant-time predicates on scalars.
+///
+/// These are useful for fast paths, e.g. skipping a multiplication by one.
+pub trait Predicates {
+    /// Is this scalar equal to zero?
+    ///
+    /// Named so that `.is_zero()` on a field element stays unambiguous
+    /// with this trait in scope.
+    fn is_zero_ct(&self) -> Choice;
+
+    /// Is this scalar equal to one?
+    fn is_one_ct(&self) -> Choice;
+}
+
+#[cfg(feature = "arithmetic")]
+impl<T: ff::Field> Predicates for T {
+    fn is_zero_ct(&self) -> Choice {
+        self.is_zero()
+    }
+
+    fn is_one_ct(&self) -> Choice {
+        self.ct_eq(&T::ONE)
+    }
+}
+
+impl<C: Curve> Predicates for ScalarPrimitive<C> {
+    fn is_zero_ct(&self) -> Choice {
+        self.is_zero()
+    }
+
+    fn is_one_ct(&self) -> Choice {
+        self.ct_eq(&Self::ONE)
+    }
+}
+
+/// Constant-time checks of whether two field elements are inverses.
+#[cfg(feature = "arithmetic")]
+pub trait IsInverse {
+    /// Is `self * other == 1`?
+    fn is_mul_inverse_of(&self, other: &Self) -> Choice;
+
+    /// Is `self + other == 0`?
+    fn is_add_inverse_of(&self, other: &Self) -> Choice;
+}
+
+#[cfg(feature = "arithmetic")]
+impl<T: ff::Field> IsInverse for T {
+    fn is_mul_inverse_of(&self, other: &Self) -> Choice {
+        (*self * other).ct_eq(&T::ONE)
+    }
+
+    fn is_add_inverse_of(&self, other: &Self) -> Choice {
+        (*self + other).is_zero()
+    }
+}
+
+/// Additive tweaks of scalars, e.g. deriving a child key as in BIP32.
+#[cfg(feature = "arithmetic")]
+pub trait AddTweak: Sized {
+    /// Compute `self + tweak`.
+    ///
+    /// Returns `None` if `tweak` is zero or the sum is zero.
+    fn add_tweak(&self, tweak: &Self) -> CtOption<Self>;
+}
+
+#[cfg(feature = "arithmetic")]
+impl<T: ff::Field> AddTweak for T {
+    fn add_tweak(&self, tweak: &Self) -> CtOption<Self> {
+        let sum = *self + tweak;
+        CtOption::new(sum, !tweak.is_zero() & !sum.is_zero())
+    }
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::{
+        invert_vartime, reduce_u128, reduce_u64, AddTweak, BlindedScalar, CanonicalUint, IsInverse,
+        Predicates,
+    };
+    use crate::{
+        dev::{MockCurve, NonZeroScalar, Scalar, ScalarPrimitive, SecretKey},
+        ScalarArithmetic,
+    };
+    use ff::Field;
+    use rand_core::OsRng;
+    use zeroize::{Zeroize, ZeroizeOnDrop};
+
+    fn assert_zeroize_on_drop<T: ZeroizeOnDrop>() {}
+
+    /// Relies only on the `DefaultIsZeroes` bound on `ScalarArithmetic::Scalar`.
+    fn zeroize_generic<C: ScalarArithmetic>(scalar: &mut C::Scalar) {
+        scalar.zeroize();
+    }
+
+    #[test]
+    fn zeroize() {
+        assert_zeroize_on_drop::<SecretKey>();
+        assert_zeroize_on_drop::<BlindedScalar<MockCurve>>();
+
+        let mut scalar = Scalar::random(&mut OsRng);
+        zeroize_generic::<MockCurve>(&mut scalar);
+        assert_eq!(scalar, Scalar::ZERO);
+
+        let mut scalar = ScalarPrimitive::random(&mut OsRng);
+        scalar.zeroize();
+        assert_eq!(scalar, ScalarPrimitive::ZERO);
+
+        // Zeroizing a `NonZeroScalar` leaves it set to one
+        let mut scalar = NonZeroScalar::random(&mut OsRng);
+        scalar.zeroize();
+        assert_eq!(*scalar, Scalar::ONE);
+    }
+
+    #[test]
+    fn predicates() {
+        assert!(bool::from(Predicates::is_zero_ct(&Scalar::ZERO)));
+        assert!(!bool::from(Predicates::is_one_ct(&Scalar::ZERO)));
+        assert!(!bool::from(Predicates::is_zero_ct(&Scalar::ONE)));
+        assert!(bool::from(Predicates::is_one_ct(&Scalar::ONE)));
+
+        assert!(bool::from(Predicates::is_zero_ct(&ScalarPrimitive::ZERO)));
+        assert!(bool::from(Predicates::is_one_ct(&ScalarPrimitive::ONE)));
+        assert!(!bool::from(Predicates::is_one_ct(&ScalarPrimitive::ZERO)));
+
+        let scalar = Scalar::random(&mut OsRng);
+        assert!(!bool::from(Predicates::is_zero_ct(&scalar)));
+        assert!(!bool::from(Predicates::is_one_ct(&scalar)));
+    }
+
+    #[test]
+    fn is_inverse() {
+        let a = Scalar::random(&mut OsRng);
+        let a_inv = a.invert().unwrap();
+
+        assert!(bool::from(a.is_mul_inverse_of(&a_inv)));
+        assert!(bool::from(a_inv.is_mul_inverse_of(&a)));
+        assert!(bool::from(a.is_add_inverse_of(&-a)));
+        assert!(bool::from(Scalar::ZERO.is_add_inverse_of(&Scalar::ZERO)));
+
+        assert!(!bool::from(a.is_mul_inverse_of(&-a_inv)));
+        assert!(!bool::from(a.is_add_inverse_of(&a)));
+        assert!(!bool::from(Scalar::ZERO.is_mul_inverse_of(&Scalar::ZERO)));
+    }
+
+    #[test]
+    fn add_tweak() {
+        let parent = Scalar::random(&mut OsRng);
+        let tweak = Scalar::random(&mut OsRng);
+        assert_eq!(parent.add_tweak(&tweak).unwrap(), parent + tweak);
+
+        assert!(bool::from(parent.add_tweak(&Scalar::ZERO).is_none()));
+        assert!(bool::from(parent.add_tweak(&-parent).is_none()));
+        assert_eq!(Scalar::ZERO.add_tweak(&tweak).unwrap(), tweak);
+    }
+
+    #[test]
+    fn invert_vartime_matches_invert() {
+        for _ in 0..8 {
+            let a = Scalar::random(&mut OsRng);
+            assert_eq!(
+                invert_vartime::<MockCurve>(&a).unwrap(),
+                a.invert().unwrap()
+            );
+        }
+
+        assert_eq!(
+            invert_vartime::<MockCurve>(&Scalar::ONE).unwrap(),
+            Scalar::ONE
+        );
+        assert!(bool::from(
+            invert_vartime::<MockCurve>(&Scalar::ZERO).is_none()
+        ));
+        assert_eq!(
+            crate::ops::Invert::invert_vartime(&-Scalar::ONE).unwrap(),
+            -Scalar::ONE
+        );
+    }
+
+    #[test]
+    fn canonical_uint() {
+        use crate::{bigint::U256, Curve};
+
+        assert_eq!(Scalar::ONE.to_canonical_uint(), U256::ONE);
+        assert_eq!(Scalar::ZERO.to_canonical_uint(), U256::ZERO);
+        assert_eq!(
+            (-Scalar::ONE).to_canonical_uint(),
+            MockCurve::ORDER.wrapping_sub(&U256::ONE)
+        );
+        assert_eq!(ScalarPrimitive::ONE.to_canonical_uint(), U256::ONE);
+
+        for _ in 0..8 {
+            let scalar = Scalar::random(&mut OsRng);
+            let uint = scalar.to_canonical_uint();
+            assert_eq!(Scalar::from_canonical_uint(uint).unwrap(), scalar);
+            assert_eq!(uint, U256::from(scalar));
+        }
+
+        assert!(bool::from(
+            Scalar::from_canonical_uint(MockCurve::ORDER).is_none()
+        ));
+        assert!(bool::from(
+            ScalarPrimitive::from_canonical_uint(U256::MAX).is_none()
+        ));
+    }
+
+    #[test]
+    fn reduce_small_integers() {
+        use crate::{bigint::U256, ops::Reduce};
+
+        for n in [0, 1, u64::MAX] {
+            assert_eq!(
+                reduce_u64::<MockCurve>(n),
+                Scalar::reduce(U256::from_u64(n))
+            );
+        }
+
+        for n in [0, 1, u128::from(u64::MAX), u128::MAX] {
+            assert_eq!(
+                reduce_u128::<MockCurve>(n),
+                Scalar::reduce(U256::from_u128(n))
+            );
+        }
+    }
+}
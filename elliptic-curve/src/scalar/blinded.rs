@@ -1,11 +1,14 @@
 //! Random blinding support for [`Scalar`]
 
 use super::Scalar;
-use crate::{ops::Invert, CurveArithmetic};
+use crate::{
+    ops::{self, Invert},
+    ScalarArithmetic,
+};
 use group::ff::Field;
 use rand_core::CryptoRngCore;
 use subtle::CtOption;
-use zeroize::Zeroize;
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
 
 /// Scalar blinded with a randomly generated masking value.
 ///
@@ -14,10 +17,24 @@ use zeroize::Zeroize;
 ///
 /// It implements masked variable-time inversions using Stein's algorithm, which
 /// may be helpful for performance on embedded platforms.
+///
+/// # Side-channel model
+///
+/// Blinding targets passive side-channel attacks (timing, power, and EM
+/// analysis) on the expensive arithmetic: the inversion only operates on the
+/// product of the scalar with a secret, uniformly random mask, and
+/// [`BlindedScalar::mul_blinded`] only multiplies the sum of the scalar and
+/// the mask, and the mask itself. The raw scalar still goes through the
+/// masking multiplication and addition, which are not protected. It assumes
+/// the mask remains secret and offers no protection against fault injection
+/// or against leakage of the unmasked scalar by the caller.
+///
+/// Calling [`BlindedScalar::rerandomize`] between sensitive operations
+/// prevents an attacker from correlating leakage across them.
 #[derive(Clone)]
 pub struct BlindedScalar<C>
 where
-    C: CurveArithmetic,
+    C: ScalarArithmetic,
 {
     /// Actual scalar value.
     scalar: Scalar<C>,
@@ -28,7 +45,7 @@ where
 
 impl<C> BlindedScalar<C>
 where
-    C: CurveArithmetic,
+    C: ScalarArithmetic,
 {
     /// Create a new [`BlindedScalar`] from a scalar and a [`CryptoRngCore`].
     pub fn new(scalar: Scalar<C>, rng: &mut impl CryptoRngCore) -> Self {
@@ -37,11 +54,36 @@ where
             mask: Scalar::<C>::random(rng),
         }
     }
+
+    /// Re-randomize the mask by multiplying it with a fresh random factor.
+    pub fn rerandomize(&mut self, rng: &mut impl CryptoRngCore) {
+        self.mask *= Scalar::<C>::random(rng);
+    }
+
+    /// Compute the inverse of the scalar via masked inversion.
+    ///
+    /// The masked value `scalar * mask` is inverted and the result is unmasked
+    /// by multiplying with `mask`, so the raw scalar is never inverted directly.
+    pub fn invert(&self) -> CtOption<Scalar<C>> {
+        // prevent side channel analysis of scalar inversion by pre-and-post-multiplying
+        // with the random masking scalar
+        ops::invert_with_mask(&self.scalar, &self.mask)
+    }
+
+    /// Multiply the scalar by `other` using additive masking.
+    ///
+    /// Computes `(scalar + mask) * other - mask * other`, so the multiplications
+    /// only operate on the masked sum and the mask, while the raw scalar only
+    /// goes through the addition.
+    pub fn mul_blinded(&self, other: &Scalar<C>) -> Scalar<C> {
+        let masked = Zeroizing::new(self.scalar + self.mask);
+        *masked * other - self.mask * other
+    }
 }
 
 impl<C> AsRef<Scalar<C>> for BlindedScalar<C>
 where
-    C: CurveArithmetic,
+    C: ScalarArithmetic,
 {
     fn as_ref(&self) -> &Scalar<C> {
         &self.scalar
@@ -50,25 +92,75 @@ where
 
 impl<C> Invert for BlindedScalar<C>
 where
-    C: CurveArithmetic,
+    C: ScalarArithmetic,
 {
     type Output = CtOption<Scalar<C>>;
 
     fn invert(&self) -> CtOption<Scalar<C>> {
-        // prevent side channel analysis of scalar inversion by pre-and-post-multiplying
-        // with the random masking scalar
-        (self.scalar * self.mask)
-            .invert_vartime()
-            .map(|s| s * self.mask)
+        BlindedScalar::invert(self)
     }
 }
 
 impl<C> Drop for BlindedScalar<C>
 where
-    C: CurveArithmetic,
+    C: ScalarArithmetic,
 {
     fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<C> Zeroize for BlindedScalar<C>
+where
+    C: ScalarArithmetic,
+{
+    fn zeroize(&mut self) {
         self.scalar.zeroize();
         self.mask.zeroize();
     }
 }
+
+impl<C> ZeroizeOnDrop for BlindedScalar<C> where C: ScalarArithmetic {}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::BlindedScalar;
+    use crate::dev::{MockCurve, Scalar};
+    use ff::Field;
+    use rand_core::OsRng;
+    use zeroize::Zeroize;
+
+    #[test]
+    fn invert() {
+        let scalar = Scalar::random(&mut OsRng);
+        let blinded = BlindedScalar::<MockCurve>::new(scalar, &mut OsRng);
+        assert_eq!(blinded.invert().unwrap(), scalar.invert().unwrap());
+    }
+
+    #[test]
+    fn mul_blinded() {
+        let scalar = Scalar::random(&mut OsRng);
+        let other = Scalar::random(&mut OsRng);
+        let blinded = BlindedScalar::<MockCurve>::new(scalar, &mut OsRng);
+        assert_eq!(blinded.mul_blinded(&other), scalar * other);
+    }
+
+    #[test]
+    fn rerandomize() {
+        let scalar = Scalar::random(&mut OsRng);
+        let mut blinded = BlindedScalar::<MockCurve>::new(scalar, &mut OsRng);
+        let mask = blinded.mask;
+        blinded.rerandomize(&mut OsRng);
+        assert_ne!(blinded.mask, mask);
+        assert_eq!(blinded.invert().unwrap(), scalar.invert().unwrap());
+        assert_eq!(*blinded.as_ref(), scalar);
+    }
+
+    #[test]
+    fn zeroize() {
+        let mut blinded = BlindedScalar::<MockCurve>::new(Scalar::from(42u64), &mut OsRng);
+        blinded.zeroize();
+        assert_eq!(blinded.scalar, Scalar::ZERO);
+        assert_eq!(blinded.mask, Scalar::ZERO);
+    }
+}
@@ -0,0 +1,253 @@
+//! Deterministic scalar derivation as described in [RFC 6979].
+//!
+//! [RFC 6979]: https://datatracker.ietf.org/doc/html/rfc6979
+
+use super::{order_bits, NonZeroScalar, ScalarPrimitive};
+use crate::{
+    bigint::{ArrayEncoding, ByteArray},
+    ops::{Reduce, ReduceNonZero},
+    FieldBytes, Scalar, ScalarArithmetic,
+};
+use digest::{core_api::BlockSizeUser, Digest, Output};
+use hmac::{Mac, SimpleHmac};
+use zeroize::Zeroizing;
+
+/// Derive a nonzero scalar deterministically from a secret scalar and a
+/// message digest, using the HMAC-DRBG construction of [RFC 6979 § 3.2].
+///
+/// `message_digest` is the message hash already truncated to the bit length of
+/// the order (i.e. `bits2int` applied), as used for computing an ECDSA
+/// signature. `additional_data` is appended to the seed of the DRBG as
+/// described in [RFC 6979 § 3.6], e.g. for hedged signatures with added
+/// randomness; with no additional data this matches the RFC test vectors.
+///
+/// Candidates which are zero or overflow the order are rejected, with the
+/// DRBG updated and queried again, so the number of iterations depends on
+/// the output but the scalar arithmetic does not.
+///
+/// [RFC 6979 § 3.2]: https://datatracker.ietf.org/doc/html/rfc6979#section-3.2
+/// [RFC 6979 § 3.6]: https://datatracker.ietf.org/doc/html/rfc6979#section-3.6
+pub fn derive_deterministic<C, D>(
+    secret: &NonZeroScalar<C>,
+    message_digest: &FieldBytes<C>,
+    additional_data: &[&[u8]],
+) -> NonZeroScalar<C>
+where
+    C: ScalarArithmetic,
+    D: Digest + BlockSizeUser,
+{
+    let x = Zeroizing::new(Into::<FieldBytes<C>>::into(*secret.as_ref()));
+    let h1: FieldBytes<C> = Scalar::<C>::reduce_bytes(message_digest).into();
+
+    let mut k = Zeroizing::new(Output::<D>::default());
+    let mut v = Zeroizing::new(Output::<D>::default());
+    v.iter_mut().for_each(|byte| *byte = 0x01);
+
+    for domain in [0x00, 0x01] {
+        *k = hmac::<D>(&k, &[&v, &[domain], &x, &h1], additional_data);
+        *v = hmac::<D>(&k, &[&v], &[]);
+    }
+
+    let shift = x.len().saturating_mul(8).saturating_sub(order_bits::<C>());
+
+    loop {
+        let mut t = Zeroizing::new(FieldBytes::<C>::default());
+        for chunk in t.chunks_mut(v.len()) {
+            *v = hmac::<D>(&k, &[&v], &[]);
+            chunk.copy_from_slice(&v[..chunk.len()]);
+        }
+
+        let candidate = ScalarPrimitive::<C>::new(bits2int::<C>(&t, shift))
+            .and_then(|scalar| NonZeroScalar::new(scalar.into()));
+
+        if let Some(scalar) = Option::from(candidate) {
+            return scalar;
+        }
+
+        *k = hmac::<D>(&k, &[&v, &[0x00]], &[]);
+        *v = hmac::<D>(&k, &[&v], &[]);
+    }
+}
+
+/// Deterministic derivation of nonce scalars, e.g. for signature schemes.
+///
+/// This is blanket impl'd for all curves whose scalars support
+/// [`ReduceNonZero`].
+pub trait DeterministicNonce: ScalarArithmetic {
+    /// Derive a nonzero nonce from a secret scalar, the `entropy` input (e.g.
+    /// a message digest) and `extra` data, using HMAC-DRBG with the digest `D`
+    /// as in [RFC 6979 § 3.2].
+    ///
+    /// Unlike [`derive_deterministic`], the output of the DRBG is reduced to a
+    /// nonzero scalar with [`ReduceNonZero`] rather than rejection sampled, so
+    /// it does not match the RFC 6979 test vectors.
+    ///
+    /// [RFC 6979 § 3.2]: https://datatracker.ietf.org/doc/html/rfc6979#section-3.2
+    fn derive_nonce<D>(secret: &Self::Scalar, entropy: &[u8], extra: &[u8]) -> Self::Scalar
+    where
+        D: Digest + BlockSizeUser;
+}
+
+impl<C> DeterministicNonce for C
+where
+    C: ScalarArithmetic,
+    Scalar<C>: ReduceNonZero<C::Uint, Bytes = FieldBytes<C>>,
+{
+    fn derive_nonce<D>(secret: &Scalar<C>, entropy: &[u8], extra: &[u8]) -> Scalar<C>
+    where
+        D: Digest + BlockSizeUser,
+    {
+        let x = Zeroizing::new(Into::<FieldBytes<C>>::into(*secret));
+
+        let mut k = Zeroizing::new(Output::<D>::default());
+        let mut v = Zeroizing::new(Output::<D>::default());
+        v.iter_mut().for_each(|byte| *byte = 0x01);
+
+        for domain in [0x00, 0x01] {
+            *k = hmac::<D>(&k, &[&v, &[domain], &x, entropy, extra], &[]);
+            *v = hmac::<D>(&k, &[&v], &[]);
+        }
+
+        let mut t = Zeroizing::new(FieldBytes::<C>::default());
+        for chunk in t.chunks_mut(v.len()) {
+            *v = hmac::<D>(&k, &[&v], &[]);
+            chunk.copy_from_slice(&v[..chunk.len()]);
+        }
+
+        Scalar::<C>::reduce_nonzero_bytes(&t)
+    }
+}
+
+/// Compute HMAC over the concatenation of `data` and `additional_data`.
+fn hmac<D>(key: &[u8], data: &[&[u8]], additional_data: &[&[u8]]) -> Output<D>
+where
+    D: Digest + BlockSizeUser,
+{
+    let mut mac = <SimpleHmac<D> as Mac>::new_from_slice(key).expect("HMAC accepts any key size");
+    for input in data.iter().chain(additional_data) {
+        mac.update(input);
+    }
+    mac.finalize().into_bytes()
+}
+
+/// Interpret `bytes` as a big endian integer, shifted right by `shift` bits,
+/// i.e. `bits2int` of RFC 6979.
+fn bits2int<C: ScalarArithmetic>(bytes: &FieldBytes<C>, shift: usize) -> C::Uint {
+    let mut byte_array = Zeroizing::new(ByteArray::<C::Uint>::default());
+    let offset = byte_array.len().saturating_sub(bytes.len());
+    byte_array[offset..].copy_from_slice(bytes);
+
+    let mut uint = C::Uint::from_be_byte_array((*byte_array).clone());
+    uint >>= shift;
+    uint
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::DeterministicNonce;
+    use crate::dev::{FieldBytes, MockCurve, NonZeroScalar, Scalar};
+    use ff::{Field, PrimeField};
+    use hex_literal::hex;
+    use sha2::{Digest, Sha256, Sha512};
+
+    /// RFC 6979 A.2.5: P-256 secret key.
+    const SECRET: [u8; 32] =
+        hex!("C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721");
+
+    fn secret() -> NonZeroScalar {
+        NonZeroScalar::from_repr(SECRET.into()).unwrap()
+    }
+
+    fn derive(secret: &NonZeroScalar, digest: &FieldBytes, data: &[&[u8]]) -> Scalar {
+        *super::derive_deterministic::<MockCurve, Sha256>(secret, digest, data)
+    }
+
+    #[test]
+    fn rfc6979_vectors() {
+        let digest = Sha256::digest(b"sample");
+        let k = derive(&secret(), &digest, &[]);
+        assert_eq!(
+            k.to_repr(),
+            hex!("A6E3C57DD01ABE90086538398355DD4C3B17AA873382B0F24D6129493D8AAD60").into()
+        );
+
+        let digest = Sha256::digest(b"test");
+        let k = derive(&secret(), &digest, &[]);
+        assert_eq!(
+            k.to_repr(),
+            hex!("D16B6AE827F17175E040871A1C7EC3500192C4C92677336EC2537ACAEE0008E0").into()
+        );
+
+        // SHA-512 digests are truncated to the leftmost 256 bits
+        let digest = Sha512::digest(b"sample");
+        let k = super::derive_deterministic::<MockCurve, Sha512>(
+            &secret(),
+            FieldBytes::from_slice(&digest[..32]),
+            &[],
+        );
+        assert_eq!(
+            k.to_repr(),
+            hex!("5FA81C63109BADB88C1F367B47DA606DA28CAD69AA22C4FE6AD7DF73A7173AA5").into()
+        );
+    }
+
+    #[test]
+    fn deterministic() {
+        let digest = Sha256::digest(b"sample");
+        let data: &[&[u8]] = &[b"hedged", b"nonce"];
+        assert_eq!(
+            derive(&secret(), &digest, data),
+            derive(&secret(), &digest, data)
+        );
+        assert_ne!(
+            derive(&secret(), &digest, data),
+            derive(&secret(), &digest, &[])
+        );
+    }
+
+    #[test]
+    fn sensitive_to_every_input_byte() {
+        let digest = Sha256::digest(b"sample");
+        let data = *b"additional data";
+        let k = derive(&secret(), &digest, &[&data]);
+
+        for i in 0..SECRET.len() {
+            let mut bytes = SECRET;
+            bytes[i] ^= 1;
+            let secret = NonZeroScalar::from_repr(bytes.into()).unwrap();
+            assert_ne!(derive(&secret, &digest, &[&data]), k);
+        }
+
+        for i in 0..digest.len() {
+            let mut bytes = digest;
+            bytes[i] ^= 1;
+            assert_ne!(derive(&secret(), &bytes, &[&data]), k);
+        }
+
+        for i in 0..data.len() {
+            let mut bytes = data;
+            bytes[i] ^= 1;
+            assert_ne!(derive(&secret(), &digest, &[&bytes]), k);
+        }
+    }
+
+    #[test]
+    fn derive_nonce() {
+        let secret = Scalar::from_repr(SECRET.into()).unwrap();
+        let nonce = |secret: &Scalar, entropy: &[u8], extra: &[u8]| {
+            MockCurve::derive_nonce::<Sha256>(secret, entropy, extra)
+        };
+
+        let k = nonce(&secret, b"message", b"extra");
+        assert_eq!(k, nonce(&secret, b"message", b"extra"));
+        assert_ne!(k, nonce(&secret, b"message", b""));
+        assert_ne!(k, nonce(&secret, b"massage", b"extra"));
+        assert_ne!(k, nonce(&-secret, b"message", b"extra"));
+
+        for i in 0..64u8 {
+            let k = nonce(&Scalar::from(u64::from(i)), &[i], &[]);
+            assert!(!bool::from(k.is_zero()));
+            assert_eq!(Scalar::from_repr(k.to_repr()).unwrap(), k);
+        }
+    }
+}
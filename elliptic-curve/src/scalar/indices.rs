@@ -0,0 +1,123 @@
+//! Scalars for small integer indices, e.g. participant identifiers in
+//! threshold schemes, and Lagrange interpolation over them.
+
+use crate::{Scalar, ScalarArithmetic};
+use ff::Field;
+use subtle::{Choice, CtOption};
+
+/// Convert a small integer index into a scalar.
+pub fn scalar_from_index<C: ScalarArithmetic>(i: usize) -> Scalar<C> {
+    Scalar::<C>::from(i as u64)
+}
+
+/// Compute the Lagrange coefficient for `xs[j]` evaluated at `at`, i.e.
+/// `Π (at - xs[m]) / (xs[j] - xs[m])` over all `m != j`.
+///
+/// Interpolating shares `(xs[i], y[i])` of a polynomial at `at` (usually
+/// zero, to recover the secret) is then `Σ y[i] * lagrange_coefficient(xs, i, at)`.
+///
+/// Returns `None` if `j` is out of bounds or `xs[j]` appears more than once.
+pub fn lagrange_coefficient<C: ScalarArithmetic>(
+    xs: &[Scalar<C>],
+    j: usize,
+    at: &Scalar<C>,
+) -> CtOption<Scalar<C>> {
+    let x_j = match xs.get(j) {
+        Some(x_j) => *x_j,
+        None => return CtOption::new(Scalar::<C>::ZERO, Choice::from(0)),
+    };
+
+    let mut num = Scalar::<C>::ONE;
+    let mut den = Scalar::<C>::ONE;
+
+    for (m, x_m) in xs.iter().enumerate() {
+        if m != j {
+            num *= *at - x_m;
+            den *= x_j - x_m;
+        }
+    }
+
+    den.invert().map(|den_inv| num * den_inv)
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::{lagrange_coefficient, scalar_from_index};
+    use crate::{
+        dev::{wide::MockWideCurve, MockCurve, Scalar},
+        Scalar as GenericScalar, ScalarArithmetic,
+    };
+    use ff::Field;
+
+    /// Interpolate at `at` using only scalar arithmetic, i.e. without
+    /// requiring the curve to provide point types.
+    fn interpolate<C: ScalarArithmetic>(
+        xs: &[GenericScalar<C>],
+        ys: &[GenericScalar<C>],
+        at: &GenericScalar<C>,
+    ) -> GenericScalar<C> {
+        ys.iter()
+            .enumerate()
+            .map(|(j, y)| *y * lagrange_coefficient::<C>(xs, j, at).unwrap())
+            .sum()
+    }
+
+    #[test]
+    fn known_weights() {
+        let xs = [1, 2, 3].map(scalar_from_index::<MockCurve>);
+        let weights = [Scalar::from(3u64), -Scalar::from(3u64), Scalar::ONE];
+
+        for (j, weight) in weights.iter().enumerate() {
+            assert_eq!(
+                lagrange_coefficient::<MockCurve>(&xs, j, &Scalar::ZERO).unwrap(),
+                *weight
+            );
+        }
+    }
+
+    #[test]
+    fn interpolate_polynomial() {
+        // f(x) = 5 + 7x + 11x²
+        let f = |x: Scalar| {
+            Scalar::from(5u64) + x * Scalar::from(7u64) + x.square() * Scalar::from(11u64)
+        };
+        let xs = [2, 5, 9, 14].map(scalar_from_index::<MockCurve>);
+
+        for at in [Scalar::ZERO, Scalar::from(42u64)] {
+            let interpolated: Scalar = (0..xs.len())
+                .map(|j| f(xs[j]) * lagrange_coefficient::<MockCurve>(&xs, j, &at).unwrap())
+                .sum();
+            assert_eq!(interpolated, f(at));
+        }
+    }
+
+    #[test]
+    fn interpolate_generic() {
+        fn check<C: ScalarArithmetic>() {
+            // f(x) = 3 + 4x
+            let f = |x: GenericScalar<C>| {
+                GenericScalar::<C>::from(3u64) + x * GenericScalar::<C>::from(4u64)
+            };
+            let xs = [1, 2].map(scalar_from_index::<C>);
+            let ys = xs.map(f);
+            assert_eq!(
+                interpolate::<C>(&xs, &ys, &GenericScalar::<C>::ZERO),
+                f(GenericScalar::<C>::ZERO)
+            );
+        }
+
+        check::<MockCurve>();
+        check::<MockWideCurve>();
+    }
+
+    #[test]
+    fn rejects_invalid_inputs() {
+        let xs = [1, 2, 2].map(scalar_from_index::<MockCurve>);
+        assert!(bool::from(
+            lagrange_coefficient::<MockCurve>(&xs, 1, &Scalar::ZERO).is_none()
+        ));
+        assert!(bool::from(
+            lagrange_coefficient::<MockCurve>(&xs[..2], 2, &Scalar::ZERO).is_none()
+        ));
+    }
+}
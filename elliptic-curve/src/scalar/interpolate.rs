@@ -0,0 +1,166 @@
+//! Lagrange interpolation of polynomials over the scalar field, e.g. to
+//! recover a secret from Shamir shares in threshold schemes.
+//!
+//! The x-coordinates are assumed to be public (e.g. participant indices), so
+//! duplicates are detected in variable time.
+
+pub use super::poly::evaluate;
+
+use crate::{Error, Result, Scalar, ScalarArithmetic};
+use alloc::vec::Vec;
+use ff::{BatchInverter, Field};
+
+/// Compute the Lagrange coefficients for the x-coordinates `xs` evaluated at
+/// zero, i.e. the weights recovering `f(0)` from `f(xs[i])`.
+///
+/// # Errors
+/// If `xs` contains duplicates, with [`ErrorKind::InvalidBatchElement`]
+/// reporting the index of the first repeated x-coordinate.
+///
+/// [`ErrorKind::InvalidBatchElement`]: crate::ErrorKind::InvalidBatchElement
+pub fn lagrange_coefficients_at_zero<C: ScalarArithmetic>(
+    xs: &[Scalar<C>],
+) -> Result<Vec<Scalar<C>>> {
+    lagrange_coefficients_at::<C>(xs, &Scalar::<C>::ZERO)
+}
+
+/// Compute the Lagrange coefficients for the x-coordinates `xs` evaluated at
+/// `at`, i.e. `Π (at - xs[m]) / (xs[j] - xs[m])` over all `m != j` for each `j`.
+///
+/// The denominators are inverted at once using batch inversion.
+///
+/// # Errors
+/// If `xs` contains duplicates, see [`lagrange_coefficients_at_zero`].
+pub fn lagrange_coefficients_at<C: ScalarArithmetic>(
+    xs: &[Scalar<C>],
+    at: &Scalar<C>,
+) -> Result<Vec<Scalar<C>>> {
+    if let Some(index) = (1..xs.len()).find(|&j| xs[..j].contains(&xs[j])) {
+        return Err(Error::invalid_batch_element(index));
+    }
+
+    let mut coefficients = Vec::with_capacity(xs.len());
+    let mut denominators = Vec::with_capacity(xs.len());
+
+    for (j, x_j) in xs.iter().enumerate() {
+        let mut num = Scalar::<C>::ONE;
+        let mut den = Scalar::<C>::ONE;
+
+        for (m, x_m) in xs.iter().enumerate() {
+            if m != j {
+                num *= *at - x_m;
+                den *= *x_j - x_m;
+            }
+        }
+
+        coefficients.push(num);
+        denominators.push(den);
+    }
+
+    // The x-coordinates are distinct, so none of the denominators are zero
+    let mut scratch = alloc::vec![Scalar::<C>::ZERO; xs.len()];
+    BatchInverter::invert_with_external_scratch(&mut denominators, &mut scratch);
+
+    for (coefficient, den_inv) in coefficients.iter_mut().zip(&denominators) {
+        *coefficient *= den_inv;
+    }
+
+    Ok(coefficients)
+}
+
+/// Evaluate the unique polynomial of degree less than `points.len()` passing
+/// through the given `(x, y)` points at `x`.
+///
+/// # Errors
+/// If the x-coordinates of `points` contain duplicates, see
+/// [`lagrange_coefficients_at_zero`].
+pub fn interpolate_at<C: ScalarArithmetic>(
+    points: &[(Scalar<C>, Scalar<C>)],
+    x: &Scalar<C>,
+) -> Result<Scalar<C>> {
+    let xs = points.iter().map(|(x, _)| *x).collect::<Vec<_>>();
+    let coefficients = lagrange_coefficients_at::<C>(&xs, x)?;
+
+    Ok(points
+        .iter()
+        .zip(&coefficients)
+        .map(|((_, y), coefficient)| *y * coefficient)
+        .sum())
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::{evaluate, interpolate_at, lagrange_coefficients_at_zero};
+    use crate::{
+        dev::{MockCurve, Scalar},
+        scalar::indices::{lagrange_coefficient, scalar_from_index},
+        ErrorKind,
+    };
+    use alloc::vec::Vec;
+    use ff::Field;
+    use rand_core::OsRng;
+
+    #[test]
+    fn recovers_random_polynomials() {
+        for t in 0..6 {
+            let coeffs = (0..=t)
+                .map(|_| Scalar::random(&mut OsRng))
+                .collect::<Vec<_>>();
+            let points = (1..=t + 1)
+                .map(|i| {
+                    let x = scalar_from_index::<MockCurve>(i);
+                    (x, evaluate::<MockCurve>(&coeffs, &x))
+                })
+                .collect::<Vec<_>>();
+
+            for _ in 0..4 {
+                let x = Scalar::random(&mut OsRng);
+                assert_eq!(
+                    interpolate_at::<MockCurve>(&points, &x).unwrap(),
+                    evaluate::<MockCurve>(&coeffs, &x)
+                );
+            }
+
+            let xs = points.iter().map(|(x, _)| *x).collect::<Vec<_>>();
+            let weights = lagrange_coefficients_at_zero::<MockCurve>(&xs).unwrap();
+            let secret: Scalar = weights.iter().zip(&points).map(|(w, (_, y))| *w * y).sum();
+            assert_eq!(secret, coeffs[0]);
+
+            // Interpolating the constant polynomial `1`
+            assert_eq!(weights.iter().sum::<Scalar>(), Scalar::ONE);
+
+            for (j, weight) in weights.iter().enumerate() {
+                assert_eq!(
+                    *weight,
+                    lagrange_coefficient::<MockCurve>(&xs, j, &Scalar::ZERO).unwrap()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn evaluate_horner() {
+        let coeffs = [1u64, 2, 3].map(Scalar::from);
+        let x = Scalar::from(10u64);
+        assert_eq!(evaluate::<MockCurve>(&coeffs, &x), Scalar::from(321u64));
+        assert_eq!(evaluate::<MockCurve>(&[], &x), Scalar::ZERO);
+    }
+
+    #[test]
+    fn rejects_duplicate_xs() {
+        let xs = [1, 2, 3, 2].map(scalar_from_index::<MockCurve>);
+        assert_eq!(
+            lagrange_coefficients_at_zero::<MockCurve>(&xs)
+                .err()
+                .map(|e| e.kind()),
+            Some(ErrorKind::InvalidBatchElement { index: 3 })
+        );
+
+        let points = xs.map(|x| (x, Scalar::ONE));
+        assert!(interpolate_at::<MockCurve>(&points, &Scalar::ZERO).is_err());
+
+        assert!(lagrange_coefficients_at_zero::<MockCurve>(&[])
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -1,13 +1,15 @@
 //! Non-zero scalar type.
 
 use crate::{
-    ops::{Invert, Reduce, ReduceNonZero},
+    hex,
+    ops::{Invert, InvertBatch, InvertInfallible, Reduce, ReduceNonZero},
     scalar::IsHigh,
-    CurveArithmetic, Error, FieldBytes, PrimeCurve, Scalar, ScalarPrimitive, SecretKey,
+    Error, ErrorKind, FieldBytes, PrimeCurve, Scalar, ScalarArithmetic, ScalarPrimitive, SecretKey,
+    TaggedFieldBytes,
 };
-use base16ct::HexDisplay;
 use core::{
     fmt,
+    iter::Product,
     ops::{Deref, Mul, Neg},
     str,
 };
@@ -16,7 +18,13 @@ use ff::{Field, PrimeField};
 use generic_array::{typenum::Unsigned, GenericArray};
 use rand_core::CryptoRngCore;
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
-use zeroize::Zeroize;
+use zeroize::{Zeroize, Zeroizing};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "arbitrary")]
+use arbitrary::{Arbitrary, Unstructured};
 
 #[cfg(feature = "serde")]
 use serdect::serde::{de, ser, Deserialize, Serialize};
@@ -29,28 +37,50 @@ use serdect::serde::{de, ser, Deserialize, Serialize};
 ///
 /// In the context of ECC, it's useful for ensuring that scalar multiplication
 /// cannot result in the point at infinity.
+///
+/// # `arbitrary` support
+///
+/// When the optional `arbitrary` feature is enabled, an [`Arbitrary`] impl is
+/// provided which reads [`FieldBytes`] worth of input and maps it into
+/// `[1, n)`, so it never fails on account of generating zero.
+///
+/// # Zeroization
+///
+/// This type is [`Copy`], so it can't zeroize itself on drop. It impls
+/// [`Zeroize`], and secret values should be wrapped in [`Zeroizing`] or held
+/// in a type such as [`SecretKey`] which zeroizes on drop.
 #[derive(Clone)]
 pub struct NonZeroScalar<C>
 where
-    C: CurveArithmetic,
+    C: ScalarArithmetic,
 {
     scalar: Scalar<C>,
 }
 
 impl<C> NonZeroScalar<C>
 where
-    C: CurveArithmetic,
+    C: ScalarArithmetic,
 {
     /// Generate a random `NonZeroScalar`.
-    pub fn random(mut rng: &mut impl CryptoRngCore) -> Self {
-        // Use rejection sampling to eliminate zero values.
-        // While this method isn't constant-time, the attacker shouldn't learn
-        // anything about unrelated outputs so long as `rng` is a secure `CryptoRng`.
-        loop {
-            if let Some(result) = Self::new(Field::random(&mut rng)).into() {
-                break result;
-            }
-        }
+    ///
+    /// # Panics
+    ///
+    /// If `rng` fails, see [`NonZeroScalar::try_random`].
+    pub fn random(rng: &mut impl CryptoRngCore) -> Self {
+        Self::try_random(rng).expect("RNG failure")
+    }
+
+    /// Generate a random `NonZeroScalar`, returning an error if `rng` fails.
+    ///
+    /// This uses rejection sampling to eliminate zero values and values
+    /// overflowing the order, with a bounded number of attempts as described
+    /// in [`ScalarPrimitive::try_random`]. While this method isn't
+    /// constant-time, the attacker shouldn't learn anything about unrelated
+    /// outputs so long as `rng` is a secure `CryptoRng`.
+    pub fn try_random(rng: &mut impl CryptoRngCore) -> crate::Result<Self> {
+        ScalarPrimitive::<C>::try_random_nonzero(rng).map(|scalar| Self {
+            scalar: scalar.into(),
+        })
     }
 
     /// Create a [`NonZeroScalar`] from a scalar.
@@ -63,15 +93,75 @@ where
         Scalar::<C>::from_repr(repr).and_then(Self::new)
     }
 
+    /// Decode a [`NonZeroScalar`] from a big endian-serialized field element
+    /// tagged with the curve `C`, see [`TaggedFieldBytes`].
+    pub fn from_tagged_bytes(bytes: &TaggedFieldBytes<C>) -> CtOption<Self> {
+        Self::from_repr(bytes.as_untagged().clone())
+    }
+
+    /// Serialize as a big endian field element tagged with the curve `C`, see
+    /// [`TaggedFieldBytes`].
+    pub fn to_tagged_bytes(&self) -> TaggedFieldBytes<C> {
+        TaggedFieldBytes::new(self.scalar.to_repr())
+    }
+
+    /// Compare the canonical encoding of this scalar against `bytes` in
+    /// constant time, without decoding `bytes`.
+    pub fn ct_eq_bytes(&self, bytes: &FieldBytes<C>) -> Choice {
+        Zeroizing::new(self.scalar.to_repr()).ct_eq(bytes)
+    }
+
+    /// Square this scalar, using [`Field::square`].
+    ///
+    /// The square of a non-zero scalar is non-zero, as the order is prime.
+    pub fn square(&self) -> Self {
+        let scalar = self.scalar.square();
+        debug_assert!(!bool::from(scalar.is_zero()));
+        Self { scalar }
+    }
+
+    /// Square this scalar in place, see [`NonZeroScalar::square`].
+    pub fn square_assign(&mut self) {
+        *self = self.square();
+    }
+
     /// Create a [`NonZeroScalar`] from a `C::Uint`.
     pub fn from_uint(uint: C::Uint) -> CtOption<Self> {
         ScalarPrimitive::new(uint).and_then(|scalar| Self::new(scalar.into()))
     }
+
+    /// Invert an array of non-zero scalars at the cost of a single inversion
+    /// using [`InvertBatch`].
+    pub fn batch_invert_array<const N: usize>(scalars: &[Self; N]) -> [Self; N] {
+        let scalars = scalars.map(|scalar| scalar.scalar);
+
+        // This will always succeed since none of the scalars are 0
+        Scalar::<C>::invert_batch(&scalars)
+            .unwrap()
+            .map(|scalar| Self { scalar })
+    }
+
+    /// Invert a slice of non-zero scalars at the cost of a single inversion
+    /// using [`InvertBatch`].
+    #[cfg(feature = "alloc")]
+    pub fn batch_invert(scalars: &[Self]) -> Vec<Self> {
+        let scalars = scalars
+            .iter()
+            .map(|scalar| scalar.scalar)
+            .collect::<Vec<_>>();
+
+        // This will always succeed since none of the scalars are 0
+        Scalar::<C>::invert_batch(scalars.as_slice())
+            .unwrap()
+            .into_iter()
+            .map(|scalar| Self { scalar })
+            .collect()
+    }
 }
 
 impl<C> AsRef<Scalar<C>> for NonZeroScalar<C>
 where
-    C: CurveArithmetic,
+    C: ScalarArithmetic,
 {
     fn as_ref(&self) -> &Scalar<C> {
         &self.scalar
@@ -80,7 +170,7 @@ where
 
 impl<C> ConditionallySelectable for NonZeroScalar<C>
 where
-    C: CurveArithmetic,
+    C: ScalarArithmetic,
 {
     fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
         Self {
@@ -91,18 +181,18 @@ where
 
 impl<C> ConstantTimeEq for NonZeroScalar<C>
 where
-    C: CurveArithmetic,
+    C: ScalarArithmetic,
 {
     fn ct_eq(&self, other: &Self) -> Choice {
         self.scalar.ct_eq(&other.scalar)
     }
 }
 
-impl<C> Copy for NonZeroScalar<C> where C: CurveArithmetic {}
+impl<C> Copy for NonZeroScalar<C> where C: ScalarArithmetic {}
 
 impl<C> Deref for NonZeroScalar<C>
 where
-    C: CurveArithmetic,
+    C: ScalarArithmetic,
 {
     type Target = Scalar<C>;
 
@@ -113,7 +203,7 @@ where
 
 impl<C> From<NonZeroScalar<C>> for FieldBytes<C>
 where
-    C: CurveArithmetic,
+    C: ScalarArithmetic,
 {
     fn from(scalar: NonZeroScalar<C>) -> FieldBytes<C> {
         Self::from(&scalar)
@@ -122,7 +212,7 @@ where
 
 impl<C> From<&NonZeroScalar<C>> for FieldBytes<C>
 where
-    C: CurveArithmetic,
+    C: ScalarArithmetic,
 {
     fn from(scalar: &NonZeroScalar<C>) -> FieldBytes<C> {
         scalar.to_repr()
@@ -131,7 +221,7 @@ where
 
 impl<C> From<NonZeroScalar<C>> for ScalarPrimitive<C>
 where
-    C: CurveArithmetic,
+    C: ScalarArithmetic,
 {
     #[inline]
     fn from(scalar: NonZeroScalar<C>) -> ScalarPrimitive<C> {
@@ -141,16 +231,16 @@ where
 
 impl<C> From<&NonZeroScalar<C>> for ScalarPrimitive<C>
 where
-    C: CurveArithmetic,
+    C: ScalarArithmetic,
 {
     fn from(scalar: &NonZeroScalar<C>) -> ScalarPrimitive<C> {
-        ScalarPrimitive::from_bytes(&scalar.to_repr()).unwrap()
+        ScalarPrimitive::from_bytes(&Zeroizing::new(scalar.to_repr())).unwrap()
     }
 }
 
 impl<C> From<SecretKey<C>> for NonZeroScalar<C>
 where
-    C: CurveArithmetic,
+    C: ScalarArithmetic,
 {
     fn from(sk: SecretKey<C>) -> NonZeroScalar<C> {
         Self::from(&sk)
@@ -159,7 +249,7 @@ where
 
 impl<C> From<&SecretKey<C>> for NonZeroScalar<C>
 where
-    C: CurveArithmetic,
+    C: ScalarArithmetic,
 {
     fn from(sk: &SecretKey<C>) -> NonZeroScalar<C> {
         let scalar = sk.as_scalar_primitive().to_scalar();
@@ -170,7 +260,7 @@ where
 
 impl<C> Invert for NonZeroScalar<C>
 where
-    C: CurveArithmetic,
+    C: ScalarArithmetic,
     Scalar<C>: Invert<Output = CtOption<Scalar<C>>>,
 {
     type Output = Self;
@@ -190,9 +280,16 @@ where
     }
 }
 
+impl<C> InvertInfallible for NonZeroScalar<C>
+where
+    C: ScalarArithmetic,
+    Scalar<C>: Invert<Output = CtOption<Scalar<C>>>,
+{
+}
+
 impl<C> IsHigh for NonZeroScalar<C>
 where
-    C: CurveArithmetic,
+    C: ScalarArithmetic,
 {
     fn is_high(&self) -> Choice {
         self.scalar.is_high()
@@ -201,7 +298,7 @@ where
 
 impl<C> Neg for NonZeroScalar<C>
 where
-    C: CurveArithmetic,
+    C: ScalarArithmetic,
 {
     type Output = NonZeroScalar<C>;
 
@@ -214,7 +311,7 @@ where
 
 impl<C> Mul<NonZeroScalar<C>> for NonZeroScalar<C>
 where
-    C: PrimeCurve + CurveArithmetic,
+    C: PrimeCurve + ScalarArithmetic,
 {
     type Output = Self;
 
@@ -226,7 +323,7 @@ where
 
 impl<C> Mul<&NonZeroScalar<C>> for NonZeroScalar<C>
 where
-    C: PrimeCurve + CurveArithmetic,
+    C: PrimeCurve + ScalarArithmetic,
 {
     type Output = Self;
 
@@ -239,10 +336,32 @@ where
     }
 }
 
+/// The product of an empty iterator is one.
+impl<C> Product for NonZeroScalar<C>
+where
+    C: PrimeCurve + ScalarArithmetic,
+{
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        let one = NonZeroScalar {
+            scalar: Scalar::<C>::ONE,
+        };
+        iter.fold(one, Mul::mul)
+    }
+}
+
+impl<'a, C> Product<&'a NonZeroScalar<C>> for NonZeroScalar<C>
+where
+    C: PrimeCurve + ScalarArithmetic,
+{
+    fn product<I: Iterator<Item = &'a NonZeroScalar<C>>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
 /// Note: this is a non-zero reduction, as it's impl'd for [`NonZeroScalar`].
 impl<C, I> Reduce<I> for NonZeroScalar<C>
 where
-    C: CurveArithmetic,
+    C: ScalarArithmetic,
     I: Integer + ArrayEncoding,
     Scalar<C>: Reduce<I> + ReduceNonZero<I>,
 {
@@ -265,7 +384,7 @@ where
 impl<C, I> ReduceNonZero<I> for NonZeroScalar<C>
 where
     Self: Reduce<I>,
-    C: CurveArithmetic,
+    C: ScalarArithmetic,
     I: Integer + ArrayEncoding,
     Scalar<C>: Reduce<I, Bytes = Self::Bytes> + ReduceNonZero<I>,
 {
@@ -280,7 +399,7 @@ where
 
 impl<C> TryFrom<&[u8]> for NonZeroScalar<C>
 where
-    C: CurveArithmetic,
+    C: ScalarArithmetic,
 {
     type Error = Error;
 
@@ -289,16 +408,16 @@ where
             Option::from(NonZeroScalar::from_repr(GenericArray::clone_from_slice(
                 bytes,
             )))
-            .ok_or(Error)
+            .ok_or(Error::new(ErrorKind::ScalarOutOfRange))
         } else {
-            Err(Error)
+            Err(Error::invalid_length(C::FieldBytesSize::USIZE, bytes.len()))
         }
     }
 }
 
 impl<C> Zeroize for NonZeroScalar<C>
 where
-    C: CurveArithmetic,
+    C: ScalarArithmetic,
 {
     fn zeroize(&mut self) {
         // Use zeroize's volatile writes to ensure value is cleared.
@@ -312,7 +431,7 @@ where
 
 impl<C> fmt::Display for NonZeroScalar<C>
 where
-    C: CurveArithmetic,
+    C: ScalarArithmetic,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{self:X}")
@@ -321,43 +440,59 @@ where
 
 impl<C> fmt::LowerHex for NonZeroScalar<C>
 where
-    C: CurveArithmetic,
+    C: ScalarArithmetic,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:x}", HexDisplay(&self.to_repr()))
+        hex::write_field_bytes::<C>(&Zeroizing::new(self.to_repr()), f)
     }
 }
 
 impl<C> fmt::UpperHex for NonZeroScalar<C>
 where
-    C: CurveArithmetic,
+    C: ScalarArithmetic,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:}", HexDisplay(&self.to_repr()))
+        hex::write_field_bytes_upper::<C>(&Zeroizing::new(self.to_repr()), f)
     }
 }
 
 impl<C> str::FromStr for NonZeroScalar<C>
 where
-    C: CurveArithmetic,
+    C: ScalarArithmetic,
 {
     type Err = Error;
 
     fn from_str(hex: &str) -> Result<Self, Error> {
-        let mut bytes = FieldBytes::<C>::default();
+        let bytes = Option::<FieldBytes<C>>::from(hex::decode_to_field_bytes::<C>(hex))
+            .map(Zeroizing::new)
+            .ok_or(Error::new(ErrorKind::Decode))?;
 
-        if base16ct::mixed::decode(hex, &mut bytes)?.len() == bytes.len() {
-            Option::from(Self::from_repr(bytes)).ok_or(Error)
-        } else {
-            Err(Error)
-        }
+        Option::from(Self::from_repr(FieldBytes::<C>::clone(&bytes)))
+            .ok_or(Error::new(ErrorKind::ScalarOutOfRange))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, C> Arbitrary<'a> for NonZeroScalar<C>
+where
+    C: ScalarArithmetic,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let scalar = ScalarPrimitive::arbitrary_nonzero(u)?;
+        Ok(Self {
+            scalar: scalar.into(),
+        })
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        ScalarPrimitive::<C>::size_hint(depth)
     }
 }
 
 #[cfg(feature = "serde")]
 impl<C> Serialize for NonZeroScalar<C>
 where
-    C: CurveArithmetic,
+    C: ScalarArithmetic,
 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -370,7 +505,7 @@ where
 #[cfg(feature = "serde")]
 impl<'de, C> Deserialize<'de> for NonZeroScalar<C>
 where
-    C: CurveArithmetic,
+    C: ScalarArithmetic,
 {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -384,9 +519,14 @@ where
 
 #[cfg(all(test, feature = "dev"))]
 mod tests {
-    use crate::dev::{NonZeroScalar, Scalar};
+    use crate::{
+        dev::{FieldBytes, NonZeroScalar, Scalar},
+        ops::Invert,
+    };
+    use alloc::vec::Vec;
     use ff::{Field, PrimeField};
     use hex_literal::hex;
+    use rand_core::OsRng;
     use zeroize::Zeroize;
 
     #[test]
@@ -396,10 +536,127 @@ mod tests {
         assert_eq!(&bytes, scalar.to_repr().as_slice());
     }
 
+    #[test]
+    fn ct_eq_bytes() {
+        let bytes = hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721");
+        let other = hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6722");
+        let scalar = NonZeroScalar::from_repr(bytes.into()).unwrap();
+        assert!(bool::from(scalar.ct_eq_bytes(&bytes.into())));
+        assert!(!bool::from(scalar.ct_eq_bytes(&other.into())));
+    }
+
     #[test]
     fn zeroize() {
         let mut scalar = NonZeroScalar::new(Scalar::from(42u64)).unwrap();
         scalar.zeroize();
         assert_eq!(*scalar, Scalar::ONE);
     }
+
+    #[test]
+    fn batch_invert() {
+        let scalars: [NonZeroScalar; 0] = [];
+        assert_eq!(NonZeroScalar::batch_invert_array(&scalars).len(), 0);
+        assert!(NonZeroScalar::batch_invert(&[]).is_empty());
+
+        let scalar = NonZeroScalar::random(&mut OsRng);
+        let [inverse] = NonZeroScalar::batch_invert_array(&[scalar]);
+        assert_eq!(*inverse, *scalar.invert());
+        assert_eq!(*inverse * *scalar, Scalar::ONE);
+
+        let scalars: Vec<_> = (0..300)
+            .map(|_| NonZeroScalar::random(&mut OsRng))
+            .collect();
+        let inverses = NonZeroScalar::batch_invert(&scalars);
+        assert_eq!(inverses.len(), scalars.len());
+        for (scalar, inverse) in scalars.iter().zip(&inverses) {
+            assert_eq!(**inverse, *scalar.invert());
+        }
+
+        let scalars: [NonZeroScalar; 16] =
+            core::array::from_fn(|_| NonZeroScalar::random(&mut OsRng));
+        let inverses = NonZeroScalar::batch_invert_array(&scalars);
+        for (scalar, inverse) in scalars.iter().zip(&inverses) {
+            assert_eq!(**inverse, *scalar.invert());
+        }
+    }
+
+    #[test]
+    fn square() {
+        for _ in 0..16 {
+            let scalar = Scalar::random(&mut OsRng);
+            assert_eq!(scalar.square(), scalar * scalar);
+
+            let mut nonzero = NonZeroScalar::random(&mut OsRng);
+            let expected = *nonzero * *nonzero;
+            assert_eq!(*nonzero.square(), expected);
+
+            nonzero.square_assign();
+            assert_eq!(*nonzero, expected);
+        }
+    }
+
+    #[test]
+    fn sum_and_product() {
+        let scalars = [1u64, 2, 3].map(Scalar::from);
+        assert_eq!(scalars.iter().copied().sum::<Scalar>(), Scalar::from(6u64));
+        assert_eq!(scalars.iter().sum::<Scalar>(), Scalar::from(6u64));
+        assert_eq!(
+            scalars.iter().copied().product::<Scalar>(),
+            Scalar::from(6u64)
+        );
+        assert_eq!(scalars.iter().product::<Scalar>(), Scalar::from(6u64));
+        assert_eq!([Scalar::ZERO; 0].into_iter().sum::<Scalar>(), Scalar::ZERO);
+        assert_eq!(
+            [Scalar::ZERO; 0].into_iter().product::<Scalar>(),
+            Scalar::ONE
+        );
+
+        let nonzero = scalars.map(|scalar| NonZeroScalar::new(scalar).unwrap());
+        assert_eq!(
+            *nonzero.iter().copied().product::<NonZeroScalar>(),
+            Scalar::from(6u64)
+        );
+        assert_eq!(
+            *nonzero.iter().product::<NonZeroScalar>(),
+            Scalar::from(6u64)
+        );
+        assert_eq!(*nonzero[..0].iter().product::<NonZeroScalar>(), Scalar::ONE);
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let inputs: [&[u8]; 4] = [&[0; 32], &[0xff; 32], &[0x5a; 64], &[0x01; 33]];
+        for input in inputs {
+            let scalar = NonZeroScalar::arbitrary(&mut Unstructured::new(input)).unwrap();
+            assert!(!bool::from(scalar.is_zero()));
+        }
+
+        // Both zero and `n - 1` map to one
+        let order_minus_one = -Scalar::ONE;
+        for input in [[0; 32], order_minus_one.to_repr().into()] {
+            let scalar = NonZeroScalar::arbitrary(&mut Unstructured::new(&input)).unwrap();
+            assert_eq!(*scalar, Scalar::ONE);
+        }
+
+        for len in [0, 1, 31] {
+            assert!(NonZeroScalar::arbitrary(&mut Unstructured::new(&[0xff; 32][..len])).is_err());
+        }
+    }
+
+    #[test]
+    fn tagged_bytes_round_trip() {
+        let scalar = NonZeroScalar::random(&mut OsRng);
+        let tagged = scalar.to_tagged_bytes();
+        assert_eq!(tagged.as_untagged(), &scalar.to_repr());
+        assert_eq!(*NonZeroScalar::from_tagged_bytes(&tagged).unwrap(), *scalar);
+        assert_eq!(tagged.into_untagged(), scalar.to_repr());
+
+        let zero = crate::TaggedFieldBytes::new(FieldBytes::default());
+        assert!(bool::from(
+            NonZeroScalar::from_tagged_bytes(&zero).is_none()
+        ));
+    }
 }
@@ -0,0 +1,120 @@
+//! Evaluation of and commitments to polynomials over the scalar field, e.g.
+//! for Shamir secret sharing or KZG commitments.
+
+use crate::{ops::MultiScalarMul, Scalar, ScalarArithmetic};
+use ff::Field;
+
+/// Polynomials over the scalar field, represented by their coefficients in
+/// ascending order of degree, i.e. `coeffs[i]` is the coefficient of `x^i`.
+///
+/// This is blanket impl'd for all curves implementing [`MultiScalarMul`], so
+/// commitments use the curve's (possibly optimized) multi-scalar multiplication.
+pub trait Polynomial: MultiScalarMul {
+    /// Evaluate the polynomial with coefficients `coeffs` at `x` using Horner's
+    /// method.
+    ///
+    /// The empty polynomial evaluates to zero.
+    fn eval(coeffs: &[Self::Scalar], x: &Self::Scalar) -> Self::Scalar {
+        evaluate::<Self>(coeffs, x)
+    }
+
+    /// Commit to the polynomial with coefficients `coeffs`, i.e. compute
+    /// `Σ coeffs[i] * srs[i]` for the structured reference string `srs`.
+    ///
+    /// The `srs` may be longer than `coeffs`, in which case only its first
+    /// `coeffs.len()` points are used.
+    ///
+    /// # Panics
+    ///
+    /// If `srs` is shorter than `coeffs`.
+    fn commit(srs: &[Self::AffinePoint], coeffs: &[Self::Scalar]) -> Self::ProjectivePoint {
+        assert!(srs.len() >= coeffs.len(), "SRS too short");
+        Self::msm(&srs[..coeffs.len()], coeffs)
+    }
+}
+
+impl<C: MultiScalarMul> Polynomial for C {}
+
+/// Evaluate the polynomial with coefficients `coeffs`, in ascending order of
+/// degree, at `x` using Horner's method.
+///
+/// The empty polynomial evaluates to zero.
+pub fn evaluate<C: ScalarArithmetic>(coeffs: &[Scalar<C>], x: &Scalar<C>) -> Scalar<C> {
+    coeffs
+        .iter()
+        .rev()
+        .fold(Scalar::<C>::ZERO, |acc, coeff| acc * x + coeff)
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::Polynomial;
+    use crate::dev::{AffinePoint, MockCurve, ProjectivePoint, Scalar};
+    use alloc::vec::Vec;
+    use ff::Field;
+    use group::{Curve, Group};
+
+    fn coeffs(n: u64) -> Vec<Scalar> {
+        (0..n)
+            .map(|i| Scalar::from(i * 7 + 3) - Scalar::from(i * i))
+            .collect()
+    }
+
+    #[test]
+    fn eval_matches_power_sum() {
+        let xs = [
+            Scalar::ZERO,
+            Scalar::ONE,
+            Scalar::from(2u64),
+            -Scalar::from(12345u64),
+        ];
+
+        for n in 0..10 {
+            let coeffs = coeffs(n);
+            for x in &xs {
+                let mut power = Scalar::ONE;
+                let mut expected = Scalar::ZERO;
+                for coeff in &coeffs {
+                    expected += *coeff * power;
+                    power *= x;
+                }
+                assert_eq!(MockCurve::eval(&coeffs, x), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn commit() {
+        let srs = (1..=8u64)
+            .map(|i| (ProjectivePoint::generator() * Scalar::from(i)).to_affine())
+            .collect::<Vec<AffinePoint>>();
+
+        for n in 0..=8 {
+            let coeffs = coeffs(n);
+            let expected = coeffs
+                .iter()
+                .zip(&srs)
+                .map(|(coeff, point)| ProjectivePoint::from(*point) * coeff)
+                .sum::<ProjectivePoint>();
+            assert_eq!(MockCurve::commit(&srs, &coeffs), expected);
+        }
+
+        // An SRS of powers of a secret `s` commits to the evaluation at `s`.
+        let s = Scalar::from(5u64);
+        let powers = (0..4)
+            .map(|i| (ProjectivePoint::generator() * s.pow_vartime([i])).to_affine())
+            .collect::<Vec<AffinePoint>>();
+        let coeffs = coeffs(4);
+        assert_eq!(
+            MockCurve::commit(&powers, &coeffs),
+            ProjectivePoint::generator() * MockCurve::eval(&coeffs, &s)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn commit_short_srs() {
+        let srs = [ProjectivePoint::generator().to_affine()];
+        MockCurve::commit(&srs, &coeffs(2));
+    }
+}
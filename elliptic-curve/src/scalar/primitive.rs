@@ -1,15 +1,17 @@
 //! Generic scalar type with primitive functionality.
 
 use crate::{
-    bigint::{prelude::*, Limb, NonZero},
-    scalar::FromUintUnchecked,
+    bigint::{prelude::*, ByteArray, Limb},
+    hex,
     scalar::IsHigh,
-    Curve, Error, FieldBytes, FieldBytesEncoding, Result,
+    scalar::{CanonicalUint, FromUintUnchecked},
+    Curve, Error, ErrorKind, FieldBytes, FieldBytesEncoding, Result, TaggedFieldBytes,
 };
-use base16ct::HexDisplay;
 use core::{
     cmp::Ordering,
     fmt,
+    iter::Sum,
+    marker::PhantomData,
     ops::{Add, AddAssign, Neg, ShrAssign, Sub, SubAssign},
     str,
 };
@@ -19,14 +21,52 @@ use subtle::{
     Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess,
     CtOption,
 };
-use zeroize::DefaultIsZeroes;
+use zeroize::{DefaultIsZeroes, Zeroize, Zeroizing};
 
 #[cfg(feature = "arithmetic")]
-use super::{CurveArithmetic, Scalar};
+use {
+    super::{Scalar, ScalarArithmetic},
+    core::iter::Product,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "arbitrary")]
+use arbitrary::{Arbitrary, Unstructured};
+
+#[cfg(feature = "rkyv")]
+use rkyv::{
+    bytecheck::CheckBytes,
+    munge::munge,
+    rancor::{Failure, Fallible, Source},
+    ser::{Allocator, Writer},
+    vec::{ArchivedVec, VecResolver},
+    Archive, Place, Portable,
+};
 
 #[cfg(feature = "serde")]
 use serdect::serde::{de, ser, Deserialize, Serialize};
 
+#[cfg(feature = "zerocopy")]
+use crate::FieldBytesRepr;
+
+/// Maximum number of candidates drawn by [`ScalarPrimitive::try_random`].
+///
+/// Each candidate is accepted with probability greater than `1/2`.
+const MAX_RANDOM_ATTEMPTS: usize = 128;
+
+/// Compile-time check that [`FieldBytes`] of `C` can hold a `u128`, used by
+/// [`ScalarPrimitive::from_u128`].
+struct AssertFieldBytesHoldU128<C>(PhantomData<C>);
+
+impl<C: Curve> AssertFieldBytesHoldU128<C> {
+    const OK: () = assert!(
+        C::FieldBytesSize::USIZE >= 16,
+        "`FieldBytes` must be at least 16 bytes for `from_u128`"
+    );
+}
+
 /// Generic scalar type with primitive functionality.
 ///
 /// This type provides a baseline level of scalar arithmetic functionality
@@ -40,6 +80,31 @@ use serdect::serde::{de, ser, Deserialize, Serialize};
 ///
 /// The serialization is a fixed-width big endian encoding. When used with
 /// textual formats, the binary data is encoded as hexadecimal.
+///
+/// # `arbitrary` support
+///
+/// When the optional `arbitrary` feature is enabled, an [`Arbitrary`] impl is
+/// provided which reads [`FieldBytes`] worth of input and reduces it modulo
+/// the curve's order, so every generated scalar is in range.
+///
+/// # `rkyv` support
+///
+/// When the optional `rkyv` feature is enabled, this type can be archived
+/// with [`rkyv`] as an [`ArchivedScalarPrimitive`], which holds the same big
+/// endian encoding as [`ScalarPrimitive::to_bytes`].
+///
+/// # `zerocopy` support
+///
+/// When the optional `zerocopy` feature is enabled, scalars can be converted
+/// to and from a [`FieldBytesRepr`] with the same big endian encoding as
+/// [`ScalarPrimitive::to_bytes`].
+///
+/// # Zeroization
+///
+/// This type is [`Copy`], so it can't zeroize itself on drop. It impls
+/// [`Zeroize`](zeroize::Zeroize) via [`DefaultIsZeroes`], and secret values
+/// should be wrapped in [`Zeroizing`] or held in a
+/// [`SecretKey`](crate::SecretKey), which zeroizes on drop.
 // TODO(tarcieri): use `crypto-bigint`'s `Residue` type, expose more functionality?
 #[derive(Copy, Clone, Debug, Default)]
 pub struct ScalarPrimitive<C: Curve> {
@@ -64,34 +129,177 @@ where
     /// Scalar modulus.
     pub const MODULUS: C::Uint = C::ORDER;
 
+    /// Create a new scalar from [`Curve::Uint`] without checking that it's
+    /// in range.
+    ///
+    /// This is a `const fn` which makes it possible to define scalar constants
+    /// and `static`s. The caller is responsible for ensuring `uint` is less
+    /// than the curve's order. See [`scalar_primitive!`] for a checked
+    /// alternative which operates on hex literals.
+    ///
+    /// [`scalar_primitive!`]: crate::scalar_primitive
+    pub const fn from_uint_unchecked(uint: C::Uint) -> Self {
+        Self { inner: uint }
+    }
+
     /// Generate a random [`ScalarPrimitive`].
+    ///
+    /// # Panics
+    ///
+    /// If `rng` fails, see [`ScalarPrimitive::try_random`].
     pub fn random(rng: &mut impl CryptoRngCore) -> Self {
-        Self {
-            inner: C::Uint::random_mod(rng, &NonZero::new(Self::MODULUS).unwrap()),
+        Self::try_random(rng).expect("RNG failure")
+    }
+
+    /// Generate a random [`ScalarPrimitive`], returning an error if `rng`
+    /// fails.
+    ///
+    /// Candidates are sampled with [`RngCore::try_fill_bytes`] and masked to
+    /// the bit length of the order, then rejected if they overflow it. To
+    /// ensure a broken RNG can't make this loop forever, an error is also
+    /// returned if too many candidates are rejected, which a working RNG
+    /// does with probability less than `2^-128`.
+    ///
+    /// [`RngCore::try_fill_bytes`]: rand_core::RngCore::try_fill_bytes
+    pub fn try_random(rng: &mut impl CryptoRngCore) -> Result<Self> {
+        Self::try_random_with(rng, |_| Choice::from(1))
+    }
+
+    /// Generate a random non-zero [`ScalarPrimitive`], as with
+    /// [`ScalarPrimitive::try_random`].
+    pub(crate) fn try_random_nonzero(rng: &mut impl CryptoRngCore) -> Result<Self> {
+        Self::try_random_with(rng, |scalar| !scalar.is_zero())
+    }
+
+    /// Rejection sampling loop for [`ScalarPrimitive::try_random`], which
+    /// also rejects candidates for which `accept` isn't set.
+    fn try_random_with(
+        rng: &mut impl CryptoRngCore,
+        accept: impl Fn(&Self) -> Choice,
+    ) -> Result<Self> {
+        let shift = C::Uint::BITS.saturating_sub(super::order_bits::<C>());
+
+        for _ in 0..MAX_RANDOM_ATTEMPTS {
+            let mut bytes = Zeroizing::new(ByteArray::<C::Uint>::default());
+            rng.try_fill_bytes(&mut bytes)
+                .map_err(|_| Error::new(ErrorKind::Other))?;
+
+            let mut uint = C::Uint::from_be_byte_array((*bytes).clone());
+            uint >>= shift;
+            let candidate =
+                Self::new(uint).and_then(|scalar| CtOption::new(scalar, accept(&scalar)));
+            uint.zeroize();
+
+            if let Some(scalar) = candidate.into() {
+                return Ok(scalar);
+            }
         }
+
+        Err(Error::new(ErrorKind::Other))
     }
 
     /// Create a new scalar from [`Curve::Uint`].
     pub fn new(uint: C::Uint) -> CtOption<Self> {
-        CtOption::new(Self { inner: uint }, uint.ct_lt(&Self::MODULUS))
+        CtOption::new(Self { inner: uint }, C::is_canonical_scalar(&uint))
+    }
+
+    /// Create a scalar from a `u64`.
+    ///
+    /// Always in range, as the order of every supported curve exceeds 2^128.
+    pub fn from_u64(n: u64) -> Self {
+        Self::from(n)
+    }
+
+    /// Create a scalar from a `u128`, reduced modulo the curve's order.
+    ///
+    /// The reduction is a no-op for every supported curve, as their orders
+    /// exceed 2^128. Fails to compile for curves whose [`FieldBytes`] are
+    /// shorter than 16 bytes, which can't hold every `u128`.
+    pub fn from_u128(n: u128) -> Self {
+        let () = AssertFieldBytesHoldU128::<C>::OK;
+
+        let mut bytes = FieldBytes::<C>::default();
+        let offset = bytes.len().saturating_sub(16);
+        bytes[offset..].copy_from_slice(&n.to_be_bytes());
+
+        Self {
+            inner: C::reduce_uint(&C::Uint::decode_field_bytes(&bytes)),
+        }
     }
 
     /// Decode [`ScalarPrimitive`] from a serialized field element
     pub fn from_bytes(bytes: &FieldBytes<C>) -> CtOption<Self> {
-        Self::new(C::Uint::decode_field_bytes(bytes))
+        C::Uint::decode_field_bytes_checked(bytes, &C::order_nz()).map(|inner| Self { inner })
     }
 
     /// Decode [`ScalarPrimitive`] from a big endian byte slice.
     pub fn from_slice(slice: &[u8]) -> Result<Self> {
         if slice.len() == C::FieldBytesSize::USIZE {
-            Option::from(Self::from_bytes(GenericArray::from_slice(slice))).ok_or(Error)
+            Option::from(Self::from_bytes(GenericArray::from_slice(slice)))
+                .ok_or(Error::new(ErrorKind::ScalarOutOfRange))
         } else {
-            Err(Error)
+            Err(Error::invalid_length(C::FieldBytesSize::USIZE, slice.len()))
+        }
+    }
+
+    /// Decode [`ScalarPrimitive`] from a big endian byte slice of any length,
+    /// e.g. with leading zeroes stripped or with an extra leading zero as in
+    /// ASN.1 DER integers.
+    ///
+    /// Shorter slices are left-padded with zeroes. Longer slices are only
+    /// accepted if the excess leading bytes are all zero.
+    ///
+    /// # Errors
+    /// If the value exceeds the field size, or is not less than the order.
+    pub fn from_be_slice_padded(slice: &[u8]) -> Result<Self> {
+        let size = C::FieldBytesSize::USIZE;
+        let excess = slice.len().saturating_sub(size);
+
+        if slice[..excess].iter().any(|&byte| byte != 0) {
+            return Err(Error::invalid_length(size, slice.len()));
         }
+
+        let slice = &slice[excess..];
+        let mut bytes = Zeroizing::new(FieldBytes::<C>::default());
+        bytes[size.saturating_sub(slice.len())..].copy_from_slice(slice);
+        Option::from(Self::from_bytes(&bytes)).ok_or(Error::new(ErrorKind::ScalarOutOfRange))
+    }
+
+    /// Decode [`ScalarPrimitive`] from a big endian byte slice of up to twice
+    /// the field size, reducing the value modulo the order.
+    ///
+    /// The reduction runs in constant time for a given slice length.
+    ///
+    /// # Errors
+    /// If the slice is longer than twice the field size.
+    pub fn from_be_slice_reduced(slice: &[u8]) -> Result<Self> {
+        let max_len = C::FieldBytesSize::USIZE.saturating_mul(2);
+
+        if slice.len() > max_len {
+            return Err(Error::invalid_length(max_len, slice.len()));
+        }
+
+        Ok(slice.iter().fold(Self::ZERO, |acc, &byte| {
+            (0..8).fold(acc, |acc, _| acc.double()) + Self::from_u64(byte.into())
+        }))
+    }
+
+    /// Encode [`ScalarPrimitive`] as a big endian byte vector of minimal
+    /// length, i.e. with leading zeroes stripped.
+    ///
+    /// Zero is encoded as an empty vector.
+    #[cfg(feature = "alloc")]
+    pub fn to_be_vec_minimal(&self) -> Vec<u8> {
+        let bytes = Zeroizing::new(self.to_bytes());
+        let offset = bytes
+            .iter()
+            .position(|&byte| byte != 0)
+            .unwrap_or(bytes.len());
+        bytes[offset..].to_vec()
     }
 
     /// Borrow the inner `C::Uint`.
-    pub fn as_uint(&self) -> &C::Uint {
+    pub const fn as_uint(&self) -> &C::Uint {
         &self.inner
     }
 
@@ -120,10 +328,72 @@ where
         self.inner.encode_field_bytes()
     }
 
+    /// Decode [`ScalarPrimitive`] from a serialized field element tagged with
+    /// the curve `C`, see [`TaggedFieldBytes`].
+    pub fn from_tagged_bytes(bytes: &TaggedFieldBytes<C>) -> CtOption<Self> {
+        Self::from_bytes(bytes.as_untagged())
+    }
+
+    /// Encode [`ScalarPrimitive`] as a serialized field element tagged with
+    /// the curve `C`, see [`TaggedFieldBytes`].
+    pub fn to_tagged_bytes(&self) -> TaggedFieldBytes<C> {
+        TaggedFieldBytes::new(self.to_bytes())
+    }
+
+    /// Compare the canonical encoding of this scalar against `bytes` in
+    /// constant time, without decoding `bytes`.
+    pub fn ct_eq_bytes(&self, bytes: &FieldBytes<C>) -> Choice {
+        Zeroizing::new(self.to_bytes()).ct_eq(bytes)
+    }
+
     /// Convert to a `C::Uint`.
-    pub fn to_uint(&self) -> C::Uint {
+    pub const fn to_uint(&self) -> C::Uint {
+        self.inner
+    }
+
+    /// Double this scalar modulo the curve's order.
+    pub fn double(&self) -> Self {
+        self + self
+    }
+
+    /// Compare two scalars in constant time.
+    ///
+    /// The comparison is performed using [`ConstantTimeLess`] and
+    /// [`ConstantTimeGreater`], and only the final result is converted into
+    /// an [`Ordering`]. Prefer this (or the `Choice`-returning traits) over
+    /// [`PartialOrd`] on a curve's `Scalar` type when comparing secret values.
+    pub fn ct_cmp(&self, other: &Self) -> Ordering {
+        let lt = self.ct_lt(other).unwrap_u8();
+        let gt = self.ct_gt(other).unwrap_u8();
+        gt.cmp(&lt)
+    }
+
+    /// Compute the minimum of two scalars interpreted as integers in constant
+    /// time, without revealing which of them is smaller.
+    pub fn ct_min(&self, other: &Self) -> Self {
+        Self::conditional_select(other, self, self.ct_lt(other))
+    }
+
+    /// Compute the maximum of two scalars interpreted as integers in constant
+    /// time, without revealing which of them is larger.
+    pub fn ct_max(&self, other: &Self) -> Self {
+        Self::conditional_select(self, other, self.ct_lt(other))
+    }
+}
+
+impl<C> CanonicalUint for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    type Uint = C::Uint;
+
+    fn to_canonical_uint(&self) -> C::Uint {
         self.inner
     }
+
+    fn from_canonical_uint(uint: C::Uint) -> CtOption<Self> {
+        Self::new(uint)
+    }
 }
 
 impl<C> FromUintUnchecked for ScalarPrimitive<C>
@@ -133,14 +403,14 @@ where
     type Uint = C::Uint;
 
     fn from_uint_unchecked(uint: C::Uint) -> Self {
-        Self { inner: uint }
+        Self::from_uint_unchecked(uint)
     }
 }
 
 #[cfg(feature = "arithmetic")]
 impl<C> ScalarPrimitive<C>
 where
-    C: CurveArithmetic,
+    C: ScalarArithmetic,
 {
     /// Convert [`ScalarPrimitive`] into a given curve's scalar type.
     pub(super) fn to_scalar(self) -> Scalar<C> {
@@ -262,6 +532,17 @@ where
     }
 }
 
+impl<C> Add<&ScalarPrimitive<C>> for &ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    type Output = ScalarPrimitive<C>;
+
+    fn add(self, other: &ScalarPrimitive<C>) -> ScalarPrimitive<C> {
+        *self + other
+    }
+}
+
 impl<C> AddAssign<ScalarPrimitive<C>> for ScalarPrimitive<C>
 where
     C: Curve,
@@ -304,6 +585,17 @@ where
     }
 }
 
+impl<C> Sub<&ScalarPrimitive<C>> for &ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    type Output = ScalarPrimitive<C>;
+
+    fn sub(self, other: &ScalarPrimitive<C>) -> ScalarPrimitive<C> {
+        *self - other
+    }
+}
+
 impl<C> SubAssign<ScalarPrimitive<C>> for ScalarPrimitive<C>
 where
     C: Curve,
@@ -346,6 +638,44 @@ where
     }
 }
 
+impl<C> Sum for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+impl<'a, C> Sum<&'a ScalarPrimitive<C>> for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn sum<I: Iterator<Item = &'a ScalarPrimitive<C>>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+#[cfg(feature = "arithmetic")]
+impl<C> Product for ScalarPrimitive<C>
+where
+    C: ScalarArithmetic,
+{
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.map(Self::to_scalar).product::<Scalar<C>>().into()
+    }
+}
+
+#[cfg(feature = "arithmetic")]
+impl<'a, C> Product<&'a ScalarPrimitive<C>> for ScalarPrimitive<C>
+where
+    C: ScalarArithmetic,
+{
+    fn product<I: Iterator<Item = &'a ScalarPrimitive<C>>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
 impl<C> ShrAssign<usize> for ScalarPrimitive<C>
 where
     C: Curve,
@@ -379,7 +709,7 @@ where
     C: Curve,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:x}", HexDisplay(&self.to_bytes()))
+        hex::write_field_bytes::<C>(&Zeroizing::new(self.to_bytes()), f)
     }
 }
 
@@ -388,7 +718,7 @@ where
     C: Curve,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:X}", HexDisplay(&self.to_bytes()))
+        hex::write_field_bytes_upper::<C>(&Zeroizing::new(self.to_bytes()), f)
     }
 }
 
@@ -399,12 +729,48 @@ where
     type Err = Error;
 
     fn from_str(hex: &str) -> Result<Self> {
-        let mut bytes = FieldBytes::<C>::default();
-        base16ct::lower::decode(hex, &mut bytes)?;
-        Self::from_slice(&bytes)
+        let bytes = Option::<FieldBytes<C>>::from(hex::decode_to_field_bytes::<C>(hex))
+            .map(Zeroizing::new)
+            .ok_or(Error::new(ErrorKind::Decode))?;
+
+        Option::from(Self::from_bytes(&bytes)).ok_or(Error::new(ErrorKind::ScalarOutOfRange))
     }
 }
 
+/// Construct a [`ScalarPrimitive`] from a big endian hex literal at compile
+/// time.
+///
+/// The value is checked to be less than the curve's order during constant
+/// evaluation, so out-of-range literals are a compile-time error when used in
+/// a `const` or `static`.
+///
+/// ```
+/// # #[cfg(feature = "dev")]
+/// # {
+/// use elliptic_curve::{dev::MockCurve, scalar_primitive, ScalarPrimitive};
+///
+/// static WEIGHT: ScalarPrimitive<MockCurve> = scalar_primitive!(
+///     MockCurve,
+///     "0000000000000000000000000000000000000000000000000000000000000007"
+/// );
+/// # }
+/// ```
+#[macro_export]
+macro_rules! scalar_primitive {
+    ($curve:ty, $hex:expr) => {{
+        const SCALAR: $crate::ScalarPrimitive<$curve> = {
+            let uint = <$curve as $crate::Curve>::Uint::from_be_hex($hex);
+            let (_, borrow) = uint.sbb(
+                &<$curve as $crate::Curve>::ORDER,
+                $crate::bigint::Limb::ZERO,
+            );
+            assert!(borrow.0 != 0, "scalar out of range");
+            $crate::ScalarPrimitive::<$curve>::from_uint_unchecked(uint)
+        };
+        SCALAR
+    }};
+}
+
 #[cfg(feature = "serde")]
 impl<C> Serialize for ScalarPrimitive<C>
 where
@@ -414,7 +780,7 @@ where
     where
         S: ser::Serializer,
     {
-        serdect::array::serialize_hex_upper_or_bin(&self.to_bytes(), serializer)
+        serdect::array::serialize_hex_upper_or_bin(&Zeroizing::new(self.to_bytes()), serializer)
     }
 }
 
@@ -432,3 +798,606 @@ where
         Self::from_slice(&bytes).map_err(|_| de::Error::custom("scalar out of range"))
     }
 }
+
+/// Archived [`ScalarPrimitive`], i.e. its big endian encoding stored inline
+/// in an [`rkyv`] archive.
+///
+/// The structure of the archive is checked by [`rkyv::access`], but the
+/// encoding may still be out of range, so archives from untrusted sources
+/// should be accessed with [`ArchivedScalarPrimitive::access`], which also
+/// checks the scalar is canonical.
+#[cfg(feature = "rkyv")]
+#[derive(CheckBytes, Portable)]
+#[bytecheck(crate = rkyv::bytecheck)]
+#[rkyv(crate = rkyv)]
+#[repr(C)]
+pub struct ArchivedScalarPrimitive<C> {
+    bytes: ArchivedVec<u8>,
+    curve: PhantomData<C>,
+}
+
+#[cfg(feature = "rkyv")]
+impl<C> ArchivedScalarPrimitive<C>
+where
+    C: Curve,
+{
+    /// Access an archived scalar in `bytes`, checking both the structure of
+    /// the archive and that the scalar is canonical.
+    pub fn access(bytes: &[u8]) -> Result<&Self> {
+        let archived =
+            rkyv::access::<Self, Failure>(bytes).map_err(|_| Error::new(ErrorKind::Decode))?;
+        archived.to_scalar_primitive()?;
+        Ok(archived)
+    }
+
+    /// Borrow the archived big endian encoding.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+
+    /// Decode the [`ScalarPrimitive`], checking that it's canonical.
+    pub fn to_scalar_primitive(&self) -> Result<ScalarPrimitive<C>> {
+        ScalarPrimitive::from_slice(self.as_bytes())
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<C> Archive for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    type Archived = ArchivedScalarPrimitive<C>;
+    type Resolver = VecResolver;
+
+    fn resolve(&self, resolver: VecResolver, out: Place<Self::Archived>) {
+        munge!(let ArchivedScalarPrimitive { bytes, curve: _ } = out);
+        ArchivedVec::resolve_from_len(C::FieldBytesSize::USIZE, resolver, bytes);
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<C, S> rkyv::Serialize<S> for ScalarPrimitive<C>
+where
+    C: Curve,
+    S: Fallible + Allocator + Writer + ?Sized,
+{
+    fn serialize(&self, serializer: &mut S) -> core::result::Result<VecResolver, S::Error> {
+        ArchivedVec::<u8>::serialize_from_slice(&Zeroizing::new(self.to_bytes()), serializer)
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<C, D> rkyv::Deserialize<ScalarPrimitive<C>, D> for ArchivedScalarPrimitive<C>
+where
+    C: Curve,
+    D: Fallible + ?Sized,
+    D::Error: Source,
+{
+    fn deserialize(&self, _: &mut D) -> core::result::Result<ScalarPrimitive<C>, D::Error> {
+        self.to_scalar_primitive().map_err(Source::new)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, C> Arbitrary<'a> for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let bytes = u.bytes(C::FieldBytesSize::USIZE)?;
+        let uint = C::Uint::decode_field_bytes(GenericArray::from_slice(bytes));
+        Ok(Self::from_uint_unchecked(C::reduce_uint(&uint)))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (C::FieldBytesSize::USIZE, Some(C::FieldBytesSize::USIZE))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<C> ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    /// Generate a non-zero scalar from [`FieldBytes`] worth of input, which
+    /// is reduced modulo `n - 1` and then incremented so it lies in `[1, n)`.
+    #[allow(clippy::integer_arithmetic)]
+    pub(crate) fn arbitrary_nonzero(u: &mut Unstructured<'_>) -> arbitrary::Result<Self> {
+        let bytes = u.bytes(C::FieldBytesSize::USIZE)?;
+        let uint = C::Uint::decode_field_bytes(GenericArray::from_slice(bytes));
+        let order_minus_one =
+            crate::bigint::NonZero::new(C::ORDER.checked_sub(&C::Uint::ONE).unwrap()).unwrap();
+        let uint = (uint % order_minus_one).checked_add(&C::Uint::ONE).unwrap();
+        Ok(Self::from_uint_unchecked(uint))
+    }
+}
+
+#[cfg(feature = "zerocopy")]
+impl<C, const N: usize> TryFrom<&ScalarPrimitive<C>> for FieldBytesRepr<N>
+where
+    C: Curve,
+{
+    type Error = Error;
+
+    fn try_from(scalar: &ScalarPrimitive<C>) -> Result<Self> {
+        Self::from_field_bytes::<C>(&scalar.to_bytes())
+    }
+}
+
+#[cfg(feature = "zerocopy")]
+impl<C, const N: usize> TryFrom<&FieldBytesRepr<N>> for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    type Error = Error;
+
+    fn try_from(bytes: &FieldBytesRepr<N>) -> Result<Self> {
+        Option::from(Self::from_bytes(&bytes.to_field_bytes::<C>()?))
+            .ok_or_else(|| Error::new(ErrorKind::ScalarOutOfRange))
+    }
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use crate::dev::{MockCurve, NonZeroScalar, Scalar, ScalarPrimitive, SecretKey};
+    use crate::{
+        bigint::{ArrayEncoding, U256},
+        Curve,
+    };
+    use core::{cmp::Ordering, str::FromStr};
+    use ff::{Field, PrimeField};
+    use rand_core::OsRng;
+
+    static SEVEN: ScalarPrimitive = crate::scalar_primitive!(
+        MockCurve,
+        "0000000000000000000000000000000000000000000000000000000000000007"
+    );
+
+    static MAX: ScalarPrimitive = crate::scalar_primitive!(
+        MockCurve,
+        "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632550"
+    );
+
+    static UNCHECKED: ScalarPrimitive =
+        ScalarPrimitive::from_uint_unchecked(U256::from_u64(0x1337));
+
+    #[test]
+    fn from_u64_and_u128() {
+        let five = (0..5).fold(ScalarPrimitive::ZERO, |acc, _| acc + ScalarPrimitive::ONE);
+        assert_eq!(ScalarPrimitive::from_u64(5), five);
+        assert_eq!(ScalarPrimitive::from_u128(5), five);
+        assert_eq!(ScalarPrimitive::from_u64(0), ScalarPrimitive::ZERO);
+
+        let max = ScalarPrimitive::from_u64(u64::MAX);
+        assert_eq!(ScalarPrimitive::from_u128(u64::MAX.into()), max);
+        assert_eq!(
+            ScalarPrimitive::from_u128(1 << 64),
+            max + ScalarPrimitive::ONE
+        );
+
+        let uint = ScalarPrimitive::from_u128(u128::MAX).to_uint();
+        assert_eq!(uint.bits(), 128);
+        assert_eq!(uint, U256::from_u128(u128::MAX));
+
+        let wide = crate::dev::wide::ScalarPrimitive::from_u128(u128::MAX);
+        assert_eq!(wide.to_uint().bits(), 128);
+    }
+
+    #[test]
+    fn const_constructors() {
+        assert_eq!(SEVEN, ScalarPrimitive::from(7u64));
+        assert_eq!(MAX, -ScalarPrimitive::ONE);
+        assert_eq!(UNCHECKED, ScalarPrimitive::from(0x1337u64));
+        assert_eq!(
+            MAX,
+            ScalarPrimitive::from_str(
+                "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632550"
+            )
+            .unwrap()
+        );
+        assert_eq!(MAX.as_uint(), &MockCurve::ORDER.wrapping_sub(&U256::ONE));
+    }
+
+    #[test]
+    fn ct_eq_bytes() {
+        assert!(bool::from(SEVEN.ct_eq_bytes(&SEVEN.to_bytes())));
+        assert!(bool::from(MAX.ct_eq_bytes(&MAX.to_bytes())));
+        assert!(!bool::from(SEVEN.ct_eq_bytes(&MAX.to_bytes())));
+        assert!(!bool::from(ScalarPrimitive::ONE.ct_eq_bytes(
+            &MockCurve::ORDER.wrapping_add(&U256::ONE).to_be_byte_array()
+        )));
+    }
+
+    #[test]
+    fn order_helpers() {
+        let order = MockCurve::ORDER;
+        let order_minus_one = order.wrapping_sub(&U256::ONE);
+        let order_plus_one = order.wrapping_add(&U256::ONE);
+
+        assert_eq!(*MockCurve::order_nz(), order);
+
+        assert!(bool::from(MockCurve::is_canonical_scalar(&U256::ZERO)));
+        assert!(bool::from(MockCurve::is_canonical_scalar(&order_minus_one)));
+        assert!(!bool::from(MockCurve::is_canonical_scalar(&order)));
+        assert!(!bool::from(MockCurve::is_canonical_scalar(&order_plus_one)));
+
+        assert_eq!(MockCurve::reduce_uint(&U256::ZERO), U256::ZERO);
+        assert_eq!(MockCurve::reduce_uint(&order_minus_one), order_minus_one);
+        assert_eq!(MockCurve::reduce_uint(&order), U256::ZERO);
+        assert_eq!(MockCurve::reduce_uint(&order_plus_one), U256::ONE);
+        assert_eq!(
+            MockCurve::reduce_uint(&U256::MAX),
+            U256::MAX.wrapping_sub(&order)
+        );
+
+        assert!(bool::from(ScalarPrimitive::new(order_minus_one).is_some()));
+        assert!(bool::from(ScalarPrimitive::new(order).is_none()));
+    }
+
+    #[test]
+    fn ct_cmp_matches_partial_ord() {
+        for _ in 0..300 {
+            let a = ScalarPrimitive::random(&mut OsRng);
+            let b = ScalarPrimitive::random(&mut OsRng);
+            assert_eq!(
+                Some(a.ct_cmp(&b)),
+                Scalar::from(a).partial_cmp(&Scalar::from(b))
+            );
+            assert_eq!(a.ct_cmp(&a), Ordering::Equal);
+        }
+    }
+
+    #[test]
+    fn ct_min_max() {
+        for _ in 0..300 {
+            let a = ScalarPrimitive::random(&mut OsRng);
+            let b = ScalarPrimitive::random(&mut OsRng);
+            let (min, max) = if a.to_uint() < b.to_uint() {
+                (a, b)
+            } else {
+                (b, a)
+            };
+
+            assert_eq!(a.ct_min(&b), min);
+            assert_eq!(b.ct_min(&a), min);
+            assert_eq!(a.ct_max(&b), max);
+            assert_eq!(b.ct_max(&a), max);
+            assert_eq!(a.ct_min(&a), a);
+            assert_eq!(a.ct_max(&a), a);
+        }
+
+        let max = -ScalarPrimitive::ONE;
+        assert_eq!(max.ct_min(&ScalarPrimitive::ZERO), ScalarPrimitive::ZERO);
+        assert_eq!(ScalarPrimitive::ZERO.ct_max(&max), max);
+    }
+
+    #[test]
+    fn add_wraps_around_order() {
+        let max = -ScalarPrimitive::ONE;
+        assert_eq!(max + ScalarPrimitive::ONE, ScalarPrimitive::ZERO);
+        assert_eq!(max.double(), max - ScalarPrimitive::ONE);
+    }
+
+    #[test]
+    fn sub_wraps_around_order() {
+        let max = -ScalarPrimitive::ONE;
+        assert_eq!(ScalarPrimitive::ZERO - ScalarPrimitive::ONE, max);
+    }
+
+    #[test]
+    fn add_neg_is_zero() {
+        let x = ScalarPrimitive::from(42u64);
+        assert_eq!(x + -x, ScalarPrimitive::ZERO);
+        assert_eq!(-ScalarPrimitive::ZERO, ScalarPrimitive::ZERO);
+    }
+
+    #[test]
+    fn sum_and_product() {
+        let scalars: [ScalarPrimitive; 300] =
+            core::array::from_fn(|_| ScalarPrimitive::random(&mut OsRng));
+
+        let sum = scalars.iter().fold(ScalarPrimitive::ZERO, |acc, x| acc + x);
+        assert_eq!(scalars.iter().sum::<ScalarPrimitive>(), sum);
+        assert_eq!(scalars.iter().copied().sum::<ScalarPrimitive>(), sum);
+
+        let product = scalars
+            .iter()
+            .fold(Scalar::ONE, |acc, &x| acc * Scalar::from(x));
+        assert_eq!(
+            Scalar::from(scalars.iter().product::<ScalarPrimitive>()),
+            product
+        );
+        assert_eq!(
+            scalars.iter().map(|&x| Scalar::from(x)).product::<Scalar>(),
+            product
+        );
+        assert_eq!(
+            scalars.iter().map(|&x| Scalar::from(x)).sum::<Scalar>(),
+            sum.into()
+        );
+    }
+
+    #[test]
+    fn empty_sum_and_product() {
+        let empty: [ScalarPrimitive; 0] = [];
+        assert_eq!(empty.iter().sum::<ScalarPrimitive>(), ScalarPrimitive::ZERO);
+        assert_eq!(
+            empty.iter().product::<ScalarPrimitive>(),
+            ScalarPrimitive::ONE
+        );
+        assert_eq!(core::iter::empty::<Scalar>().sum::<Scalar>(), Scalar::ZERO);
+        assert_eq!(
+            core::iter::empty::<Scalar>().product::<Scalar>(),
+            Scalar::ONE
+        );
+    }
+
+    #[test]
+    fn matches_scalar_arithmetic() {
+        let x = ScalarPrimitive::from(42u64);
+        let y = -ScalarPrimitive::from(1337u64);
+        let (a, b) = (Scalar::from(x), Scalar::from(y));
+        assert_eq!(Scalar::from(x + y), a + b);
+        assert_eq!(Scalar::from(x - y), a - b);
+        assert_eq!(Scalar::from(-x), -a);
+        assert_eq!(Scalar::from(y.double()), b.double());
+    }
+
+    #[cfg(feature = "zerocopy")]
+    #[test]
+    fn zerocopy() {
+        use crate::{FieldBytes, FieldBytesRepr};
+        use zerocopy::{AsBytes, FromBytes};
+
+        let scalars: [ScalarPrimitive; 3] =
+            core::array::from_fn(|_| ScalarPrimitive::random(&mut OsRng));
+
+        for scalar in &scalars {
+            let array = FieldBytesRepr::<32>::try_from(scalar).unwrap();
+            assert_eq!(array.as_bytes(), scalar.to_bytes().as_slice());
+            assert_eq!(
+                array.as_bytes(),
+                FieldBytes::<MockCurve>::from(Scalar::from(*scalar)).as_slice()
+            );
+
+            let read = FieldBytesRepr::<32>::read_from(array.as_bytes()).unwrap();
+            assert_eq!(ScalarPrimitive::try_from(&read).unwrap(), *scalar);
+        }
+
+        // View a buffer of concatenated scalars without copying
+        let mut buffer = [0u8; 96];
+        for (chunk, scalar) in buffer.chunks_mut(32).zip(&scalars) {
+            chunk.copy_from_slice(&scalar.to_bytes());
+        }
+        let view = FieldBytesRepr::<32>::slice_from(&buffer).unwrap();
+        for (array, scalar) in view.iter().zip(&scalars) {
+            assert_eq!(ScalarPrimitive::try_from(array).unwrap(), *scalar);
+        }
+
+        // Out of range and wrong length
+        assert!(ScalarPrimitive::try_from(&FieldBytesRepr([0xff; 32])).is_err());
+        assert!(FieldBytesRepr::<31>::try_from(&scalars[0]).is_err());
+        assert!(ScalarPrimitive::try_from(&FieldBytesRepr([0; 33])).is_err());
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let inputs: [&[u8]; 3] = [&[0; 32], &[0xff; 32], &[0x5a; 64]];
+        for input in inputs {
+            let scalar = ScalarPrimitive::arbitrary(&mut Unstructured::new(input)).unwrap();
+            assert!(scalar.as_uint() < &MockCurve::ORDER);
+        }
+
+        // Bytes at or above the order are reduced rather than rejected
+        let scalar = ScalarPrimitive::arbitrary(&mut Unstructured::new(&[0xff; 32])).unwrap();
+        assert_eq!(scalar.to_uint(), U256::MAX.wrapping_sub(&MockCurve::ORDER));
+
+        assert!(ScalarPrimitive::arbitrary(&mut Unstructured::new(&[0; 31])).is_err());
+    }
+
+    /// RNG producing `byte` until `remaining` bytes were produced, then
+    /// failing.
+    struct MockRng {
+        byte: u8,
+        remaining: usize,
+    }
+
+    impl MockRng {
+        fn new(byte: u8, remaining: usize) -> Self {
+            Self { byte, remaining }
+        }
+    }
+
+    impl rand_core::RngCore for MockRng {
+        fn next_u32(&mut self) -> u32 {
+            rand_core::impls::next_u32_via_fill(self)
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            rand_core::impls::next_u64_via_fill(self)
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            self.try_fill_bytes(dest).unwrap()
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+            self.remaining = self.remaining.checked_sub(dest.len()).ok_or_else(|| {
+                rand_core::Error::from(
+                    core::num::NonZeroU32::new(rand_core::Error::CUSTOM_START).unwrap(),
+                )
+            })?;
+            dest.fill(self.byte);
+            Ok(())
+        }
+    }
+
+    impl rand_core::CryptoRng for MockRng {}
+
+    #[test]
+    fn try_random() {
+        let expected = ScalarPrimitive::from_slice(&[0x01; 32]).unwrap();
+        let rng = &mut MockRng::new(0x01, 32);
+        assert_eq!(ScalarPrimitive::try_random(rng).unwrap(), expected);
+        assert!(ScalarPrimitive::try_random(rng).is_err());
+
+        let rng = &mut MockRng::new(0x01, 32);
+        assert_eq!(
+            SecretKey::try_random(rng).unwrap().as_scalar_primitive(),
+            &expected
+        );
+        assert_eq!(
+            *NonZeroScalar::try_random(&mut MockRng::new(0x01, 32)).unwrap(),
+            Scalar::from(expected)
+        );
+        assert!(NonZeroScalar::try_random(&mut MockRng::new(0x01, 31)).is_err());
+    }
+
+    #[test]
+    fn try_random_rejection_is_bounded() {
+        // `0xFF...` always overflows the order and zero is rejected for
+        // secret keys, so these only stop because of the attempt limit
+        let rng = &mut MockRng::new(0xFF, usize::MAX);
+        assert!(ScalarPrimitive::try_random(rng).is_err());
+        assert!(SecretKey::try_random(rng).is_err());
+
+        let rng = &mut MockRng::new(0x00, usize::MAX);
+        assert_eq!(
+            ScalarPrimitive::try_random(rng).unwrap(),
+            ScalarPrimitive::ZERO
+        );
+        assert!(SecretKey::try_random(rng).is_err());
+        assert!(NonZeroScalar::try_random(rng).is_err());
+
+        #[cfg(feature = "ecdh")]
+        assert!(crate::ecdh::EphemeralSecret::<MockCurve>::try_random(rng).is_err());
+    }
+
+    #[cfg(feature = "rkyv")]
+    #[test]
+    fn rkyv() {
+        use crate::ArchivedScalarPrimitive;
+        use rkyv::rancor;
+
+        let scalar = ScalarPrimitive::random(&mut OsRng);
+        let mut bytes = rkyv::to_bytes::<rancor::Error>(&scalar).unwrap();
+
+        let archived = ArchivedScalarPrimitive::<MockCurve>::access(&bytes).unwrap();
+        assert_eq!(archived.as_bytes(), scalar.to_bytes().as_slice());
+        assert_eq!(archived.to_scalar_primitive().unwrap(), scalar);
+        assert_eq!(
+            rkyv::from_bytes::<ScalarPrimitive, rancor::Error>(&bytes).unwrap(),
+            scalar
+        );
+
+        // The archived length is the last field of the root
+        let len = bytes.len();
+        let mut truncated = bytes.clone();
+        truncated[len - 4..].copy_from_slice(&31u32.to_le_bytes());
+        assert!(ArchivedScalarPrimitive::<MockCurve>::access(&truncated).is_err());
+        truncated[len - 4..].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(ArchivedScalarPrimitive::<MockCurve>::access(&truncated).is_err());
+
+        // Out of range scalar with an intact archive structure
+        let offset = bytes
+            .windows(32)
+            .position(|window| window == scalar.to_bytes().as_slice())
+            .unwrap();
+        bytes[offset..offset + 32].fill(0xff);
+        assert!(rkyv::access::<ArchivedScalarPrimitive<MockCurve>, rancor::Error>(&bytes).is_ok());
+        assert!(ArchivedScalarPrimitive::<MockCurve>::access(&bytes).is_err());
+        assert!(rkyv::from_bytes::<ScalarPrimitive, rancor::Error>(&bytes).is_err());
+    }
+
+    #[test]
+    fn from_be_slice_padded() {
+        let bytes = MAX.to_bytes();
+
+        // 31 bytes
+        let short = ScalarPrimitive::from_be_slice_padded(&SEVEN.to_bytes()[1..]).unwrap();
+        assert_eq!(short, SEVEN);
+        assert_eq!(
+            ScalarPrimitive::from_be_slice_padded(&[]).unwrap(),
+            ScalarPrimitive::ZERO
+        );
+
+        // 33 bytes with a leading zero
+        let mut long = [0u8; 33];
+        long[1..].copy_from_slice(&bytes);
+        assert_eq!(ScalarPrimitive::from_be_slice_padded(&long).unwrap(), MAX);
+
+        // 33 bytes without a leading zero
+        long[0] = 1;
+        assert!(ScalarPrimitive::from_be_slice_padded(&long).is_err());
+
+        // exact order
+        let order = MockCurve::ORDER.to_be_byte_array();
+        assert!(ScalarPrimitive::from_be_slice_padded(&order).is_err());
+        assert!(ScalarPrimitive::from_slice(&order).is_err());
+
+        for _ in 0..16 {
+            let scalar = ScalarPrimitive::random(&mut OsRng);
+            let bytes = scalar.to_bytes();
+            assert_eq!(
+                ScalarPrimitive::from_be_slice_padded(&bytes).unwrap(),
+                scalar
+            );
+            assert_eq!(ScalarPrimitive::from_slice(&bytes).unwrap(), scalar);
+        }
+    }
+
+    #[test]
+    fn from_be_slice_reduced() {
+        let order = MockCurve::ORDER.to_be_byte_array();
+        assert_eq!(
+            ScalarPrimitive::from_be_slice_reduced(&order).unwrap(),
+            ScalarPrimitive::ZERO
+        );
+        assert_eq!(ScalarPrimitive::from_be_slice_reduced(&[7]).unwrap(), SEVEN);
+        assert_eq!(
+            ScalarPrimitive::from_be_slice_reduced(&[]).unwrap(),
+            ScalarPrimitive::ZERO
+        );
+
+        // 2^256 mod n, i.e. 33 bytes with a leading one
+        let mut long = [0u8; 33];
+        long[0] = 1;
+        let two_256 =
+            ScalarPrimitive::from_uint_unchecked(U256::ZERO.wrapping_sub(&MockCurve::ORDER));
+        assert_eq!(
+            ScalarPrimitive::from_be_slice_reduced(&long).unwrap(),
+            two_256
+        );
+
+        // wide values reduce as scalars
+        let hi = Scalar::random(&mut OsRng);
+        let lo = Scalar::random(&mut OsRng);
+        let mut wide = [0u8; 64];
+        wide[..32].copy_from_slice(&hi.to_repr());
+        wide[32..].copy_from_slice(&lo.to_repr());
+        let two_256 = Scalar::from(two_256);
+        assert_eq!(
+            Scalar::from(ScalarPrimitive::from_be_slice_reduced(&wide).unwrap()),
+            hi * two_256 + lo
+        );
+
+        assert!(ScalarPrimitive::from_be_slice_reduced(&[0; 65]).is_err());
+    }
+
+    #[test]
+    fn to_be_vec_minimal() {
+        assert_eq!(SEVEN.to_be_vec_minimal(), [7]);
+        assert!(ScalarPrimitive::ZERO.to_be_vec_minimal().is_empty());
+        assert_eq!(MAX.to_be_vec_minimal(), MAX.to_bytes().as_slice());
+
+        let short = ScalarPrimitive::from_be_slice_padded(&[0x12, 0x34]).unwrap();
+        assert_eq!(short.to_be_vec_minimal(), [0x12, 0x34]);
+        assert_eq!(
+            ScalarPrimitive::from_be_slice_padded(&short.to_be_vec_minimal()).unwrap(),
+            short
+        );
+    }
+}
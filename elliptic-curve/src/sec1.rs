@@ -4,12 +4,19 @@
 
 pub use sec1::point::{Coordinates, ModulusSize, Tag};
 
-use crate::{Curve, FieldBytesSize, Result, SecretKey};
-use generic_array::GenericArray;
-use subtle::CtOption;
+use crate::{bigint::ArrayEncoding, Curve, Error, ErrorKind, FieldBytesSize, Result, SecretKey};
+use core::cmp::Ordering;
+use generic_array::{ArrayLength, GenericArray};
+use subtle::{Choice, ConstantTimeEq, ConstantTimeLess, CtOption};
 
 #[cfg(feature = "arithmetic")]
-use crate::{AffinePoint, CurveArithmetic, Error};
+use {
+    crate::{AffinePoint, CurveArithmetic, ProjectivePoint},
+    ff::PrimeField,
+};
+
+#[cfg(feature = "arbitrary")]
+use {crate::FieldBytes, arbitrary::Unstructured, generic_array::typenum::Unsigned};
 
 /// Encoded elliptic curve point with point compression.
 pub type CompressedPoint<C> = GenericArray<u8, CompressedPointSize<C>>;
@@ -37,6 +44,19 @@ where
 {
     /// Deserialize the type this trait is impl'd on from an [`EncodedPoint`].
     fn from_encoded_point(point: &EncodedPoint<C>) -> CtOption<Self>;
+
+    /// Deserialize the type this trait is impl'd on from the bytes of a
+    /// compressed or uncompressed SEC1 encoded point, or of the identity.
+    ///
+    /// # Errors
+    /// [`ErrorKind::Decode`] if the length or tag are invalid, or
+    /// [`ErrorKind::PointNotOnCurve`] if [`FromEncodedPoint::from_encoded_point`]
+    /// rejects the point, e.g. as its coordinates are not canonical or it is
+    /// not on the curve.
+    fn from_sec1_bytes(bytes: &[u8]) -> Result<Self> {
+        let point = EncodedPoint::<C>::from_bytes(bytes).map_err(|_| ErrorKind::Decode)?;
+        Option::from(Self::from_encoded_point(&point)).ok_or(ErrorKind::PointNotOnCurve.into())
+    }
 }
 
 /// Trait for serializing a value to a SEC1 encoded curve point.
@@ -65,6 +85,114 @@ where
     fn to_compact_encoded_point(&self) -> CtOption<EncodedPoint<C>>;
 }
 
+/// Trait for serializing a projective point directly to a SEC1 compressed point.
+///
+/// This is blanket impl'd for all [`group::Curve`] types whose affine
+/// representation impls [`ToEncodedPoint`].
+#[cfg(feature = "arithmetic")]
+pub trait ToCompressedPoint<C>
+where
+    C: Curve,
+    FieldBytesSize<C>: ModulusSize,
+{
+    /// Convert this point to affine coordinates and serialize it as a SEC1
+    /// compressed point.
+    ///
+    /// Returns `None` if this point is the identity, which has no compressed
+    /// encoding of this size.
+    fn to_encoded_point_compressed(&self) -> CtOption<CompressedPoint<C>>;
+
+    /// Serialize this point as a canonical SEC1 compressed point suitable for
+    /// absorbing into a Fiat–Shamir transcript.
+    ///
+    /// Equal points always produce identical bytes regardless of their
+    /// projective representation. The identity is encoded as all-zeroes.
+    fn challenge_bytes(&self) -> CompressedPoint<C>;
+}
+
+#[cfg(feature = "arithmetic")]
+impl<C, P> ToCompressedPoint<C> for P
+where
+    C: Curve,
+    P: group::Curve,
+    P::AffineRepr: ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    fn to_encoded_point_compressed(&self) -> CtOption<CompressedPoint<C>> {
+        CtOption::new(compress(&self.to_affine()), !self.is_identity())
+    }
+
+    fn challenge_bytes(&self) -> CompressedPoint<C> {
+        compress(&self.to_affine())
+    }
+}
+
+/// Trait for serializing a value as a SEC1 compressed point into a
+/// caller-provided buffer, without allocating.
+///
+/// This is blanket impl'd for all types which impl [`ToEncodedPoint`].
+pub trait CompressInto<C>
+where
+    C: Curve,
+    FieldBytesSize<C>: ModulusSize,
+{
+    /// Size of a compressed point, i.e. [`CompressedPointSize`].
+    type CompressedSize: ArrayLength<u8>;
+
+    /// Serialize this value as a SEC1 compressed point into `out`.
+    ///
+    /// The identity has no compressed encoding and is written as all-zeroes.
+    fn compress_into(&self, out: &mut GenericArray<u8, Self::CompressedSize>);
+
+    /// Compare two values by the lexicographic order of their compressed
+    /// encodings, e.g. to sort public keys.
+    ///
+    /// The identity sorts before all other points.
+    ///
+    /// ⚠️ WARNING: this runs in variable time and must not be used with
+    /// secret points.
+    fn cmp_canonical(&self, other: &Self) -> Ordering {
+        let mut lhs = GenericArray::default();
+        let mut rhs = GenericArray::default();
+        self.compress_into(&mut lhs);
+        other.compress_into(&mut rhs);
+        lhs.cmp(&rhs)
+    }
+}
+
+impl<C, P> CompressInto<C> for P
+where
+    C: Curve,
+    P: ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    type CompressedSize = CompressedPointSize<C>;
+
+    fn compress_into(&self, out: &mut CompressedPoint<C>) {
+        let encoded = self.to_encoded_point(true);
+        out.fill(0);
+
+        // The identity is encoded as a single byte and is left as all-zeroes
+        if let Some(dst) = out.get_mut(..encoded.len()) {
+            dst.copy_from_slice(encoded.as_bytes());
+        }
+    }
+}
+
+/// Serialize a point as a SEC1 compressed point, encoding the identity as
+/// all-zeroes.
+#[cfg(feature = "arithmetic")]
+pub(crate) fn compress<C, P>(point: &P) -> CompressedPoint<C>
+where
+    C: Curve,
+    P: ToEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    let mut bytes = CompressedPoint::<C>::default();
+    point.compress_into(&mut bytes);
+    bytes
+}
+
 /// Validate that the given [`EncodedPoint`] represents the encoded public key
 /// value of the given secret.
 ///
@@ -108,7 +236,471 @@ where
         if public_key == &pk {
             Ok(())
         } else {
-            Err(Error)
+            Err(Error::new(ErrorKind::Other))
+        }
+    }
+}
+
+/// Checks performed when validating a standalone public key, from least to
+/// most strict.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd)]
+pub enum ValidationPolicy {
+    /// Only check that the point isn't the identity.
+    ///
+    /// The SEC1 tag and length are already checked when parsing an
+    /// [`EncodedPoint`], but the coordinates are not range checked.
+    NotIdentity,
+
+    /// Also check that the coordinates are canonical field elements, i.e.
+    /// less than the field modulus `p`.
+    Canonical,
+
+    /// Also check that the point is on the curve.
+    OnCurve,
+
+    /// Also check that the point is in the prime order subgroup.
+    #[default]
+    Full,
+}
+
+/// Validate a peer's [`EncodedPoint`] as a public key, without a secret key.
+///
+/// Curve implementations which also impl [`CurveArithmetic`] will receive
+/// a blanket impl of this trait which performs all checks.
+pub trait ValidateEncodedPoint
+where
+    Self: Curve,
+    FieldBytesSize<Self>: ModulusSize,
+{
+    /// Field modulus `p` the coordinates are range checked against by the
+    /// default [`ValidateEncodedPoint::validate_encoded_point`], if known.
+    ///
+    /// The blanket impl for curves with an arithmetic implementation uses
+    /// [`CurveArithmetic::FIELD_MODULUS`].
+    const COORDINATE_MODULUS: Option<Self::Uint> = None;
+
+    /// Validate the given [`EncodedPoint`] according to `policy`.
+    ///
+    /// The default implementation only performs the byte-level checks of
+    /// [`ValidationPolicy::NotIdentity`] and, if
+    /// [`ValidateEncodedPoint::COORDINATE_MODULUS`] is provided,
+    /// [`ValidationPolicy::Canonical`]. It returns [`ErrorKind::Unsupported`]
+    /// for stricter policies, which need the curve equation of an arithmetic
+    /// implementation.
+    fn validate_encoded_point(point: &EncodedPoint<Self>, policy: ValidationPolicy) -> Result<()> {
+        validate_encoding::<Self>(point)?;
+
+        if policy == ValidationPolicy::NotIdentity {
+            return Ok(());
+        }
+
+        let modulus = Self::COORDINATE_MODULUS.ok_or(Error::new(ErrorKind::Unsupported))?;
+        let modulus = modulus.to_be_byte_array();
+
+        for coordinate in [point.x(), point.y()].into_iter().flatten() {
+            let p = modulus
+                .get(modulus.len().saturating_sub(coordinate.len())..)
+                .ok_or(Error::new(ErrorKind::Unsupported))?;
+
+            if !bool::from(ct_lt_be(coordinate, p)) {
+                return Err(Error::new(ErrorKind::PointNotOnCurve));
+            }
+        }
+
+        match policy {
+            ValidationPolicy::Canonical => Ok(()),
+            _ => Err(Error::new(ErrorKind::Unsupported)),
+        }
+    }
+}
+
+#[cfg(feature = "arithmetic")]
+impl<C> ValidateEncodedPoint for C
+where
+    C: CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C>,
+    FieldBytesSize<C>: ModulusSize,
+{
+    const COORDINATE_MODULUS: Option<C::Uint> = Some(C::FIELD_MODULUS);
+
+    fn validate_encoded_point(point: &EncodedPoint<C>, policy: ValidationPolicy) -> Result<()> {
+        validate_encoding::<C>(point)?;
+
+        if policy == ValidationPolicy::NotIdentity {
+            return Ok(());
+        }
+
+        let not_on_curve = Error::new(ErrorKind::PointNotOnCurve);
+
+        for coordinate in [point.x(), point.y()].into_iter().flatten() {
+            if C::FieldElement::from_repr(coordinate.clone())
+                .is_none()
+                .into()
+            {
+                return Err(not_on_curve);
+            }
+        }
+
+        if policy == ValidationPolicy::Canonical {
+            return Ok(());
+        }
+
+        let affine: AffinePoint<C> =
+            Option::from(AffinePoint::<C>::from_encoded_point(point)).ok_or(not_on_curve)?;
+
+        if policy == ValidationPolicy::Full
+            && !bool::from(C::is_torsion_free(&ProjectivePoint::<C>::from(affine)))
+        {
+            return Err(Error::new(ErrorKind::PointNotInSubgroup));
         }
+
+        Ok(())
+    }
+}
+
+/// Validate a peer's [`EncodedPoint`] as a public key of the curve `C`
+/// according to `policy`, using [`ValidateEncodedPoint`].
+pub fn validate<C>(point: &EncodedPoint<C>, policy: ValidationPolicy) -> Result<()>
+where
+    C: ValidateEncodedPoint,
+    FieldBytesSize<C>: ModulusSize,
+{
+    C::validate_encoded_point(point, policy)
+}
+
+/// Generate an [`EncodedPoint`] with an arbitrary tag and raw coordinate
+/// bytes.
+///
+/// The coordinates are taken from `u` as-is without checking that they are
+/// field elements or that they lie on the curve, which makes the result
+/// suitable for fuzzing decoders and [`validate`]. Use
+/// [`point::arbitrary_affine_point`](crate::point::arbitrary_affine_point)
+/// for valid points instead.
+///
+/// Returns [`arbitrary::Error::NotEnoughData`] if `u` runs out of bytes for
+/// the coordinates.
+#[cfg(feature = "arbitrary")]
+pub fn arbitrary_encoded_point<C>(u: &mut Unstructured<'_>) -> arbitrary::Result<EncodedPoint<C>>
+where
+    C: Curve,
+    FieldBytesSize<C>: ModulusSize,
+{
+    let tag = *u.choose(&[
+        Tag::Identity,
+        Tag::CompressedEvenY,
+        Tag::CompressedOddY,
+        Tag::Uncompressed,
+    ])?;
+
+    if tag == Tag::Identity {
+        return Ok(EncodedPoint::<C>::identity());
+    }
+
+    let x = FieldBytes::<C>::clone_from_slice(u.bytes(FieldBytesSize::<C>::USIZE)?);
+    let mut y = FieldBytes::<C>::default();
+
+    if tag.is_compressed() {
+        // Only the parity of `y` ends up in the encoding
+        if let Some(last) = y.last_mut() {
+            *last = u8::from(tag == Tag::CompressedOddY);
+        }
+    } else {
+        y.copy_from_slice(u.bytes(FieldBytesSize::<C>::USIZE)?);
+    }
+
+    Ok(EncodedPoint::<C>::from_affine_coordinates(
+        &x,
+        &y,
+        tag.is_compressed(),
+    ))
+}
+
+/// Is the big endian integer `a` less than `b`, in constant time?
+///
+/// Both must have the same length.
+fn ct_lt_be(a: &[u8], b: &[u8]) -> Choice {
+    let mut lt = Choice::from(0);
+    let mut decided = Choice::from(0);
+
+    for (x, y) in a.iter().zip(b) {
+        lt |= !decided & x.ct_lt(y);
+        decided |= !x.ct_eq(y);
+    }
+
+    lt
+}
+
+/// Check of [`ValidationPolicy::NotIdentity`].
+fn validate_encoding<C>(point: &EncodedPoint<C>) -> Result<()>
+where
+    C: Curve,
+    FieldBytesSize<C>: ModulusSize,
+{
+    if point.is_identity() {
+        Err(Error::new(ErrorKind::PointAtInfinity))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::{validate, ValidateEncodedPoint, ValidationPolicy};
+    use crate::{
+        bigint::U256,
+        consts::U32,
+        dev::{
+            cofactor::{self, MockCofactorCurve},
+            AffinePoint, EncodedPoint, MockCurve, ProjectivePoint,
+        },
+        sec1::{FromEncodedPoint, ToEncodedPoint},
+        CurveArithmetic, Error, ErrorKind, FieldBytesEncoding,
+    };
+    use group::{Curve, Group};
+    use hex_literal::hex;
+
+    const POLICIES: [ValidationPolicy; 4] = [
+        ValidationPolicy::NotIdentity,
+        ValidationPolicy::Canonical,
+        ValidationPolicy::OnCurve,
+        ValidationPolicy::Full,
+    ];
+
+    /// Uncompressed encoding of the P-256 generator.
+    const GENERATOR: [u8; 65] = hex!(
+        "046b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296"
+        "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5"
+    );
+
+    /// Curve without an arithmetic implementation, which gets the provided
+    /// [`ValidateEncodedPoint`] impl with the field modulus of [`MockCurve`].
+    #[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd)]
+    struct BytesOnlyCurve;
+
+    impl crate::Curve for BytesOnlyCurve {
+        type FieldBytesSize = U32;
+        type Uint = U256;
+
+        const ORDER: U256 = MockCurve::ORDER;
+    }
+
+    impl FieldBytesEncoding<BytesOnlyCurve> for U256 {}
+
+    impl ValidateEncodedPoint for BytesOnlyCurve {
+        const COORDINATE_MODULUS: Option<U256> = Some(MockCurve::FIELD_MODULUS);
+    }
+
+    fn kind(point: &EncodedPoint, policy: ValidationPolicy) -> Option<ErrorKind> {
+        validate::<MockCurve>(point, policy)
+            .err()
+            .map(|err| err.kind())
+    }
+
+    #[test]
+    fn valid_points() {
+        let g = ProjectivePoint::generator().to_affine();
+
+        for compress in [false, true] {
+            let point = g.to_encoded_point(compress);
+            for policy in POLICIES {
+                assert_eq!(validate::<MockCurve>(&point, policy), Ok(()));
+            }
+        }
+
+        let point = EncodedPoint::from_bytes(GENERATOR).unwrap();
+        assert_eq!(validate::<MockCurve>(&point, Default::default()), Ok(()));
+    }
+
+    #[test]
+    fn rejects_identity() {
+        for policy in POLICIES {
+            assert_eq!(
+                kind(&EncodedPoint::identity(), policy),
+                Some(ErrorKind::PointAtInfinity)
+            );
+        }
+    }
+
+    #[test]
+    fn default_impl_checks_bytes() {
+        let kind = |point: &EncodedPoint, policy| {
+            validate::<BytesOnlyCurve>(point, policy)
+                .err()
+                .map(|err| err.kind())
+        };
+
+        // `EncodedPoint` only depends on the field size, which is shared
+        let point = EncodedPoint::from_bytes(GENERATOR).unwrap();
+        assert_eq!(kind(&point, ValidationPolicy::NotIdentity), None);
+        assert_eq!(kind(&point, ValidationPolicy::Canonical), None);
+        assert_eq!(
+            kind(&point, ValidationPolicy::OnCurve),
+            Some(ErrorKind::Unsupported)
+        );
+        assert_eq!(
+            kind(&point, ValidationPolicy::Full),
+            Some(ErrorKind::Unsupported)
+        );
+        assert_eq!(
+            kind(&EncodedPoint::identity(), ValidationPolicy::NotIdentity),
+            Some(ErrorKind::PointAtInfinity)
+        );
+
+        // p - 1, p and p + 1 as either coordinate
+        let p = hex!("ffffffff00000001000000000000000000000000ffffffffffffffffffffffff");
+        let p_minus_one = hex!("ffffffff00000001000000000000000000000000fffffffffffffffffffffffe");
+        let p_plus_one = hex!("ffffffff00000001000000000000000000000001000000000000000000000000");
+
+        for range in [1..33, 33..65] {
+            for (coordinate, canonical) in [(p_minus_one, true), (p, false), (p_plus_one, false)] {
+                let mut bytes = GENERATOR;
+                bytes[range.clone()].copy_from_slice(&coordinate);
+                let point = EncodedPoint::from_bytes(bytes).unwrap();
+                assert_eq!(kind(&point, ValidationPolicy::NotIdentity), None);
+
+                let expected = if canonical {
+                    None
+                } else {
+                    Some(ErrorKind::PointNotOnCurve)
+                };
+                assert_eq!(kind(&point, ValidationPolicy::Canonical), expected);
+                assert_eq!(
+                    kind(&point, ValidationPolicy::Full),
+                    expected.or(Some(ErrorKind::Unsupported))
+                );
+            }
+        }
+
+        // x = p in a compressed point
+        let mut bytes = [0x02; 33];
+        bytes[1..].copy_from_slice(&p);
+        let point = EncodedPoint::from_bytes(bytes).unwrap();
+        assert_eq!(
+            kind(&point, ValidationPolicy::Canonical),
+            Some(ErrorKind::PointNotOnCurve)
+        );
+    }
+
+    #[test]
+    fn rejects_x_not_canonical() {
+        // x = p
+        let mut bytes = GENERATOR;
+        bytes[1..33].copy_from_slice(&hex!(
+            "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff"
+        ));
+        let point = EncodedPoint::from_bytes(bytes).unwrap();
+        assert_eq!(kind(&point, ValidationPolicy::NotIdentity), None);
+        assert_eq!(
+            kind(&point, ValidationPolicy::Canonical),
+            Some(ErrorKind::PointNotOnCurve)
+        );
+
+        bytes[0] = 0x02;
+        let compressed = EncodedPoint::from_bytes(&bytes[..33]).unwrap();
+        assert_eq!(
+            kind(&compressed, ValidationPolicy::Full),
+            Some(ErrorKind::PointNotOnCurve)
+        );
+    }
+
+    #[test]
+    fn rejects_point_not_on_curve() {
+        let mut bytes = GENERATOR;
+        bytes[64] ^= 1;
+        let point = EncodedPoint::from_bytes(bytes).unwrap();
+        assert_eq!(kind(&point, ValidationPolicy::Canonical), None);
+        assert_eq!(
+            kind(&point, ValidationPolicy::OnCurve),
+            Some(ErrorKind::PointNotOnCurve)
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_encoding() {
+        // Wrong tag
+        let mut bytes = GENERATOR;
+        bytes[0] = 0x05;
+        let err = EncodedPoint::from_bytes(bytes).map_err(Error::from);
+        assert_eq!(err.err().map(|err| err.kind()), Some(ErrorKind::Decode));
+
+        // Truncated
+        let err = EncodedPoint::from_bytes(&GENERATOR[..64]).map_err(Error::from);
+        assert_eq!(err.err().map(|err| err.kind()), Some(ErrorKind::Decode));
+    }
+
+    #[test]
+    fn rejects_point_not_in_subgroup() {
+        let point = (cofactor::ProjectivePoint::GENERATOR + cofactor::ProjectivePoint::TORSION)
+            .to_affine()
+            .to_encoded_point(false);
+        let validate = |policy| validate::<MockCofactorCurve>(&point, policy);
+
+        assert_eq!(validate(ValidationPolicy::OnCurve), Ok(()));
+        assert_eq!(
+            validate(ValidationPolicy::Full),
+            Err(Error::new(ErrorKind::PointNotInSubgroup))
+        );
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary_encoded_point() {
+        use super::{arbitrary_encoded_point, Tag};
+        use arbitrary::Unstructured;
+
+        let inputs: [&[u8]; 5] = [&[0; 65], &[1; 65], &[2; 65], &[3; 65], &[0xff; 97]];
+        for input in inputs {
+            let point =
+                arbitrary_encoded_point::<MockCurve>(&mut Unstructured::new(input)).unwrap();
+            let expected_len = match point.tag() {
+                Tag::Identity => 1,
+                Tag::CompressedEvenY | Tag::CompressedOddY => 33,
+                Tag::Uncompressed => 65,
+                Tag::Compact => unreachable!(),
+            };
+
+            assert_eq!(point.len(), expected_len);
+            assert_eq!(EncodedPoint::from_bytes(point.as_bytes()).unwrap(), point);
+        }
+
+        // Running out of coordinate bytes is an error rather than a panic
+        for input in [[1; 32], [2; 32], [3; 32]] {
+            let mut u = Unstructured::new(&input);
+            if let Ok(point) = arbitrary_encoded_point::<MockCurve>(&mut u) {
+                assert!(point.is_identity());
+            }
+        }
+    }
+
+    #[test]
+    fn from_sec1_bytes() {
+        let generator = AffinePoint::GENERATOR;
+        let compressed = generator.to_encoded_point(true);
+        let parse = |bytes: &[u8]| AffinePoint::try_from(bytes).map_err(|err| err.kind());
+
+        assert_eq!(parse(compressed.as_bytes()), Ok(generator));
+        assert_eq!(parse(&GENERATOR), Ok(generator));
+        assert_eq!(AffinePoint::from_sec1_bytes(&GENERATOR), Ok(generator));
+        assert_eq!(parse(&[0]), Ok(AffinePoint::IDENTITY));
+
+        // wrong length
+        assert_eq!(parse(&GENERATOR[..64]), Err(ErrorKind::Decode));
+        assert_eq!(parse(&compressed.as_bytes()[..32]), Err(ErrorKind::Decode));
+        assert_eq!(parse(&[]), Err(ErrorKind::Decode));
+
+        // bad tag
+        let mut bytes = GENERATOR;
+        bytes[0] = 0x07;
+        assert_eq!(parse(&bytes), Err(ErrorKind::Decode));
+
+        // off curve
+        let mut bytes = GENERATOR;
+        bytes[64] ^= 1;
+        assert_eq!(parse(&bytes), Err(ErrorKind::PointNotOnCurve));
+
+        // non-canonical x-coordinate
+        let mut bytes = [0xff; 33];
+        bytes[0] = 0x02;
+        assert_eq!(parse(&bytes), Err(ErrorKind::PointNotOnCurve));
     }
 }
@@ -8,14 +8,15 @@
 #[cfg(all(feature = "pkcs8", feature = "sec1"))]
 mod pkcs8;
 
-use crate::{Curve, Error, FieldBytes, Result, ScalarPrimitive};
+use crate::{Curve, Error, ErrorKind, FieldBytes, Result, ScalarPrimitive, TaggedFieldBytes};
 use core::fmt::{self, Debug};
 use generic_array::typenum::Unsigned;
+use rand_core::CryptoRngCore;
 use subtle::{Choice, ConstantTimeEq};
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
 #[cfg(feature = "arithmetic")]
-use crate::{rand_core::CryptoRngCore, CurveArithmetic, NonZeroScalar, PublicKey};
+use crate::{CurveArithmetic, NonZeroScalar, PublicKey, ScalarArithmetic};
 
 #[cfg(feature = "jwk")]
 use crate::jwk::{JwkEcKey, JwkParameters};
@@ -75,6 +76,12 @@ use {crate::pkcs8::DecodePrivateKey, core::str::FromStr};
 ///
 /// When the `pem` feature of this crate (or a specific RustCrypto elliptic
 /// curve crate) is enabled, a [`FromStr`] impl is also available.
+///
+/// # `arbitrary` support
+///
+/// When the optional `arbitrary` feature is enabled, an `Arbitrary` impl is
+/// provided which generates non-zero scalars in the same way as the one for
+/// [`NonZeroScalar`](crate::NonZeroScalar).
 #[derive(Clone)]
 pub struct SecretKey<C: Curve> {
     /// Scalar value
@@ -86,14 +93,19 @@ where
     C: Curve,
 {
     /// Generate a random [`SecretKey`].
-    #[cfg(feature = "arithmetic")]
-    pub fn random(rng: &mut impl CryptoRngCore) -> Self
-    where
-        C: CurveArithmetic,
-    {
-        Self {
-            inner: NonZeroScalar::<C>::random(rng).into(),
-        }
+    ///
+    /// # Panics
+    ///
+    /// If `rng` fails, see [`SecretKey::try_random`].
+    pub fn random(rng: &mut impl CryptoRngCore) -> Self {
+        Self::try_random(rng).expect("RNG failure")
+    }
+
+    /// Generate a random [`SecretKey`], returning an error if `rng` fails.
+    ///
+    /// See [`ScalarPrimitive::try_random`] for the sampling method.
+    pub fn try_random(rng: &mut impl CryptoRngCore) -> Result<Self> {
+        ScalarPrimitive::try_random_nonzero(rng).map(|inner| Self { inner })
     }
 
     /// Create a new secret key from a scalar value.
@@ -122,7 +134,7 @@ where
     #[cfg(feature = "arithmetic")]
     pub fn to_nonzero_scalar(&self) -> NonZeroScalar<C>
     where
-        C: CurveArithmetic,
+        C: ScalarArithmetic,
     {
         self.into()
     }
@@ -137,12 +149,15 @@ where
     }
 
     /// Deserialize secret key from an encoded secret scalar.
+    ///
+    /// Zero and out-of-range scalars are both reported as
+    /// [`ErrorKind::ScalarOutOfRange`].
     pub fn from_bytes(bytes: &FieldBytes<C>) -> Result<Self> {
-        let inner: ScalarPrimitive<C> =
-            Option::from(ScalarPrimitive::from_bytes(bytes)).ok_or(Error)?;
+        let inner: ScalarPrimitive<C> = Option::from(ScalarPrimitive::from_bytes(bytes))
+            .ok_or(Error::new(ErrorKind::ScalarOutOfRange))?;
 
         if inner.is_zero().into() {
-            return Err(Error);
+            return Err(ErrorKind::ScalarOutOfRange.into());
         }
 
         Ok(Self { inner })
@@ -156,7 +171,7 @@ where
     /// zero-padding the value.
     pub fn from_slice(slice: &[u8]) -> Result<Self> {
         if slice.len() > C::FieldBytesSize::USIZE {
-            return Err(Error);
+            return Err(Error::invalid_length(C::FieldBytesSize::USIZE, slice.len()));
         }
 
         /// Maximum number of "missing" bytes to interpret as zeroes.
@@ -174,7 +189,7 @@ where
             bytes.zeroize();
             ret
         } else {
-            Err(Error)
+            Err(Error::invalid_length(C::FieldBytesSize::USIZE, slice.len()))
         }
     }
 
@@ -183,6 +198,18 @@ where
         self.inner.to_bytes()
     }
 
+    /// Deserialize secret key from an encoded secret scalar tagged with the
+    /// curve `C`, see [`TaggedFieldBytes`].
+    pub fn from_tagged_bytes(bytes: &TaggedFieldBytes<C>) -> Result<Self> {
+        Self::from_bytes(bytes.as_untagged())
+    }
+
+    /// Serialize raw secret scalar as a big endian integer tagged with the
+    /// curve `C`, see [`TaggedFieldBytes`].
+    pub fn to_tagged_bytes(&self) -> TaggedFieldBytes<C> {
+        TaggedFieldBytes::new(self.to_bytes())
+    }
+
     /// Deserialize secret key encoded in the SEC1 ASN.1 DER `ECPrivateKey` format.
     #[cfg(all(feature = "sec1"))]
     pub fn from_sec1_der(der_bytes: &[u8]) -> Result<Self>
@@ -192,7 +219,7 @@ where
     {
         sec1::EcPrivateKey::try_from(der_bytes)?
             .try_into()
-            .map_err(|_| Error)
+            .map_err(|_| Error::new(ErrorKind::Other))
     }
 
     /// Serialize secret key in the SEC1 ASN.1 DER `ECPrivateKey` format.
@@ -231,13 +258,13 @@ where
         C: Curve + ValidatePublicKey,
         FieldBytesSize<C>: ModulusSize,
     {
-        let (label, der_bytes) = pem::decode_vec(s.as_bytes()).map_err(|_| Error)?;
+        let (label, der_bytes) = pem::decode_vec(s.as_bytes()).map_err(|_| ErrorKind::Decode)?;
 
         if label != sec1::EcPrivateKey::PEM_LABEL {
-            return Err(Error);
+            return Err(ErrorKind::Decode.into());
         }
 
-        Self::from_sec1_der(&der_bytes).map_err(|_| Error)
+        Self::from_sec1_der(&der_bytes)
     }
 
     /// Serialize private key as self-zeroizing PEM-encoded SEC1 `ECPrivateKey`
@@ -257,7 +284,7 @@ where
                 pem::encode_string(sec1::EcPrivateKey::PEM_LABEL, line_ending, &der).ok()
             })
             .map(Zeroizing::new)
-            .ok_or(Error)
+            .ok_or(Error::new(ErrorKind::Other))
     }
 
     /// Parse a [`JwkEcKey`] JSON Web Key (JWK) into a [`SecretKey`].
@@ -344,6 +371,20 @@ where
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a, C> arbitrary::Arbitrary<'a> for SecretKey<C>
+where
+    C: Curve,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        ScalarPrimitive::arbitrary_nonzero(u).map(|inner| Self { inner })
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <ScalarPrimitive<C> as arbitrary::Arbitrary<'a>>::size_hint(depth)
+    }
+}
+
 #[cfg(all(feature = "sec1"))]
 impl<C> TryFrom<sec1::EcPrivateKey<'_>> for SecretKey<C>
 where
@@ -373,7 +414,7 @@ where
 #[cfg(feature = "arithmetic")]
 impl<C> From<NonZeroScalar<C>> for SecretKey<C>
 where
-    C: CurveArithmetic,
+    C: ScalarArithmetic,
 {
     fn from(scalar: NonZeroScalar<C>) -> SecretKey<C> {
         SecretKey::from(&scalar)
@@ -383,7 +424,7 @@ where
 #[cfg(feature = "arithmetic")]
 impl<C> From<&NonZeroScalar<C>> for SecretKey<C>
 where
-    C: CurveArithmetic,
+    C: ScalarArithmetic,
 {
     fn from(scalar: &NonZeroScalar<C>) -> SecretKey<C> {
         SecretKey {
@@ -22,7 +22,7 @@ use {
 // Imports for actual PEM support
 #[cfg(feature = "pem")]
 use {
-    crate::{error::Error, Result},
+    crate::{error::Error, ErrorKind, Result},
     core::str::FromStr,
     pkcs8::DecodePrivateKey,
 };
@@ -85,6 +85,6 @@ where
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self> {
-        Self::from_pkcs8_pem(s).map_err(|_| Error)
+        Self::from_pkcs8_pem(s).map_err(|_| Error::new(ErrorKind::Other))
     }
 }
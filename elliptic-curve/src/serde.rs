@@ -0,0 +1,259 @@
+//! Serde adapters for points, for use with `#[serde(with = "...")]`.
+//!
+//! Protocol messages often contain points which aren't public keys, e.g.
+//! commitments or proof elements. These modules let such fields be
+//! serialized generically, without requiring `Serialize`/`Deserialize` on
+//! the point types:
+//!
+//! ```ignore
+//! #[derive(Serialize, Deserialize)]
+//! struct Proof<C: CurveArithmetic> {
+//!     #[serde(with = "elliptic_curve::serde::point")]
+//!     commitment: AffinePoint<C>,
+//! }
+//! ```
+//!
+//! Points are serialized as their [`GroupEncoding`] representation, i.e.
+//! SEC1 compressed points for Weierstrass curves, using hex strings for
+//! human-readable formats and fixed-size byte tuples without a length prefix
+//! otherwise. Deserialization validates the point with
+//! [`GroupEncoding::from_bytes`].
+//!
+//! # Subgroup checks
+//!
+//! [`GroupEncoding::from_bytes`] only guarantees the point is on the curve.
+//! For curves with a cofactor it may accept points outside of the prime
+//! order subgroup, and these adapters don't check for that since they also
+//! accept affine points, which don't impl [`group::cofactor::CofactorGroup`].
+//! Protocols on such curves should check
+//! [`CurveArithmetic::is_torsion_free`](crate::CurveArithmetic::is_torsion_free)
+//! after deserializing.
+
+use group::GroupEncoding;
+use serdect::serde::{de, ser};
+use subtle::ConstantTimeEq;
+
+/// Serialize and deserialize any point type except the identity, which is
+/// rejected in both directions.
+///
+/// The identity is the [`Default`] value of the point type, as with
+/// [`NonIdentity`](crate::point::NonIdentity).
+pub mod point {
+    use super::*;
+
+    /// Serialize a non-identity point.
+    pub fn serialize<P, S>(point: &P, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        P: ConstantTimeEq + Default + GroupEncoding,
+        S: ser::Serializer,
+    {
+        if point.ct_eq(&P::default()).into() {
+            return Err(ser::Error::custom("identity point is not representable"));
+        }
+
+        allow_identity::serialize(point, serializer)
+    }
+
+    /// Deserialize a non-identity point.
+    ///
+    /// The point isn't checked to be torsion free, see the
+    /// [module docs](super#subgroup-checks).
+    pub fn deserialize<'de, P, D>(deserializer: D) -> Result<P, D::Error>
+    where
+        P: ConstantTimeEq + Default + GroupEncoding,
+        D: de::Deserializer<'de>,
+    {
+        let point: P = allow_identity::deserialize(deserializer)?;
+
+        if point.ct_eq(&P::default()).into() {
+            return Err(de::Error::custom("expected non-identity point"));
+        }
+
+        Ok(point)
+    }
+}
+
+/// Serialize and deserialize any point type, including the identity, e.g.
+/// for commitments which may legitimately be the identity.
+pub mod allow_identity {
+    use super::*;
+
+    /// Serialize a point.
+    pub fn serialize<P, S>(point: &P, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        P: GroupEncoding,
+        S: ser::Serializer,
+    {
+        serdect::array::serialize_hex_lower_or_bin(&point.to_bytes(), serializer)
+    }
+
+    /// Deserialize a point.
+    ///
+    /// The point isn't checked to be torsion free, see the
+    /// [module docs](super#subgroup-checks).
+    pub fn deserialize<'de, P, D>(deserializer: D) -> Result<P, D::Error>
+    where
+        P: GroupEncoding,
+        D: de::Deserializer<'de>,
+    {
+        let mut repr = P::Repr::default();
+        serdect::array::deserialize_hex_or_bin(repr.as_mut(), deserializer)?;
+        Option::from(P::from_bytes(&repr)).ok_or_else(|| de::Error::custom("invalid point"))
+    }
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::{allow_identity, point};
+    use crate::{
+        dev::{cofactor, AffinePoint, ProjectivePoint},
+        CurveArithmetic,
+    };
+    use alloc::{string::String, vec::Vec};
+    use group::{Curve, Group, GroupEncoding};
+    use rand_core::OsRng;
+
+    fn to_json<F>(serialize: F) -> Result<String, serde_json::Error>
+    where
+        F: FnOnce(&mut serde_json::Serializer<&mut Vec<u8>>) -> Result<(), serde_json::Error>,
+    {
+        let mut buf = Vec::new();
+        serialize(&mut serde_json::Serializer::new(&mut buf))?;
+        Ok(String::from_utf8(buf).unwrap())
+    }
+
+    fn from_json<P: GroupEncoding + Default + subtle::ConstantTimeEq>(
+        json: &str,
+        allow: bool,
+    ) -> Result<P, serde_json::Error> {
+        let mut deserializer = serde_json::Deserializer::from_str(json);
+        if allow {
+            allow_identity::deserialize(&mut deserializer)
+        } else {
+            point::deserialize(&mut deserializer)
+        }
+    }
+
+    fn to_bincode<F>(serialize: F) -> Result<Vec<u8>, bincode::Error>
+    where
+        F: FnOnce(
+            &mut bincode::Serializer<&mut Vec<u8>, bincode::DefaultOptions>,
+        ) -> Result<(), bincode::Error>,
+    {
+        let mut buf = Vec::new();
+        serialize(&mut bincode::Serializer::new(
+            &mut buf,
+            bincode::DefaultOptions::new(),
+        ))?;
+        Ok(buf)
+    }
+
+    fn from_bincode<P: GroupEncoding + Default + subtle::ConstantTimeEq>(
+        bytes: &[u8],
+        allow: bool,
+    ) -> Result<P, bincode::Error> {
+        let mut deserializer =
+            bincode::Deserializer::from_slice(bytes, bincode::DefaultOptions::new());
+        if allow {
+            allow_identity::deserialize(&mut deserializer)
+        } else {
+            point::deserialize(&mut deserializer)
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let projective = ProjectivePoint::random(&mut OsRng);
+        let affine = projective.to_affine();
+
+        let json = to_json(|s| point::serialize(&affine, s)).unwrap();
+        assert_eq!(json, to_json(|s| point::serialize(&projective, s)).unwrap());
+        assert_eq!(json.len(), 2 + 2 * 33);
+        assert_eq!(from_json::<AffinePoint>(&json, false).unwrap(), affine);
+        assert_eq!(
+            from_json::<ProjectivePoint>(&json, true).unwrap(),
+            projective
+        );
+    }
+
+    #[test]
+    fn identity() {
+        let identity = ProjectivePoint::identity();
+        assert!(to_json(|s| point::serialize(&identity, s)).is_err());
+
+        let json = to_json(|s| allow_identity::serialize(&identity, s)).unwrap();
+        assert_eq!(from_json::<ProjectivePoint>(&json, true).unwrap(), identity);
+        assert!(from_json::<ProjectivePoint>(&json, false).is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_encodings() {
+        let json = to_json(|s| point::serialize(&ProjectivePoint::generator(), s)).unwrap();
+
+        // Truncated
+        let truncated = format!("{}\"", &json[..json.len() - 3]);
+        assert!(from_json::<AffinePoint>(&truncated, true).is_err());
+
+        // x³ - 3x + b is not a square for x = p - 2
+        let off_curve = "\"02ffffffff00000001000000000000000000000000fffffffffffffffffffffffd\"";
+        assert!(from_json::<AffinePoint>(off_curve, true).is_err());
+    }
+
+    #[test]
+    fn bincode_round_trip() {
+        let projective = ProjectivePoint::random(&mut OsRng);
+        let affine = projective.to_affine();
+
+        // The compressed point without a length prefix
+        let bytes = to_bincode(|s| point::serialize(&affine, s)).unwrap();
+        assert_eq!(
+            bytes,
+            to_bincode(|s| point::serialize(&projective, s)).unwrap()
+        );
+        assert_eq!(bytes.as_slice(), affine.to_bytes().as_slice());
+        assert_eq!(from_bincode::<AffinePoint>(&bytes, false).unwrap(), affine);
+        assert_eq!(
+            from_bincode::<ProjectivePoint>(&bytes, true).unwrap(),
+            projective
+        );
+
+        let identity = ProjectivePoint::identity();
+        assert!(to_bincode(|s| point::serialize(&identity, s)).is_err());
+
+        let bytes = to_bincode(|s| allow_identity::serialize(&identity, s)).unwrap();
+        assert_eq!(
+            from_bincode::<ProjectivePoint>(&bytes, true).unwrap(),
+            identity
+        );
+        assert!(from_bincode::<ProjectivePoint>(&bytes, false).is_err());
+    }
+
+    #[test]
+    fn bincode_rejects_invalid_encodings() {
+        let bytes = to_bincode(|s| point::serialize(&ProjectivePoint::generator(), s)).unwrap();
+
+        // Truncated
+        assert!(from_bincode::<AffinePoint>(&bytes[..bytes.len() - 1], true).is_err());
+
+        // Off curve, as above
+        let mut off_curve = bytes;
+        off_curve[0] = 0x02;
+        off_curve[1..].copy_from_slice(&hex_literal::hex!(
+            "ffffffff00000001000000000000000000000000fffffffffffffffffffffffd"
+        ));
+        assert!(from_bincode::<AffinePoint>(&off_curve, true).is_err());
+    }
+
+    /// Deserialization doesn't check the subgroup, callers must.
+    #[test]
+    fn accepts_torsion_points() {
+        let point = cofactor::ProjectivePoint::GENERATOR + cofactor::ProjectivePoint::TORSION;
+        let json = to_json(|s| point::serialize(&point, s)).unwrap();
+
+        let decoded: cofactor::ProjectivePoint = from_json(&json, false).unwrap();
+        assert_eq!(decoded, point);
+        assert!(!bool::from(cofactor::MockCofactorCurve::is_torsion_free(
+            &decoded
+        )));
+    }
+}
@@ -3,6 +3,31 @@
 //! <https://datatracker.ietf.org/doc/draft-irtf-cfrg-voprf/>
 
 use crate::PrimeCurve;
+use digest::Digest;
+
+#[cfg(feature = "hash2curve")]
+mod dleq;
+
+#[cfg(feature = "hash2curve")]
+pub use self::dleq::*;
+
+#[cfg(feature = "hash2curve")]
+use crate::{
+    hash2curve::{ExpandMsgXmd, FromOkm, GroupDigest},
+    ops::Invert,
+    point::NonIdentity,
+    CurveArithmetic, Error, ErrorKind, NonZeroScalar, ProjectivePoint, PublicKey, Result, Scalar,
+};
+#[cfg(feature = "hash2curve")]
+use {
+    digest::{
+        core_api::BlockSizeUser,
+        generic_array::typenum::{IsLess, IsLessOrEqual, U256},
+        FixedOutput, HashMarker, Output, OutputSizeUser,
+    },
+    group::{cofactor::CofactorGroup, GroupEncoding},
+    rand_core::CryptoRngCore,
+};
 
 /// Elliptic curve parameters used by VOPRF.
 pub trait VoprfParameters: PrimeCurve {
@@ -16,5 +41,476 @@ pub trait VoprfParameters: PrimeCurve {
     /// ciphersuite as defined in [section 4 of `draft-irtf-cfrg-voprf-19`][voprf].
     ///
     /// [voprf]: https://www.ietf.org/archive/id/draft-irtf-cfrg-voprf-19.html#name-ciphersuites-2
-    type Hash: digest::Digest;
+    type Hash: Digest;
+}
+
+/// Protocol variant, as identified in the context string.
+///
+/// <https://www.rfc-editor.org/rfc/rfc9497.html#section-3.1>
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Mode {
+    /// Base mode, `modeOPRF`.
+    Oprf,
+
+    /// Verifiable mode, `modeVOPRF`.
+    Voprf,
+
+    /// Partially-oblivious mode, `modePOPRF`.
+    Poprf,
+}
+
+impl Mode {
+    /// Get the `contextString` for this mode and the ciphersuite of `C`, i.e.
+    /// `"OPRFV1-" || I2OSP(mode, 1) || "-" || identifier`, split into parts.
+    pub fn context_string<C: VoprfParameters>(self) -> [&'static [u8]; 4] {
+        let mode: &'static [u8] = match self {
+            Self::Oprf => &[0x00],
+            Self::Voprf => &[0x01],
+            Self::Poprf => &[0x02],
+        };
+
+        [b"OPRFV1-", mode, b"-", C::ID.as_bytes()]
+    }
+}
+
+/// Deterministically derive a key pair from `seed` and `info`, i.e.
+/// `DeriveKeyPair` from [RFC 9497 §3.2.1].
+///
+/// `HashToScalar` is [`GroupDigest::hash_to_nonzero_scalar`] with
+/// [`ExpandMsgXmd`] over [`VoprfParameters::Hash`], as used by the NIST
+/// curve ciphersuites, with the counter incremented on a zero scalar.
+///
+/// # Errors
+/// If `info` is longer than `u16::MAX` bytes, or if no non-zero scalar is
+/// found within 256 attempts.
+///
+/// [RFC 9497 §3.2.1]: https://www.rfc-editor.org/rfc/rfc9497.html#section-3.2.1
+#[cfg(feature = "hash2curve")]
+pub fn derive_key_pair<C>(
+    mode: Mode,
+    seed: &[u8; 32],
+    info: &[u8],
+) -> Result<(NonZeroScalar<C>, PublicKey<C>)>
+where
+    C: VoprfParameters + GroupDigest,
+    ProjectivePoint<C>: CofactorGroup,
+    Scalar<C>: FromOkm,
+    C::Hash: BlockSizeUser + Default + FixedOutput + HashMarker,
+    <C::Hash as OutputSizeUser>::OutputSize:
+        IsLess<U256> + IsLessOrEqual<<C::Hash as BlockSizeUser>::BlockSize>,
+{
+    derive_key_pair_with(mode, seed, info, |msgs, dsts| {
+        C::hash_to_nonzero_scalar::<ExpandMsgXmd<C::Hash>>(msgs, dsts)
+    })
+}
+
+/// [`derive_key_pair`] with the given `HashToScalar` function.
+#[cfg(feature = "hash2curve")]
+fn derive_key_pair_with<C>(
+    mode: Mode,
+    seed: &[u8; 32],
+    info: &[u8],
+    mut hash_to_scalar: impl FnMut(&[&[u8]], &[&[u8]]) -> Result<NonZeroScalar<C>>,
+) -> Result<(NonZeroScalar<C>, PublicKey<C>)>
+where
+    C: VoprfParameters + GroupDigest,
+    ProjectivePoint<C>: CofactorGroup,
+{
+    let info_len = u16::try_from(info.len())
+        .map_err(|_| Error::new(ErrorKind::Other))?
+        .to_be_bytes();
+    let [a, b, c, d] = mode.context_string::<C>();
+    let dst: [&[u8]; 5] = [b"DeriveKeyPair", a, b, c, d];
+
+    for counter in 0..=u8::MAX {
+        match hash_to_scalar(&[seed, &info_len, info, &[counter]], &dst) {
+            Ok(secret) => return Ok((secret, PublicKey::from_secret_scalar(&secret))),
+            Err(err) if err.kind() == ErrorKind::ScalarOutOfRange => continue,
+            Err(err) => return Err(err),
+        }
+    }
+
+    Err(Error::new(ErrorKind::Other))
+}
+
+/// Blinded input sent from the client to the server, i.e. the output of
+/// [`blind`].
+///
+/// Always a valid, non-identity point.
+#[cfg(feature = "hash2curve")]
+#[derive(Clone, Copy)]
+pub struct BlindedElement<C: CurveArithmetic> {
+    point: NonIdentity<ProjectivePoint<C>>,
+}
+
+#[cfg(feature = "hash2curve")]
+impl<C> BlindedElement<C>
+where
+    C: CurveArithmetic,
+    ProjectivePoint<C>: GroupEncoding,
+{
+    /// Decode a [`BlindedElement`], rejecting invalid points and the identity.
+    pub fn from_bytes(bytes: &<ProjectivePoint<C> as GroupEncoding>::Repr) -> Result<Self> {
+        Option::from(NonIdentity::from_repr(bytes))
+            .map(|point| Self { point })
+            .ok_or(Error::new(ErrorKind::Other))
+    }
+
+    /// Encode this [`BlindedElement`].
+    pub fn to_bytes(&self) -> <ProjectivePoint<C> as GroupEncoding>::Repr {
+        self.point.to_bytes()
+    }
+}
+
+/// Evaluated element sent from the server to the client, i.e. the output of
+/// [`blind_evaluate`].
+///
+/// Always a valid, non-identity point.
+#[cfg(feature = "hash2curve")]
+#[derive(Clone, Copy)]
+pub struct EvaluatedElement<C: CurveArithmetic> {
+    point: NonIdentity<ProjectivePoint<C>>,
+}
+
+#[cfg(feature = "hash2curve")]
+impl<C> EvaluatedElement<C>
+where
+    C: CurveArithmetic,
+    ProjectivePoint<C>: GroupEncoding,
+{
+    /// Decode an [`EvaluatedElement`], rejecting invalid points and the
+    /// identity.
+    pub fn from_bytes(bytes: &<ProjectivePoint<C> as GroupEncoding>::Repr) -> Result<Self> {
+        Option::from(NonIdentity::from_repr(bytes))
+            .map(|point| Self { point })
+            .ok_or(Error::new(ErrorKind::Other))
+    }
+
+    /// Encode this [`EvaluatedElement`].
+    pub fn to_bytes(&self) -> <ProjectivePoint<C> as GroupEncoding>::Repr {
+        self.point.to_bytes()
+    }
+}
+
+/// Blind `input` with a random scalar, i.e. `Blind` from
+/// [RFC 9497 §3.3.1].
+///
+/// Returns the blind, which the client keeps for [`finalize`], and the
+/// [`BlindedElement`] sent to the server.
+///
+/// # Errors
+/// If `input` hashes to the identity, or if hashing fails.
+///
+/// [RFC 9497 §3.3.1]: https://www.rfc-editor.org/rfc/rfc9497.html#section-3.3.1
+#[cfg(feature = "hash2curve")]
+pub fn blind<C>(
+    input: &[u8],
+    rng: &mut impl CryptoRngCore,
+) -> Result<(NonZeroScalar<C>, BlindedElement<C>)>
+where
+    C: VoprfParameters + GroupDigest,
+    ProjectivePoint<C>: CofactorGroup,
+    C::Hash: BlockSizeUser + Default + FixedOutput + HashMarker,
+    <C::Hash as OutputSizeUser>::OutputSize:
+        IsLess<U256> + IsLessOrEqual<<C::Hash as BlockSizeUser>::BlockSize>,
+{
+    let blind = NonZeroScalar::random(rng);
+    Ok((blind, blind_with(input, &blind)?))
+}
+
+/// [`blind`] with the given blind.
+#[cfg(feature = "hash2curve")]
+fn blind_with<C>(input: &[u8], blind: &NonZeroScalar<C>) -> Result<BlindedElement<C>>
+where
+    C: VoprfParameters + GroupDigest,
+    ProjectivePoint<C>: CofactorGroup,
+    C::Hash: BlockSizeUser + Default + FixedOutput + HashMarker,
+    <C::Hash as OutputSizeUser>::OutputSize:
+        IsLess<U256> + IsLessOrEqual<<C::Hash as BlockSizeUser>::BlockSize>,
+{
+    let point = hash_to_group::<C>(Mode::Oprf, input)?;
+    Ok(BlindedElement {
+        point: point * *blind,
+    })
+}
+
+/// Evaluate a blinded element with the server's secret key, i.e.
+/// `BlindEvaluate` from [RFC 9497 §3.3.1].
+///
+/// [RFC 9497 §3.3.1]: https://www.rfc-editor.org/rfc/rfc9497.html#section-3.3.1
+#[cfg(feature = "hash2curve")]
+pub fn blind_evaluate<C>(key: &NonZeroScalar<C>, blinded: &BlindedElement<C>) -> EvaluatedElement<C>
+where
+    C: VoprfParameters + CurveArithmetic,
+{
+    EvaluatedElement {
+        point: blinded.point * *key,
+    }
+}
+
+/// Unblind the evaluated element and compute the PRF output for `input`,
+/// i.e. `Finalize` from [RFC 9497 §3.3.1].
+///
+/// # Errors
+/// If `input` is longer than `u16::MAX` bytes.
+///
+/// [RFC 9497 §3.3.1]: https://www.rfc-editor.org/rfc/rfc9497.html#section-3.3.1
+#[cfg(feature = "hash2curve")]
+pub fn finalize<C>(
+    input: &[u8],
+    blind: &NonZeroScalar<C>,
+    evaluated: &EvaluatedElement<C>,
+) -> Result<Output<C::Hash>>
+where
+    C: VoprfParameters + CurveArithmetic,
+    ProjectivePoint<C>: GroupEncoding,
+{
+    finalize_hash::<C>(input, &(evaluated.point * blind.invert()))
+}
+
+/// Compute the PRF output for `input` directly with the server's secret key,
+/// i.e. `Evaluate` from [RFC 9497 §3.3.1].
+///
+/// The output is identical to running [`blind`], [`blind_evaluate`] and
+/// [`finalize`].
+///
+/// # Errors
+/// If `input` hashes to the identity, if it is longer than `u16::MAX` bytes,
+/// or if hashing fails.
+///
+/// [RFC 9497 §3.3.1]: https://www.rfc-editor.org/rfc/rfc9497.html#section-3.3.1
+#[cfg(feature = "hash2curve")]
+pub fn evaluate<C>(key: &NonZeroScalar<C>, input: &[u8]) -> Result<Output<C::Hash>>
+where
+    C: VoprfParameters + GroupDigest,
+    ProjectivePoint<C>: CofactorGroup,
+    C::Hash: BlockSizeUser + Default + FixedOutput + HashMarker,
+    <C::Hash as OutputSizeUser>::OutputSize:
+        IsLess<U256> + IsLessOrEqual<<C::Hash as BlockSizeUser>::BlockSize>,
+{
+    let point = hash_to_group::<C>(Mode::Oprf, input)?;
+    finalize_hash::<C>(input, &(point * *key))
+}
+
+/// `HashToGroup` with the DST for the given mode, rejecting the identity.
+#[cfg(feature = "hash2curve")]
+fn hash_to_group<C>(mode: Mode, input: &[u8]) -> Result<NonIdentity<ProjectivePoint<C>>>
+where
+    C: VoprfParameters + GroupDigest,
+    ProjectivePoint<C>: CofactorGroup,
+    C::Hash: BlockSizeUser + Default + FixedOutput + HashMarker,
+    <C::Hash as OutputSizeUser>::OutputSize:
+        IsLess<U256> + IsLessOrEqual<<C::Hash as BlockSizeUser>::BlockSize>,
+{
+    let [a, b, c, d] = mode.context_string::<C>();
+    let point =
+        C::hash_from_bytes::<ExpandMsgXmd<C::Hash>>(&[input], &[b"HashToGroup-", a, b, c, d])?;
+    Option::from(NonIdentity::new(point)).ok_or(Error::new(ErrorKind::Other))
+}
+
+/// Hash `input` and the unblinded element with the `Finalize` framing.
+#[cfg(feature = "hash2curve")]
+fn finalize_hash<C>(
+    input: &[u8],
+    unblinded: &NonIdentity<ProjectivePoint<C>>,
+) -> Result<Output<C::Hash>>
+where
+    C: VoprfParameters + CurveArithmetic,
+    ProjectivePoint<C>: GroupEncoding,
+{
+    let element = unblinded.to_bytes();
+    let element = element.as_ref();
+    let input_len = u16::try_from(input.len()).map_err(|_| Error::new(ErrorKind::Other))?;
+    let element_len = u16::try_from(element.len()).map_err(|_| Error::new(ErrorKind::Other))?;
+
+    Ok(C::Hash::new()
+        .chain_update(input_len.to_be_bytes())
+        .chain_update(input)
+        .chain_update(element_len.to_be_bytes())
+        .chain_update(element)
+        .chain_update(b"Finalize")
+        .finalize())
+}
+
+#[cfg(all(test, feature = "dev", feature = "hash2curve"))]
+mod tests {
+    use super::{
+        blind, blind_evaluate, blind_with, derive_key_pair, derive_key_pair_with, evaluate,
+        finalize, BlindedElement, EvaluatedElement, Mode, VoprfParameters,
+    };
+    use crate::{
+        dev::{MockCurve, ProjectivePoint},
+        hash2curve::{ExpandMsgXmd, GroupDigest},
+        ErrorKind, NonZeroScalar, PublicKey,
+    };
+    use ff::PrimeField;
+    use group::{Group, GroupEncoding};
+    use hex_literal::hex;
+    use rand_core::{OsRng, RngCore};
+    use sha2::{Digest, Sha256};
+
+    impl VoprfParameters for MockCurve {
+        const ID: &'static str = "P256-SHA256";
+
+        type Hash = Sha256;
+    }
+
+    const SEED: [u8; 32] = [0xa3; 32];
+    const INFO: &[u8] = b"test key";
+
+    /// RFC 9497 Appendix A.3 secret keys for `P256-SHA256`.
+    #[test]
+    fn derive_key_pair_vectors() {
+        for (mode, expected) in [
+            (
+                Mode::Oprf,
+                hex!("159749d750713afe245d2d39ccfaae8381c53ce92d098a9375ee70739c7ac0bf"),
+            ),
+            (
+                Mode::Voprf,
+                hex!("ca5d94c8807817669a51b196c34c1b7f8442fde4334a7121ae4736364312fca6"),
+            ),
+            (
+                Mode::Poprf,
+                hex!("6ad2173efa689ef2c27772566ad7ff6e2d59b3b196f00219451fb2c89ee4dae2"),
+            ),
+        ] {
+            let (secret, public) = derive_key_pair::<MockCurve>(mode, &SEED, INFO).unwrap();
+            assert_eq!(secret.to_repr().as_slice(), &expected);
+            assert_eq!(public, PublicKey::from_secret_scalar(&secret));
+        }
+    }
+
+    #[test]
+    fn derive_key_pair_is_deterministic() {
+        let (a, _) = derive_key_pair::<MockCurve>(Mode::Oprf, &SEED, INFO).unwrap();
+        let (b, _) = derive_key_pair::<MockCurve>(Mode::Oprf, &SEED, INFO).unwrap();
+        assert_eq!(*a, *b);
+
+        let (c, _) = derive_key_pair::<MockCurve>(Mode::Oprf, &SEED, b"other key").unwrap();
+        assert_ne!(*a, *c);
+
+        let (d, _) = derive_key_pair::<MockCurve>(Mode::Oprf, &SEED, b"").unwrap();
+        assert_ne!(*a, *d);
+
+        let (e, _) = derive_key_pair::<MockCurve>(Mode::Oprf, &[0xa4; 32], INFO).unwrap();
+        assert_ne!(*a, *e);
+    }
+
+    #[test]
+    fn derive_key_pair_info_too_long() {
+        let info = [0u8; 0x1_0000];
+        assert!(derive_key_pair::<MockCurve>(Mode::Oprf, &SEED, &info).is_err());
+    }
+
+    #[test]
+    fn derive_key_pair_counter() {
+        // Skipping zero scalars yields the key for the first non-zero counter
+        let (secret, _) =
+            derive_key_pair_with::<MockCurve>(Mode::Oprf, &SEED, INFO, |msgs, dsts| {
+                if msgs[3] == [2] {
+                    MockCurve::hash_to_nonzero_scalar::<ExpandMsgXmd<Sha256>>(msgs, dsts)
+                } else {
+                    Err(ErrorKind::ScalarOutOfRange.into())
+                }
+            })
+            .unwrap();
+
+        let mut input = SEED.to_vec();
+        input.extend_from_slice(&[0, 8]);
+        input.extend_from_slice(INFO);
+        input.push(2);
+        let expected = MockCurve::hash_to_scalar::<ExpandMsgXmd<Sha256>>(
+            &[&input],
+            &[b"DeriveKeyPairOPRFV1-\x00-P256-SHA256"],
+        )
+        .unwrap();
+        assert_eq!(*secret, expected);
+
+        // All 256 counters yielding zero is an error
+        let mut attempts = 0;
+        let result = derive_key_pair_with::<MockCurve>(Mode::Oprf, &SEED, INFO, |_, _| {
+            attempts += 1;
+            Err(ErrorKind::ScalarOutOfRange.into())
+        });
+        assert!(matches!(result, Err(err) if err.kind() == ErrorKind::Other));
+        assert_eq!(attempts, 256);
+    }
+
+    /// RFC 9497 Appendix A.3.1 test vector 1.
+    #[test]
+    fn oprf_vector() {
+        let (key, _) = derive_key_pair::<MockCurve>(Mode::Oprf, &SEED, INFO).unwrap();
+        let input = [0x00];
+        let blind = NonZeroScalar::from_repr(
+            hex!("3338fa65ec36e0290022b48eb562889d89dbfa691d1cde91517fa222ed7ad364").into(),
+        )
+        .unwrap();
+
+        let blinded = blind_with::<MockCurve>(&input, &blind).unwrap();
+        assert_eq!(
+            blinded.to_bytes().as_slice(),
+            &hex!("03723a1e5c09b8b9c18d1dcbca29e8007e95f14f4732d9346d490ffc195110368d")
+        );
+
+        let evaluated = blind_evaluate(&key, &blinded);
+        assert_eq!(
+            evaluated.to_bytes().as_slice(),
+            &hex!("030de02ffec47a1fd53efcdd1c6faf5bdc270912b8749e783c7ca75bb412958832")
+        );
+
+        let expected = hex!("a0b34de5fa4c5b6da07e72af73cc507cceeb48981b97b7285fc375345fe495dd");
+        assert_eq!(
+            finalize(&input, &blind, &evaluated).unwrap().as_slice(),
+            &expected
+        );
+        assert_eq!(evaluate(&key, &input).unwrap().as_slice(), &expected);
+    }
+
+    #[test]
+    fn finalize_matches_direct_evaluation() {
+        let key = NonZeroScalar::<MockCurve>::random(&mut OsRng);
+
+        for len in 0..32 {
+            let mut input = [0u8; 32];
+            OsRng.fill_bytes(&mut input[..len]);
+            let input = &input[..len];
+
+            let (blind, blinded) = blind::<MockCurve>(input, &mut OsRng).unwrap();
+            let blinded = BlindedElement::from_bytes(&blinded.to_bytes()).unwrap();
+            let evaluated = blind_evaluate(&key, &blinded);
+            let evaluated = EvaluatedElement::from_bytes(&evaluated.to_bytes()).unwrap();
+            let output = finalize(input, &blind, &evaluated).unwrap();
+
+            let point = MockCurve::hash_from_bytes::<ExpandMsgXmd<Sha256>>(
+                &[input],
+                &[b"HashToGroup-OPRFV1-\x00-P256-SHA256"],
+            )
+            .unwrap()
+                * *key;
+            let element = point.to_bytes();
+            let expected = Sha256::new()
+                .chain_update(u16::try_from(len).unwrap().to_be_bytes())
+                .chain_update(input)
+                .chain_update([0, 33])
+                .chain_update(element)
+                .chain_update(b"Finalize")
+                .finalize();
+
+            assert_eq!(output, expected);
+            assert_eq!(evaluate(&key, input).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn elements_reject_identity() {
+        let identity = ProjectivePoint::identity().to_bytes();
+        assert!(BlindedElement::<MockCurve>::from_bytes(&identity).is_err());
+        assert!(EvaluatedElement::<MockCurve>::from_bytes(&identity).is_err());
+
+        let mut invalid = ProjectivePoint::generator().to_bytes();
+        invalid[0] = 0x05;
+        assert!(BlindedElement::<MockCurve>::from_bytes(&invalid).is_err());
+        assert!(EvaluatedElement::<MockCurve>::from_bytes(&invalid).is_err());
+    }
 }
@@ -0,0 +1,545 @@
+//! Discrete logarithm equivalence (DLEQ) proofs for the verifiable modes.
+//!
+//! <https://www.rfc-editor.org/rfc/rfc9497.html#section-2.2>
+
+use super::{Mode, VoprfParameters};
+use crate::{
+    hash2curve::{ExpandMsgXmd, FromOkm, GroupDigest},
+    ops::LinearCombination,
+    CurveArithmetic, Error, ErrorKind, FieldBytes, NonZeroScalar, ProjectivePoint, Result, Scalar,
+};
+use core::fmt;
+use digest::{
+    core_api::BlockSizeUser,
+    generic_array::typenum::{IsLess, IsLessOrEqual, U256},
+    Digest, FixedOutput, HashMarker, Output, OutputSizeUser,
+};
+use ff::PrimeField;
+use group::{cofactor::CofactorGroup, Group, GroupEncoding};
+use rand_core::CryptoRngCore;
+use subtle::{Choice, ConstantTimeEq};
+
+#[cfg(feature = "serde")]
+use {
+    crate::ScalarPrimitive,
+    serdect::serde::{de, ser, Deserialize, Serialize},
+};
+
+/// DLEQ proof `(c, s)`, i.e. the output of [`generate_proof`].
+pub struct Proof<C: CurveArithmetic> {
+    c: Scalar<C>,
+    s: Scalar<C>,
+}
+
+impl<C: CurveArithmetic> Proof<C> {
+    /// Create a [`Proof`] from its challenge `c` and response `s`.
+    pub fn new(c: Scalar<C>, s: Scalar<C>) -> Self {
+        Self { c, s }
+    }
+
+    /// Get the challenge `c`.
+    pub fn c(&self) -> Scalar<C> {
+        self.c
+    }
+
+    /// Get the response `s`.
+    pub fn s(&self) -> Scalar<C> {
+        self.s
+    }
+
+    /// Decode a [`Proof`] from the concatenated encodings of `c` and `s`.
+    ///
+    /// # Errors
+    /// If `bytes` has the wrong length or either scalar is not canonical.
+    pub fn from_slice(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() != C::FIELD_BYTES_SIZE * 2 {
+            return Err(Error::new(ErrorKind::Other));
+        }
+
+        let (c, s) = bytes.split_at(C::FIELD_BYTES_SIZE);
+        let c = Scalar::<C>::from_repr(FieldBytes::<C>::clone_from_slice(c));
+        let s = Scalar::<C>::from_repr(FieldBytes::<C>::clone_from_slice(s));
+        Option::from(c.and_then(|c| s.map(|s| Self { c, s }))).ok_or(Error::new(ErrorKind::Other))
+    }
+
+    /// Encode this [`Proof`] as `[c, s]`, whose concatenation is the RFC 9497
+    /// serialization.
+    pub fn to_bytes(&self) -> [FieldBytes<C>; 2] {
+        [self.c.to_repr(), self.s.to_repr()]
+    }
+}
+
+impl<C: CurveArithmetic> Clone for Proof<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: CurveArithmetic> Copy for Proof<C> {}
+
+impl<C: CurveArithmetic> ConstantTimeEq for Proof<C> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.c.ct_eq(&other.c) & self.s.ct_eq(&other.s)
+    }
+}
+
+impl<C: CurveArithmetic> Eq for Proof<C> {}
+
+impl<C: CurveArithmetic> PartialEq for Proof<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl<C: CurveArithmetic> fmt::Debug for Proof<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Proof")
+            .field("c", &self.c)
+            .field("s", &self.s)
+            .finish()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<C: CurveArithmetic> Serialize for Proof<C> {
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        let c: ScalarPrimitive<C> = self.c.into();
+        let s: ScalarPrimitive<C> = self.s.into();
+        (c, s).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, C: CurveArithmetic> Deserialize<'de> for Proof<C> {
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        let (c, s) = <(ScalarPrimitive<C>, ScalarPrimitive<C>)>::deserialize(deserializer)?;
+        Ok(Self::new(c.into(), s.into()))
+    }
+}
+
+/// Prove that `b = k * a` and `ds[i] = k * cs[i]` for all `i`, i.e.
+/// `GenerateProof` from [RFC 9497 §2.2.1].
+///
+/// # Errors
+/// If `cs` and `ds` have different lengths, or if hashing fails.
+///
+/// [RFC 9497 §2.2.1]: https://www.rfc-editor.org/rfc/rfc9497.html#section-2.2.1
+pub fn generate_proof<C>(
+    mode: Mode,
+    k: &NonZeroScalar<C>,
+    a: &ProjectivePoint<C>,
+    b: &ProjectivePoint<C>,
+    cs: &[ProjectivePoint<C>],
+    ds: &[ProjectivePoint<C>],
+    rng: &mut impl CryptoRngCore,
+) -> Result<Proof<C>>
+where
+    C: VoprfParameters + GroupDigest,
+    ProjectivePoint<C>: CofactorGroup,
+    Scalar<C>: FromOkm,
+    C::Hash: BlockSizeUser + Default + FixedOutput + HashMarker,
+    <C::Hash as OutputSizeUser>::OutputSize:
+        IsLess<U256> + IsLessOrEqual<<C::Hash as BlockSizeUser>::BlockSize>,
+{
+    generate_proof_with(mode, k, a, b, cs, ds, &NonZeroScalar::random(rng))
+}
+
+/// [`generate_proof`] with the given random scalar `r`.
+fn generate_proof_with<C>(
+    mode: Mode,
+    k: &NonZeroScalar<C>,
+    a: &ProjectivePoint<C>,
+    b: &ProjectivePoint<C>,
+    cs: &[ProjectivePoint<C>],
+    ds: &[ProjectivePoint<C>],
+    r: &NonZeroScalar<C>,
+) -> Result<Proof<C>>
+where
+    C: VoprfParameters + GroupDigest,
+    ProjectivePoint<C>: CofactorGroup,
+    Scalar<C>: FromOkm,
+    C::Hash: BlockSizeUser + Default + FixedOutput + HashMarker,
+    <C::Hash as OutputSizeUser>::OutputSize:
+        IsLess<U256> + IsLessOrEqual<<C::Hash as BlockSizeUser>::BlockSize>,
+{
+    let (m, z) = compute_composites_fast::<C>(mode, k, b, cs, ds)?;
+    let t2 = *a * **r;
+    let t3 = m * **r;
+    let c = challenge::<C>(mode, b, &m, &z, &t2, &t3)?;
+
+    Ok(Proof::new(c, **r - c * **k))
+}
+
+/// Verify a proof that `b = k * a` and `ds[i] = k * cs[i]` for all `i` and
+/// some `k`, i.e. `VerifyProof` from [RFC 9497 §2.2.2].
+///
+/// # Errors
+/// If the proof is invalid, if `cs` and `ds` have different lengths, or if
+/// hashing fails.
+///
+/// [RFC 9497 §2.2.2]: https://www.rfc-editor.org/rfc/rfc9497.html#section-2.2.2
+pub fn verify_proof<C>(
+    mode: Mode,
+    a: &ProjectivePoint<C>,
+    b: &ProjectivePoint<C>,
+    cs: &[ProjectivePoint<C>],
+    ds: &[ProjectivePoint<C>],
+    proof: &Proof<C>,
+) -> Result<()>
+where
+    C: VoprfParameters + GroupDigest,
+    ProjectivePoint<C>: CofactorGroup,
+    Scalar<C>: FromOkm,
+    C::Hash: BlockSizeUser + Default + FixedOutput + HashMarker,
+    <C::Hash as OutputSizeUser>::OutputSize:
+        IsLess<U256> + IsLessOrEqual<<C::Hash as BlockSizeUser>::BlockSize>,
+{
+    let (m, z) = compute_composites::<C>(mode, b, cs, ds)?;
+    let t2 = ProjectivePoint::<C>::lincomb(a, &proof.s, b, &proof.c);
+    let t3 = ProjectivePoint::<C>::lincomb(&m, &proof.s, &z, &proof.c);
+    let expected = challenge::<C>(mode, b, &m, &z, &t2, &t3)?;
+
+    if expected.ct_eq(&proof.c).into() {
+        Ok(())
+    } else {
+        Err(Error::new(ErrorKind::Other))
+    }
+}
+
+/// `ComputeComposites`, used by the verifier.
+fn compute_composites<C>(
+    mode: Mode,
+    b: &ProjectivePoint<C>,
+    cs: &[ProjectivePoint<C>],
+    ds: &[ProjectivePoint<C>],
+) -> Result<(ProjectivePoint<C>, ProjectivePoint<C>)>
+where
+    C: VoprfParameters + GroupDigest,
+    ProjectivePoint<C>: CofactorGroup,
+    Scalar<C>: FromOkm,
+    C::Hash: BlockSizeUser + Default + FixedOutput + HashMarker,
+    <C::Hash as OutputSizeUser>::OutputSize:
+        IsLess<U256> + IsLessOrEqual<<C::Hash as BlockSizeUser>::BlockSize>,
+{
+    if cs.len() != ds.len() {
+        return Err(Error::new(ErrorKind::Other));
+    }
+
+    let seed = composite_seed::<C>(mode, b)?;
+    let mut m = ProjectivePoint::<C>::identity();
+    let mut z = ProjectivePoint::<C>::identity();
+
+    for (i, (c, d)) in cs.iter().zip(ds).enumerate() {
+        let di = composite_scalar::<C>(mode, &seed, i, c, d)?;
+        m += *c * di;
+        z += *d * di;
+    }
+
+    Ok((m, z))
+}
+
+/// `ComputeCompositesFast`, used by the prover who knows `k`.
+fn compute_composites_fast<C>(
+    mode: Mode,
+    k: &NonZeroScalar<C>,
+    b: &ProjectivePoint<C>,
+    cs: &[ProjectivePoint<C>],
+    ds: &[ProjectivePoint<C>],
+) -> Result<(ProjectivePoint<C>, ProjectivePoint<C>)>
+where
+    C: VoprfParameters + GroupDigest,
+    ProjectivePoint<C>: CofactorGroup,
+    Scalar<C>: FromOkm,
+    C::Hash: BlockSizeUser + Default + FixedOutput + HashMarker,
+    <C::Hash as OutputSizeUser>::OutputSize:
+        IsLess<U256> + IsLessOrEqual<<C::Hash as BlockSizeUser>::BlockSize>,
+{
+    if cs.len() != ds.len() {
+        return Err(Error::new(ErrorKind::Other));
+    }
+
+    let seed = composite_seed::<C>(mode, b)?;
+    let mut m = ProjectivePoint::<C>::identity();
+
+    for (i, (c, d)) in cs.iter().zip(ds).enumerate() {
+        m += *c * composite_scalar::<C>(mode, &seed, i, c, d)?;
+    }
+
+    Ok((m, m * **k))
+}
+
+/// `seed = Hash(I2OSP(len(Bm), 2) || Bm || I2OSP(len(seedDST), 2) || seedDST)`
+fn composite_seed<C>(mode: Mode, b: &ProjectivePoint<C>) -> Result<Output<C::Hash>>
+where
+    C: VoprfParameters + CurveArithmetic,
+    ProjectivePoint<C>: GroupEncoding,
+{
+    let bm = b.to_bytes();
+    let [ctx_a, ctx_b, ctx_c, ctx_d] = mode.context_string::<C>();
+    let dst: [&[u8]; 5] = [b"Seed-", ctx_a, ctx_b, ctx_c, ctx_d];
+    let dst_len = dst.iter().map(|part| part.len()).sum::<usize>();
+
+    Ok(C::Hash::new()
+        .chain_update(len_prefix(bm.as_ref())?)
+        .chain_update(bm)
+        .chain_update(
+            u16::try_from(dst_len)
+                .map_err(|_| Error::new(ErrorKind::Other))?
+                .to_be_bytes(),
+        )
+        .chain_update(dst[0])
+        .chain_update(dst[1])
+        .chain_update(dst[2])
+        .chain_update(dst[3])
+        .chain_update(dst[4])
+        .finalize())
+}
+
+/// Hash the composite transcript for the `i`-th pair to a scalar.
+fn composite_scalar<C>(
+    mode: Mode,
+    seed: &Output<C::Hash>,
+    i: usize,
+    c: &ProjectivePoint<C>,
+    d: &ProjectivePoint<C>,
+) -> Result<Scalar<C>>
+where
+    C: VoprfParameters + GroupDigest,
+    ProjectivePoint<C>: CofactorGroup,
+    Scalar<C>: FromOkm,
+    C::Hash: BlockSizeUser + Default + FixedOutput + HashMarker,
+    <C::Hash as OutputSizeUser>::OutputSize:
+        IsLess<U256> + IsLessOrEqual<<C::Hash as BlockSizeUser>::BlockSize>,
+{
+    let ci = c.to_bytes();
+    let di = d.to_bytes();
+    let index = u16::try_from(i)
+        .map_err(|_| Error::new(ErrorKind::Other))?
+        .to_be_bytes();
+
+    hash_to_scalar::<C>(
+        mode,
+        &[
+            &len_prefix(seed)?,
+            seed,
+            &index,
+            &len_prefix(ci.as_ref())?,
+            ci.as_ref(),
+            &len_prefix(di.as_ref())?,
+            di.as_ref(),
+            b"Composite",
+        ],
+    )
+}
+
+/// Hash the challenge transcript to a scalar.
+fn challenge<C>(
+    mode: Mode,
+    b: &ProjectivePoint<C>,
+    m: &ProjectivePoint<C>,
+    z: &ProjectivePoint<C>,
+    t2: &ProjectivePoint<C>,
+    t3: &ProjectivePoint<C>,
+) -> Result<Scalar<C>>
+where
+    C: VoprfParameters + GroupDigest,
+    ProjectivePoint<C>: CofactorGroup,
+    Scalar<C>: FromOkm,
+    C::Hash: BlockSizeUser + Default + FixedOutput + HashMarker,
+    <C::Hash as OutputSizeUser>::OutputSize:
+        IsLess<U256> + IsLessOrEqual<<C::Hash as BlockSizeUser>::BlockSize>,
+{
+    let [bm, a0, a1, a2, a3] = [b, m, z, t2, t3].map(GroupEncoding::to_bytes);
+
+    hash_to_scalar::<C>(
+        mode,
+        &[
+            &len_prefix(bm.as_ref())?,
+            bm.as_ref(),
+            &len_prefix(a0.as_ref())?,
+            a0.as_ref(),
+            &len_prefix(a1.as_ref())?,
+            a1.as_ref(),
+            &len_prefix(a2.as_ref())?,
+            a2.as_ref(),
+            &len_prefix(a3.as_ref())?,
+            a3.as_ref(),
+            b"Challenge",
+        ],
+    )
+}
+
+/// `HashToScalar` with the default `"HashToScalar-" || contextString` DST.
+fn hash_to_scalar<C>(mode: Mode, msgs: &[&[u8]]) -> Result<Scalar<C>>
+where
+    C: VoprfParameters + GroupDigest,
+    ProjectivePoint<C>: CofactorGroup,
+    Scalar<C>: FromOkm,
+    C::Hash: BlockSizeUser + Default + FixedOutput + HashMarker,
+    <C::Hash as OutputSizeUser>::OutputSize:
+        IsLess<U256> + IsLessOrEqual<<C::Hash as BlockSizeUser>::BlockSize>,
+{
+    let [a, b, c, d] = mode.context_string::<C>();
+    C::hash_to_scalar::<ExpandMsgXmd<C::Hash>>(msgs, &[b"HashToScalar-", a, b, c, d])
+}
+
+/// `I2OSP(len(bytes), 2)`
+fn len_prefix(bytes: &[u8]) -> Result<[u8; 2]> {
+    u16::try_from(bytes.len())
+        .map(u16::to_be_bytes)
+        .map_err(|_| Error::new(ErrorKind::Other))
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::{
+        compute_composites, compute_composites_fast, generate_proof, generate_proof_with,
+        verify_proof, Proof,
+    };
+    use crate::{
+        dev::{MockCurve, ProjectivePoint, Scalar},
+        voprf::Mode,
+        NonZeroScalar,
+    };
+    use alloc::vec::Vec;
+    use ff::Field;
+    use group::{Group, GroupEncoding};
+    use hex_literal::hex;
+    use rand_core::OsRng;
+
+    fn statement(
+        k: &NonZeroScalar<MockCurve>,
+        n: usize,
+    ) -> (Vec<ProjectivePoint>, Vec<ProjectivePoint>) {
+        let cs: Vec<_> = (0..n)
+            .map(|_| ProjectivePoint::random(&mut OsRng))
+            .collect();
+        let ds = cs.iter().map(|c| *c * **k).collect();
+        (cs, ds)
+    }
+
+    #[test]
+    fn proof_verifies() {
+        let k = NonZeroScalar::random(&mut OsRng);
+        let a = ProjectivePoint::generator();
+        let b = a * *k;
+
+        for n in [1, 2, 5] {
+            let (cs, ds) = statement(&k, n);
+
+            for mode in [Mode::Voprf, Mode::Poprf] {
+                let proof = generate_proof(mode, &k, &a, &b, &cs, &ds, &mut OsRng).unwrap();
+                assert!(verify_proof(mode, &a, &b, &cs, &ds, &proof).is_ok());
+            }
+
+            let proof = generate_proof(Mode::Voprf, &k, &a, &b, &cs, &ds, &mut OsRng).unwrap();
+            assert!(verify_proof(Mode::Poprf, &a, &b, &cs, &ds, &proof).is_err());
+        }
+    }
+
+    #[test]
+    fn tampering_fails() {
+        let k = NonZeroScalar::random(&mut OsRng);
+        let a = ProjectivePoint::generator();
+        let b = a * *k;
+        let (cs, ds) = statement(&k, 3);
+        let mode = Mode::Voprf;
+        let proof = generate_proof(mode, &k, &a, &b, &cs, &ds, &mut OsRng).unwrap();
+        let g = ProjectivePoint::generator();
+
+        assert!(verify_proof(mode, &a, &b, &cs[1..], &ds, &proof).is_err());
+        assert!(verify_proof(mode, &(a + g), &b, &cs, &ds, &proof).is_err());
+        assert!(verify_proof(mode, &a, &(b + g), &cs, &ds, &proof).is_err());
+
+        for i in 0..cs.len() {
+            let mut tampered = cs.clone();
+            tampered[i] += g;
+            assert!(verify_proof(mode, &a, &b, &tampered, &ds, &proof).is_err());
+
+            let mut tampered = ds.clone();
+            tampered[i] += g;
+            assert!(verify_proof(mode, &a, &b, &cs, &tampered, &proof).is_err());
+        }
+
+        let one = Scalar::ONE;
+        let tampered = Proof::<MockCurve>::new(proof.c() + one, proof.s());
+        assert!(verify_proof(mode, &a, &b, &cs, &ds, &tampered).is_err());
+        let tampered = Proof::<MockCurve>::new(proof.c(), proof.s() + one);
+        assert!(verify_proof(mode, &a, &b, &cs, &ds, &tampered).is_err());
+
+        // A different key for the batch elements
+        let other = NonZeroScalar::<MockCurve>::random(&mut OsRng);
+        let ds_other: Vec<_> = cs.iter().map(|c| *c * *other).collect();
+        let proof = generate_proof(mode, &k, &a, &b, &cs, &ds_other, &mut OsRng).unwrap();
+        assert!(verify_proof(mode, &a, &b, &cs, &ds_other, &proof).is_err());
+    }
+
+    #[test]
+    fn composites_agree() {
+        let k = NonZeroScalar::random(&mut OsRng);
+        let b = ProjectivePoint::generator() * *k;
+
+        for n in [1, 4] {
+            let (cs, ds) = statement(&k, n);
+            assert_eq!(
+                compute_composites::<MockCurve>(Mode::Voprf, &b, &cs, &ds).unwrap(),
+                compute_composites_fast(Mode::Voprf, &k, &b, &cs, &ds).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn proof_encoding() {
+        let k = NonZeroScalar::random(&mut OsRng);
+        let a = ProjectivePoint::generator();
+        let b = a * *k;
+        let (cs, ds) = statement(&k, 2);
+        let proof = generate_proof(Mode::Voprf, &k, &a, &b, &cs, &ds, &mut OsRng).unwrap();
+
+        let [c, s] = proof.to_bytes();
+        let bytes = [c.as_slice(), s.as_slice()].concat();
+        assert_eq!(Proof::<MockCurve>::from_slice(&bytes).unwrap(), proof);
+        assert!(Proof::<MockCurve>::from_slice(&bytes[1..]).is_err());
+        assert!(Proof::<MockCurve>::from_slice(&[0xff; 64]).is_err());
+    }
+
+    /// RFC 9497 Appendix A.3.2 test vector 1.
+    #[test]
+    fn proof_vector() {
+        let k = NonZeroScalar::<MockCurve>::from_repr(
+            hex!("ca5d94c8807817669a51b196c34c1b7f8442fde4334a7121ae4736364312fca6").into(),
+        )
+        .unwrap();
+        let r = NonZeroScalar::<MockCurve>::from_repr(
+            hex!("f9db001266677f62c095021db018cd8cbb55941d4073698ce45c405d1348b7b1").into(),
+        )
+        .unwrap();
+        let a = ProjectivePoint::generator();
+        let b = a * *k;
+        let cs = [ProjectivePoint::from_bytes(
+            &hex!("02dd05901038bb31a6fae01828fd8d0e49e35a486b5c5d4b4994013648c01277da").into(),
+        )
+        .unwrap()];
+        let ds = [ProjectivePoint::from_bytes(
+            &hex!("0209f33cab60cf8fe69239b0afbcfcd261af4c1c5632624f2e9ba29b90ae83e4a2").into(),
+        )
+        .unwrap()];
+
+        let proof = generate_proof_with(Mode::Voprf, &k, &a, &b, &cs, &ds, &r).unwrap();
+        let [c, s] = proof.to_bytes();
+        assert_eq!(
+            [c.as_slice(), s.as_slice()].concat(),
+            hex!(
+                "e7c2b3c5c954c035949f1f74e6bce2ed539a3be267d1481e9ddb178533df4c26"
+                "64f69d065c604a4fd953e100b856ad83804eb3845189babfa5a702090d6fc5fa"
+            )
+        );
+        assert!(verify_proof(Mode::Voprf, &a, &b, &cs, &ds, &proof).is_ok());
+    }
+}
@@ -0,0 +1,128 @@
+//! Feldman verifiable secret sharing (VSS), i.e. Shamir secret sharing where
+//! the dealer publishes commitments `C_j = a_j * G` to the coefficients `a_j`
+//! of the sharing polynomial, so participants can check their shares.
+
+use crate::{
+    ops::LinearCombination, precompute::comb::MulByGeneratorIter, scalar::reduce_u64,
+    CurveArithmetic, NonZeroScalar, ProjectivePoint, Scalar,
+};
+use alloc::vec::Vec;
+use ff::Field;
+use group::Group;
+use subtle::{Choice, ConstantTimeEq};
+
+/// Commit to the coefficients of a sharing polynomial, i.e. compute
+/// `a_j * G` for each coefficient `a_j` in ascending order of degree.
+///
+/// The products are computed with a single precomputed generator table.
+pub fn commit_polynomial<C: CurveArithmetic>(coeffs: &[Scalar<C>]) -> Vec<ProjectivePoint<C>> {
+    MulByGeneratorIter::<C, _>::new(coeffs.iter().copied()).collect()
+}
+
+/// Verify the share of the participant with the given `index` against the
+/// dealer's `commitments`, i.e. check `share * G == Σ C_j * index^j`.
+///
+/// The right hand side is evaluated in the exponent with Horner's method.
+/// This runs in constant time with respect to `share`, and rejects the index
+/// zero (reduced modulo the curve order), whose share would be the secret.
+pub fn verify_share<C: CurveArithmetic>(
+    index: u64,
+    share: &NonZeroScalar<C>,
+    commitments: &[ProjectivePoint<C>],
+) -> Choice {
+    let x = reduce_u64::<C>(index);
+
+    let expected = commitments
+        .iter()
+        .rev()
+        .fold(ProjectivePoint::<C>::identity(), |acc, commitment| {
+            ProjectivePoint::<C>::lincomb(&acc, &x, commitment, &Scalar::<C>::ONE)
+        });
+
+    (ProjectivePoint::<C>::generator() * share.as_ref()).ct_eq(&expected) & !x.is_zero()
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::{commit_polynomial, verify_share};
+    use crate::{
+        dev::{MockCurve, NonZeroScalar, ProjectivePoint, Scalar},
+        scalar::{interpolate::evaluate, reduce_u64},
+    };
+    use alloc::vec::Vec;
+    use ff::{Field, PrimeField};
+    use group::{Group, GroupEncoding};
+    use rand_core::OsRng;
+
+    fn deal(threshold: usize, index: u64) -> (Vec<Scalar>, NonZeroScalar) {
+        let coeffs = (0..threshold)
+            .map(|_| Scalar::random(&mut OsRng))
+            .collect::<Vec<_>>();
+        let share = evaluate::<MockCurve>(&coeffs, &reduce_u64::<MockCurve>(index));
+        (coeffs, NonZeroScalar::new(share).unwrap())
+    }
+
+    #[test]
+    fn commit_polynomial_matches_scalar_mul() {
+        let coeffs = [Scalar::ZERO, Scalar::ONE, Scalar::random(&mut OsRng)];
+        let commitments = commit_polynomial::<MockCurve>(&coeffs);
+        assert_eq!(commitments.len(), coeffs.len());
+
+        for (commitment, coeff) in commitments.iter().zip(&coeffs) {
+            assert_eq!(*commitment, ProjectivePoint::generator() * coeff);
+        }
+    }
+
+    #[test]
+    fn honest_shares_verify() {
+        let coeffs = (0..4)
+            .map(|_| Scalar::random(&mut OsRng))
+            .collect::<Vec<_>>();
+        let commitments = commit_polynomial::<MockCurve>(&coeffs);
+
+        for index in 1..=10 {
+            let x = reduce_u64::<MockCurve>(index);
+            let share = NonZeroScalar::new(evaluate::<MockCurve>(&coeffs, &x)).unwrap();
+            assert!(bool::from(verify_share(index, &share, &commitments)));
+            assert!(!bool::from(verify_share(index + 1, &share, &commitments)));
+        }
+
+        let share = NonZeroScalar::new(coeffs[0]).unwrap();
+        assert!(!bool::from(verify_share(0, &share, &commitments)));
+    }
+
+    #[test]
+    fn bit_flips_fail() {
+        let index = 7;
+        let (coeffs, share) = deal(3, index);
+        let commitments = commit_polynomial::<MockCurve>(&coeffs);
+        assert!(bool::from(verify_share(index, &share, &commitments)));
+
+        // Sample every 11th bit to keep the test fast
+        let bytes = share.to_repr();
+        for i in (0..bytes.len() * 8).step_by(11) {
+            let mut flipped = bytes;
+            flipped[i / 8] ^= 1 << (i % 8);
+
+            if let Some(share) = Option::<NonZeroScalar>::from(NonZeroScalar::from_repr(flipped)) {
+                assert!(!bool::from(verify_share(index, &share, &commitments)));
+            }
+        }
+
+        for j in 0..commitments.len() {
+            let bytes = commitments[j].to_bytes();
+            for i in (0..bytes.len() * 8).step_by(11) {
+                let mut flipped = bytes;
+                flipped[i / 8] ^= 1 << (i % 8);
+
+                if let Some(point) =
+                    Option::<ProjectivePoint>::from(ProjectivePoint::from_bytes(&flipped))
+                {
+                    let mut tampered = commitments.clone();
+                    tampered[j] = point;
+                    assert!(!bool::from(verify_share(index, &share, &tampered)));
+                }
+            }
+        }
+    }
+}
@@ -126,3 +126,244 @@ where
 
     (dx, dy, dz)
 }
+
+/// Implements the complete addition formula from [Renes-Costello-Batina 2015]
+/// (Algorithm 1) for curves with an arbitrary `a` coefficient.
+///
+/// [Renes-Costello-Batina 2015]: https://eprint.iacr.org/2015/1060
+#[inline(always)]
+pub fn add_with_a<Fe>(
+    (ax, ay, az): ProjectivePoint<Fe>,
+    (bx, by, bz): ProjectivePoint<Fe>,
+    curve_equation_a: Fe,
+    curve_equation_b: Fe,
+) -> ProjectivePoint<Fe>
+where
+    Fe: Field,
+{
+    // The comments after each line indicate which algorithm steps are being
+    // performed.
+    let b3 = curve_equation_b.double() + &curve_equation_b;
+    let xx = ax * &bx; // 1
+    let yy = ay * &by; // 2
+    let zz = az * &bz; // 3
+    let xy_pairs = ((ax + &ay) * &(bx + &by)) - &(xx + &yy); // 4, 5, 6, 7, 8
+    let xz_pairs = ((ax + &az) * &(bx + &bz)) - &(xx + &zz); // 9, 10, 11, 12, 13
+    let yz_pairs = ((ay + &az) * &(by + &bz)) - &(yy + &zz); // 14, 15, 16, 17, 18
+
+    let azz_part = (curve_equation_a * &xz_pairs) + &(b3 * &zz); // 19, 20, 21
+    let yy_m_azz = yy - &azz_part; // 22
+    let yy_p_azz = yy + &azz_part; // 23
+
+    let xx3 = xx.double() + &xx; // 25, 26
+    let a_zz = curve_equation_a * &zz; // 27
+    let t1 = xx3 + &a_zz; // 29
+    let t4 = (b3 * &xz_pairs) + &(curve_equation_a * &(xx - &a_zz)); // 28, 30, 31, 32
+
+    (
+        (xy_pairs * &yy_m_azz) - &(yz_pairs * &t4), // 35, 36, 37
+        (yy_m_azz * &yy_p_azz) + &(t1 * &t4),       // 24, 33, 34
+        (yz_pairs * &yy_p_azz) + &(xy_pairs * &t1), // 38, 39, 40
+    )
+}
+
+/// Implements the mixed addition formula from [Renes-Costello-Batina 2015]
+/// (Algorithm 2) for curves with an arbitrary `a` coefficient.
+///
+/// This is complete for any projective `lhs`, but the affine `rhs` can't
+/// represent the identity.
+///
+/// [Renes-Costello-Batina 2015]: https://eprint.iacr.org/2015/1060
+#[inline(always)]
+pub fn add_mixed_with_a<Fe>(
+    (ax, ay, az): ProjectivePoint<Fe>,
+    (bx, by): AffinePoint<Fe>,
+    curve_equation_a: Fe,
+    curve_equation_b: Fe,
+) -> ProjectivePoint<Fe>
+where
+    Fe: Field,
+{
+    // The comments after each line indicate which algorithm steps are being
+    // performed.
+    let b3 = curve_equation_b.double() + &curve_equation_b;
+    let xx = ax * &bx; // 1
+    let yy = ay * &by; // 2
+    let xy_pairs = ((bx + &by) * &(ax + &ay)) - &(xx + &yy); // 3, 4, 5, 6, 7
+    let xz_pairs = (bx * &az) + &ax; // 8, 9
+    let yz_pairs = (by * &az) + &ay; // 10, 11
+
+    let az_part = (curve_equation_a * &xz_pairs) + &(b3 * &az); // 12, 13, 14
+    let yy_m_az = yy - &az_part; // 15
+    let yy_p_az = yy + &az_part; // 16
+
+    let xx3 = xx.double() + &xx; // 18, 19
+    let a_z = curve_equation_a * &az; // 20
+    let t1 = xx3 + &a_z; // 22
+    let t4 = (b3 * &xz_pairs) + &(curve_equation_a * &(xx - &a_z)); // 21, 23, 24, 25
+
+    (
+        (xy_pairs * &yy_m_az) - &(yz_pairs * &t4), // 28, 29, 30
+        (yy_m_az * &yy_p_az) + &(t1 * &t4),        // 17, 26, 27
+        (yz_pairs * &yy_p_az) + &(xy_pairs * &t1), // 31, 32, 33
+    )
+}
+
+/// Implements the exception-free point doubling formula from
+/// [Renes-Costello-Batina 2015] (Algorithm 3) for curves with an arbitrary
+/// `a` coefficient.
+///
+/// [Renes-Costello-Batina 2015]: https://eprint.iacr.org/2015/1060
+#[inline(always)]
+pub fn double_with_a<Fe>(
+    (x, y, z): ProjectivePoint<Fe>,
+    curve_equation_a: Fe,
+    curve_equation_b: Fe,
+) -> ProjectivePoint<Fe>
+where
+    Fe: Field,
+{
+    // The comments after each line indicate which algorithm steps are being
+    // performed.
+    let b3 = curve_equation_b.double() + &curve_equation_b;
+    let xx = x.square(); // 1
+    let yy = y.square(); // 2
+    let zz = z.square(); // 3
+    let xy2 = (x * &y).double(); // 4, 5
+    let xz2 = (x * &z).double(); // 6, 7
+
+    let azz_part = (curve_equation_a * &xz2) + &(b3 * &zz); // 8, 9, 10
+    let yy_m_azz = yy - &azz_part; // 11
+    let yy_p_azz = yy + &azz_part; // 12
+    let y_frag = yy_m_azz * &yy_p_azz; // 13
+    let x_frag = xy2 * &yy_m_azz; // 14
+
+    let a_zz = curve_equation_a * &zz; // 16
+    let t3 = (curve_equation_a * &(xx - &a_zz)) + &(b3 * &xz2); // 15, 17, 18, 19
+    let t0 = xx.double() + &xx + &a_zz; // 20, 21, 22
+
+    let dy = y_frag + &(t0 * &t3); // 23, 24
+    let yz2 = (y * &z).double(); // 25, 26
+    let dx = x_frag - &(yz2 * &t3); // 27, 28
+    let dz = (yz2 * &yy).double().double(); // 29, 30, 31
+
+    (dx, dy, dz)
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::{add, add_mixed, add_mixed_with_a, add_with_a, double, double_with_a};
+    use crate::dev::{FieldElement, MockCurve};
+    use crate::CurveArithmetic;
+    use ff::Field;
+
+    type Affine = Option<(FieldElement, FieldElement)>;
+
+    /// Textbook affine addition on `y² = x³ + ax + b`, with `None` as the
+    /// identity.
+    fn naive_add(p: Affine, q: Affine, a: FieldElement) -> Affine {
+        let ((px, py), (qx, qy)) = match (p, q) {
+            (None, q) => return q,
+            (p, None) => return p,
+            (Some(p), Some(q)) => (p, q),
+        };
+
+        let lambda = if px == qx {
+            if py + qy == FieldElement::ZERO {
+                return None;
+            }
+            (px.square().double() + px.square() + a) * py.double().invert().unwrap()
+        } else {
+            (qy - py) * (qx - px).invert().unwrap()
+        };
+
+        let x = lambda.square() - px - qx;
+        Some((x, lambda * (px - x) - py))
+    }
+
+    fn to_affine((x, y, z): (FieldElement, FieldElement, FieldElement)) -> Affine {
+        z.invert().map(|z_inv| (x * z_inv, y * z_inv)).into()
+    }
+
+    fn to_projective(p: Affine) -> (FieldElement, FieldElement, FieldElement) {
+        match p {
+            Some((x, y)) => (x, y, FieldElement::ONE),
+            None => (FieldElement::ZERO, FieldElement::ONE, FieldElement::ZERO),
+        }
+    }
+
+    /// Find the point with the smallest x-coordinate not less than `x`.
+    fn point_at(mut x: u64, a: FieldElement, b: FieldElement) -> Affine {
+        loop {
+            let fe = FieldElement::from(x);
+            let rhs = fe.square() * fe + a * fe + b;
+            if let Some(y) = Option::from(rhs.sqrt()) {
+                return Some((fe, y));
+            }
+            x += 1;
+        }
+    }
+
+    /// Check the formulas against naive addition for `P + Q`, `P + P`,
+    /// `P + (-P)` and identity operands.
+    fn check_formulas(a: FieldElement, b: FieldElement) {
+        let p = point_at(2, a, b);
+        let q = point_at(100, a, b);
+        let minus_p = p.map(|(x, y)| (x, -y));
+
+        for (p, q) in [
+            (p, q),
+            (p, p),
+            (p, minus_p),
+            (p, None),
+            (None, q),
+            (None, None),
+        ] {
+            let expected = naive_add(p, q, a);
+            let sum = add_with_a(to_projective(p), to_projective(q), a, b);
+            assert_eq!(to_affine(sum), expected);
+
+            if let Some(q_affine) = q {
+                let sum = add_mixed_with_a(to_projective(p), q_affine, a, b);
+                assert_eq!(to_affine(sum), expected);
+            }
+        }
+
+        for p in [p, q, None] {
+            assert_eq!(
+                to_affine(double_with_a(to_projective(p), a, b)),
+                naive_add(p, p, a)
+            );
+        }
+    }
+
+    #[test]
+    fn formulas_with_a_minus_3() {
+        check_formulas(MockCurve::EQUATION_A, MockCurve::EQUATION_B);
+    }
+
+    #[test]
+    fn formulas_with_a_zero() {
+        check_formulas(FieldElement::ZERO, FieldElement::from(7u64));
+    }
+
+    #[test]
+    fn formulas_with_arbitrary_a() {
+        check_formulas(FieldElement::from(5u64), FieldElement::from(11u64));
+    }
+
+    #[test]
+    fn matches_a_minus_3_formulas() {
+        let (a, b) = (MockCurve::EQUATION_A, MockCurve::EQUATION_B);
+        let p = to_projective(point_at(2, a, b));
+        let (qx, qy, _) = to_projective(point_at(100, a, b));
+        let q = (qx.double(), qy.double(), FieldElement::from(2u64));
+
+        assert_eq!(to_affine(add_with_a(p, q, a, b)), to_affine(add(p, q, b)));
+        assert_eq!(
+            to_affine(add_mixed_with_a(p, (qx, qy), a, b)),
+            to_affine(add_mixed(p, (qx, qy), b))
+        );
+        assert_eq!(to_affine(double_with_a(p, a, b)), to_affine(double(p, b)));
+    }
+}
@@ -26,3 +26,19 @@ fn from_slice_too_short() {
     let bytes = [1u8; 27];
     assert!(SecretKey::from_slice(&bytes).is_err());
 }
+
+#[test]
+fn tagged_bytes_round_trip() {
+    let secret_key = SecretKey::random(&mut rand_core::OsRng);
+    let tagged = secret_key.to_tagged_bytes();
+    assert_eq!(tagged.as_untagged(), &secret_key.to_bytes());
+    assert_eq!(SecretKey::from_tagged_bytes(&tagged).unwrap(), secret_key);
+    assert_eq!(tagged.clone(), tagged);
+
+    let scalar = secret_key.as_scalar_primitive();
+    assert_eq!(
+        elliptic_curve::dev::ScalarPrimitive::from_tagged_bytes(&scalar.to_tagged_bytes()).unwrap(),
+        *scalar
+    );
+    assert_eq!(tagged.into_untagged(), secret_key.to_bytes());
+}
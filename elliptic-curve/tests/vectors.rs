@@ -0,0 +1,96 @@
+//! Test vector runner tests
+
+#![cfg(feature = "dev")]
+
+use elliptic_curve::dev::{
+    vectors::{run_vectors, Outcome, VectorKind},
+    MockCurve,
+};
+
+/// Vectors for the mock curve covering every schema
+const MOCK_CURVE_VECTORS: &str = include_str!("examples/mock-curve-vectors.json");
+
+#[test]
+fn mock_curve_vectors_without_hash() {
+    let report = run_vectors::<MockCurve>(MOCK_CURVE_VECTORS);
+    report.assert_success();
+    assert_eq!(report.count(Outcome::Pass), 15);
+    assert_eq!(report.count(Outcome::Skip), 6);
+    assert!(report
+        .results()
+        .iter()
+        .filter(|result| result.outcome == Outcome::Skip)
+        .all(|result| matches!(
+            result.kind,
+            Some(VectorKind::HashToCurve | VectorKind::HashToScalar)
+        )));
+}
+
+#[cfg(feature = "hash2curve")]
+#[test]
+fn mock_curve_vectors() {
+    use elliptic_curve::{dev::vectors::run_vectors_with_hash, hash2curve::ExpandMsgXmd};
+    use sha2::Sha256;
+
+    let report = run_vectors_with_hash::<MockCurve, ExpandMsgXmd<Sha256>>(MOCK_CURVE_VECTORS);
+    report.assert_success();
+    assert_eq!(report.count(Outcome::Pass), report.results().len());
+
+    for kind in [
+        VectorKind::ScalarReduce,
+        VectorKind::ScalarRepr,
+        VectorKind::PointDecode,
+        VectorKind::Ecdh,
+        VectorKind::HashToCurve,
+        VectorKind::HashToScalar,
+    ] {
+        assert!(report
+            .results()
+            .iter()
+            .any(|result| result.kind == Some(kind)));
+    }
+}
+
+#[test]
+fn failures_reported_with_index() {
+    let json = r#"[
+        { "type": "scalar_reduce", "input": "05", "output": "05" },
+        {
+            "type": "scalar_repr",
+            "input": "0000000000000000000000000000000000000000000000000000000000000005",
+            "valid": false
+        },
+        { "type": "point_decode", "input": "00", "valid": "yes" },
+        { "type": "unknown" },
+        {
+            "type": "scalar_reduce",
+            "input": "0000000000000000000000000000000000000000000000000000000000000005",
+            "output": "0000000000000000000000000000000000000000000000000000000000000005"
+        }
+    ]"#;
+
+    let report = run_vectors::<MockCurve>(json);
+    assert!(!report.is_success());
+
+    let failures: Vec<_> = report
+        .failures()
+        .map(|result| (result.index, result.outcome))
+        .collect();
+    assert_eq!(
+        failures,
+        [
+            (0, Outcome::Malformed),
+            (1, Outcome::Fail),
+            (2, Outcome::Malformed),
+            (3, Outcome::Malformed)
+        ]
+    );
+    assert_eq!(report.results()[3].kind, None);
+    assert_eq!(report.results()[4].outcome, Outcome::Pass);
+}
+
+#[test]
+#[should_panic(expected = "test vector failures")]
+fn assert_success_panics() {
+    run_vectors::<MockCurve>(r#"[{ "type": "unknown" }]"#).assert_success();
+}
@@ -0,0 +1,37 @@
+//! Zeroize on drop tests
+//!
+//! These inspect the memory of a value after it has been dropped, which needs
+//! `unsafe` code that is forbidden in the crate itself.
+
+#![cfg(feature = "dev")]
+
+use core::mem::{size_of, MaybeUninit};
+use elliptic_curve::{
+    dev::{MockCurve, Scalar},
+    scalar::BlindedScalar,
+};
+use rand_core::OsRng;
+
+/// Drop `value` in place and return the bytes it leaves behind.
+///
+/// `T` must not contain any padding.
+fn bytes_after_drop<T, const N: usize>(value: T) -> [u8; N] {
+    assert_eq!(size_of::<T>(), N);
+    let mut slot = MaybeUninit::new(value);
+
+    // SAFETY: `slot` is initialized and dropped exactly once, after which its
+    // `N` bytes are still valid memory, all initialized as `T` has no padding.
+    unsafe {
+        slot.assume_init_drop();
+        core::ptr::read(slot.as_ptr().cast::<[u8; N]>())
+    }
+}
+
+#[test]
+fn blinded_scalar_drop_zeroizes() {
+    let blinded = BlindedScalar::<MockCurve>::new(Scalar::from(42u64), &mut OsRng);
+    assert_ne!(*blinded.as_ref(), Scalar::from(0u64));
+
+    let bytes: [u8; 2 * size_of::<Scalar>()] = bytes_after_drop(blinded);
+    assert!(bytes.iter().all(|&b| b == 0));
+}